@@ -0,0 +1,26 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use smbioslib::*;
+
+fn parse(c: &mut Criterion) {
+    // Windows dump, the table follows an 8 byte header
+    let raw = std::fs::read("tests/jeffgerlap_3_2_0.dat").unwrap()[8..].to_vec();
+
+    c.bench_function("from_slice_and_version", |b| {
+        b.iter(|| SMBiosData::from_slice_and_version(black_box(&raw), None))
+    });
+    c.bench_function("table_ref_iter", |b| {
+        b.iter(|| SMBiosTableRef::new(black_box(&raw)).iter().count())
+    });
+
+    let data = SMBiosData::from_slice_and_version(&raw, None);
+    c.bench_function("get_field_string", |b| {
+        b.iter(|| {
+            data.iter()
+                .filter_map(|undefined_struct| undefined_struct.get_field_string(0x04))
+                .count()
+        })
+    });
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
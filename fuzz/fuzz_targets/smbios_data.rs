@@ -0,0 +1,47 @@
+//! Parses arbitrary bytes as a structure table and reads every structure
+//!
+//! The first byte selects the version the table is parsed as, so that the
+//! version dependent fields are reached.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use smbioslib::*;
+
+fuzz_target!(|data: &[u8]| {
+    let (version, table) = match data.split_first() {
+        Some((selector, table)) => (
+            Some(SMBiosVersion::new(2 + selector % 2, selector / 2 % 9, 0)),
+            table,
+        ),
+        None => (None, data),
+    };
+
+    let smbios_data = SMBiosData::from_vec_and_version(table.to_vec(), version);
+
+    // Debug, Display and Serialize read each field through its accessor
+    for undefined_struct in smbios_data.iter() {
+        let defined_struct = undefined_struct.defined_struct();
+        let _ = format!("{:?}", defined_struct);
+        let _ = defined_struct.to_string();
+        let _ = serde_json::to_string(&defined_struct);
+    }
+
+    let _ = smbios_data.validate();
+    let _ = smbios_data.check_consistency();
+    for profile in ConformanceProfile::ALL.iter() {
+        let _ = smbios_data.check_conformance(*profile);
+    }
+    let _ = format!("{:?}", smbios_data.summary());
+    let _ = format!("{:?}", smbios_data.memory_topology());
+    let _ = format!("{:?}", smbios_data.processor_topology());
+    let _ = format!("{:?}", smbios_data.management_device_tree());
+
+    let _ = format!("{:?}", UndefinedStruct::new(&table.to_vec()));
+
+    for undefined_struct in SMBiosTableRef::new(table) {
+        let _ = format!("{:?}", undefined_struct);
+        let _ = undefined_struct.strings().count();
+        let _ = undefined_struct.to_undefined_struct();
+    }
+});
@@ -0,0 +1,14 @@
+//! Parses arbitrary bytes as the raw SMBIOS data returned by Windows
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use smbioslib::*;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(win_smbios_data) = WinSMBiosData::new(data.to_vec()) {
+        let _ = win_smbios_data.windows_header();
+        let _ = format!("{:?}", win_smbios_data);
+        let _ = serde_json::to_string(&win_smbios_data);
+    }
+});
@@ -0,0 +1,97 @@
+//! Parses many SMBIOS dump files in parallel.
+//!
+//! Intended for analytics over large collections of captured tables, such
+//! as those gathered from a fleet of machines.
+use crate::core::SMBiosData;
+use crate::error::Error;
+use crate::file_io::load_smbios_data_from_file;
+use rayon::prelude::*;
+use std::{
+    fs::read_dir,
+    path::{Path, PathBuf},
+};
+
+/// Loads the given dump files in parallel
+///
+/// Yields each path with its [SMBiosData], or the [Error] reading it. The
+/// files are read as by [load_smbios_data_from_file]. Results arrive in no
+/// particular order.
+pub fn par_load_files<P>(
+    paths: P,
+) -> impl ParallelIterator<Item = (PathBuf, Result<SMBiosData, Error>)>
+where
+    P: IntoParallelIterator<Item = PathBuf>,
+{
+    paths.into_par_iter().map(|path| {
+        let data = load_smbios_data_from_file(&path);
+        (path, data)
+    })
+}
+
+/// Loads the dump files of a _folder_ in parallel
+///
+/// Every file directly within `folder` is loaded, see [par_load_files];
+/// subfolders are not visited. Returns an [Error] when `folder` cannot be
+/// listed.
+///
+/// ```no_run
+/// # use smbioslib::*;
+/// # use rayon::prelude::*;
+/// # fn count(folder: &std::path::Path) -> Result<(), Error> {
+/// let failures = par_load_dir(folder)?
+///     .filter(|(_, data)| data.is_err())
+///     .count();
+/// println!("{} dump files failed to load", failures);
+/// # Ok(())
+/// # }
+/// ```
+pub fn par_load_dir(
+    folder: &Path,
+) -> Result<impl ParallelIterator<Item = (PathBuf, Result<SMBiosData, Error>)>, Error> {
+    let mut paths = Vec::new();
+    for entry in read_dir(folder)? {
+        let path = entry?.path();
+        if path.is_file() {
+            paths.push(path);
+        }
+    }
+
+    Ok(par_load_files(paths))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_load_dir() {
+        let folder = std::env::temp_dir().join(format!("smbios-batch-{}", std::process::id()));
+        std::fs::create_dir_all(folder.join("nested")).unwrap();
+        std::fs::copy(
+            "tests/jeffgerlap_3_2_0.dat",
+            folder.join("jeffgerlap_3_2_0.dat"),
+        )
+        .unwrap();
+        std::fs::write(
+            folder.join("end_of_table.bin"),
+            [0x7F, 0x04, 0x00, 0x00, 0x00, 0x00],
+        )
+        .unwrap();
+
+        let mut results: Vec<_> = par_load_dir(&folder).unwrap().collect();
+        results.sort_by(|a, b| a.0.cmp(&b.0));
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, folder.join("end_of_table.bin"));
+        assert_eq!(results[0].1.as_ref().unwrap().len(), 1);
+        assert_eq!(
+            results[1].1.as_ref().unwrap().version,
+            Some(crate::core::SMBiosVersion::new(3, 2, 0))
+        );
+
+        let missing = par_load_files(vec![folder.join("missing.bin")]).collect::<Vec<_>>();
+        assert!(missing[0].1.is_err());
+        assert!(par_load_dir(&folder).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use super::StructBuilder;
+use crate::structs::{SMBiosInformation, SMBiosStruct};
+#[cfg(feature = "no_std")]
+use alloc::string::{String, ToString};
+
+/// # BIOS Information (Type 0) Builder
+///
+/// Builds the SMBIOS 3.1 and later layout (1Ah bytes). Strings which are
+/// not set are left empty (no string).
+pub struct BiosInformationBuilder {
+    vendor: String,
+    version: String,
+    starting_address_segment: u16,
+    release_date: String,
+    rom_size: u8,
+    characteristics: u32,
+    characteristics_extension0: u8,
+    characteristics_extension1: u8,
+    system_bios_major_release: u8,
+    system_bios_minor_release: u8,
+    e_c_firmware_major_release: u8,
+    e_c_firmware_minor_release: u8,
+    extended_rom_size: u16,
+}
+
+impl BiosInformationBuilder {
+    /// Creates a builder with BIOS characteristics not supported and
+    /// the release fields not supported (FFh)
+    pub fn new() -> Self {
+        Self {
+            vendor: String::new(),
+            version: String::new(),
+            starting_address_segment: 0xE800,
+            release_date: String::new(),
+            rom_size: 0,
+            characteristics: 0x08,
+            characteristics_extension0: 0,
+            characteristics_extension1: 0,
+            system_bios_major_release: 0xFF,
+            system_bios_minor_release: 0xFF,
+            e_c_firmware_major_release: 0xFF,
+            e_c_firmware_minor_release: 0xFF,
+            extended_rom_size: 0,
+        }
+    }
+
+    /// BIOS vendor
+    pub fn vendor(mut self, vendor: &str) -> Self {
+        self.vendor = vendor.to_string();
+        self
+    }
+
+    /// BIOS version
+    pub fn version(mut self, version: &str) -> Self {
+        self.version = version.to_string();
+        self
+    }
+
+    /// Segment location of the BIOS starting address, defaults to E800h
+    pub fn starting_address_segment(mut self, starting_address_segment: u16) -> Self {
+        self.starting_address_segment = starting_address_segment;
+        self
+    }
+
+    /// BIOS release date, in mm/dd/yyyy format
+    pub fn release_date(mut self, release_date: &str) -> Self {
+        self.release_date = release_date.to_string();
+        self
+    }
+
+    /// BIOS ROM size (n) where 64K * (n+1) is the size of the physical device
+    ///
+    /// FFh indicates the size is given by [Self::extended_rom_size].
+    pub fn rom_size(mut self, rom_size: u8) -> Self {
+        self.rom_size = rom_size;
+        self
+    }
+
+    /// BIOS characteristics, see [crate::BiosCharacteristics] for the bits
+    pub fn characteristics(mut self, characteristics: u32) -> Self {
+        self.characteristics = characteristics;
+        self
+    }
+
+    /// Characteristics extension byte 0, see [crate::BiosCharacteristicsExtension0] for the bits
+    pub fn characteristics_extension0(mut self, characteristics_extension0: u8) -> Self {
+        self.characteristics_extension0 = characteristics_extension0;
+        self
+    }
+
+    /// Characteristics extension byte 1, see [crate::BiosCharacteristicsExtension1] for the bits
+    pub fn characteristics_extension1(mut self, characteristics_extension1: u8) -> Self {
+        self.characteristics_extension1 = characteristics_extension1;
+        self
+    }
+
+    /// System BIOS major and minor release
+    pub fn system_bios_release(mut self, major: u8, minor: u8) -> Self {
+        self.system_bios_major_release = major;
+        self.system_bios_minor_release = minor;
+        self
+    }
+
+    /// Embedded controller firmware major and minor release
+    pub fn e_c_firmware_release(mut self, major: u8, minor: u8) -> Self {
+        self.e_c_firmware_major_release = major;
+        self.e_c_firmware_minor_release = minor;
+        self
+    }
+
+    /// Extended BIOS ROM size, see [crate::ExtendedRomSize] for the encoding
+    pub fn extended_rom_size(mut self, extended_rom_size: u16) -> Self {
+        self.extended_rom_size = extended_rom_size;
+        self
+    }
+}
+
+impl Default for BiosInformationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<BiosInformationBuilder> for StructBuilder {
+    fn from(builder: BiosInformationBuilder) -> Self {
+        StructBuilder::new(SMBiosInformation::STRUCT_TYPE)
+            .string(&builder.vendor)
+            .string(&builder.version)
+            .word(builder.starting_address_segment)
+            .string(&builder.release_date)
+            .byte(builder.rom_size)
+            .dword(builder.characteristics)
+            // BIOS and system vendor reserved characteristics
+            .dword(0)
+            .byte(builder.characteristics_extension0)
+            .byte(builder.characteristics_extension1)
+            .byte(builder.system_bios_major_release)
+            .byte(builder.system_bios_minor_release)
+            .byte(builder.e_c_firmware_major_release)
+            .byte(builder.e_c_firmware_minor_release)
+            .word(builder.extended_rom_size)
+    }
+}
@@ -0,0 +1,203 @@
+use super::{BuildError, StringSet};
+use crate::core::{handle_field_offsets, string_field_offsets};
+use crate::core::{Handle, Header, Strings, StructFields, UndefinedStruct};
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+/// # Mutable Structure
+///
+/// Edits the fields and strings of a structure of a [crate::SMBiosDataMut].
+///
+/// Fields are addressed by their offset in the SMBIOS specification
+/// (relative to the start of the header) and must lie within the formatted
+/// area of the structure; the header is not writable. The structure length
+/// and the position of its strings are recomputed when the table is written
+/// back to bytes.
+pub struct DefinedStructMut<'a> {
+    parts: &'a mut UndefinedStruct,
+}
+
+impl<'a> DefinedStructMut<'a> {
+    /// Creates an editor of the given structure
+    pub fn new(parts: &'a mut UndefinedStruct) -> Self {
+        Self { parts }
+    }
+
+    /// The structure being edited
+    pub fn parts(&self) -> &UndefinedStruct {
+        self.parts
+    }
+
+    /// Type of the structure
+    pub fn struct_type(&self) -> u8 {
+        self.parts.header.struct_type()
+    }
+
+    /// Handle of the structure
+    pub fn handle(&self) -> Handle {
+        self.parts.header.handle()
+    }
+
+    /// Down casts the structure to its specific defined BIOS structure type
+    pub fn defined_struct(&self) -> DefinedStruct<'_> {
+        self.parts.defined_struct()
+    }
+
+    /// Casts the structure to the given structure type
+    pub fn as_type<'b, T: SMBiosStruct<'b>>(&'b self) -> Option<T> {
+        self.parts.as_type()
+    }
+
+    /// Sets the handle of the structure
+    ///
+    /// Fields of other structures referencing the previous handle are not
+    /// updated, see [crate::SMBiosDataMut::renumber_handles].
+    pub fn set_handle(&mut self, handle: Handle) {
+        let mut header = *self.parts.header;
+        header[Header::HANDLE_OFFSET..Header::HANDLE_OFFSET + Handle::SIZE]
+            .copy_from_slice(&handle.to_le_bytes());
+        self.set_header(header);
+    }
+
+    /// Rewrites the structure as an Inactive (Type 126) structure
+    ///
+    /// The length and handle are preserved, so references to the structure
+    /// remain valid. The formatted area following the header is cleared and
+    /// the strings are removed, as inactive structures are not to be
+    /// interpreted.
+    pub fn inactivate(&mut self) {
+        let mut header = *self.parts.header;
+        header[Header::STRUCT_TYPE_OFFSET] = SMBiosInactive::STRUCT_TYPE;
+        self.set_header(header);
+        for byte in self.parts.fields.iter_mut().skip(Header::SIZE) {
+            *byte = 0;
+        }
+        let decoding = self.parts.strings.decoding();
+        self.parts.strings = Strings::new(Vec::new());
+        self.parts.strings.set_decoding(decoding);
+    }
+
+    fn set_header(&mut self, header: [u8; Header::SIZE]) {
+        self.parts.header = Header::new(header);
+        if let Some(fields_header) = self.parts.fields.get_mut(..Header::SIZE) {
+            fields_header.copy_from_slice(&header);
+        }
+    }
+
+    /// Offsets of the fields referencing other structures by [Handle]
+    ///
+    /// Covers the handle fields of the structure types defined by the
+    /// SMBIOS specification, including the handle lists of Baseboard
+    /// Information (Type 2), Memory Controller Information (Type 5), Group
+    /// Associations (Type 14), Memory Channel (Type 37) and Additional
+    /// Information (Type 40). Fields beyond the length of the structure are
+    /// not included.
+    pub fn handle_field_offsets(&self) -> Vec<usize> {
+        handle_field_offsets(self.parts)
+    }
+
+    /// Offsets of the fields referencing a string of the string-set
+    ///
+    /// Covers the string fields of the structure types defined by the
+    /// SMBIOS specification, including the string fields of the entries of
+    /// On Board Devices Information (Type 10) and Additional Information
+    /// (Type 40). Fields beyond the length of the structure are not included.
+    pub fn string_field_offsets(&self) -> Vec<usize> {
+        string_field_offsets(self.parts)
+    }
+
+    /// Edits the string-set of the structure
+    pub fn string_set(&mut self) -> StringSet<'_> {
+        StringSet::new(self.parts)
+    }
+
+    /// Sets the BYTE at the given offset
+    pub fn set_field_byte(&mut self, offset: usize, value: u8) -> Result<(), BuildError> {
+        self.set_field_data(offset, &[value])
+    }
+
+    /// Sets the WORD at the given offset
+    pub fn set_field_word(&mut self, offset: usize, value: u16) -> Result<(), BuildError> {
+        self.set_field_data(offset, &value.to_le_bytes())
+    }
+
+    /// Sets the DWORD at the given offset
+    pub fn set_field_dword(&mut self, offset: usize, value: u32) -> Result<(), BuildError> {
+        self.set_field_data(offset, &value.to_le_bytes())
+    }
+
+    /// Sets the QWORD at the given offset
+    pub fn set_field_qword(&mut self, offset: usize, value: u64) -> Result<(), BuildError> {
+        self.set_field_data(offset, &value.to_le_bytes())
+    }
+
+    /// Sets the [Handle] at the given offset, referencing another structure
+    pub fn set_field_handle(&mut self, offset: usize, handle: Handle) -> Result<(), BuildError> {
+        self.set_field_word(offset, *handle)
+    }
+
+    /// Sets a block of bytes starting at the given offset
+    pub fn set_field_data(&mut self, offset: usize, data: &[u8]) -> Result<(), BuildError> {
+        let length = self.parts.fields.len();
+        if offset < Header::SIZE || offset >= length || length - offset < data.len() {
+            return Err(BuildError::FieldOutOfRange {
+                handle: self.handle(),
+                offset,
+                length,
+            });
+        }
+
+        self.parts.fields[offset..offset + data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Sets the string field at the given offset
+    ///
+    /// When the field references a string no other string field (see
+    /// [DefinedStructMut::string_field_offsets]) references, that string is
+    /// replaced. Otherwise the field is pointed at an identical string of
+    /// the string-set, or at a string appended to it, leaving the fields
+    /// sharing the previous string unchanged.
+    ///
+    /// An empty string sets the field to 0, meaning no string. The string
+    /// previously referenced remains in the string-set.
+    ///
+    /// Strings are validated as by [StringSet::append].
+    pub fn set_field_string(&mut self, offset: usize, value: &str) -> Result<(), BuildError> {
+        let index = match self.parts.get_field_byte(offset) {
+            Some(index) if offset >= Header::SIZE => index,
+            _ => {
+                return Err(BuildError::FieldOutOfRange {
+                    handle: self.handle(),
+                    offset,
+                    length: self.parts.fields.len(),
+                })
+            }
+        };
+
+        if value.is_empty() {
+            return self.set_field_byte(offset, 0);
+        }
+
+        let encoded = self.string_set().encode(value)?;
+        let shared = string_field_offsets(self.parts)
+            .into_iter()
+            .any(|other| other != offset && self.parts.fields[other] == index);
+        let index = if !shared && self.string_set().get(index).is_some() {
+            self.string_set().replace(index, value)?;
+            index
+        } else if let Some(position) = self
+            .parts
+            .strings
+            .iter()
+            .position(|string| *string == encoded)
+        {
+            position as u8 + 1
+        } else {
+            self.string_set().append(value)?
+        };
+
+        self.set_field_byte(offset, index)
+    }
+}
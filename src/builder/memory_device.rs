@@ -0,0 +1,265 @@
+use super::StructBuilder;
+use crate::core::Handle;
+use crate::structs::{SMBiosMemoryDevice, SMBiosStruct};
+#[cfg(feature = "no_std")]
+use alloc::string::{String, ToString};
+
+/// # Memory Device (Type 17) Builder
+///
+/// Builds the SMBIOS 3.3 layout (5Ch bytes). Fields which are not set are
+/// left unknown (or 0 when the field has no unknown value) and strings which
+/// are not set are left empty (no string).
+pub struct MemoryDeviceBuilder {
+    physical_memory_array_handle: Handle,
+    memory_error_information_handle: Handle,
+    total_width: u16,
+    data_width: u16,
+    size_mb: Option<u32>,
+    form_factor: u8,
+    device_set: u8,
+    device_locator: String,
+    bank_locator: String,
+    memory_type: u8,
+    type_detail: u16,
+    speed_mts: u32,
+    manufacturer: String,
+    serial_number: String,
+    asset_tag: String,
+    part_number: String,
+    attributes: u8,
+    configured_memory_speed_mts: u32,
+    minimum_voltage: u16,
+    maximum_voltage: u16,
+    configured_voltage: u16,
+    memory_technology: u8,
+    memory_operating_mode_capability: u16,
+}
+
+impl MemoryDeviceBuilder {
+    /// Creates a builder for a memory device of the Physical Memory Array
+    /// (Type 16) with handle `physical_memory_array_handle`
+    pub fn new(physical_memory_array_handle: Handle) -> Self {
+        Self {
+            physical_memory_array_handle,
+            memory_error_information_handle: Handle(0xFFFE),
+            total_width: 0xFFFF,
+            data_width: 0xFFFF,
+            size_mb: None,
+            form_factor: 0x02,
+            device_set: 0,
+            device_locator: String::new(),
+            bank_locator: String::new(),
+            memory_type: 0x02,
+            type_detail: 0x0004,
+            speed_mts: 0,
+            manufacturer: String::new(),
+            serial_number: String::new(),
+            asset_tag: String::new(),
+            part_number: String::new(),
+            attributes: 0,
+            configured_memory_speed_mts: 0,
+            minimum_voltage: 0,
+            maximum_voltage: 0,
+            configured_voltage: 0,
+            memory_technology: 0x02,
+            memory_operating_mode_capability: 0x0004,
+        }
+    }
+
+    /// Handle of the Memory Error Information structure
+    ///
+    /// Defaults to FFFEh, no error information is provided.
+    pub fn memory_error_information_handle(mut self, handle: Handle) -> Self {
+        self.memory_error_information_handle = handle;
+        self
+    }
+
+    /// Total width, in bits, including any check or error-correction bits
+    pub fn total_width(mut self, total_width: u16) -> Self {
+        self.total_width = total_width;
+        self
+    }
+
+    /// Data width, in bits
+    pub fn data_width(mut self, data_width: u16) -> Self {
+        self.data_width = data_width;
+        self
+    }
+
+    /// Size of the memory device, in megabytes
+    ///
+    /// A size of 0 indicates no memory device is installed in the socket.
+    /// Sizes of 32 GB - 1 MB and greater are stored in the Extended Size
+    /// field. Defaults to unknown.
+    pub fn size_mb(mut self, size_mb: u32) -> Self {
+        self.size_mb = Some(size_mb & 0x7FFF_FFFF);
+        self
+    }
+
+    /// Form factor, see [crate::MemoryFormFactor] for the values
+    pub fn form_factor(mut self, form_factor: u8) -> Self {
+        self.form_factor = form_factor;
+        self
+    }
+
+    /// Device set
+    pub fn device_set(mut self, device_set: u8) -> Self {
+        self.device_set = device_set;
+        self
+    }
+
+    /// Device locator, such as "DIMM 0"
+    pub fn device_locator(mut self, device_locator: &str) -> Self {
+        self.device_locator = device_locator.to_string();
+        self
+    }
+
+    /// Bank locator, such as "Bank 0"
+    pub fn bank_locator(mut self, bank_locator: &str) -> Self {
+        self.bank_locator = bank_locator.to_string();
+        self
+    }
+
+    /// Memory type, see [crate::MemoryDeviceType] for the values
+    pub fn memory_type(mut self, memory_type: u8) -> Self {
+        self.memory_type = memory_type;
+        self
+    }
+
+    /// Type detail, see [crate::MemoryTypeDetails] for the bits
+    pub fn type_detail(mut self, type_detail: u16) -> Self {
+        self.type_detail = type_detail;
+        self
+    }
+
+    /// Maximum capable speed, in megatransfers per second (MT/s)
+    ///
+    /// Speeds of 65535 MT/s and greater are stored in the Extended Speed field.
+    pub fn speed_mts(mut self, speed_mts: u32) -> Self {
+        self.speed_mts = speed_mts;
+        self
+    }
+
+    /// Manufacturer
+    pub fn manufacturer(mut self, manufacturer: &str) -> Self {
+        self.manufacturer = manufacturer.to_string();
+        self
+    }
+
+    /// Serial number
+    pub fn serial_number(mut self, serial_number: &str) -> Self {
+        self.serial_number = serial_number.to_string();
+        self
+    }
+
+    /// Asset tag
+    pub fn asset_tag(mut self, asset_tag: &str) -> Self {
+        self.asset_tag = asset_tag.to_string();
+        self
+    }
+
+    /// Part number
+    pub fn part_number(mut self, part_number: &str) -> Self {
+        self.part_number = part_number.to_string();
+        self
+    }
+
+    /// Attributes, bits 3:0 being the rank
+    pub fn attributes(mut self, attributes: u8) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    /// Configured memory speed, in megatransfers per second (MT/s)
+    ///
+    /// Speeds of 65535 MT/s and greater are stored in the Extended
+    /// Configured Memory Speed field.
+    pub fn configured_memory_speed_mts(mut self, configured_memory_speed_mts: u32) -> Self {
+        self.configured_memory_speed_mts = configured_memory_speed_mts;
+        self
+    }
+
+    /// Minimum operating voltage, in millivolts
+    pub fn minimum_voltage(mut self, minimum_voltage: u16) -> Self {
+        self.minimum_voltage = minimum_voltage;
+        self
+    }
+
+    /// Maximum operating voltage, in millivolts
+    pub fn maximum_voltage(mut self, maximum_voltage: u16) -> Self {
+        self.maximum_voltage = maximum_voltage;
+        self
+    }
+
+    /// Configured voltage, in millivolts
+    pub fn configured_voltage(mut self, configured_voltage: u16) -> Self {
+        self.configured_voltage = configured_voltage;
+        self
+    }
+
+    /// Memory technology, see [crate::MemoryDeviceTechnology] for the values
+    pub fn memory_technology(mut self, memory_technology: u8) -> Self {
+        self.memory_technology = memory_technology;
+        self
+    }
+
+    /// Memory operating mode capability, see [crate::MemoryOperatingModeCapabilities] for the bits
+    pub fn memory_operating_mode_capability(mut self, capability: u16) -> Self {
+        self.memory_operating_mode_capability = capability;
+        self
+    }
+}
+
+/// Splits a speed between the WORD field and the extended DWORD field
+fn speed_fields(speed_mts: u32) -> (u16, u32) {
+    if speed_mts < 0xFFFF {
+        (speed_mts as u16, 0)
+    } else {
+        (0xFFFF, speed_mts)
+    }
+}
+
+impl From<MemoryDeviceBuilder> for StructBuilder {
+    fn from(builder: MemoryDeviceBuilder) -> Self {
+        let (size, extended_size) = match builder.size_mb {
+            None => (0xFFFF, 0),
+            Some(size_mb) if size_mb < 0x7FFF => (size_mb as u16, 0),
+            Some(size_mb) => (0x7FFF, size_mb),
+        };
+        let (speed, extended_speed) = speed_fields(builder.speed_mts);
+        let (configured_memory_speed, extended_configured_memory_speed) =
+            speed_fields(builder.configured_memory_speed_mts);
+
+        StructBuilder::new(SMBiosMemoryDevice::STRUCT_TYPE)
+            .handle(builder.physical_memory_array_handle)
+            .handle(builder.memory_error_information_handle)
+            .word(builder.total_width)
+            .word(builder.data_width)
+            .word(size)
+            .byte(builder.form_factor)
+            .byte(builder.device_set)
+            .string(&builder.device_locator)
+            .string(&builder.bank_locator)
+            .byte(builder.memory_type)
+            .word(builder.type_detail)
+            .word(speed)
+            .string(&builder.manufacturer)
+            .string(&builder.serial_number)
+            .string(&builder.asset_tag)
+            .string(&builder.part_number)
+            .byte(builder.attributes)
+            .dword(extended_size)
+            .word(configured_memory_speed)
+            .word(builder.minimum_voltage)
+            .word(builder.maximum_voltage)
+            .word(builder.configured_voltage)
+            .byte(builder.memory_technology)
+            .word(builder.memory_operating_mode_capability)
+            // firmware version, module and memory subsystem controller IDs
+            .data(&[0; 9])
+            // non-volatile, volatile, cache and logical sizes
+            .data(&[0; 32])
+            .dword(extended_speed)
+            .dword(extended_configured_memory_speed)
+    }
+}
@@ -0,0 +1,684 @@
+//! SMBIOS table builder.
+//!
+//! Synthesizes SMBIOS structure tables, for example the table a hypervisor
+//! or firmware presents to its guest or operating system.
+//!
+//! Structures are assembled with a [StructBuilder], either directly for any
+//! structure type or through one of the typed builders (such as
+//! [SystemInformationBuilder]), and added to a [SMBiosTableBuilder] which
+//! assigns their handles. The resulting [SMBiosData] provides the table bytes
+//! ([SMBiosData::raw_bytes]) and a matching entry point
+//! ([SMBiosData::entry_point_32] and [SMBiosData::entry_point_64]).
+//! Tables may also be described declaratively with a [TableSpec], for
+//! example as JSON or TOML fixtures.
+//!
+//! Existing tables are edited through [SMBiosDataMut], whose structures are
+//! modified in place with [DefinedStructMut] and written back to bytes.
+//! [QemuBlobs] exports a table for replay inside QEMU virtual machines.
+
+mod bios_information;
+mod defined_struct_mut;
+mod memory_device;
+mod qemu;
+mod smbios_data_mut;
+mod string_set;
+mod system_information;
+mod table_spec;
+mod table_writer;
+mod unknown;
+
+pub use bios_information::*;
+pub use defined_struct_mut::*;
+pub use memory_device::*;
+pub use qemu::*;
+pub use smbios_data_mut::*;
+pub use string_set::*;
+pub use system_information::*;
+pub use table_spec::*;
+pub use unknown::*;
+
+use crate::core::{Handle, Header, SMBiosData, SMBiosVersion, UndefinedStruct};
+use crate::structs::{SMBiosEndOfTable, SMBiosStruct};
+#[cfg(feature = "no_std")]
+use alloc::{collections::BTreeSet, format, vec::Vec};
+use core::fmt;
+#[cfg(not(feature = "no_std"))]
+use std::collections::BTreeSet;
+
+/// Table build errors
+pub enum BuildError {
+    /// An SMBIOS version is required to create an entry point
+    MissingVersion,
+    /// The handle is already assigned to another structure
+    DuplicateHandle(Handle),
+    /// The handle is outside of the range 0 to [SMBiosTableBuilder::MAXIMUM_HANDLE]
+    ReservedHandle(Handle),
+    /// Every handle has been assigned
+    HandlesExhausted,
+    /// The formatted area of a structure exceeds 255 bytes
+    StructTooLong {
+        /// Type of the structure
+        struct_type: u8,
+        /// Length of the formatted area, including the header
+        length: usize,
+    },
+    /// The table size exceeds what the entry point can describe
+    TableTooLarge {
+        /// Size of the table
+        size: usize,
+        /// The limit exceeded
+        limit: usize,
+    },
+    /// The number of structures exceeds what the entry point can describe
+    TooManyStructures {
+        /// The limit exceeded
+        limit: usize,
+    },
+    /// The field is outside of the formatted area (past the header) of the structure
+    FieldOutOfRange {
+        /// Handle of the structure
+        handle: Handle,
+        /// Offset of the field
+        offset: usize,
+        /// Length of the formatted area, including the header
+        length: usize,
+    },
+    /// The string-set of the structure already holds 255 strings
+    StringsExhausted(Handle),
+    /// The string-set of the structure has no string at the index
+    StringNotFound {
+        /// Handle of the structure
+        handle: Handle,
+        /// 1 based index of the string
+        index: u8,
+    },
+    /// Strings of a string-set may not be empty
+    EmptyString(Handle),
+    /// Strings may not contain NUL or characters outside of ISO-8859-1
+    InvalidCharacter {
+        /// Handle of the structure
+        handle: Handle,
+        /// The character which cannot be stored
+        character: char,
+    },
+}
+
+impl fmt::Debug for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BuildError")
+            .field(&format!("{}", &self))
+            .finish()
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::MissingVersion => {
+                f.write_str("An SMBIOS version is required to write an entry point")
+            }
+            BuildError::DuplicateHandle(handle) => {
+                write!(f, "Handle {:#06X} is already assigned", **handle)
+            }
+            BuildError::ReservedHandle(handle) => {
+                write!(f, "Handle {:#06X} is reserved", **handle)
+            }
+            BuildError::HandlesExhausted => f.write_str("Every structure handle is assigned"),
+            BuildError::StructTooLong {
+                struct_type,
+                length,
+            } => write!(
+                f,
+                "The formatted area of {} bytes of a type {} structure exceeds 255 bytes",
+                length, struct_type
+            ),
+            BuildError::TableTooLarge { size, limit } => write!(
+                f,
+                "The table size of {} bytes exceeds the entry point limit of {} bytes",
+                size, limit
+            ),
+            BuildError::TooManyStructures { limit } => write!(
+                f,
+                "The table contains more than the entry point limit of {} structures",
+                limit
+            ),
+            BuildError::FieldOutOfRange {
+                handle,
+                offset,
+                length,
+            } => write!(
+                f,
+                "Offset {} is outside of the formatted area of {} bytes of structure {:#06X}",
+                offset, length, **handle
+            ),
+            BuildError::StringsExhausted(handle) => write!(
+                f,
+                "The string-set of structure {:#06X} already holds 255 strings",
+                **handle
+            ),
+            BuildError::StringNotFound { handle, index } => write!(
+                f,
+                "The string-set of structure {:#06X} has no string {}",
+                **handle, index
+            ),
+            BuildError::EmptyString(handle) => write!(
+                f,
+                "An empty string cannot be stored in the string-set of structure {:#06X}",
+                **handle
+            ),
+            BuildError::InvalidCharacter { handle, character } => write!(
+                f,
+                "The character {:?} cannot be stored in the string-set of structure {:#06X}",
+                character, **handle
+            ),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for BuildError {}
+
+/// # Structure Builder
+///
+/// Assembles a single structure: the formatted area is appended field by
+/// field, in offset order, following the 4 byte header. Strings are
+/// collected into the string-set and referenced by their 1 based index.
+///
+/// Handles are assigned when the structure is added to a [SMBiosTableBuilder].
+pub struct StructBuilder {
+    struct_type: u8,
+    fields: Vec<u8>,
+    strings: Vec<Vec<u8>>,
+}
+
+impl StructBuilder {
+    /// Creates a builder for a structure of the given type
+    pub fn new(struct_type: u8) -> Self {
+        Self {
+            struct_type,
+            fields: Vec::new(),
+            strings: Vec::new(),
+        }
+    }
+
+    /// Type of the structure
+    pub fn struct_type(&self) -> u8 {
+        self.struct_type
+    }
+
+    /// Length of the formatted area, including the header
+    pub fn length(&self) -> usize {
+        Header::SIZE + self.fields.len()
+    }
+
+    /// Appends a BYTE field
+    pub fn byte(mut self, value: u8) -> Self {
+        self.fields.push(value);
+        self
+    }
+
+    /// Appends a WORD field
+    pub fn word(mut self, value: u16) -> Self {
+        self.fields.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Appends a DWORD field
+    pub fn dword(mut self, value: u32) -> Self {
+        self.fields.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Appends a QWORD field
+    pub fn qword(mut self, value: u64) -> Self {
+        self.fields.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Appends a [Handle] field referencing another structure
+    pub fn handle(self, handle: Handle) -> Self {
+        self.word(*handle)
+    }
+
+    /// Appends a block of bytes
+    pub fn data(mut self, data: &[u8]) -> Self {
+        self.fields.extend_from_slice(data);
+        self
+    }
+
+    /// Appends a string field
+    ///
+    /// The string is added to the string-set and the field is set to its
+    /// index. An empty string sets the field to 0, meaning no string.
+    /// Identical strings share a single entry of the string-set.
+    ///
+    /// Strings are stored as ISO-8859-1, the encoding used when they are read
+    /// back. Characters outside of ISO-8859-1 and NUL are replaced by '?'.
+    /// The string-set holds at most 255 strings, further strings set the field to 0.
+    pub fn string(mut self, value: &str) -> Self {
+        let index = self.add_string(value);
+        self.byte(index)
+    }
+
+    /// Appends a string to the string-set without a field referencing it
+    ///
+    /// For structures whose strings are not referenced by index fields, such
+    /// as OEM Strings (Type 11), or whose fields are given as a block of
+    /// bytes. The string is not shared with an identical string, its index is
+    /// the number of strings added so far. Strings may not be empty.
+    pub fn append_string(mut self, value: &str) -> Self {
+        self.strings.push(encode_string(value));
+        self
+    }
+
+    fn add_string(&mut self, value: &str) -> u8 {
+        if value.is_empty() {
+            return 0;
+        }
+
+        let encoded = encode_string(value);
+        match self.strings.iter().position(|string| *string == encoded) {
+            Some(position) => (position + 1) as u8,
+            None if self.strings.len() < u8::MAX as usize => {
+                self.strings.push(encoded);
+                self.strings.len() as u8
+            }
+            None => 0,
+        }
+    }
+
+    /// The raw bytes of the structure with the given handle
+    pub(crate) fn to_raw(&self, handle: Handle) -> Result<Vec<u8>, BuildError> {
+        let length = self.length();
+        if length > u8::MAX as usize {
+            return Err(BuildError::StructTooLong {
+                struct_type: self.struct_type,
+                length,
+            });
+        }
+
+        if self.strings.iter().any(|string| string.is_empty()) {
+            return Err(BuildError::EmptyString(handle));
+        }
+        if self.strings.len() > u8::MAX as usize {
+            return Err(BuildError::StringsExhausted(handle));
+        }
+
+        let mut raw = Vec::with_capacity(length + 2);
+        raw.push(self.struct_type);
+        raw.push(length as u8);
+        raw.extend_from_slice(&handle.to_le_bytes());
+        raw.extend_from_slice(&self.fields);
+
+        if self.strings.is_empty() {
+            raw.extend_from_slice(&[0, 0]);
+        } else {
+            for string in self.strings.iter() {
+                raw.extend_from_slice(string);
+                raw.push(0);
+            }
+            raw.push(0);
+        }
+
+        Ok(raw)
+    }
+}
+
+impl From<&UndefinedStruct> for StructBuilder {
+    /// A builder recreating a parsed structure
+    ///
+    /// The formatted area is copied as a block of bytes and the strings are
+    /// appended in order with their raw bytes.
+    fn from(undefined_struct: &UndefinedStruct) -> Self {
+        Self {
+            struct_type: undefined_struct.header.struct_type(),
+            fields: undefined_struct
+                .fields
+                .get(Header::SIZE..)
+                .unwrap_or(&[])
+                .to_vec(),
+            strings: undefined_struct.strings.iter().cloned().collect(),
+        }
+    }
+}
+
+/// Encodes a string as ISO-8859-1, replacing NUL and characters outside of
+/// ISO-8859-1 by '?'
+fn encode_string(value: &str) -> Vec<u8> {
+    value
+        .chars()
+        .map(|c| match c as u32 {
+            0x01..=0xFF => c as u8,
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// # SMBIOS Table Builder
+///
+/// Collects structures in order, assigning each a unique handle, and builds
+/// the structure table terminated by an End-of-Table (Type 127) structure.
+pub struct SMBiosTableBuilder {
+    version: SMBiosVersion,
+    table: Vec<u8>,
+    handles: BTreeSet<u16>,
+    next_handle: u16,
+    last_struct_type: Option<u8>,
+}
+
+impl SMBiosTableBuilder {
+    /// The largest handle which may be assigned to a structure
+    ///
+    /// Handles FF00h and above are reserved, FFFEh and FFFFh being used by
+    /// handle fields to indicate that no structure is referenced.
+    pub const MAXIMUM_HANDLE: u16 = 0xFEFF;
+
+    /// Creates a builder for a table of the given SMBIOS `version`
+    pub fn new(version: SMBiosVersion) -> Self {
+        Self {
+            version,
+            table: Vec::new(),
+            handles: BTreeSet::new(),
+            next_handle: 0,
+            last_struct_type: None,
+        }
+    }
+
+    /// Adds a structure, assigning it the next free handle
+    ///
+    /// Returns the assigned handle, for use in the fields of structures which
+    /// reference this one.
+    pub fn add<T: Into<StructBuilder>>(&mut self, builder: T) -> Result<Handle, BuildError> {
+        let handle = (self.next_handle..=Self::MAXIMUM_HANDLE)
+            .find(|handle| !self.handles.contains(handle))
+            .ok_or(BuildError::HandlesExhausted)?;
+
+        self.add_with_handle(Handle(handle), builder)
+    }
+
+    /// Adds a structure with the given handle
+    pub fn add_with_handle<T: Into<StructBuilder>>(
+        &mut self,
+        handle: Handle,
+        builder: T,
+    ) -> Result<Handle, BuildError> {
+        if *handle > Self::MAXIMUM_HANDLE {
+            return Err(BuildError::ReservedHandle(handle));
+        }
+        if self.handles.contains(&handle) {
+            return Err(BuildError::DuplicateHandle(handle));
+        }
+
+        let builder = builder.into();
+        let mut raw = builder.to_raw(handle)?;
+        self.table.append(&mut raw);
+        self.handles.insert(*handle);
+        self.last_struct_type = Some(builder.struct_type());
+
+        if *handle == self.next_handle {
+            self.next_handle = self.next_handle.saturating_add(1);
+        }
+
+        Ok(handle)
+    }
+
+    /// Builds the table
+    ///
+    /// An End-of-Table (Type 127) structure is appended unless it was the
+    /// last structure added.
+    pub fn build(mut self) -> Result<SMBiosData, BuildError> {
+        if self.last_struct_type != Some(SMBiosEndOfTable::STRUCT_TYPE) {
+            self.add(StructBuilder::new(SMBiosEndOfTable::STRUCT_TYPE))?;
+        }
+
+        Ok(SMBiosData::from_vec_and_version(
+            self.table,
+            Some(self.version),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{MaybeValue, MegatransferSpeed, SMBiosEntryPoint32, SMBiosEntryPoint64};
+    use crate::structs::{
+        MemoryDeviceType, MemoryFormFactor, MemorySize, MemorySizeExtended, MemorySpeed,
+        MemorySpeedExtended, SMBiosInformation, SMBiosMemoryDevice, SMBiosOemStrings, SMBiosStruct,
+        SMBiosSystemInformation, SystemUuidData, SystemWakeUpType,
+    };
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_struct_builder() {
+        let oem_strings = StructBuilder::new(11)
+            .byte(3)
+            .string("One")
+            .string("")
+            .string("One")
+            .string("Two");
+
+        assert_eq!(oem_strings.length(), 9);
+        assert_eq!(
+            oem_strings.to_raw(Handle(0x0102)).unwrap(),
+            vec![
+                0x0B, 0x09, 0x02, 0x01, 0x03, 0x01, 0x00, 0x01, 0x02, b'O', b'n', b'e', 0x00, b'T',
+                b'w', b'o', 0x00, 0x00
+            ]
+        );
+
+        assert_eq!(
+            StructBuilder::new(0x80)
+                .word(0x1234)
+                .to_raw(Handle(0))
+                .unwrap(),
+            vec![0x80, 0x06, 0x00, 0x00, 0x34, 0x12, 0x00, 0x00]
+        );
+
+        assert!(matches!(
+            StructBuilder::new(0x80).data(&[0; 252]).to_raw(Handle(0)),
+            Err(BuildError::StructTooLong {
+                struct_type: 0x80,
+                length: 256
+            })
+        ));
+    }
+
+    #[test]
+    fn test_table_builder() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+
+        let first = builder
+            .add(StructBuilder::new(11).byte(1).string("OEM"))
+            .unwrap();
+        let explicit = builder
+            .add_with_handle(Handle(1), StructBuilder::new(0x80))
+            .unwrap();
+        let second = builder.add(StructBuilder::new(0x81)).unwrap();
+
+        assert_eq!(first, Handle(0));
+        assert_eq!(explicit, Handle(1));
+        assert_eq!(second, Handle(2));
+        assert!(matches!(
+            builder.add_with_handle(Handle(2), StructBuilder::new(0x82)),
+            Err(BuildError::DuplicateHandle(Handle(2)))
+        ));
+        assert!(matches!(
+            builder.add_with_handle(Handle(0xFFFE), StructBuilder::new(0x82)),
+            Err(BuildError::ReservedHandle(Handle(0xFFFE)))
+        ));
+
+        let data = builder.build().unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 4, 0)));
+        assert!(data.warnings().is_empty());
+
+        let handles: Vec<u16> = data.iter().map(|parts| *parts.header.handle()).collect();
+        assert_eq!(handles, vec![0, 1, 2, 3]);
+        assert_eq!(
+            data.iter().last().unwrap().header.struct_type(),
+            SMBiosEndOfTable::STRUCT_TYPE
+        );
+
+        let oem_strings = data.first::<SMBiosOemStrings<'_>>().unwrap();
+        assert_eq!(oem_strings.count(), Some(1));
+        assert_eq!(
+            oem_strings.parts().get_field_string(0x04),
+            Some("OEM".to_string())
+        );
+
+        let table = data.raw_bytes();
+        let entry_point =
+            SMBiosEntryPoint64::try_from(data.entry_point_64(0x000F_0000).unwrap()).unwrap();
+        assert_eq!(entry_point.major_version(), 3);
+        assert_eq!(entry_point.minor_version(), 4);
+        assert_eq!(entry_point.structure_table_address(), 0x000F_0000);
+        assert_eq!(
+            entry_point.structure_table_maximum_size() as usize,
+            table.len()
+        );
+
+        let entry_point =
+            SMBiosEntryPoint32::try_from(data.entry_point_32(0x000F_0000).unwrap()).unwrap();
+        assert_eq!(entry_point.structure_table_address(), 0x000F_0000);
+        assert_eq!(entry_point.structure_table_length() as usize, table.len());
+        assert_eq!(entry_point.number_of_smbios_structures(), 4);
+    }
+
+    #[test]
+    fn test_table_builder_end_of_table() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(2, 8, 0));
+        builder
+            .add_with_handle(Handle(0xFEFF), StructBuilder::new(127))
+            .unwrap();
+
+        let data = builder.build().unwrap();
+        assert_eq!(data.iter().count(), 1);
+        assert_eq!(*data.iter().next().unwrap().header.handle(), 0xFEFF);
+    }
+
+    #[test]
+    fn test_typed_builders() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 3, 0));
+
+        builder
+            .add(
+                BiosInformationBuilder::new()
+                    .vendor("Vendor")
+                    .version("1.0")
+                    .release_date("01/02/2023")
+                    .system_bios_release(1, 0),
+            )
+            .unwrap();
+        builder
+            .add(
+                SystemInformationBuilder::new()
+                    .manufacturer("Vendor")
+                    .product_name("Virtual Machine")
+                    .serial_number("VM-0001")
+                    .uuid([
+                        0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xAA, 0xBB,
+                        0xCC, 0xDD, 0xEE, 0xFF,
+                    ])
+                    .wakeup_type(0x06)
+                    .family("Virtual"),
+            )
+            .unwrap();
+        let array = builder
+            .add(StructBuilder::new(16).data(&[0; 0x13]))
+            .unwrap();
+        builder
+            .add(
+                MemoryDeviceBuilder::new(array)
+                    .size_mb(65536)
+                    .form_factor(0x09)
+                    .device_locator("DIMM 0")
+                    .memory_type(0x22)
+                    .speed_mts(4800)
+                    .configured_memory_speed_mts(70000)
+                    .manufacturer("Vendor"),
+            )
+            .unwrap();
+        builder
+            .add(MemoryDeviceBuilder::new(array).size_mb(0))
+            .unwrap();
+
+        let data = builder.build().unwrap();
+        assert!(data.warnings().is_empty());
+
+        let bios_information = data.first::<SMBiosInformation<'_>>().unwrap();
+        assert_eq!(bios_information.parts().header.length(), 0x1A);
+        assert_eq!(bios_information.vendor(), Some("Vendor".to_string()));
+        assert_eq!(bios_information.version(), Some("1.0".to_string()));
+        assert_eq!(
+            bios_information.release_date(),
+            Some("01/02/2023".to_string())
+        );
+        assert_eq!(bios_information.system_bios_major_release(), Some(1));
+        assert_eq!(bios_information.e_c_firmware_major_release(), Some(0xFF));
+
+        let system_information = data.first::<SMBiosSystemInformation<'_>>().unwrap();
+        assert_eq!(system_information.parts().header.length(), 0x1B);
+        assert_eq!(
+            system_information.product_name(),
+            Some("Virtual Machine".to_string())
+        );
+        assert_eq!(system_information.version(), None);
+        assert_eq!(
+            system_information.serial_number(),
+            Some("VM-0001".to_string())
+        );
+        match system_information.uuid().unwrap() {
+            SystemUuidData::Uuid(uuid) => {
+                assert_eq!(format!("{}", uuid), "00112233-4455-6677-8899-AABBCCDDEEFF")
+            }
+            _ => panic!("expected a UUID"),
+        }
+        assert_eq!(
+            *system_information.wakeup_type().unwrap(),
+            SystemWakeUpType::PowerSwitch
+        );
+        assert_eq!(system_information.family(), Some("Virtual".to_string()));
+        assert_eq!(system_information.parts().strings.iter().count(), 4);
+
+        let memory_devices = data.collect::<SMBiosMemoryDevice<'_>>();
+        assert_eq!(memory_devices.len(), 2);
+
+        let memory_device = &memory_devices[0];
+        assert_eq!(memory_device.parts().header.length(), 0x5C);
+        assert_eq!(memory_device.physical_memory_array_handle(), Some(array));
+        assert_eq!(
+            *memory_device.memory_error_information_handle().unwrap(),
+            0xFFFE
+        );
+        assert_eq!(memory_device.size(), Some(MemorySize::SeeExtendedSize));
+        assert_eq!(
+            memory_device.extended_size(),
+            Some(MemorySizeExtended::Megabytes(65536))
+        );
+        assert_eq!(
+            *memory_device.form_factor().unwrap(),
+            MemoryFormFactor::Dimm
+        );
+        assert_eq!(memory_device.device_locator(), Some("DIMM 0".to_string()));
+        assert_eq!(
+            *memory_device.memory_type().unwrap(),
+            MemoryDeviceType::Ddr5
+        );
+        assert_eq!(memory_device.speed(), Some(MemorySpeed::MTs(4800)));
+        assert_eq!(
+            memory_device.configured_memory_speed(),
+            Some(MemorySpeed::SeeExtendedSpeed)
+        );
+        assert_eq!(
+            memory_device.extended_configured_memory_speed(),
+            Some(MemorySpeedExtended::MTs(70000))
+        );
+        assert_eq!(
+            memory_device.effective_configured_memory_speed(),
+            Some(MaybeValue::Value(MegatransferSpeed::new(70000)))
+        );
+        assert_eq!(memory_device.manufacturer(), Some("Vendor".to_string()));
+
+        assert_eq!(memory_devices[1].size(), Some(MemorySize::NotInstalled));
+    }
+}
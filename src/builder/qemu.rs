@@ -0,0 +1,170 @@
+use crate::core::SMBiosData;
+use crate::structs::{SMBiosEndOfTable, SMBiosInactive, SMBiosStruct};
+#[cfg(feature = "no_std")]
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+#[cfg(not(feature = "no_std"))]
+use std::{collections::BTreeMap, fs::write, io::Error, path::Path};
+
+/// # QEMU SMBIOS Blobs
+///
+/// The structures of a table grouped into one binary blob per structure
+/// type, for use with the `-smbios file=<blob>` option of QEMU.
+///
+/// QEMU generates the structures it needs (for example Types 0, 1, 3, 4,
+/// 16, 17 and 19) unless a blob provides structures of that type, so the
+/// blobs of a captured table replace the identity of the virtual machine.
+/// A blob may be left out to fall back to the structure generated by QEMU,
+/// which can then be configured with `-smbios type=N,...`.
+///
+/// Inactive (Type 126) and End-of-Table (Type 127) structures are not
+/// exported, QEMU terminates the table itself. Structures keep their
+/// handles.
+pub struct QemuBlobs {
+    blobs: BTreeMap<u8, Vec<u8>>,
+}
+
+impl QemuBlobs {
+    /// Iterates the structure types and their blobs, in type order
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &[u8])> {
+        self.blobs
+            .iter()
+            .map(|(struct_type, blob)| (*struct_type, blob.as_slice()))
+    }
+
+    /// The blob of the given structure type
+    pub fn get(&self, struct_type: u8) -> Option<&[u8]> {
+        self.blobs.get(&struct_type).map(Vec::as_slice)
+    }
+
+    /// File name of the blob of the given structure type (`smbios_type_N.bin`)
+    pub fn file_name(struct_type: u8) -> String {
+        format!("smbios_type_{}.bin", struct_type)
+    }
+
+    /// QEMU command-line fragment loading the blobs from `directory`
+    ///
+    /// One `-smbios file=<path>` option per blob, commas in paths being
+    /// escaped as required by QEMU's option parser.
+    #[cfg(not(feature = "no_std"))]
+    pub fn command_line(&self, directory: &Path) -> String {
+        self.blobs
+            .keys()
+            .map(|struct_type| {
+                let path = directory.join(Self::file_name(*struct_type));
+                format!("-smbios file={}", path.to_string_lossy().replace(',', ",,"))
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Writes the blobs to `directory`, returning the QEMU command-line
+    /// fragment loading them (see [QemuBlobs::command_line])
+    #[cfg(not(feature = "no_std"))]
+    pub fn write(&self, directory: &Path) -> Result<String, Error> {
+        for (struct_type, blob) in self.iter() {
+            write(directory.join(Self::file_name(struct_type)), blob)?;
+        }
+
+        Ok(self.command_line(directory))
+    }
+}
+
+impl From<&SMBiosData> for QemuBlobs {
+    fn from(data: &SMBiosData) -> Self {
+        let mut blobs: BTreeMap<u8, Vec<u8>> = BTreeMap::new();
+        for undefined_struct in data.iter() {
+            let struct_type = undefined_struct.header.struct_type();
+            if struct_type == SMBiosInactive::STRUCT_TYPE
+                || struct_type == SMBiosEndOfTable::STRUCT_TYPE
+            {
+                continue;
+            }
+
+            blobs
+                .entry(struct_type)
+                .or_default()
+                .append(&mut undefined_struct.raw());
+        }
+
+        Self { blobs }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{
+        SMBiosDataMut, SMBiosTableBuilder, StructBuilder, SystemInformationBuilder,
+    };
+    use crate::core::{Handle, SMBiosVersion};
+
+    fn test_data() -> SMBiosData {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add(SystemInformationBuilder::new().manufacturer("Manufacturer"))
+            .unwrap();
+        builder
+            .add(StructBuilder::new(17).data(&[0; 0x24]).string("DIMM 0"))
+            .unwrap();
+        builder
+            .add(StructBuilder::new(17).data(&[0; 0x24]).string("DIMM 1"))
+            .unwrap();
+        builder.add(StructBuilder::new(11).byte(0)).unwrap();
+        let mut data = SMBiosDataMut::from(builder.build().unwrap());
+        data.inactivate(&Handle(3));
+        SMBiosData::from(data)
+    }
+
+    #[test]
+    fn test_qemu_blobs() {
+        let data = test_data();
+        let blobs = QemuBlobs::from(&data);
+
+        let types: Vec<u8> = blobs.iter().map(|(struct_type, _)| struct_type).collect();
+        assert_eq!(types, vec![1, 17]);
+        assert_eq!(
+            blobs.get(1).unwrap(),
+            data.find_by_handle(&Handle(0)).unwrap().raw().as_slice()
+        );
+        // both memory devices are in the same blob, in table order
+        let memory_devices =
+            SMBiosData::from_vec_and_version(blobs.get(17).unwrap().to_vec(), None);
+        let handles: Vec<Handle> = memory_devices
+            .iter()
+            .map(|undefined_struct| undefined_struct.header.handle())
+            .collect();
+        assert_eq!(handles, vec![Handle(1), Handle(2)]);
+        assert_eq!(memory_devices.warnings().len(), 1);
+    }
+
+    #[test]
+    fn test_qemu_command_line() {
+        let blobs = QemuBlobs::from(&test_data());
+        // the comma of the directory name is escaped on the command line
+        let directory =
+            std::env::temp_dir().join(format!("smbios-lib-qemu,blobs-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let command_line = blobs.write(&directory).unwrap();
+        let blob_path = |struct_type| {
+            directory
+                .join(QemuBlobs::file_name(struct_type))
+                .to_string_lossy()
+                .replace(',', ",,")
+        };
+        assert_eq!(
+            command_line,
+            format!(
+                "-smbios file={} -smbios file={}",
+                blob_path(1),
+                blob_path(17)
+            )
+        );
+        assert_eq!(
+            std::fs::read(directory.join("smbios_type_17.bin")).unwrap(),
+            blobs.get(17).unwrap()
+        );
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,763 @@
+use super::{BuildError, DefinedStructMut, SMBiosTableBuilder, StructBuilder};
+use crate::core::{Handle, SMBiosData, SMBiosVersion, StructFields, UndefinedStruct};
+use crate::structs::{SMBiosEndOfTable, SMBiosStruct};
+#[cfg(feature = "no_std")]
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::slice::Iter;
+#[cfg(not(feature = "no_std"))]
+use std::collections::{BTreeMap, BTreeSet};
+
+/// # Mutable SMBIOS Data
+///
+/// An editable copy of the structures of a [SMBiosData]. Structures are
+/// modified with [DefinedStructMut] and the table is written back to bytes
+/// with [SMBiosDataMut::raw_bytes], or converted back into a [SMBiosData]
+/// to produce an entry point or a dump image.
+pub struct SMBiosDataMut {
+    structs: Vec<UndefinedStruct>,
+    /// Version of the contained SMBIOS structures.
+    pub version: Option<SMBiosVersion>,
+}
+
+impl SMBiosDataMut {
+    /// Iterates the structures
+    pub fn iter(&self) -> Iter<'_, UndefinedStruct> {
+        self.structs.iter()
+    }
+
+    /// Iterates the structures for editing
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = DefinedStructMut<'_>> {
+        self.structs.iter_mut().map(DefinedStructMut::new)
+    }
+
+    /// Finds a structure by its handle
+    pub fn find_by_handle(&self, handle: &Handle) -> Option<&UndefinedStruct> {
+        self.structs
+            .iter()
+            .find(|smbios_struct| smbios_struct.header.handle() == *handle)
+    }
+
+    /// Finds a structure by its handle for editing
+    pub fn find_by_handle_mut(&mut self, handle: &Handle) -> Option<DefinedStructMut<'_>> {
+        self.structs
+            .iter_mut()
+            .find(|smbios_struct| smbios_struct.header.handle() == *handle)
+            .map(DefinedStructMut::new)
+    }
+
+    /// Adds a structure before the End-of-Table (Type 127) structure,
+    /// assigning it the lowest free handle
+    ///
+    /// Returns the assigned handle. The structure is added at the end of a
+    /// table without End-of-Table structure.
+    pub fn add<T: Into<StructBuilder>>(&mut self, builder: T) -> Result<Handle, BuildError> {
+        let handles: BTreeSet<u16> = self
+            .iter()
+            .map(|smbios_struct| *smbios_struct.header.handle())
+            .collect();
+        let handle = (0..=SMBiosTableBuilder::MAXIMUM_HANDLE)
+            .find(|handle| !handles.contains(handle))
+            .ok_or(BuildError::HandlesExhausted)?;
+
+        self.add_with_handle(Handle(handle), builder)
+    }
+
+    /// Adds a structure with the given handle before the End-of-Table
+    /// (Type 127) structure
+    pub fn add_with_handle<T: Into<StructBuilder>>(
+        &mut self,
+        handle: Handle,
+        builder: T,
+    ) -> Result<Handle, BuildError> {
+        if *handle > SMBiosTableBuilder::MAXIMUM_HANDLE {
+            return Err(BuildError::ReservedHandle(handle));
+        }
+        if self.find_by_handle(&handle).is_some() {
+            return Err(BuildError::DuplicateHandle(handle));
+        }
+
+        let raw = builder.into().to_raw(handle)?;
+        let position = self
+            .structs
+            .iter()
+            .position(is_end_of_table)
+            .unwrap_or(self.structs.len());
+        self.structs.insert(position, UndefinedStruct::new(&raw));
+        Ok(handle)
+    }
+
+    /// Removes the structure with the given handle
+    ///
+    /// The handles of the other structures are unchanged. Fields referencing
+    /// the removed structure are not updated, use [SMBiosDataMut::inactivate]
+    /// to keep such references resolvable.
+    pub fn remove(&mut self, handle: &Handle) -> Option<UndefinedStruct> {
+        let position = self
+            .structs
+            .iter()
+            .position(|smbios_struct| smbios_struct.header.handle() == *handle)?;
+        Some(self.structs.remove(position))
+    }
+
+    /// Rewrites the structure with the given handle as an Inactive (Type 126) structure
+    ///
+    /// See [DefinedStructMut::inactivate]. Returns false when no structure
+    /// has the given handle.
+    pub fn inactivate(&mut self, handle: &Handle) -> bool {
+        match self.find_by_handle_mut(handle) {
+            Some(mut smbios_struct) => {
+                smbios_struct.inactivate();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Assigns unique handles to the structures
+    ///
+    /// Structures are numbered in table order starting at 0, and the fields
+    /// referencing other structures (see [DefinedStructMut::handle_field_offsets])
+    /// are updated to the new handles. When several structures share a
+    /// handle, references to it resolve to the first of them. References to
+    /// a handle of no structure below FFFEh are dangling and are rewritten
+    /// to FFFFh, as the renumbered structures may take their handle; FFFEh
+    /// and FFFFh are left unchanged.
+    ///
+    /// Fails when the table holds more structures than there are handles.
+    pub fn renumber_handles(&mut self) -> Result<(), BuildError> {
+        if self.structs.len() > SMBiosTableBuilder::MAXIMUM_HANDLE as usize + 1 {
+            return Err(BuildError::HandlesExhausted);
+        }
+
+        let mut handles = BTreeMap::new();
+        for (index, smbios_struct) in self.structs.iter().enumerate() {
+            let handle = *smbios_struct.header.handle();
+            if handle <= SMBiosTableBuilder::MAXIMUM_HANDLE {
+                handles.entry(handle).or_insert(index as u16);
+            }
+        }
+
+        for (index, mut smbios_struct) in self.iter_mut().enumerate() {
+            smbios_struct.set_handle(Handle(index as u16));
+            remap_references(&mut smbios_struct, &handles, Some(0xFFFF));
+        }
+
+        Ok(())
+    }
+
+    /// Appends the structures of another table
+    ///
+    /// The End-of-Table (Type 127) structures of both tables are replaced by
+    /// a single one at the end of the table. Appended structures keep their
+    /// handle unless it is already in use, in which case they are assigned
+    /// a free handle and the fields of the appended structures referencing
+    /// them are updated. The version becomes the later of both versions.
+    ///
+    /// Fails, leaving the table unchanged, when the combined table exceeds
+    /// the size limit of the entry point of its version (see
+    /// [SMBiosData::entry_point_32] and [SMBiosData::entry_point_64]) or
+    /// there are not enough handles.
+    pub fn append(&mut self, other: SMBiosData) -> Result<(), BuildError> {
+        let version = self.version.max(other.version);
+        let (mut structs, other_end_of_table): (Vec<UndefinedStruct>, Vec<UndefinedStruct>) = other
+            .into_iter()
+            .partition(|smbios_struct| !is_end_of_table(smbios_struct));
+        let has_end_of_table = self.structs.iter().any(is_end_of_table);
+
+        let end_of_table = if has_end_of_table {
+            self.structs
+                .iter()
+                .find(|smbios_struct| is_end_of_table(smbios_struct))
+        } else {
+            other_end_of_table.first()
+        };
+        let struct_sizes: Vec<usize> = self
+            .structs
+            .iter()
+            .filter(|smbios_struct| !is_end_of_table(smbios_struct))
+            .chain(structs.iter())
+            .chain(end_of_table)
+            .map(|smbios_struct| smbios_struct.raw().len())
+            .collect();
+        check_table_limits(version, &struct_sizes)?;
+
+        if !has_end_of_table {
+            structs.extend(other_end_of_table.into_iter().take(1));
+        }
+
+        // Appended structures keep the handles not used by this table, the
+        // others are assigned the lowest free handles
+        let mut handles: BTreeSet<u16> = self
+            .structs
+            .iter()
+            .map(|smbios_struct| *smbios_struct.header.handle())
+            .collect();
+        let kept: Vec<bool> = structs
+            .iter()
+            .map(|smbios_struct| {
+                let handle = *smbios_struct.header.handle();
+                handle <= SMBiosTableBuilder::MAXIMUM_HANDLE && handles.insert(handle)
+            })
+            .collect();
+        let mut free_handles =
+            (0..=SMBiosTableBuilder::MAXIMUM_HANDLE).filter(|handle| !handles.contains(handle));
+        let mut new_handles = Vec::with_capacity(structs.len());
+        for (smbios_struct, kept) in structs.iter().zip(kept) {
+            new_handles.push(if kept {
+                *smbios_struct.header.handle()
+            } else {
+                free_handles.next().ok_or(BuildError::HandlesExhausted)?
+            });
+        }
+
+        let mut remapped = BTreeMap::new();
+        for (smbios_struct, new_handle) in structs.iter().zip(new_handles.iter()) {
+            remapped
+                .entry(*smbios_struct.header.handle())
+                .or_insert(*new_handle);
+        }
+        for (smbios_struct, new_handle) in structs.iter_mut().zip(new_handles) {
+            let mut smbios_struct = DefinedStructMut::new(smbios_struct);
+            smbios_struct.set_handle(Handle(new_handle));
+            remap_references(&mut smbios_struct, &remapped, None);
+        }
+
+        if has_end_of_table {
+            let (table, end_of_table): (Vec<UndefinedStruct>, Vec<UndefinedStruct>) =
+                core::mem::take(&mut self.structs)
+                    .into_iter()
+                    .partition(|smbios_struct| !is_end_of_table(smbios_struct));
+            self.structs = table;
+            structs.extend(end_of_table.into_iter().take(1));
+        }
+        self.structs.append(&mut structs);
+        self.version = version;
+        Ok(())
+    }
+
+    /// The raw bytes of the structure table
+    ///
+    /// Each structure is written with its length and string-set as edited.
+    pub fn raw_bytes(&self) -> Vec<u8> {
+        self.iter()
+            .flat_map(|undefined_struct| undefined_struct.raw())
+            .collect()
+    }
+}
+
+fn is_end_of_table(smbios_struct: &UndefinedStruct) -> bool {
+    smbios_struct.header.struct_type() == SMBiosEndOfTable::STRUCT_TYPE
+}
+
+/// Updates the fields of a structure referencing a remapped handle
+///
+/// References below FFFEh to a handle missing from `handles` are rewritten
+/// to `dangling`, or left unchanged when it is None.
+fn remap_references(
+    smbios_struct: &mut DefinedStructMut<'_>,
+    handles: &BTreeMap<u16, u16>,
+    dangling: Option<u16>,
+) {
+    for offset in smbios_struct.handle_field_offsets() {
+        let new_handle = smbios_struct
+            .parts()
+            .get_field_word(offset)
+            .and_then(|handle| match handles.get(&handle) {
+                Some(new_handle) => Some(*new_handle),
+                None if handle < 0xFFFE => dangling,
+                None => None,
+            });
+        if let Some(new_handle) = new_handle {
+            smbios_struct
+                .set_field_word(offset, new_handle)
+                .expect("handle fields are within the structure");
+        }
+    }
+}
+
+/// Checks a table of structures of the given sizes fits the entry point of `version`
+fn check_table_limits(
+    version: Option<SMBiosVersion>,
+    struct_sizes: &[usize],
+) -> Result<(), BuildError> {
+    if struct_sizes.len() > SMBiosTableBuilder::MAXIMUM_HANDLE as usize + 1 {
+        return Err(BuildError::HandlesExhausted);
+    }
+
+    let size: usize = struct_sizes.iter().sum();
+    let limit = match version {
+        Some(version) if version.major >= 3 => u32::MAX as usize,
+        _ => u16::MAX as usize,
+    };
+    if size > limit {
+        return Err(BuildError::TableTooLarge { size, limit });
+    }
+
+    Ok(())
+}
+
+impl From<SMBiosData> for SMBiosDataMut {
+    fn from(data: SMBiosData) -> Self {
+        let version = data.version;
+        Self {
+            structs: data.into_iter().collect(),
+            version,
+        }
+    }
+}
+
+impl From<SMBiosDataMut> for SMBiosData {
+    fn from(data: SMBiosDataMut) -> Self {
+        SMBiosData::from_vec_and_version(data.raw_bytes(), data.version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{MemoryDeviceBuilder, SMBiosUnknownBuilder, SystemInformationBuilder};
+    use crate::core::Header;
+    use crate::structs::{
+        SMBiosBaseboardInformation, SMBiosGroupAssociations, SMBiosInactive,
+        SMBiosMemoryArrayMappedAddress, SMBiosMemoryDevice, SMBiosPhysicalMemoryArray,
+        SMBiosSystemInformation,
+    };
+
+    fn test_data() -> SMBiosData {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add(
+                SystemInformationBuilder::new()
+                    .manufacturer("Manufacturer")
+                    .product_name("Product")
+                    .serial_number("Serial-0001")
+                    .family("Manufacturer"),
+            )
+            .unwrap();
+        // Memory Array Mapped Address (Type 19) without strings
+        builder
+            .add(
+                StructBuilder::new(19)
+                    .dword(0)
+                    .dword(0x003F_FFFF)
+                    .handle(Handle(0))
+                    .byte(1),
+            )
+            .unwrap();
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_edit_strings() {
+        let mut data = SMBiosDataMut::from(test_data());
+
+        let mut system_information = data.find_by_handle_mut(&Handle(0)).unwrap();
+        assert_eq!(system_information.struct_type(), 1);
+        // replace the serial number with a longer string
+        system_information
+            .set_field_string(0x07, "Serial-0001-Clone")
+            .unwrap();
+        // version had no string, an existing identical string is shared
+        system_information
+            .set_field_string(0x06, "Product")
+            .unwrap();
+        // SKU number had no string, a new string is added
+        system_information.set_field_string(0x19, "SKU").unwrap();
+        // remove the product name
+        system_information.set_field_string(0x05, "").unwrap();
+        // non ISO-8859-1 characters and NUL are rejected
+        assert!(matches!(
+            system_information.set_field_string(0x04, "Manufacturer\u{263A}"),
+            Err(BuildError::InvalidCharacter {
+                handle: Handle(0),
+                character: '\u{263A}'
+            })
+        ));
+        assert!(matches!(
+            system_information.set_field_string(0x04, "Manufacturer\u{0}"),
+            Err(BuildError::InvalidCharacter { .. })
+        ));
+        // the manufacturer string is shared with the family, which keeps it
+        system_information
+            .set_field_string(0x04, "Manufacturer \u{00C6}")
+            .unwrap();
+
+        let sys_info = system_information
+            .as_type::<SMBiosSystemInformation<'_>>()
+            .unwrap();
+        assert_eq!(
+            sys_info.manufacturer(),
+            Some("Manufacturer \u{00C6}".to_string())
+        );
+        assert_eq!(sys_info.product_name(), None);
+        assert_eq!(sys_info.version(), Some("Product".to_string()));
+        assert_eq!(
+            sys_info.serial_number(),
+            Some("Serial-0001-Clone".to_string())
+        );
+        assert_eq!(sys_info.sku_number(), Some("SKU".to_string()));
+        assert_eq!(sys_info.family(), Some("Manufacturer".to_string()));
+
+        // the table is reparsed with the new string offsets
+        let data = SMBiosData::from(data);
+        let sys_info = data.first::<SMBiosSystemInformation<'_>>().unwrap();
+        assert_eq!(
+            sys_info.serial_number(),
+            Some("Serial-0001-Clone".to_string())
+        );
+        assert_eq!(sys_info.sku_number(), Some("SKU".to_string()));
+        assert!(data.first::<SMBiosMemoryArrayMappedAddress<'_>>().is_some());
+        assert_eq!(data.iter().count(), 3);
+        assert!(data.warnings().is_empty());
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 4, 0)));
+    }
+
+    #[test]
+    fn test_edit_fields() {
+        let mut data = SMBiosDataMut::from(test_data());
+
+        for mut smbios_struct in data.iter_mut() {
+            if smbios_struct.struct_type() == 19 {
+                smbios_struct.set_field_dword(0x08, 0x007F_FFFF).unwrap();
+                smbios_struct.set_field_handle(0x0C, Handle(0x10)).unwrap();
+                smbios_struct.set_field_byte(0x0E, 2).unwrap();
+                assert!(matches!(
+                    smbios_struct.set_field_word(0x0E, 0),
+                    Err(BuildError::FieldOutOfRange {
+                        handle: Handle(1),
+                        offset: 0x0E,
+                        length: 0x0F
+                    })
+                ));
+                assert!(matches!(
+                    smbios_struct.set_field_byte(0x00, 0),
+                    Err(BuildError::FieldOutOfRange { .. })
+                ));
+                assert!(matches!(
+                    smbios_struct.set_field_string(0x0F, "string"),
+                    Err(BuildError::FieldOutOfRange { .. })
+                ));
+            }
+        }
+
+        let raw = data.raw_bytes();
+        let data = SMBiosData::from(data);
+        assert_eq!(raw, data.raw_bytes());
+        let mapped_address = data.first::<SMBiosMemoryArrayMappedAddress<'_>>().unwrap();
+        assert_eq!(mapped_address.ending_address(), Some(0x007F_FFFF));
+        assert_eq!(
+            mapped_address.physical_memory_array_handle(),
+            Some(Handle(0x10))
+        );
+        assert_eq!(mapped_address.partition_width(), Some(2));
+    }
+
+    #[test]
+    fn test_unmodified_round_trip() {
+        let data = test_data();
+        let raw = data.raw_bytes();
+        assert_eq!(SMBiosDataMut::from(data).raw_bytes(), raw);
+    }
+
+    fn references_data() -> SMBiosData {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        // Physical Memory Array (Type 16) without error information
+        builder
+            .add_with_handle(
+                Handle(0x0100),
+                StructBuilder::new(16)
+                    .data(&[0x03, 0x03, 0x03])
+                    .dword(0x0100_0000)
+                    .handle(Handle(0xFFFE))
+                    .word(1),
+            )
+            .unwrap();
+        builder
+            .add_with_handle(Handle(0x0200), MemoryDeviceBuilder::new(Handle(0x0100)))
+            .unwrap();
+        // Group Associations (Type 14) of the memory device and array
+        builder
+            .add_with_handle(
+                Handle(0x0300),
+                StructBuilder::new(14)
+                    .string("Memory")
+                    .byte(17)
+                    .handle(Handle(0x0200))
+                    .byte(16)
+                    .handle(Handle(0x0100)),
+            )
+            .unwrap();
+        // Baseboard Information (Type 2) of a chassis not in the table
+        builder
+            .add_with_handle(
+                Handle(0x0400),
+                StructBuilder::new(2)
+                    .data(&[0; 7])
+                    .handle(Handle(0x0500))
+                    .byte(0x0A)
+                    .byte(1)
+                    .handle(Handle(0x0200)),
+            )
+            .unwrap();
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_handle_field_offsets() {
+        let mut data = SMBiosDataMut::from(references_data());
+        let offsets: Vec<Vec<usize>> = data
+            .iter_mut()
+            .map(|smbios_struct| smbios_struct.handle_field_offsets())
+            .collect();
+        assert_eq!(
+            offsets,
+            vec![
+                vec![0x0B],
+                vec![0x04, 0x06],
+                vec![0x06, 0x09],
+                vec![0x0B, 0x0F],
+                vec![]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_renumber_handles() {
+        let mut data = SMBiosDataMut::from(references_data());
+        data.renumber_handles().unwrap();
+
+        let data = SMBiosData::from(data);
+        let handles: Vec<u16> = data
+            .iter()
+            .map(|smbios_struct| *smbios_struct.header.handle())
+            .collect();
+        assert_eq!(handles, vec![0, 1, 2, 3, 4]);
+
+        let physical_memory_array = data.first::<SMBiosPhysicalMemoryArray<'_>>().unwrap();
+        assert_eq!(
+            physical_memory_array.memory_error_information_handle(),
+            Some(Handle(0xFFFE))
+        );
+        let memory_device = data.first::<SMBiosMemoryDevice<'_>>().unwrap();
+        assert_eq!(
+            memory_device.physical_memory_array_handle(),
+            Some(Handle(0))
+        );
+        assert_eq!(
+            memory_device.memory_error_information_handle(),
+            Some(Handle(0xFFFE))
+        );
+        let group_associations = data.first::<SMBiosGroupAssociations<'_>>().unwrap();
+        let items: Vec<Option<Handle>> = group_associations
+            .item_iterator()
+            .map(|item| item.item_handle())
+            .collect();
+        assert_eq!(items, vec![Some(Handle(1)), Some(Handle(0))]);
+        let baseboard = data.first::<SMBiosBaseboardInformation<'_>>().unwrap();
+        assert_eq!(baseboard.chassis_handle(), Some(Handle(0xFFFF)));
+        let contained: Vec<Handle> = baseboard.contained_object_handle_iterator().collect();
+        assert_eq!(contained, vec![Handle(1)]);
+    }
+
+    #[test]
+    fn test_renumber_dangling_handles() {
+        let mut data = SMBiosDataMut::from(references_data());
+        // a dangling reference to the handle the memory device is renumbered to
+        data.find_by_handle_mut(&Handle(0x0400))
+            .unwrap()
+            .set_field_word(0x0B, 0x0001)
+            .unwrap();
+        assert!(data.find_by_handle(&Handle(0x0001)).is_none());
+
+        data.renumber_handles().unwrap();
+        let memory_device = data.find_by_handle(&Handle(1)).unwrap();
+        assert_eq!(memory_device.header.struct_type(), 17);
+        let data = SMBiosData::from(data);
+        let baseboard = data.first::<SMBiosBaseboardInformation<'_>>().unwrap();
+        assert_eq!(baseboard.chassis_handle(), Some(Handle(0xFFFF)));
+    }
+
+    #[test]
+    fn test_renumber_duplicate_handles() {
+        let mut data = SMBiosDataMut::from(references_data());
+        // the group associations collide with the physical memory array
+        data.find_by_handle_mut(&Handle(0x0300))
+            .unwrap()
+            .set_handle(Handle(0x0100));
+        assert_eq!(
+            data.iter()
+                .filter(|smbios_struct| smbios_struct.header.handle() == Handle(0x0100))
+                .count(),
+            2
+        );
+
+        data.renumber_handles().unwrap();
+        let memory_device = data.find_by_handle(&Handle(1)).unwrap();
+        assert_eq!(memory_device.header.struct_type(), 17);
+        assert_eq!(memory_device.get_field_handle(0x04), Some(Handle(0)));
+        let group_associations = data.find_by_handle(&Handle(2)).unwrap();
+        assert_eq!(group_associations.header.struct_type(), 14);
+        assert_eq!(group_associations.get_field_handle(0x09), Some(Handle(0)));
+    }
+
+    #[test]
+    fn test_append() {
+        let mut data = SMBiosDataMut::from(test_data());
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 5, 0));
+        let physical_memory_array = builder
+            .add(StructBuilder::new(16).data(&[0x03, 0x03, 0x03, 0, 0, 0, 0, 0xFE, 0xFF, 1, 0]))
+            .unwrap();
+        builder
+            .add(MemoryDeviceBuilder::new(physical_memory_array))
+            .unwrap();
+        builder
+            .add_with_handle(Handle(0x10), StructBuilder::new(0x80).string("OEM"))
+            .unwrap();
+        data.append(builder.build().unwrap()).unwrap();
+
+        let structs: Vec<(u8, u16)> = data
+            .iter()
+            .map(|smbios_struct| {
+                (
+                    smbios_struct.header.struct_type(),
+                    *smbios_struct.header.handle(),
+                )
+            })
+            .collect();
+        // the appended handles 0 and 1 collide, 0x10 does not, and the
+        // End-of-Table of the first table is moved to the end
+        assert_eq!(
+            structs,
+            vec![(1, 0), (19, 1), (16, 3), (17, 4), (0x80, 0x10), (127, 2)]
+        );
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 5, 0)));
+
+        let data = SMBiosData::from(data);
+        let memory_device = data.first::<SMBiosMemoryDevice<'_>>().unwrap();
+        assert_eq!(
+            memory_device.physical_memory_array_handle(),
+            Some(Handle(3))
+        );
+        // references of the first table are unchanged
+        let mapped_address = data.first::<SMBiosMemoryArrayMappedAddress<'_>>().unwrap();
+        assert_eq!(
+            mapped_address.physical_memory_array_handle(),
+            Some(Handle(0))
+        );
+        assert!(data.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_append_table_too_large() {
+        let mut data = SMBiosDataMut::from(SMBiosData::from_vec_and_version(
+            test_data().raw_bytes(),
+            Some(SMBiosVersion::new(2, 8, 0)),
+        ));
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(2, 8, 0));
+        for _ in 0..300 {
+            builder
+                .add(StructBuilder::new(0x80).data(&[0; 250]))
+                .unwrap();
+        }
+        let oem_data = builder.build().unwrap();
+        let raw = data.raw_bytes();
+
+        assert!(matches!(
+            data.append(oem_data),
+            Err(BuildError::TableTooLarge { limit: 0xFFFF, .. })
+        ));
+        assert_eq!(data.raw_bytes(), raw);
+
+        // the first table has no End-of-Table structure
+        let mut data = SMBiosDataMut::from(SMBiosData::from_vec_and_version(
+            raw[..raw.len() - 6].to_vec(),
+            None,
+        ));
+        data.append(test_data()).unwrap();
+        let handles: Vec<u16> = data
+            .iter()
+            .map(|smbios_struct| *smbios_struct.header.handle())
+            .collect();
+        // the End-of-Table keeps its free handle 2
+        assert_eq!(handles, vec![0, 1, 3, 4, 2]);
+        assert_eq!(
+            data.find_by_handle(&Handle(4))
+                .unwrap()
+                .get_field_handle(0x0C),
+            Some(Handle(3))
+        );
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 4, 0)));
+    }
+
+    #[test]
+    fn test_remove_and_inactivate() {
+        let mut data = SMBiosDataMut::from(references_data());
+
+        let removed = data.remove(&Handle(0x0300)).unwrap();
+        assert_eq!(removed.header.struct_type(), 14);
+        assert!(data.remove(&Handle(0x0300)).is_none());
+
+        assert!(data.inactivate(&Handle(0x0400)));
+        assert!(!data.inactivate(&Handle(0x0500)));
+        let inactive = data.find_by_handle(&Handle(0x0400)).unwrap();
+        assert_eq!(inactive.header.struct_type(), 126);
+        assert_eq!(inactive.header.length(), 0x11);
+        assert!(inactive.fields[Header::SIZE..]
+            .iter()
+            .all(|byte| *byte == 0));
+
+        let data = SMBiosData::from(data);
+        let handles: Vec<(u8, u16)> = data
+            .iter()
+            .map(|smbios_struct| {
+                (
+                    smbios_struct.header.struct_type(),
+                    *smbios_struct.header.handle(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            handles,
+            vec![(16, 0x0100), (17, 0x0200), (126, 0x0400), (127, 0)]
+        );
+        assert!(data.first::<SMBiosInactive<'_>>().is_some());
+        assert!(data.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_add_oem_structure() {
+        let mut data = SMBiosDataMut::from(test_data());
+        let handle = data
+            .add(SMBiosUnknownBuilder::new(0x80, &[0x01, 0xAA, 0x02]).strings(["OEM", "OEM"]))
+            .unwrap();
+        assert_eq!(handle, Handle(3));
+        assert!(matches!(
+            data.add_with_handle(Handle(0), SMBiosUnknownBuilder::new(0x81, &[])),
+            Err(BuildError::DuplicateHandle(Handle(0)))
+        ));
+        assert!(matches!(
+            data.add_with_handle(Handle(0xFF00), SMBiosUnknownBuilder::new(0x81, &[])),
+            Err(BuildError::ReservedHandle(Handle(0xFF00)))
+        ));
+        assert!(matches!(
+            data.add(SMBiosUnknownBuilder::new(0x81, &[]).string("")),
+            Err(BuildError::EmptyString(Handle(4)))
+        ));
+
+        let data = SMBiosData::from(data);
+        let types: Vec<u8> = data
+            .iter()
+            .map(|smbios_struct| smbios_struct.header.struct_type())
+            .collect();
+        assert_eq!(types, vec![1, 19, 0x80, 127]);
+        let oem = data.find_by_handle(&Handle(3)).unwrap();
+        assert_eq!(oem.header.length(), 7);
+        assert_eq!(oem.get_field_data(4, 7), Some(&[0x01, 0xAA, 0x02][..]));
+        // identical strings are not shared
+        assert_eq!(oem.strings.iter().len(), 2);
+        assert_eq!(oem.get_field_string(0x06), Some("OEM".to_string()));
+    }
+}
@@ -0,0 +1,274 @@
+use super::BuildError;
+use crate::core::{string_field_offsets, Handle, Strings, UndefinedStruct};
+use crate::structs::{
+    SMBiosBiosLanguageInformation, SMBiosOemStrings, SMBiosStruct, SMBiosSystemConfigurationOptions,
+};
+#[cfg(feature = "no_std")]
+use alloc::{string::String, vec::Vec};
+
+/// # String-Set Editor
+///
+/// Appends, replaces and deletes the strings of a structure, see
+/// [crate::DefinedStructMut::string_set]. Strings are addressed by their
+/// 1 based index.
+///
+/// Strings are stored as ISO-8859-1 and must not be empty, contain NUL or
+/// characters outside of ISO-8859-1. The string-set is written back with
+/// each string NUL terminated and the set terminated by an additional NUL
+/// (two NULs when the set is empty).
+///
+/// When a string is deleted the following strings move down by one, the
+/// string fields of the structure (see
+/// [crate::DefinedStructMut::string_field_offsets]) are updated to match.
+/// Fields of structure types not defined by the SMBIOS specification are
+/// not known and therefore not updated. The count of strings of OEM Strings
+/// (Type 11), System Configuration Options (Type 12) and BIOS Language
+/// Information (Type 13) structures is kept up to date.
+pub struct StringSet<'a> {
+    parts: &'a mut UndefinedStruct,
+}
+
+impl<'a> StringSet<'a> {
+    pub(super) fn new(parts: &'a mut UndefinedStruct) -> Self {
+        Self { parts }
+    }
+
+    /// Number of strings
+    pub fn len(&self) -> usize {
+        self.parts.strings.iter().len()
+    }
+
+    /// Whether the string-set holds no strings
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The string at the given index
+    pub fn get(&self, index: u8) -> Option<String> {
+        self.parts.strings.get_string(index)
+    }
+
+    /// Iterates the strings
+    pub fn iter(&self) -> Strings {
+        (&self.parts.strings).into_iter()
+    }
+
+    /// Appends a string, returning its index
+    pub fn append(&mut self, value: &str) -> Result<u8, BuildError> {
+        let encoded = self.encode(value)?;
+        let mut strings = self.strings();
+        if strings.len() >= u8::MAX as usize {
+            return Err(BuildError::StringsExhausted(self.handle()));
+        }
+
+        strings.push(encoded);
+        let index = strings.len() as u8;
+        self.set_strings(strings);
+        Ok(index)
+    }
+
+    /// Replaces the string at the given index
+    pub fn replace(&mut self, index: u8, value: &str) -> Result<(), BuildError> {
+        let encoded = self.encode(value)?;
+        let mut strings = self.strings();
+        match (index as usize)
+            .checked_sub(1)
+            .and_then(|position| strings.get_mut(position))
+        {
+            Some(string) => *string = encoded,
+            None => return Err(self.not_found(index)),
+        }
+
+        self.set_strings(strings);
+        Ok(())
+    }
+
+    /// Deletes the string at the given index
+    ///
+    /// String fields referencing the deleted string are set to 0 (no
+    /// string), those referencing a following string are decremented.
+    pub fn delete(&mut self, index: u8) -> Result<(), BuildError> {
+        let mut strings = self.strings();
+        if index == 0 || index as usize > strings.len() {
+            return Err(self.not_found(index));
+        }
+
+        strings.remove(index as usize - 1);
+        for offset in string_field_offsets(self.parts) {
+            let field = &mut self.parts.fields[offset];
+            if *field == index {
+                *field = 0;
+            } else if *field > index {
+                *field -= 1;
+            }
+        }
+
+        self.set_strings(strings);
+        Ok(())
+    }
+
+    fn handle(&self) -> Handle {
+        self.parts.header.handle()
+    }
+
+    fn not_found(&self, index: u8) -> BuildError {
+        BuildError::StringNotFound {
+            handle: self.handle(),
+            index,
+        }
+    }
+
+    pub(super) fn encode(&self, value: &str) -> Result<Vec<u8>, BuildError> {
+        if value.is_empty() {
+            return Err(BuildError::EmptyString(self.handle()));
+        }
+
+        value
+            .chars()
+            .map(|character| match character as u32 {
+                0x01..=0xFF => Ok(character as u8),
+                _ => Err(BuildError::InvalidCharacter {
+                    handle: self.handle(),
+                    character,
+                }),
+            })
+            .collect()
+    }
+
+    fn strings(&self) -> Vec<Vec<u8>> {
+        self.parts.strings.iter().cloned().collect()
+    }
+
+    fn set_strings(&mut self, strings: Vec<Vec<u8>>) {
+        match self.parts.header.struct_type() {
+            SMBiosOemStrings::STRUCT_TYPE
+            | SMBiosSystemConfigurationOptions::STRUCT_TYPE
+            | SMBiosBiosLanguageInformation::STRUCT_TYPE => {
+                if let Some(count) = self.parts.fields.get_mut(0x04) {
+                    *count = strings.len() as u8;
+                }
+            }
+            _ => (),
+        }
+
+        let decoding = self.parts.strings.decoding();
+        self.parts.strings = Strings::new(strings.join(&0));
+        self.parts.strings.set_decoding(decoding);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{
+        SMBiosDataMut, SMBiosTableBuilder, StructBuilder, SystemInformationBuilder,
+    };
+    use crate::core::{SMBiosData, SMBiosVersion, StructFields};
+    use crate::structs::SMBiosSystemInformation;
+
+    fn test_data() -> SMBiosDataMut {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add(
+                SystemInformationBuilder::new()
+                    .manufacturer("Manufacturer")
+                    .product_name("Product")
+                    .serial_number("Serial")
+                    .family("Family"),
+            )
+            .unwrap();
+        // OEM Strings (Type 11) without strings
+        builder.add(StructBuilder::new(11).byte(0)).unwrap();
+        SMBiosDataMut::from(builder.build().unwrap())
+    }
+
+    #[test]
+    fn test_string_set_edits() {
+        let mut data = test_data();
+        let mut system_information = data.find_by_handle_mut(&Handle(0)).unwrap();
+        let mut strings = system_information.string_set();
+        assert_eq!(strings.len(), 4);
+        assert_eq!(strings.get(2), Some("Product".to_string()));
+
+        assert_eq!(strings.append("SKU").unwrap(), 5);
+        strings.replace(3, "Serial-Clone").unwrap();
+        // product name moves serial number, family and SKU down by one
+        strings.delete(2).unwrap();
+        assert_eq!(
+            strings.iter().collect::<Vec<String>>(),
+            vec!["Manufacturer", "Serial-Clone", "Family", "SKU"]
+        );
+        system_information.set_field_byte(0x19, 4).unwrap();
+
+        let data = SMBiosData::from(data);
+        let sys_info = data.first::<SMBiosSystemInformation<'_>>().unwrap();
+        assert_eq!(sys_info.manufacturer(), Some("Manufacturer".to_string()));
+        assert_eq!(sys_info.product_name(), None);
+        assert_eq!(sys_info.serial_number(), Some("Serial-Clone".to_string()));
+        assert_eq!(sys_info.sku_number(), Some("SKU".to_string()));
+        assert_eq!(sys_info.family(), Some("Family".to_string()));
+    }
+
+    #[test]
+    fn test_string_set_validation() {
+        let mut data = test_data();
+        let mut system_information = data.find_by_handle_mut(&Handle(0)).unwrap();
+        let mut strings = system_information.string_set();
+
+        assert!(matches!(
+            strings.append(""),
+            Err(BuildError::EmptyString(Handle(0)))
+        ));
+        assert!(matches!(
+            strings.append("NUL\0"),
+            Err(BuildError::InvalidCharacter {
+                handle: Handle(0),
+                character: '\0'
+            })
+        ));
+        assert!(matches!(
+            strings.replace(1, "\u{263A}"),
+            Err(BuildError::InvalidCharacter {
+                handle: Handle(0),
+                character: '\u{263A}'
+            })
+        ));
+        assert!(matches!(
+            strings.replace(5, "string"),
+            Err(BuildError::StringNotFound {
+                handle: Handle(0),
+                index: 5
+            })
+        ));
+        assert!(matches!(
+            strings.delete(0),
+            Err(BuildError::StringNotFound {
+                handle: Handle(0),
+                index: 0
+            })
+        ));
+        // ISO-8859-1 characters are accepted
+        strings.replace(1, "Manufacturer \u{00C6}").unwrap();
+        assert_eq!(strings.get(1), Some("Manufacturer \u{00C6}".to_string()));
+        assert_eq!(strings.len(), 4);
+    }
+
+    #[test]
+    fn test_string_set_count_and_terminator() {
+        let mut data = test_data();
+        let mut oem_strings = data.find_by_handle_mut(&Handle(1)).unwrap();
+        for string in ["OEM 1", "OEM 2", "OEM 3"].iter() {
+            oem_strings.string_set().append(string).unwrap();
+        }
+        assert_eq!(oem_strings.parts().get_field_byte(0x04), Some(3));
+
+        let mut strings = oem_strings.string_set();
+        for _ in 0..3 {
+            strings.delete(1).unwrap();
+        }
+        assert!(strings.is_empty());
+        assert_eq!(oem_strings.parts().get_field_byte(0x04), Some(0));
+        // an empty string-set is terminated by two NULs
+        assert_eq!(oem_strings.parts().raw(), vec![11, 5, 1, 0, 0, 0, 0]);
+    }
+}
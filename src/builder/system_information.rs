@@ -0,0 +1,112 @@
+use super::StructBuilder;
+use crate::structs::{SMBiosStruct, SMBiosSystemInformation};
+#[cfg(feature = "no_std")]
+use alloc::string::{String, ToString};
+
+/// # System Information (Type 1) Builder
+///
+/// Builds the SMBIOS 2.4 and later layout (1Bh bytes). Strings which are
+/// not set are left empty (no string).
+pub struct SystemInformationBuilder {
+    manufacturer: String,
+    product_name: String,
+    version: String,
+    serial_number: String,
+    uuid: [u8; 16],
+    wakeup_type: u8,
+    sku_number: String,
+    family: String,
+}
+
+impl SystemInformationBuilder {
+    /// Creates a builder with no UUID (all 00h) and an unknown wake-up type
+    pub fn new() -> Self {
+        Self {
+            manufacturer: String::new(),
+            product_name: String::new(),
+            version: String::new(),
+            serial_number: String::new(),
+            uuid: [0; 16],
+            wakeup_type: 0x02,
+            sku_number: String::new(),
+            family: String::new(),
+        }
+    }
+
+    /// Manufacturer
+    pub fn manufacturer(mut self, manufacturer: &str) -> Self {
+        self.manufacturer = manufacturer.to_string();
+        self
+    }
+
+    /// Product name
+    pub fn product_name(mut self, product_name: &str) -> Self {
+        self.product_name = product_name.to_string();
+        self
+    }
+
+    /// Version
+    pub fn version(mut self, version: &str) -> Self {
+        self.version = version.to_string();
+        self
+    }
+
+    /// Serial number
+    pub fn serial_number(mut self, serial_number: &str) -> Self {
+        self.serial_number = serial_number.to_string();
+        self
+    }
+
+    /// Universal unique ID number, in the byte order stored in the structure
+    ///
+    /// Since SMBIOS 2.6 the first three fields of the UUID are stored
+    /// little-endian.
+    pub fn uuid(mut self, uuid: [u8; 16]) -> Self {
+        self.uuid = uuid;
+        self
+    }
+
+    /// Universal unique ID number, stored in the SMBIOS 2.6 and later byte order
+    #[cfg(feature = "uuid")]
+    pub fn system_uuid(self, uuid: uuid::Uuid) -> Self {
+        self.uuid(uuid.to_bytes_le())
+    }
+
+    /// Wake-up type, see [crate::SystemWakeUpType] for the values
+    pub fn wakeup_type(mut self, wakeup_type: u8) -> Self {
+        self.wakeup_type = wakeup_type;
+        self
+    }
+
+    /// SKU number
+    pub fn sku_number(mut self, sku_number: &str) -> Self {
+        self.sku_number = sku_number.to_string();
+        self
+    }
+
+    /// Family
+    pub fn family(mut self, family: &str) -> Self {
+        self.family = family.to_string();
+        self
+    }
+}
+
+impl Default for SystemInformationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<SystemInformationBuilder> for StructBuilder {
+    fn from(builder: SystemInformationBuilder) -> Self {
+        StructBuilder::new(SMBiosSystemInformation::STRUCT_TYPE)
+            .string(&builder.manufacturer)
+            .string(&builder.product_name)
+            .string(&builder.version)
+            .string(&builder.serial_number)
+            .data(&builder.uuid)
+            .byte(builder.wakeup_type)
+            .string(&builder.sku_number)
+            .string(&builder.family)
+    }
+}
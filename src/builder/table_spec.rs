@@ -0,0 +1,233 @@
+use super::{BuildError, SMBiosTableBuilder, StructBuilder};
+use crate::core::{Handle, SMBiosData, SMBiosVersion};
+#[cfg(feature = "no_std")]
+use alloc::{string::String, vec::Vec};
+use serde::Deserialize;
+
+/// # SMBIOS Table Specification
+///
+/// A declarative description of a structure table, deserialized from any
+/// serde format such as JSON or TOML, from which the table is generated
+/// with [TableSpec::build]. For example in TOML:
+///
+/// ```toml
+/// version = { major = 3, minor = 4 }
+///
+/// [[structures]]
+/// type = 1
+/// fields = [
+///     { string = "Manufacturer" },
+///     { string = "Product" },
+///     { string = "" },
+///     { string = "Serial" },
+///     { data = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0] },
+///     { byte = 6 },
+/// ]
+///
+/// [[structures]]
+/// type = 11
+/// handle = 0x10
+/// fields = [{ byte = 2 }]
+/// strings = ["OEM 1", "OEM 2"]
+/// ```
+///
+/// Structures are added in order, with the given handle or the next free
+/// one, and the table is terminated by an End-of-Table (Type 127) structure
+/// unless the last structure is one.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TableSpec {
+    /// SMBIOS version of the table
+    pub version: SMBiosVersion,
+    /// Structures of the table, in order
+    #[serde(default)]
+    pub structures: Vec<StructSpec>,
+}
+
+/// # SMBIOS Structure Specification
+///
+/// A structure of a [TableSpec], see [StructBuilder].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StructSpec {
+    /// Type of the structure
+    #[serde(rename = "type")]
+    pub struct_type: u8,
+    /// Handle of the structure, the next free handle when absent
+    #[serde(default)]
+    pub handle: Option<u16>,
+    /// Fields of the formatted area following the header, in order
+    #[serde(default)]
+    pub fields: Vec<FieldSpec>,
+    /// Strings appended to the string-set after those of string fields,
+    /// without a field referencing them (see [StructBuilder::append_string])
+    #[serde(default)]
+    pub strings: Vec<String>,
+}
+
+/// # SMBIOS Field Specification
+///
+/// A field of a [StructSpec], written as a single entry map of its kind
+/// and value (for example `{ "word": 4096 }` in JSON).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldSpec {
+    /// A BYTE field
+    Byte(u8),
+    /// A WORD field
+    Word(u16),
+    /// A DWORD field
+    Dword(u32),
+    /// A QWORD field
+    Qword(u64),
+    /// A [Handle] field referencing another structure
+    Handle(u16),
+    /// A block of bytes
+    Data(Vec<u8>),
+    /// A string field (see [StructBuilder::string])
+    String(String),
+}
+
+impl TableSpec {
+    /// Parses a specification from JSON
+    #[cfg(feature = "serde_json")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Generates the table
+    ///
+    /// The table bytes are available with [SMBiosData::raw_bytes].
+    pub fn build(&self) -> Result<SMBiosData, BuildError> {
+        let mut builder = SMBiosTableBuilder::new(self.version);
+        for struct_spec in self.structures.iter() {
+            match struct_spec.handle {
+                Some(handle) => builder.add_with_handle(Handle(handle), struct_spec)?,
+                None => builder.add(struct_spec)?,
+            };
+        }
+
+        builder.build()
+    }
+}
+
+impl From<&StructSpec> for StructBuilder {
+    fn from(spec: &StructSpec) -> Self {
+        let mut builder = StructBuilder::new(spec.struct_type);
+        for field in spec.fields.iter() {
+            builder = match field {
+                FieldSpec::Byte(value) => builder.byte(*value),
+                FieldSpec::Word(value) => builder.word(*value),
+                FieldSpec::Dword(value) => builder.dword(*value),
+                FieldSpec::Qword(value) => builder.qword(*value),
+                FieldSpec::Handle(value) => builder.handle(Handle(*value)),
+                FieldSpec::Data(value) => builder.data(value),
+                FieldSpec::String(value) => builder.string(value),
+            };
+        }
+
+        for string in spec.strings.iter() {
+            builder = builder.append_string(string);
+        }
+
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::{SMBiosOemStrings, SMBiosSystemInformation};
+
+    fn test_spec() -> TableSpec {
+        TableSpec {
+            version: SMBiosVersion::new(3, 4, 0),
+            structures: vec![
+                StructSpec {
+                    struct_type: 1,
+                    handle: None,
+                    fields: vec![
+                        FieldSpec::String("Manufacturer".into()),
+                        FieldSpec::String("Product".into()),
+                        FieldSpec::String("".into()),
+                        FieldSpec::String("Serial".into()),
+                        FieldSpec::Data(vec![0; 16]),
+                        FieldSpec::Byte(6),
+                    ],
+                    strings: vec![],
+                },
+                StructSpec {
+                    struct_type: 11,
+                    handle: Some(0x10),
+                    fields: vec![FieldSpec::Byte(2)],
+                    strings: vec!["OEM 1".into(), "OEM 2".into()],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_table_spec_build() {
+        let data = test_spec().build().unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 4, 0)));
+
+        let handles: Vec<Handle> = data
+            .iter()
+            .map(|undefined_struct| undefined_struct.header.handle())
+            .collect();
+        assert_eq!(handles, vec![Handle(0), Handle(0x10), Handle(1)]);
+
+        let sys_info = data.first::<SMBiosSystemInformation<'_>>().unwrap();
+        assert_eq!(sys_info.manufacturer(), Some("Manufacturer".to_string()));
+        assert_eq!(sys_info.version(), None);
+        assert_eq!(sys_info.serial_number(), Some("Serial".to_string()));
+        let oem_strings = data.first::<SMBiosOemStrings<'_>>().unwrap();
+        assert_eq!(oem_strings.oem_strings().iter().count(), 2);
+
+        let mut spec = test_spec();
+        spec.structures[0].handle = Some(0x10);
+        assert!(matches!(
+            spec.build(),
+            Err(BuildError::DuplicateHandle(Handle(0x10)))
+        ));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_table_spec_from_json() {
+        let spec = TableSpec::from_json(
+            r#"{
+                "version": { "major": 3, "minor": 4 },
+                "structures": [
+                    {
+                        "type": 1,
+                        "fields": [
+                            { "string": "Manufacturer" },
+                            { "string": "Product" },
+                            { "string": "" },
+                            { "string": "Serial" },
+                            { "data": [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0] },
+                            { "byte": 6 }
+                        ]
+                    },
+                    {
+                        "type": 11,
+                        "handle": 16,
+                        "fields": [{ "byte": 2 }],
+                        "strings": ["OEM 1", "OEM 2"]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            spec.build().unwrap().raw_bytes(),
+            test_spec().build().unwrap().raw_bytes()
+        );
+        assert!(
+            TableSpec::from_json(r#"{ "version": { "major": 3, "minor": 4 }, "tables": [] }"#)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use super::{BuildError, StructBuilder};
+use crate::core::{SMBiosData, SMBiosEntryPoint32, SMBiosEntryPoint64};
+#[cfg(not(feature = "no_std"))]
+use crate::Error;
+#[cfg(feature = "no_std")]
+use alloc::{vec, vec::Vec};
+use core::convert::TryInto;
+#[cfg(not(feature = "no_std"))]
+use std::fs::write;
+
+/// Writes the structure table along with an entry point describing it
+impl SMBiosData {
+    /// Writes the SMBios table data to a `dmidecode --dump-bin` file
+    ///
+    /// See [SMBiosData::to_dump_bin] for the layout of the file. Fails with
+    /// [Error::Build] when the image cannot be created and with
+    /// [Error::OsError] when the file cannot be written.
+    #[cfg(not(feature = "no_std"))]
+    pub fn write_dump_bin(&self, filename: &str) -> Result<(), Error> {
+        write(filename, self.to_dump_bin()?)?;
+        Ok(())
+    }
+
+    /// Creates a `dmidecode --dump-bin` image of the SMBios table data
+    ///
+    /// The image consists of an entry point, padded to 20h bytes, followed by
+    /// the structure table. The structure table address of the entry point is
+    /// set to 20h, the offset of the table within the image.
+    ///
+    /// A 64-bit (_SM3_) entry point is written for SMBIOS 3.0 and later,
+    /// otherwise a 32-bit (_SM_) entry point is written.
+    ///
+    /// Fails when `version` is [None] or the table does not fit the
+    /// 32-bit entry point.
+    #[cfg(not(feature = "no_std"))]
+    pub fn to_dump_bin(&self) -> Result<Vec<u8>, BuildError> {
+        const TABLE_OFFSET: usize = 0x20;
+
+        let version = self.version.ok_or(BuildError::MissingVersion)?;
+        let entry_point = if version.major >= 3 {
+            self.entry_point_64(TABLE_OFFSET as u64)?
+        } else {
+            self.entry_point_32(TABLE_OFFSET as u32)?
+        };
+
+        let mut image = entry_point;
+        image.resize(TABLE_OFFSET, 0);
+        image.append(&mut self.raw_bytes());
+        Ok(image)
+    }
+
+    /// Creates a 32-bit (_SM_) entry point for the structure table
+    ///
+    /// `structure_table_address` is the physical address at which the
+    /// structure table ([SMBiosData::raw_bytes]) is placed.
+    ///
+    /// Fails when `version` is [None] or the table exceeds the 64 KiB or
+    /// 65535 structure limits of a 32-bit entry point.
+    pub fn entry_point_32(&self, structure_table_address: u32) -> Result<Vec<u8>, BuildError> {
+        let version = self.version.ok_or(BuildError::MissingVersion)?;
+
+        let struct_sizes: Vec<usize> = self
+            .iter()
+            .map(|undefined_struct| undefined_struct.raw().len())
+            .collect();
+        let table_size: usize = struct_sizes.iter().sum();
+        let table_length: u16 = table_size
+            .try_into()
+            .map_err(|_| BuildError::TableTooLarge {
+                size: table_size,
+                limit: u16::MAX as usize,
+            })?;
+        let number_of_structures: u16 =
+            struct_sizes
+                .len()
+                .try_into()
+                .map_err(|_| BuildError::TooManyStructures {
+                    limit: u16::MAX as usize,
+                })?;
+        let maximum_structure_size = struct_sizes.iter().copied().max().unwrap_or(0);
+        // The BCD revision is 00h when the version cannot be represented
+        let bcd_revision = if version.major < 10 && version.minor < 10 {
+            version.major << 4 | version.minor
+        } else {
+            0
+        };
+
+        let mut entry_point = vec![0u8; SMBiosEntryPoint32::MINIMUM_SIZE];
+        entry_point[..4].copy_from_slice(&SMBiosEntryPoint32::SM_ANCHOR);
+        entry_point[SMBiosEntryPoint32::ENTRY_POINT_LENGTH_OFFSET] =
+            SMBiosEntryPoint32::MINIMUM_SIZE as u8;
+        entry_point[SMBiosEntryPoint32::MAJOR_VERSION_OFFSET] = version.major;
+        entry_point[SMBiosEntryPoint32::MINOR_VERSION_OFFSET] = version.minor;
+        entry_point[SMBiosEntryPoint32::MAXIMUM_STRUCTURE_SIZE_OFFSET
+            ..SMBiosEntryPoint32::MAXIMUM_STRUCTURE_SIZE_OFFSET + 2]
+            .copy_from_slice(&(maximum_structure_size.min(u16::MAX as usize) as u16).to_le_bytes());
+        entry_point[SMBiosEntryPoint32::INTERMEDIATE_ANCHOR_OFFSET
+            ..SMBiosEntryPoint32::INTERMEDIATE_ANCHOR_OFFSET + 5]
+            .copy_from_slice(&SMBiosEntryPoint32::DMI_ANCHOR);
+        entry_point[SMBiosEntryPoint32::STRUCTURE_TABLE_LENGTH_OFFSET
+            ..SMBiosEntryPoint32::STRUCTURE_TABLE_LENGTH_OFFSET + 2]
+            .copy_from_slice(&table_length.to_le_bytes());
+        entry_point[SMBiosEntryPoint32::STRUCTURE_TABLE_ADDRESS_OFFSET
+            ..SMBiosEntryPoint32::STRUCTURE_TABLE_ADDRESS_OFFSET + 4]
+            .copy_from_slice(&structure_table_address.to_le_bytes());
+        entry_point[SMBiosEntryPoint32::NUMBER_OF_SMBIOS_STRUCTURES_OFFSET
+            ..SMBiosEntryPoint32::NUMBER_OF_SMBIOS_STRUCTURES_OFFSET + 2]
+            .copy_from_slice(&number_of_structures.to_le_bytes());
+        entry_point[SMBiosEntryPoint32::BCD_REVISION_OFFSET] = bcd_revision;
+        SMBiosEntryPoint32::fix_checksums(&mut entry_point).expect("entry point is MINIMUM_SIZE");
+
+        Ok(entry_point)
+    }
+
+    /// Creates a 64-bit (_SM3_) entry point for the structure table
+    ///
+    /// `structure_table_address` is the physical address at which the
+    /// structure table ([SMBiosData::raw_bytes]) is placed.
+    ///
+    /// Fails when `version` is [None] or the table exceeds 4 GiB.
+    pub fn entry_point_64(&self, structure_table_address: u64) -> Result<Vec<u8>, BuildError> {
+        let version = self.version.ok_or(BuildError::MissingVersion)?;
+
+        let table_size: usize = self
+            .iter()
+            .map(|undefined_struct| undefined_struct.raw().len())
+            .sum();
+        let table_maximum_size: u32 =
+            table_size
+                .try_into()
+                .map_err(|_| BuildError::TableTooLarge {
+                    size: table_size,
+                    limit: u32::MAX as usize,
+                })?;
+
+        let mut entry_point = vec![0u8; SMBiosEntryPoint64::MINIMUM_SIZE];
+        entry_point[..5].copy_from_slice(&SMBiosEntryPoint64::SM3_ANCHOR);
+        entry_point[SMBiosEntryPoint64::ENTRY_POINT_LENGTH_OFFSET] =
+            SMBiosEntryPoint64::MINIMUM_SIZE as u8;
+        entry_point[SMBiosEntryPoint64::MAJOR_VERSION_OFFSET] = version.major;
+        entry_point[SMBiosEntryPoint64::MINOR_VERSION_OFFSET] = version.minor;
+        entry_point[SMBiosEntryPoint64::DOCREV_OFFSET] = version.revision;
+        entry_point[SMBiosEntryPoint64::ENTRY_POINT_REVISION_OFFSET] = 0x01;
+        entry_point[SMBiosEntryPoint64::STRUCTURE_TABLE_MAXIMUM_SIZE_OFFSET
+            ..SMBiosEntryPoint64::STRUCTURE_TABLE_MAXIMUM_SIZE_OFFSET + 4]
+            .copy_from_slice(&table_maximum_size.to_le_bytes());
+        entry_point[SMBiosEntryPoint64::STRUCTURE_TABLE_ADDRESS_OFFSET
+            ..SMBiosEntryPoint64::STRUCTURE_TABLE_ADDRESS_OFFSET + 8]
+            .copy_from_slice(&structure_table_address.to_le_bytes());
+        SMBiosEntryPoint64::fix_checksums(&mut entry_point).expect("entry point is MINIMUM_SIZE");
+
+        Ok(entry_point)
+    }
+
+    /// Rebuilds the structure table from the parsed structures
+    ///
+    /// Each structure is written by the [StructBuilder] from its type,
+    /// handle, formatted area and strings. For a table loaded from raw data
+    /// the result equals the structures of the input byte for byte, which
+    /// verifies that the writer does not corrupt structures it did not
+    /// modify. Padding and trailing data of the input are not part of the
+    /// result (see [SMBiosData::warnings]).
+    ///
+    /// Structures the writer cannot represent, such as those with an empty
+    /// string in their string-set, return a [BuildError]. With the
+    /// `round_trip_assert` feature the comparison is made with a debug
+    /// assertion for every structure as it is parsed.
+    pub fn reserialize(&self) -> Result<Vec<u8>, BuildError> {
+        let mut raw = Vec::new();
+        for undefined_struct in self.iter() {
+            raw.append(
+                &mut StructBuilder::from(undefined_struct)
+                    .to_raw(undefined_struct.header.handle())?,
+            );
+        }
+
+        Ok(raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Handle, SMBiosVersion};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_to_dump_bin() {
+        let table = vec![
+            0x00, 0x04, 0x00, 0x00, b'B', b'I', b'O', b'S', 0x00, b'1', 0x00, 0x00, 0x01, 0x04,
+            0x01, 0x00, 0x00, 0x00, 0x7F, 0x04, 0x02, 0x00, 0x00, 0x00,
+        ];
+
+        for version in [SMBiosVersion::new(3, 4, 0), SMBiosVersion::new(2, 8, 0)].iter() {
+            let data = SMBiosData::from_vec_and_version(table.clone(), Some(*version));
+            assert_eq!(data.raw_bytes(), table);
+            let image = data.to_dump_bin().unwrap();
+
+            assert_eq!(image.len(), 0x20 + table.len());
+            assert_eq!(
+                SMBiosData::raw_table_from_dump_bin(&image).unwrap(),
+                &table[..]
+            );
+
+            let reloaded = SMBiosData::try_from_dump_bin(&image).unwrap();
+            assert_eq!(reloaded.version, Some(*version));
+            assert_eq!(reloaded.iter().count(), 3);
+            assert!(reloaded.integrity().unwrap().is_consistent());
+        }
+
+        let image =
+            SMBiosData::from_vec_and_version(table.clone(), Some(SMBiosVersion::new(2, 8, 0)))
+                .to_dump_bin()
+                .unwrap();
+        let entry_point = SMBiosEntryPoint32::try_from(image[..0x1F].to_vec()).unwrap();
+        assert_eq!(entry_point.maximum_structure_size(), 12);
+        assert_eq!(entry_point.number_of_smbios_structures(), 3);
+        assert_eq!(entry_point.bcd_revision(), 0x28);
+
+        assert!(matches!(
+            SMBiosData::from_vec_and_version(table, None).to_dump_bin(),
+            Err(BuildError::MissingVersion)
+        ));
+    }
+
+    #[test]
+    fn test_reserialize() {
+        // Windows dump, the table follows an 8 byte header
+        let raw = std::fs::read("tests/jeffgerlap_3_2_0.dat").unwrap()[8..].to_vec();
+        let data = SMBiosData::from_vec_and_version(raw.clone(), None);
+        let reserialized = data.reserialize().unwrap();
+
+        assert!(data.warnings().is_empty());
+        assert_eq!(data.iter().count(), 20);
+        assert_eq!(reserialized, raw);
+
+        // a string-set starting with an empty string cannot be written
+        let table = vec![
+            0x0B, 0x05, 0x00, 0x00, 0x01, 0x00, b'O', b'E', b'M', 0x00, 0x00,
+        ];
+        let data = SMBiosData::from_vec_and_version(table, None);
+        assert!(matches!(
+            data.reserialize(),
+            Err(BuildError::EmptyString(Handle(0)))
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+use super::StructBuilder;
+#[cfg(feature = "no_std")]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// # OEM or Unknown Structure Builder
+///
+/// Builds a structure of any type from the bytes of its formatted area
+/// following the header (the payload) and its strings, for OEM-specific
+/// structures (types 128 through 255) or types this library does not
+/// define.
+///
+/// String fields within the payload reference the strings by their 1 based
+/// position in the order they are added.
+pub struct SMBiosUnknownBuilder {
+    struct_type: u8,
+    payload: Vec<u8>,
+    strings: Vec<String>,
+}
+
+impl SMBiosUnknownBuilder {
+    /// Creates a builder of a structure of the given type and payload
+    pub fn new(struct_type: u8, payload: &[u8]) -> Self {
+        Self {
+            struct_type,
+            payload: payload.to_vec(),
+            strings: Vec::new(),
+        }
+    }
+
+    /// Adds a string
+    ///
+    /// Strings may not be empty. Characters outside of ISO-8859-1 and NUL
+    /// are replaced by '?'.
+    pub fn string(mut self, value: &str) -> Self {
+        self.strings.push(value.to_string());
+        self
+    }
+
+    /// Adds strings
+    pub fn strings<I, S>(self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        values
+            .into_iter()
+            .fold(self, |builder, value| builder.string(value.as_ref()))
+    }
+}
+
+impl From<SMBiosUnknownBuilder> for StructBuilder {
+    fn from(builder: SMBiosUnknownBuilder) -> Self {
+        builder.strings.iter().fold(
+            StructBuilder::new(builder.struct_type).data(&builder.payload),
+            |struct_builder, string| struct_builder.append_string(string),
+        )
+    }
+}
@@ -0,0 +1,384 @@
+//! CIM XML output.
+//!
+//! The structures as instances of DMTF Common Information Model (CIM)
+//! classes, in the XML representation of WS-Management (WS-CIM, DSP0230).
+
+use crate::core::{trim_padding, Handle, MaybeValue, SMBiosData};
+use crate::structs::*;
+use quick_xml::events::{BytesDecl, BytesText, Event};
+use quick_xml::Writer;
+use std::io::{Result, Write};
+
+/// Namespace of the WS-Management schema
+pub const WSMAN_NAMESPACE: &str = "http://schemas.dmtf.org/wbem/wsman/1/wsman.xsd";
+
+/// Resource URI prefix of the CIM classes, followed by the class name
+pub const CIM_SCHEMA_URI: &str = "http://schemas.dmtf.org/wbem/wscim/1/cim-schema/2/";
+
+/// An instance of a CIM class
+struct Instance {
+    class_name: &'static str,
+    properties: Vec<(&'static str, String)>,
+}
+
+impl Instance {
+    fn new(class_name: &'static str) -> Self {
+        Instance {
+            class_name,
+            properties: Vec::new(),
+        }
+    }
+
+    fn set<T: ToString>(&mut self, name: &'static str, value: Option<T>) {
+        if let Some(value) = value {
+            self.properties.push((name, value.to_string()));
+        }
+    }
+
+    /// Sets a string property, see [trim_padding]
+    fn string(&mut self, name: &'static str, value: Option<String>) {
+        self.set(name, trim_padding(value));
+    }
+
+    /// Sets the `Tag` or `DeviceID` key from the handle of the structure
+    fn key(&mut self, name: &'static str, handle: Handle) {
+        self.set("CreationClassName", Some(self.class_name));
+        self.set(name, Some(format!("0x{:04X}", handle.0)));
+    }
+
+    fn write<W: Write>(&self, writer: &mut Writer<W>) -> Result<()> {
+        let namespace = format!("{}{}", CIM_SCHEMA_URI, self.class_name);
+        writer
+            .create_element(format!("p:{}", self.class_name))
+            .with_attribute(("xmlns:p", namespace.as_str()))
+            .write_inner_content(|writer| {
+                for (name, value) in self.properties.iter() {
+                    writer
+                        .create_element(format!("p:{}", name))
+                        .write_text_content(BytesText::new(value))?;
+                }
+
+                Ok(())
+            })?;
+
+        Ok(())
+    }
+}
+
+impl SMBiosData {
+    /// The structures as CIM instances, in the XML of a WS-Management
+    /// enumeration
+    ///
+    /// ```xml
+    /// <?xml version="1.0" encoding="UTF-8"?>
+    /// <wsman:Items xmlns:wsman="http://schemas.dmtf.org/wbem/wsman/1/wsman.xsd">
+    ///   <p:CIM_Processor xmlns:p="http://schemas.dmtf.org/wbem/wscim/1/cim-schema/2/CIM_Processor">
+    ///     <p:CreationClassName>CIM_Processor</p:CreationClassName>
+    ///     <p:DeviceID>0x0004</p:DeviceID>
+    ///     <p:ElementName>CPU 0</p:ElementName>
+    ///     <p:MaxClockSpeed>4000</p:MaxClockSpeed>
+    ///   </p:CIM_Processor>
+    /// </wsman:Items>
+    /// ```
+    ///
+    /// Instances are in table order, with the following classes:
+    ///
+    /// - BIOS Information (Type 0) as `CIM_BIOSElement`
+    /// - System Enclosure or Chassis (Type 3) as `CIM_Chassis`
+    /// - Processor Information (Type 4) as `CIM_Processor`
+    /// - Memory Device (Type 17) as `CIM_PhysicalMemory`, when installed
+    ///
+    /// Other structures have no instance. The `Tag` and `DeviceID` keys are
+    /// the handle of the structure, in hexadecimal. Enumerated properties
+    /// hold the values of the CIM schema, which match those of the SMBIOS
+    /// specification for `ChassisPackageType`, `Family`, `UpgradeMethod`
+    /// and `CPUStatus`. Memory speeds are in MT/s as given by the table. A
+    /// property is omitted when its value is unknown.
+    pub fn write_cim_xml<W: Write>(&self, out: W) -> Result<()> {
+        let mut writer = Writer::new_with_indent(out, b' ', 2);
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+        writer
+            .create_element("wsman:Items")
+            .with_attribute(("xmlns:wsman", WSMAN_NAMESPACE))
+            .write_inner_content(|writer| {
+                for instance in self
+                    .iter()
+                    .map(DefinedStruct::from)
+                    .filter_map(cim_instance)
+                {
+                    instance.write(writer)?;
+                }
+
+                Ok(())
+            })?;
+
+        Ok(())
+    }
+
+    /// The structures as CIM instances, see [SMBiosData::write_cim_xml]
+    pub fn to_cim_xml(&self) -> String {
+        let mut xml = Vec::new();
+        self.write_cim_xml(&mut xml)
+            .expect("writing to a Vec does not fail");
+        String::from_utf8(xml).expect("the XML writer emits UTF-8")
+    }
+}
+
+fn cim_instance(structure: DefinedStruct<'_>) -> Option<Instance> {
+    match structure {
+        DefinedStruct::Information(bios_info) => {
+            let mut instance = Instance::new("CIM_BIOSElement");
+            instance.string("Manufacturer", bios_info.vendor());
+            instance.string("Name", bios_info.version());
+            instance.string("Version", bios_info.version());
+            Some(instance)
+        }
+        DefinedStruct::SystemChassisInformation(chassis_info) => {
+            let mut instance = Instance::new("CIM_Chassis");
+            instance.key("Tag", chassis_info.parts().header.handle());
+            instance.string("Manufacturer", chassis_info.manufacturer());
+            instance.string("Version", chassis_info.version());
+            instance.string("SerialNumber", chassis_info.serial_number());
+            instance.string("SKU", chassis_info.sku_number());
+            if let Some(chassis_type) = chassis_info.chassis_type() {
+                instance.set(
+                    "ChassisPackageType",
+                    Some(chassis_type.raw & 0x7F).filter(|raw| (0x01..=0x1C).contains(raw)),
+                );
+                instance.set("LockPresent", Some(chassis_type.raw & 0x80 == 0x80));
+            }
+            Some(instance)
+        }
+        DefinedStruct::ProcessorInformation(processor_info) => {
+            let mut instance = Instance::new("CIM_Processor");
+            instance.key("DeviceID", processor_info.parts().header.handle());
+            instance.string("ElementName", processor_info.socket_designation());
+            instance.string("Name", processor_info.processor_version());
+            instance.set(
+                "Family",
+                processor_info
+                    .effective_processor_family()
+                    .map(|family| family.raw),
+            );
+            instance.set(
+                "UpgradeMethod",
+                processor_info
+                    .processor_upgrade()
+                    .map(|upgrade| upgrade.raw),
+            );
+            instance.set(
+                "MaxClockSpeed",
+                processor_info
+                    .max_speed()
+                    .and_then(|speed| speed.megahertz().value())
+                    .map(|speed| speed.as_megahertz()),
+            );
+            instance.set(
+                "CurrentClockSpeed",
+                processor_info
+                    .current_speed()
+                    .and_then(|speed| speed.megahertz().value())
+                    .map(|speed| speed.as_megahertz()),
+            );
+            instance.set(
+                "ExternalBusClockSpeed",
+                processor_info
+                    .external_clock()
+                    .and_then(|speed| speed.megahertz().value())
+                    .map(|speed| speed.as_megahertz()),
+            );
+            instance.set(
+                "CPUStatus",
+                processor_info.status().map(|status| status.raw & 0x07),
+            );
+            Some(instance)
+        }
+        DefinedStruct::MemoryDevice(memory_device) => {
+            let capacity = match memory_device.effective_size() {
+                MaybeValue::NotProvided => return None,
+                size => size.as_bytes(),
+            };
+
+            let mut instance = Instance::new("CIM_PhysicalMemory");
+            instance.key("Tag", memory_device.parts().header.handle());
+            instance.string("ElementName", memory_device.device_locator());
+            instance.string("BankLabel", memory_device.bank_locator());
+            instance.string("Manufacturer", memory_device.manufacturer());
+            instance.string("SerialNumber", memory_device.serial_number());
+            instance.string("PartNumber", memory_device.part_number());
+            instance.set("Capacity", capacity);
+            instance.set(
+                "FormFactor",
+                memory_device.form_factor().map(|form_factor| {
+                    use MemoryFormFactor::*;
+                    match form_factor.value {
+                        Unknown => 0u16,
+                        Sip => 2,
+                        Dip => 3,
+                        Zip => 4,
+                        ProprietaryCard => 6,
+                        Simm => 7,
+                        Dimm => 8,
+                        Tsop => 9,
+                        Rimm => 11,
+                        Sodimm => 12,
+                        Srimm => 13,
+                        _ => 1,
+                    }
+                }),
+            );
+            instance.set(
+                "MemoryType",
+                memory_device.memory_type().map(|memory_type| {
+                    use MemoryDeviceType::*;
+                    match memory_type.value {
+                        Unknown => 0u16,
+                        Dram => 2,
+                        Edram => 6,
+                        Vram => 7,
+                        Sram => 8,
+                        Ram => 9,
+                        Rom => 10,
+                        Flash => 11,
+                        Eeprom => 12,
+                        Feprom => 13,
+                        Eprom => 14,
+                        Cdram => 15,
+                        ThreeDram => 16,
+                        Sdram => 17,
+                        Sgram => 18,
+                        Rdram => 19,
+                        Ddr => 20,
+                        Ddr2 => 21,
+                        Ddr2Fbdimm => 23,
+                        Ddr3 => 24,
+                        Fbd2 => 25,
+                        Ddr4 => 26,
+                        Lpddr => 27,
+                        Lpddr2 => 28,
+                        Lpddr3 => 29,
+                        Lpddr4 => 30,
+                        _ => 1,
+                    }
+                }),
+            );
+            instance.set(
+                "DataWidth",
+                memory_device.data_width().filter(|width| *width != 0xFFFF),
+            );
+            instance.set(
+                "TotalWidth",
+                memory_device.total_width().filter(|width| *width != 0xFFFF),
+            );
+            instance.set(
+                "MaxMemorySpeed",
+                memory_device
+                    .effective_speed()
+                    .and_then(MaybeValue::value)
+                    .map(|speed| speed.as_megatransfers()),
+            );
+            instance.set(
+                "ConfiguredMemoryClockSpeed",
+                memory_device
+                    .effective_configured_memory_speed()
+                    .and_then(MaybeValue::value)
+                    .map(|speed| speed.as_megatransfers()),
+            );
+            Some(instance)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::{SMBiosTableBuilder, StructBuilder};
+    use crate::core::{SMBiosData, SMBiosVersion};
+
+    #[test]
+    fn test_to_cim_xml() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add(
+                StructBuilder::new(4)
+                    .string("CPU <0>")
+                    .byte(3)
+                    .byte(0xB3)
+                    .string("Intel")
+                    .qword(0)
+                    .string("Xeon")
+                    .byte(0)
+                    .word(0)
+                    .word(4000)
+                    .word(2100)
+                    .byte(0x41)
+                    .byte(1),
+            )
+            .unwrap();
+        builder
+            .add(
+                StructBuilder::new(17)
+                    .word(0x1000)
+                    .word(0xFFFE)
+                    .word(72)
+                    .word(64)
+                    .word(0) // not installed
+                    .byte(0x09)
+                    .byte(0)
+                    .string("DIMM A1")
+                    .string("P0")
+                    .byte(0x1A)
+                    .word(0)
+                    .word(0),
+            )
+            .unwrap();
+        builder
+            .add(
+                StructBuilder::new(17)
+                    .word(0x1000)
+                    .word(0xFFFE)
+                    .word(72)
+                    .word(64)
+                    .word(0x4000) // 16 GiB
+                    .byte(0x09)
+                    .byte(0)
+                    .string("DIMM A2")
+                    .string("P0")
+                    .byte(0x1A)
+                    .word(0)
+                    .word(3200),
+            )
+            .unwrap();
+        let data: SMBiosData = builder.build().unwrap();
+
+        assert_eq!(
+            data.to_cim_xml(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<wsman:Items xmlns:wsman="http://schemas.dmtf.org/wbem/wsman/1/wsman.xsd">
+  <p:CIM_Processor xmlns:p="http://schemas.dmtf.org/wbem/wscim/1/cim-schema/2/CIM_Processor">
+    <p:CreationClassName>CIM_Processor</p:CreationClassName>
+    <p:DeviceID>0x0000</p:DeviceID>
+    <p:ElementName>CPU &lt;0&gt;</p:ElementName>
+    <p:Name>Xeon</p:Name>
+    <p:Family>179</p:Family>
+    <p:UpgradeMethod>1</p:UpgradeMethod>
+    <p:MaxClockSpeed>4000</p:MaxClockSpeed>
+    <p:CurrentClockSpeed>2100</p:CurrentClockSpeed>
+    <p:CPUStatus>1</p:CPUStatus>
+  </p:CIM_Processor>
+  <p:CIM_PhysicalMemory xmlns:p="http://schemas.dmtf.org/wbem/wscim/1/cim-schema/2/CIM_PhysicalMemory">
+    <p:CreationClassName>CIM_PhysicalMemory</p:CreationClassName>
+    <p:Tag>0x0002</p:Tag>
+    <p:ElementName>DIMM A2</p:ElementName>
+    <p:BankLabel>P0</p:BankLabel>
+    <p:Capacity>17179869184</p:Capacity>
+    <p:FormFactor>8</p:FormFactor>
+    <p:MemoryType>26</p:MemoryType>
+    <p:DataWidth>64</p:DataWidth>
+    <p:TotalWidth>72</p:TotalWidth>
+    <p:MaxMemorySpeed>3200</p:MaxMemorySpeed>
+  </p:CIM_PhysicalMemory>
+</wsman:Items>"#
+        );
+    }
+}
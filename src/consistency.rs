@@ -0,0 +1,354 @@
+use crate::core::{Handle, MaybeValue, SMBiosData};
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use core::fmt;
+use serde::Serialize;
+
+/// # Consistency Finding
+///
+/// A contradiction between structures of a table, or between fields of a
+/// structure, see [SMBiosData::check_consistency].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum ConsistencyFinding {
+    /// The Memory Devices of a Physical Memory Array hold more memory than
+    /// its maximum capacity
+    ArrayCapacityExceeded {
+        /// Handle of the Physical Memory Array (Type 16)
+        array: Handle,
+        /// Maximum capacity of the array, in bytes
+        maximum_capacity: u64,
+        /// Size of the Memory Devices of the array, in bytes
+        installed: u64,
+    },
+    /// The Physical Memory Array Handle of a Memory Device references no
+    /// Physical Memory Array
+    MemoryDeviceWithoutArray {
+        /// Handle of the Memory Device (Type 17)
+        device: Handle,
+        /// The referenced handle
+        reference: Handle,
+    },
+    /// A processor has more enabled cores than cores
+    CoresEnabledExceedCoreCount {
+        /// Handle of the Processor Information (Type 4)
+        processor: Handle,
+        /// Number of cores of the processor
+        core_count: u16,
+        /// Number of enabled cores of the processor
+        cores_enabled: u16,
+    },
+    /// A cache handle of a processor references no Cache Information
+    CacheNotFound {
+        /// Handle of the Processor Information (Type 4)
+        processor: Handle,
+        /// Level of the cache handle (1 to 3)
+        level: u8,
+        /// The referenced handle
+        reference: Handle,
+    },
+    /// A cache handle of a processor references a cache of another level
+    CacheLevelMismatch {
+        /// Handle of the Processor Information (Type 4)
+        processor: Handle,
+        /// Handle of the Cache Information (Type 7)
+        cache: Handle,
+        /// Level of the cache handle (1 to 3)
+        expected: u8,
+        /// Level of the cache
+        found: u8,
+    },
+    /// The boot status of a System Boot Information structure is not 1 to
+    /// 10 bytes long
+    BootStatusLength {
+        /// Handle of the System Boot Information (Type 32)
+        handle: Handle,
+        /// Length of the structure, the boot status starting at offset 0Ah
+        length: u8,
+    },
+}
+
+impl fmt::Display for ConsistencyFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConsistencyFinding::ArrayCapacityExceeded {
+                array,
+                maximum_capacity,
+                installed,
+            } => write!(
+                f,
+                "Memory array {:#06X}: {} bytes installed exceed the maximum capacity of {} bytes",
+                **array, installed, maximum_capacity
+            ),
+            ConsistencyFinding::MemoryDeviceWithoutArray { device, reference } => write!(
+                f,
+                "Memory device {:#06X}: references missing memory array {:#06X}",
+                **device, **reference
+            ),
+            ConsistencyFinding::CoresEnabledExceedCoreCount {
+                processor,
+                core_count,
+                cores_enabled,
+            } => write!(
+                f,
+                "Processor {:#06X}: {} enabled cores exceed the core count of {}",
+                **processor, cores_enabled, core_count
+            ),
+            ConsistencyFinding::CacheNotFound {
+                processor,
+                level,
+                reference,
+            } => write!(
+                f,
+                "Processor {:#06X}: L{} cache handle references missing cache {:#06X}",
+                **processor, level, **reference
+            ),
+            ConsistencyFinding::CacheLevelMismatch {
+                processor,
+                cache,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Processor {:#06X}: L{} cache handle references L{} cache {:#06X}",
+                **processor, expected, found, **cache
+            ),
+            ConsistencyFinding::BootStatusLength { handle, length } => write!(
+                f,
+                "System boot information {:#06X}: length {} does not hold 1 to 10 bytes of boot status",
+                **handle, length
+            ),
+        }
+    }
+}
+
+impl SMBiosData {
+    /// Checks the structures of the table against each other
+    ///
+    /// Returns the findings, empty for a consistent table, in this order:
+    /// - Physical Memory Arrays whose Memory Devices exceed the maximum
+    ///   capacity, then Memory Devices referencing no Physical Memory Array.
+    /// - For each processor, more enabled cores than cores, then L1, L2 and
+    ///   L3 cache handles referencing no Cache Information or a cache of
+    ///   another level. A cache handle of FFFFh is not a reference.
+    /// - System Boot Information structures whose length does not hold 1 to
+    ///   10 bytes of boot status.
+    ///
+    /// See [SMBiosData::validate] for the conformance of each structure to
+    /// the specification.
+    pub fn check_consistency(&self) -> Vec<ConsistencyFinding> {
+        let mut findings = Vec::new();
+        let topology = self.memory_topology();
+
+        for array in topology.arrays() {
+            let maximum_capacity = match array.array().effective_maximum_capacity() {
+                Some(MaybeValue::Value(capacity)) => capacity,
+                _ => continue,
+            };
+            let installed = array
+                .devices()
+                .iter()
+                .filter_map(|device| memory_device_size(device.device()))
+                .sum();
+
+            if installed > maximum_capacity {
+                findings.push(ConsistencyFinding::ArrayCapacityExceeded {
+                    array: array.array().parts().header.handle(),
+                    maximum_capacity,
+                    installed,
+                });
+            }
+        }
+
+        findings.extend(topology.unassigned_devices().iter().filter_map(|device| {
+            Some(ConsistencyFinding::MemoryDeviceWithoutArray {
+                device: device.device().parts().header.handle(),
+                reference: device.device().physical_memory_array_handle()?,
+            })
+        }));
+
+        for socket in self.processor_topology().sockets() {
+            let processor = socket.processor().parts().header.handle();
+
+            if let (Some(core_count), Some(cores_enabled)) =
+                (socket.core_count(), socket.cores_enabled())
+            {
+                if cores_enabled > core_count {
+                    findings.push(ConsistencyFinding::CoresEnabledExceedCoreCount {
+                        processor,
+                        core_count,
+                        cores_enabled,
+                    });
+                }
+            }
+
+            let cache_handles = [
+                socket.processor().l1cache_handle(),
+                socket.processor().l2cache_handle(),
+                socket.processor().l3cache_handle(),
+            ];
+            for (level, reference) in (1..).zip(cache_handles.iter()) {
+                let reference = match reference {
+                    Some(reference) if **reference != 0xFFFF => *reference,
+                    _ => continue,
+                };
+
+                match self
+                    .resolve::<SMBiosCacheInformation<'_>>(&reference)
+                    .map(|cache| cache.cache_configuration())
+                {
+                    None => findings.push(ConsistencyFinding::CacheNotFound {
+                        processor,
+                        level,
+                        reference,
+                    }),
+                    Some(Some(configuration)) if configuration.cache_level() != level => findings
+                        .push(ConsistencyFinding::CacheLevelMismatch {
+                            processor,
+                            cache: reference,
+                            expected: level,
+                            found: configuration.cache_level(),
+                        }),
+                    Some(_) => {}
+                }
+            }
+        }
+
+        findings.extend(
+            self.defined_struct_iter::<SMBiosSystemBootInformation<'_>>()
+                .map(|boot_information| &boot_information.parts().header)
+                .filter(|header| !(0x0B..=0x14).contains(&header.length()))
+                .map(|header| ConsistencyFinding::BootStatusLength {
+                    handle: header.handle(),
+                    length: header.length(),
+                }),
+        );
+
+        findings
+    }
+}
+
+/// Size of an installed Memory Device in bytes, None when not installed
+/// or unknown
+fn memory_device_size(device: &SMBiosMemoryDevice<'_>) -> Option<u64> {
+    device.effective_size().as_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{MemoryDeviceBuilder, SMBiosTableBuilder, StructBuilder};
+    use crate::core::SMBiosVersion;
+
+    #[test]
+    fn test_check_consistency() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        // 8 GiB maximum capacity
+        let array = builder
+            .add(
+                StructBuilder::new(16)
+                    .byte(0x03)
+                    .byte(0x03)
+                    .byte(0x06)
+                    .dword(8 << 20)
+                    .word(0xFFFE)
+                    .word(2)
+                    .qword(0),
+            )
+            .unwrap();
+        builder
+            .add(MemoryDeviceBuilder::new(array).size_mb(8192))
+            .unwrap();
+        builder
+            .add(MemoryDeviceBuilder::new(array).size_mb(4096))
+            .unwrap();
+        let device = builder
+            .add(MemoryDeviceBuilder::new(Handle(0x1000)).size_mb(4096))
+            .unwrap();
+        let cache = builder
+            .add(
+                StructBuilder::new(7)
+                    .string("L2")
+                    .word(0x0181)
+                    .word(0x0200)
+                    .word(0x0200)
+                    .word(0x0020)
+                    .word(0x0020)
+                    .byte(0)
+                    .byte(0x05)
+                    .byte(0x05)
+                    .byte(0x08),
+            )
+            .unwrap();
+        // 8 cores of which 10 enabled, the L1 handle referencing the L2 cache
+        let processor = builder
+            .add(
+                StructBuilder::new(4)
+                    .string("CPU")
+                    .byte(3)
+                    .byte(0xB3)
+                    .string("Intel")
+                    .qword(0)
+                    .string("Xeon")
+                    .byte(0)
+                    .word(0)
+                    .word(4000)
+                    .word(2100)
+                    .byte(0x41)
+                    .byte(1)
+                    .word(*cache)
+                    .word(0x2000)
+                    .word(0xFFFF)
+                    .string("")
+                    .string("")
+                    .string("")
+                    .byte(8)
+                    .byte(10)
+                    .byte(16),
+            )
+            .unwrap();
+        let boot_information = builder.add(StructBuilder::new(32).data(&[0; 6])).unwrap();
+        builder.add(StructBuilder::new(32).data(&[0; 7])).unwrap();
+        let data = builder.build().unwrap();
+
+        let findings = data.check_consistency();
+        assert_eq!(
+            findings,
+            vec![
+                ConsistencyFinding::ArrayCapacityExceeded {
+                    array,
+                    maximum_capacity: 8 << 30,
+                    installed: 12 << 30,
+                },
+                ConsistencyFinding::MemoryDeviceWithoutArray {
+                    device,
+                    reference: Handle(0x1000),
+                },
+                ConsistencyFinding::CoresEnabledExceedCoreCount {
+                    processor,
+                    core_count: 8,
+                    cores_enabled: 10,
+                },
+                ConsistencyFinding::CacheLevelMismatch {
+                    processor,
+                    cache,
+                    expected: 1,
+                    found: 2,
+                },
+                ConsistencyFinding::CacheNotFound {
+                    processor,
+                    level: 2,
+                    reference: Handle(0x2000),
+                },
+                ConsistencyFinding::BootStatusLength {
+                    handle: boot_information,
+                    length: 0x0A,
+                },
+            ]
+        );
+        assert_eq!(
+            findings[3].to_string(),
+            "Processor 0x0005: L1 cache handle references L2 cache 0x0004"
+        );
+    }
+}
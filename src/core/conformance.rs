@@ -0,0 +1,365 @@
+use super::header::Handle;
+use super::maybe_value::MaybeValue;
+use super::smbios_data::{SMBiosData, SMBiosVersion};
+use super::validation::minimum_length;
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use core::fmt;
+use serde::Serialize;
+
+/// Structure types obsoleted by the specification, see
+/// [ConformanceIssue::DeprecatedType]
+pub const DEPRECATED_STRUCT_TYPES: [u8; 2] = [
+    SMBiosMemoryControllerInformation::STRUCT_TYPE,
+    SMBiosMemoryModuleInformation::STRUCT_TYPE,
+];
+
+/// # Conformance Profile
+///
+/// A revision of the DMTF SMBIOS specification a table is checked against,
+/// see [SMBiosData::check_conformance].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum ConformanceProfile {
+    /// SMBIOS 2.8
+    V2_8,
+    /// SMBIOS 3.2
+    V3_2,
+    /// SMBIOS 3.5
+    V3_5,
+    /// SMBIOS 3.7
+    V3_7,
+}
+
+impl ConformanceProfile {
+    /// All profiles, oldest first
+    pub const ALL: [ConformanceProfile; 4] = [
+        ConformanceProfile::V2_8,
+        ConformanceProfile::V3_2,
+        ConformanceProfile::V3_5,
+        ConformanceProfile::V3_7,
+    ];
+
+    /// The version of the specification
+    pub fn version(&self) -> SMBiosVersion {
+        match self {
+            ConformanceProfile::V2_8 => SMBiosVersion::new(2, 8, 0),
+            ConformanceProfile::V3_2 => SMBiosVersion::new(3, 2, 0),
+            ConformanceProfile::V3_5 => SMBiosVersion::new(3, 5, 0),
+            ConformanceProfile::V3_7 => SMBiosVersion::new(3, 7, 0),
+        }
+    }
+}
+
+/// # Conformance Issue
+///
+/// A departure of a structure from a [ConformanceProfile]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum ConformanceIssue {
+    /// The structure is shorter than the profile requires
+    TooShort {
+        /// Length of the formatted area, including the header
+        length: u8,
+        /// Length required by the profile
+        minimum: usize,
+    },
+    /// The structure is of a type obsoleted by the specification (see
+    /// [DEPRECATED_STRUCT_TYPES])
+    DeprecatedType,
+    /// A required string field has no string
+    FieldNotSet {
+        /// Offset of the string field
+        offset: usize,
+        /// Name of the field in the specification
+        name: &'static str,
+    },
+    /// A required string field references a string which is not in the
+    /// string-set
+    InvalidStringIndex {
+        /// Offset of the string field
+        offset: usize,
+        /// Name of the field in the specification
+        name: &'static str,
+        /// The string index of the field
+        index: u8,
+    },
+}
+
+impl fmt::Display for ConformanceIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConformanceIssue::TooShort { length, minimum } => write!(
+                f,
+                "length {} is shorter than the required {}",
+                length, minimum
+            ),
+            ConformanceIssue::DeprecatedType => f.write_str("the structure type is obsolete"),
+            ConformanceIssue::FieldNotSet { offset, name } => write!(
+                f,
+                "the required field {} at offset {:#04X} is not set",
+                name, offset
+            ),
+            ConformanceIssue::InvalidStringIndex {
+                offset,
+                name,
+                index,
+            } => write!(
+                f,
+                "the required field {} at offset {:#04X} references the missing string {}",
+                name, offset, index
+            ),
+        }
+    }
+}
+
+/// # Structure Conformance
+///
+/// The conformance of one structure to a [ConformanceProfile]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StructureConformance {
+    /// Handle of the structure
+    pub handle: Handle,
+    /// Type of the structure
+    pub struct_type: u8,
+    /// The issues of the structure, empty for a conforming structure
+    pub issues: Vec<ConformanceIssue>,
+}
+
+impl StructureConformance {
+    /// Whether the structure conforms to the profile
+    pub fn is_conforming(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// # Conformance Report
+///
+/// The conformance of a table to a [ConformanceProfile], see
+/// [SMBiosData::check_conformance].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConformanceReport {
+    /// The profile checked against
+    pub profile: ConformanceProfile,
+    /// The result of each structure, in table order
+    pub structures: Vec<StructureConformance>,
+}
+
+impl ConformanceReport {
+    /// Whether every structure conforms to the profile
+    pub fn is_conforming(&self) -> bool {
+        self.structures
+            .iter()
+            .all(StructureConformance::is_conforming)
+    }
+
+    /// The structures which do not conform to the profile
+    pub fn nonconforming(&self) -> impl Iterator<Item = &StructureConformance> {
+        self.structures
+            .iter()
+            .filter(|structure| !structure.is_conforming())
+    }
+}
+
+impl SMBiosData {
+    /// Checks each structure of the table against a revision `profile` of
+    /// the DMTF SMBIOS specification, regardless of the version of the table
+    ///
+    /// The issues of a structure are, in this order:
+    /// - A length shorter than the profile requires, for the types checked
+    ///   by [SMBiosData::validate].
+    /// - A type of the [DEPRECATED_STRUCT_TYPES].
+    /// - Required string fields, of the types 0, 1, 3, 4 and 17, which
+    ///   have no string or reference a missing string. Fields the structure
+    ///   is too short to hold are covered by [ConformanceIssue::TooShort].
+    pub fn check_conformance(&self, profile: ConformanceProfile) -> ConformanceReport {
+        let version = profile.version();
+
+        let structures = self
+            .iter()
+            .map(|undefined_struct| {
+                let struct_type = undefined_struct.header.struct_type();
+                let mut issues = Vec::new();
+
+                if let Some(minimum) = minimum_length(undefined_struct, version) {
+                    let length = undefined_struct.header.length();
+                    if (length as usize) < minimum {
+                        issues.push(ConformanceIssue::TooShort { length, minimum });
+                    }
+                }
+
+                if DEPRECATED_STRUCT_TYPES.contains(&struct_type) {
+                    issues.push(ConformanceIssue::DeprecatedType);
+                }
+
+                issues.extend(required_string_fields(struct_type).iter().filter_map(
+                    |&(offset, name)| match undefined_struct.access_field_string(offset) {
+                        MaybeValue::NotProvided => {
+                            Some(ConformanceIssue::FieldNotSet { offset, name })
+                        }
+                        MaybeValue::InvalidIndex(index) => {
+                            Some(ConformanceIssue::InvalidStringIndex {
+                                offset,
+                                name,
+                                index,
+                            })
+                        }
+                        _ => None,
+                    },
+                ));
+
+                StructureConformance {
+                    handle: undefined_struct.header.handle(),
+                    struct_type,
+                    issues,
+                }
+            })
+            .collect();
+
+        ConformanceReport {
+            profile,
+            structures,
+        }
+    }
+}
+
+/// String fields, as (offset, name), each structure of the type is
+/// required to set
+fn required_string_fields(struct_type: u8) -> &'static [(usize, &'static str)] {
+    match struct_type {
+        SMBiosInformation::STRUCT_TYPE => &[
+            (0x04, "Vendor"),
+            (0x05, "BIOS Version"),
+            (0x08, "BIOS Release Date"),
+        ],
+        SMBiosSystemInformation::STRUCT_TYPE => &[
+            (0x04, "Manufacturer"),
+            (0x05, "Product Name"),
+            (0x06, "Version"),
+            (0x07, "Serial Number"),
+            (0x19, "SKU Number"),
+            (0x1A, "Family"),
+        ],
+        SMBiosSystemChassisInformation::STRUCT_TYPE => &[
+            (0x04, "Manufacturer"),
+            (0x06, "Version"),
+            (0x07, "Serial Number"),
+            (0x08, "Asset Tag Number"),
+        ],
+        SMBiosProcessorInformation::STRUCT_TYPE => &[
+            (0x04, "Socket Designation"),
+            (0x07, "Processor Manufacturer"),
+            (0x10, "Processor Version"),
+        ],
+        SMBiosMemoryDevice::STRUCT_TYPE => &[(0x10, "Device Locator")],
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{
+        MemoryDeviceBuilder, SMBiosTableBuilder, StructBuilder, SystemInformationBuilder,
+    };
+
+    #[test]
+    fn test_check_conformance() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        let system_information = builder
+            .add(
+                SystemInformationBuilder::new()
+                    .manufacturer("Manufacturer")
+                    .product_name("Product")
+                    .version("1.0")
+                    .serial_number("0001")
+                    .family("Family"),
+            )
+            .unwrap();
+        let memory_controller = builder
+            .add(
+                StructBuilder::new(5)
+                    .byte(0x03)
+                    .byte(0x03)
+                    .byte(0x03)
+                    .byte(0x03)
+                    .byte(0x03)
+                    .word(0)
+                    .word(0)
+                    .word(0)
+                    .byte(0)
+                    .byte(0),
+            )
+            .unwrap();
+        let memory_device = builder
+            .add(
+                MemoryDeviceBuilder::new(Handle(0xFFFE))
+                    .device_locator("DIMM 0")
+                    .size_mb(8192),
+            )
+            .unwrap();
+        let data = builder.build().unwrap();
+
+        let report = data.check_conformance(ConformanceProfile::V3_2);
+        assert!(!report.is_conforming());
+        assert_eq!(
+            report.nonconforming().cloned().collect::<Vec<_>>(),
+            vec![
+                StructureConformance {
+                    handle: system_information,
+                    struct_type: 1,
+                    issues: vec![ConformanceIssue::FieldNotSet {
+                        offset: 0x19,
+                        name: "SKU Number",
+                    }],
+                },
+                StructureConformance {
+                    handle: memory_controller,
+                    struct_type: 5,
+                    issues: vec![ConformanceIssue::DeprecatedType],
+                },
+            ]
+        );
+        assert_eq!(report.structures.len(), 4);
+
+        // the 5Ch bytes Memory Device lacks the fields of SMBIOS 3.7
+        let report = data.check_conformance(ConformanceProfile::V3_7);
+        let device = &report.structures[2];
+        assert_eq!(device.handle, memory_device);
+        assert_eq!(
+            device.issues,
+            vec![ConformanceIssue::TooShort {
+                length: 0x5C,
+                minimum: 0x64,
+            }]
+        );
+        assert_eq!(
+            device.issues[0].to_string(),
+            "length 92 is shorter than the required 100"
+        );
+
+        // BIOS Information with no version and a release date index of FFh
+        let mut bios = vec![0x00, 0x1A, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xFF];
+        bios.resize(0x1A, 0x00);
+        bios.extend_from_slice(b"Vendor\0\0");
+        let data = SMBiosData::from_vec_and_version(bios, None);
+        let report = data.check_conformance(ConformanceProfile::V3_2);
+        assert_eq!(
+            report.structures[0].issues,
+            vec![
+                ConformanceIssue::FieldNotSet {
+                    offset: 0x05,
+                    name: "BIOS Version",
+                },
+                ConformanceIssue::InvalidStringIndex {
+                    offset: 0x08,
+                    name: "BIOS Release Date",
+                    index: 0xFF,
+                },
+            ]
+        );
+        assert_eq!(
+            report.structures[0].issues[1].to_string(),
+            "the required field BIOS Release Date at offset 0x08 references the missing string 255"
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use super::field_offsets::handle_field_offsets;
+use super::header::{Handle, Header};
+use super::smbios_data::SMBiosData;
+use super::undefined_struct::UndefinedStruct;
+
+/// # Content Hash Options
+///
+/// Parts of a table left out of [SMBiosData::content_hash_with_options].
+///
+/// [ContentHashOptions::default] hashes the whole content.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ContentHashOptions {
+    /// Leaves out the handles, which firmware may renumber between boots
+    ///
+    /// Both the handles of the structure headers and the fields referencing
+    /// other structures by handle (see
+    /// [crate::DefinedStructMut::handle_field_offsets]), such as the
+    /// Physical Memory Array Handle of a Memory Device (Type 17), are left
+    /// out.
+    pub ignore_handles: bool,
+    /// Leaves out fields describing the state of the system at boot rather
+    /// than its inventory:
+    ///
+    /// - Wake-up Type of System Information (Type 1)
+    /// - Boot-up, Power Supply and Thermal State of System Enclosure or
+    ///   Chassis (Type 3)
+    /// - Log Status and Log Change Token of System Event Log (Type 15)
+    pub ignore_volatile_fields: bool,
+}
+
+/// Byte ranges of the formatted area of volatile fields, by structure type
+const VOLATILE_FIELDS: &[(u8, usize, usize)] =
+    &[(1, 0x18, 0x19), (3, 0x09, 0x0C), (15, 0x0B, 0x10)];
+
+/// 64-bit FNV-1a
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Fnv1a(0xCBF2_9CE4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01B3);
+        }
+    }
+
+    /// Writes a length-prefixed block, keeping consecutive blocks apart
+    fn write_block(&mut self, bytes: &[u8]) {
+        self.write(&(bytes.len() as u32).to_le_bytes());
+        self.write(bytes);
+    }
+}
+
+impl SMBiosData {
+    /// A digest of the content of the table, see
+    /// [SMBiosData::content_hash_with_options]
+    pub fn content_hash(&self) -> u64 {
+        self.content_hash_with_options(&ContentHashOptions::default())
+    }
+
+    /// A digest of the content of the table, for detecting changes of the
+    /// firmware inventory between runs
+    ///
+    /// The digest covers the SMBIOS version and, in table order, the
+    /// formatted area and strings of each structure, except the parts left
+    /// out by `options`.
+    ///
+    /// The digest is the 64-bit FNV-1a hash of this content, stable across
+    /// platforms and versions of this library. It is not a cryptographic
+    /// hash and must not be relied upon to detect tampering.
+    pub fn content_hash_with_options(&self, options: &ContentHashOptions) -> u64 {
+        let mut hash = Fnv1a::new();
+        match self.version {
+            Some(version) => hash.write(&[1, version.major, version.minor, version.revision]),
+            None => hash.write(&[0]),
+        }
+
+        for undefined_struct in self.iter() {
+            hash_struct(&mut hash, undefined_struct, options);
+        }
+
+        hash.0
+    }
+}
+
+fn hash_struct(hash: &mut Fnv1a, undefined_struct: &UndefinedStruct, options: &ContentHashOptions) {
+    let struct_type = undefined_struct.header.struct_type();
+    let mut fields = undefined_struct.fields.clone();
+    if options.ignore_handles {
+        for byte in fields[Header::HANDLE_OFFSET..Header::SIZE].iter_mut() {
+            *byte = 0;
+        }
+        for offset in handle_field_offsets(undefined_struct) {
+            for byte in fields[offset..offset + Handle::SIZE].iter_mut() {
+                *byte = 0;
+            }
+        }
+    }
+
+    if options.ignore_volatile_fields {
+        for (volatile_type, start, end) in VOLATILE_FIELDS.iter() {
+            if *volatile_type == struct_type && *end <= fields.len() {
+                for byte in fields[*start..*end].iter_mut() {
+                    *byte = 0;
+                }
+            }
+        }
+    }
+
+    hash.write_block(&fields);
+    let strings = undefined_struct.strings.iter();
+    hash.write(&(strings.len() as u32).to_le_bytes());
+    for string in strings {
+        hash.write_block(string);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{SMBiosTableBuilder, StructBuilder, SystemInformationBuilder};
+    use crate::core::SMBiosVersion;
+
+    fn test_data(handle: u16, wakeup_type: u8) -> SMBiosData {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add_with_handle(
+                Handle(handle),
+                SystemInformationBuilder::new()
+                    .manufacturer("Manufacturer")
+                    .wakeup_type(wakeup_type),
+            )
+            .unwrap();
+        builder
+            .add(StructBuilder::new(11).byte(1).string("OEM"))
+            .unwrap();
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let data = test_data(0x10, 6);
+        assert_eq!(data.content_hash(), test_data(0x10, 6).content_hash());
+        assert_ne!(data.content_hash(), test_data(0x11, 6).content_hash());
+        assert_ne!(data.content_hash(), test_data(0x10, 5).content_hash());
+
+        let options = ContentHashOptions {
+            ignore_handles: true,
+            ignore_volatile_fields: true,
+        };
+        assert_eq!(
+            data.content_hash_with_options(&options),
+            test_data(0x11, 5).content_hash_with_options(&options)
+        );
+        assert_ne!(
+            data.content_hash_with_options(&options),
+            SMBiosData::from_vec_and_version(data.raw_bytes(), None)
+                .content_hash_with_options(&options)
+        );
+    }
+
+    /// A Physical Memory Array and a Memory Device referencing it
+    fn memory_data(array_handle: u16, device_handle: u16, size: u16) -> SMBiosData {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add_with_handle(
+                Handle(array_handle),
+                StructBuilder::new(16)
+                    .byte(0x03)
+                    .byte(0x03)
+                    .byte(0x03)
+                    .dword(0x0100_0000)
+                    .handle(Handle(0xFFFE))
+                    .word(1),
+            )
+            .unwrap();
+        builder
+            .add_with_handle(
+                Handle(device_handle),
+                StructBuilder::new(17)
+                    .handle(Handle(array_handle))
+                    .handle(Handle(0xFFFE))
+                    .word(72)
+                    .word(64)
+                    .word(size),
+            )
+            .unwrap();
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_content_hash_renumbered_handles() {
+        let options = ContentHashOptions {
+            ignore_handles: true,
+            ignore_volatile_fields: false,
+        };
+        let data = memory_data(0x1000, 0x1100, 0x4000);
+        let renumbered = memory_data(0x20, 0x21, 0x4000);
+        assert_ne!(data.content_hash(), renumbered.content_hash());
+        assert_eq!(
+            data.content_hash_with_options(&options),
+            renumbered.content_hash_with_options(&options)
+        );
+        assert_ne!(
+            data.content_hash_with_options(&options),
+            memory_data(0x20, 0x21, 0x2000).content_hash_with_options(&options)
+        );
+    }
+}
@@ -5,12 +5,14 @@ use std::{
     convert::TryInto,
     fmt,
     fs::{read, File},
-    io::{prelude::*, Error, ErrorKind, SeekFrom},
+    io::{prelude::*, SeekFrom},
     num::Wrapping,
     ops::RangeBounds,
     path::Path,
     any
 };
+#[cfg(not(feature = "no_std"))]
+use crate::error::Error;
 #[cfg(feature = "no_std")]
 use core::{
     convert::TryFrom,
@@ -21,6 +23,7 @@ use core::{
 };
 #[cfg(feature = "no_std")]
 use alloc::{vec::Vec, format};
+use super::smbios_data::SMBiosVersion;
 
 /// SMBiosEntryPoint32 structure parse errors
 pub enum SMBiosEntryPoint32Error {
@@ -40,13 +43,6 @@ pub enum SMBiosEntryPoint32Error {
     EntryPointNotFound,
 }
 
-#[cfg(not(feature = "no_std"))]
-impl SMBiosEntryPoint32Error {
-    fn into_io_error(&self) -> Error {
-        Error::new(ErrorKind::InvalidData, self.to_string())
-    }
-}
-
 impl fmt::Debug for SMBiosEntryPoint32Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("SMBiosEntryPoint32Error")
@@ -295,14 +291,68 @@ impl<'a> SMBiosEntryPoint32 {
         self.raw[Self::BCD_REVISION_OFFSET]
     }
 
+    /// The raw bytes of the entry point
+    pub fn raw(&self) -> &[u8] {
+        &self.raw
+    }
+
+    /// Verifies the EPS and IEPS checksums of a raw entry point
+    ///
+    /// The EPS is summed for `entry_point_length` bytes and the IEPS
+    /// for 0Fh bytes starting at offset 10h.
+    pub fn has_valid_checksums(raw: &[u8]) -> bool {
+        if raw.len() < Self::MINIMUM_SIZE {
+            return false;
+        }
+
+        let entry_point_length = raw[Self::ENTRY_POINT_LENGTH_OFFSET] as usize;
+        match raw.get(0..entry_point_length) {
+            Some(checked_bytes) => {
+                verify_checksum(checked_bytes)
+                    && verify_checksum(
+                        &raw[Self::INTERMEDIATE_ANCHOR_OFFSET
+                            ..Self::INTERMEDIATE_ANCHOR_OFFSET + 0x0F],
+                    )
+            }
+            None => false,
+        }
+    }
+
+    /// Recomputes the EPS and IEPS checksums of a raw entry point
+    ///
+    /// The IEPS checksum is repaired first, as the IEPS is part of the EPS.
+    pub fn fix_checksums(raw: &mut [u8]) -> Result<(), SMBiosEntryPoint32Error> {
+        if raw.len() < Self::MINIMUM_SIZE {
+            return Err(SMBiosEntryPoint32Error::SliceTooSmall);
+        }
+
+        let entry_point_length = raw[Self::ENTRY_POINT_LENGTH_OFFSET] as usize;
+        if entry_point_length > raw.len() {
+            return Err(SMBiosEntryPoint32Error::EntryPointLengthTooBig);
+        }
+
+        fix_checksum(
+            &mut raw[Self::INTERMEDIATE_ANCHOR_OFFSET..Self::INTERMEDIATE_ANCHOR_OFFSET + 0x0F],
+            Self::INTERMEDIATE_CHECKSUM_OFFSET - Self::INTERMEDIATE_ANCHOR_OFFSET,
+        );
+        fix_checksum(
+            &mut raw[..entry_point_length],
+            Self::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET,
+        );
+
+        Ok(())
+    }
+
     /// Load this structure from a file
     #[cfg(not(feature = "no_std"))]
     pub fn try_load_from_file(filename: &Path) -> Result<Self, Error> {
-        read(filename)?.try_into().map_err(|e: SMBiosEntryPoint32Error| e.into_io_error())
+        Ok(read(filename)?.try_into()?)
     }
 
     /// Load this structure by scanning a file within the given offsets,
     /// looking for the [SMBiosEntryPoint32::SM_ANCHOR] string.
+    ///
+    /// Returns [Error::EntryPointNotFound] when the anchor is not found.
     #[cfg(not(feature = "no_std"))]
     pub fn try_scan_from_file<T: Iterator<Item = u64>>(
         file: &mut File,
@@ -318,17 +368,17 @@ impl<'a> SMBiosEntryPoint32 {
             if anchor == Self::SM_ANCHOR {
                 let mut length = [0; 2];
                 file.read_exact(&mut length)?;
-                let struct_length = length[1] as usize;
+                // Version 2.1 implementations may report a length of 1Eh
+                let struct_length = (length[1] as usize).max(Self::MINIMUM_SIZE);
                 let mut entry_point_buffer = Vec::with_capacity(struct_length);
                 entry_point_buffer.resize(struct_length, 0);
                 file.seek(SeekFrom::Start(offset))?;
                 file.read_exact(&mut entry_point_buffer)?;
-                let entry_point: Self = entry_point_buffer.try_into()
-                    .map_err(|e: SMBiosEntryPoint32Error| e.into_io_error())?;
+                let entry_point: Self = entry_point_buffer.try_into()?;
                 return Ok(entry_point);
             }
         }
-        Err(Error::new(ErrorKind::UnexpectedEof, "Not found"))
+        Err(Error::EntryPointNotFound)
     }
 
     /// Load this structure by scanning given memory slice,
@@ -337,11 +387,17 @@ impl<'a> SMBiosEntryPoint32 {
     pub fn try_scan_from_raw(data: &[u8]) -> Result<Self, SMBiosEntryPoint32Error> {
         let range = 0..data.len();
         for offset in range.step_by(0x10) {
-            let anchor = &data[offset..offset + 5];
-            if anchor == Self::SM_ANCHOR {
-                let length = &data[offset + 5..offset + 7];
-                let struct_length = length[1] as usize;
-                let entry_point_buffer = data[0..struct_length].to_vec();
+            let anchor = &data[offset..];
+            if anchor.starts_with(&Self::SM_ANCHOR) {
+                // Version 2.1 implementations may report a length of 1Eh
+                let struct_length = anchor
+                    .get(Self::ENTRY_POINT_LENGTH_OFFSET)
+                    .map_or(Self::MINIMUM_SIZE, |length| *length as usize)
+                    .max(Self::MINIMUM_SIZE);
+                let entry_point_buffer = anchor
+                    .get(..struct_length)
+                    .ok_or(SMBiosEntryPoint32Error::SliceTooSmall)?
+                    .to_vec();
                 let entry_point: Self = entry_point_buffer.try_into()?;
                 return Ok(entry_point);
             }
@@ -394,7 +450,7 @@ impl<'a> TryFrom<Vec<u8>> for SMBiosEntryPoint32 {
         // Verify the IEPS checksum
         // The checksum is calculated for a length of 0x0F
         let intermediate_entry_point_structure: [u8; 0x0F] = raw
-            [Self::INTERMEDIATE_ANCHOR_OFFSET..]
+            [Self::INTERMEDIATE_ANCHOR_OFFSET..Self::INTERMEDIATE_ANCHOR_OFFSET + 0x0F]
             .try_into()
             .expect("0x0F bytes");
 
@@ -406,6 +462,14 @@ impl<'a> TryFrom<Vec<u8>> for SMBiosEntryPoint32 {
     }
 }
 
+impl From<&SMBiosEntryPoint32> for SMBiosVersion {
+    /// The version of the entry point, without a revision as the 32-bit
+    /// entry point does not hold one
+    fn from(entry_point: &SMBiosEntryPoint32) -> Self {
+        SMBiosVersion::new(entry_point.major_version(), entry_point.minor_version(), 0)
+    }
+}
+
 impl fmt::Debug for SMBiosEntryPoint32 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(any::type_name::<SMBiosEntryPoint32>())
@@ -465,23 +529,16 @@ impl Serialize for SMBiosEntryPoint32 {
 pub enum SMBiosEntryPoint64Error {
     /// Slice is smaller than SMBiosEntryPoint64::MINIMUM_SIZE
     SliceTooSmall,
-    /// Entry Point Structure checksum verification failed
+    /// Expected _SM3_ identifier not found
     SM3NotFound,
-    /// The Entry Point Length field specified a value which exceeded the bounds of the Entry Point Structure
+    /// Entry Point Structure checksum verification failed
     ChecksumVerificationFailed,
-    /// Expected _SM3_ identifier not found
+    /// The Entry Point Length field specified a value which exceeded the bounds of the Entry Point Structure
     EntryPointLengthTooBig,
     /// Entry Point not found
     EntryPointNotFound
 }
 
-#[cfg(not(feature = "no_std"))]
-impl SMBiosEntryPoint64Error {
-    fn into_io_error(&self) -> Error {
-        Error::new(ErrorKind::InvalidData, self.to_string())
-    }
-}
-
 impl fmt::Debug for SMBiosEntryPoint64Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("SMBiosEntryPoint64Error")
@@ -635,20 +692,66 @@ impl<'a> SMBiosEntryPoint64 {
     pub fn structure_table_address(&self) -> u64 {
         u64::from_le_bytes(
             self.raw
-                [Self::STRUCTURE_TABLE_ADDRESS_OFFSET..Self::STRUCTURE_TABLE_ADDRESS_OFFSET + 4]
+                [Self::STRUCTURE_TABLE_ADDRESS_OFFSET..Self::STRUCTURE_TABLE_ADDRESS_OFFSET + 8]
                 .try_into()
                 .expect("u64 is 8 bytes"),
         )
     }
 
+    /// The raw bytes of the entry point
+    pub fn raw(&self) -> &[u8] {
+        &self.raw
+    }
+
+    /// Verifies the EPS checksum of a raw entry point
+    ///
+    /// The EPS is summed for `entry_point_length` bytes. Unlike the 32-bit
+    /// entry point (see [SMBiosEntryPoint32::has_valid_checksums]) there is
+    /// no IEPS checksum.
+    pub fn has_valid_checksums(raw: &[u8]) -> bool {
+        if raw.len() < Self::MINIMUM_SIZE {
+            return false;
+        }
+
+        let entry_point_length = raw[Self::ENTRY_POINT_LENGTH_OFFSET] as usize;
+        match raw.get(0..entry_point_length) {
+            Some(checked_bytes) => verify_checksum(checked_bytes),
+            None => false,
+        }
+    }
+
+    /// Recomputes the EPS checksum of a raw entry point
+    ///
+    /// Unlike the 32-bit entry point (see [SMBiosEntryPoint32::fix_checksums])
+    /// there is no IEPS checksum.
+    pub fn fix_checksums(raw: &mut [u8]) -> Result<(), SMBiosEntryPoint64Error> {
+        if raw.len() < Self::MINIMUM_SIZE {
+            return Err(SMBiosEntryPoint64Error::SliceTooSmall);
+        }
+
+        let entry_point_length = raw[Self::ENTRY_POINT_LENGTH_OFFSET] as usize;
+        if entry_point_length > raw.len() {
+            return Err(SMBiosEntryPoint64Error::EntryPointLengthTooBig);
+        }
+
+        fix_checksum(
+            &mut raw[..entry_point_length],
+            Self::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET,
+        );
+
+        Ok(())
+    }
+
     /// Load this structure from a file
     #[cfg(not(feature = "no_std"))]
     pub fn try_load_from_file(filename: &Path) -> Result<Self, Error> {
-        read(filename)?.try_into().map_err(|e: SMBiosEntryPoint64Error| e.into_io_error())
+        Ok(read(filename)?.try_into()?)
     }
 
     /// Load this structure by scanning a file within the given offsets,
     /// looking for the [SMBiosEntryPoint64::SM3_ANCHOR] string.
+    ///
+    /// Returns [Error::EntryPointNotFound] when the anchor is not found.
     #[cfg(not(feature = "no_std"))]
     pub fn try_scan_from_file<T: Iterator<Item = u64>>(
         file: &mut File,
@@ -664,17 +767,16 @@ impl<'a> SMBiosEntryPoint64 {
             if anchor == Self::SM3_ANCHOR {
                 let mut length = [0; 2];
                 file.read_exact(&mut length)?;
-                let struct_length = length[1] as usize;
+                let struct_length = (length[1] as usize).max(Self::MINIMUM_SIZE);
                 let mut entry_point_buffer = Vec::with_capacity(struct_length);
                 entry_point_buffer.resize(struct_length, 0);
                 file.seek(SeekFrom::Start(offset))?;
                 file.read_exact(&mut entry_point_buffer)?;
-                let entry_point: Self = entry_point_buffer.try_into()
-                    .map_err(|e: SMBiosEntryPoint64Error| e.into_io_error())?;
+                let entry_point: Self = entry_point_buffer.try_into()?;
                 return Ok(entry_point);
             }
         }
-        Err(Error::new(ErrorKind::UnexpectedEof, "Not found"))
+        Err(Error::EntryPointNotFound)
     }
 
     /// Load this structure by scanning given memory slice,
@@ -683,11 +785,16 @@ impl<'a> SMBiosEntryPoint64 {
     pub fn try_scan_from_raw(data: &[u8]) -> Result<Self, SMBiosEntryPoint64Error> {
         let range = 0..data.len();
         for offset in range.step_by(0x10) {
-            let anchor = &data[offset..offset + 6];
-            if anchor == Self::SM3_ANCHOR {
-                let length = &data[offset + 6..offset + 8];
-                let struct_length = length[1] as usize;
-                let entry_point_buffer = data[0..struct_length].to_vec();
+            let anchor = &data[offset..];
+            if anchor.starts_with(&Self::SM3_ANCHOR) {
+                let struct_length = anchor
+                    .get(Self::ENTRY_POINT_LENGTH_OFFSET)
+                    .map_or(Self::MINIMUM_SIZE, |length| *length as usize)
+                    .max(Self::MINIMUM_SIZE);
+                let entry_point_buffer = anchor
+                    .get(..struct_length)
+                    .ok_or(SMBiosEntryPoint64Error::SliceTooSmall)?
+                    .to_vec();
                 let entry_point: Self = entry_point_buffer.try_into()?;
                 return Ok(entry_point);
             }
@@ -707,6 +814,7 @@ impl fmt::Debug for SMBiosEntryPoint64 {
             .field("major_version", &self.major_version())
             .field("minor_version", &self.minor_version())
             .field("docrev", &self.docrev())
+            .field("entry_point_revision", &self.entry_point_revision())
             .field(
                 "structure_table_maximum_size",
                 &self.structure_table_maximum_size(),
@@ -721,7 +829,7 @@ impl Serialize for SMBiosEntryPoint64 {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosEntryPoint64", 7)?;
+        let mut state = serializer.serialize_struct("SMBiosEntryPoint64", 8)?;
         state.serialize_field(
             "entry_point_structure_checksum",
             &self.entry_point_structure_checksum(),
@@ -730,6 +838,7 @@ impl Serialize for SMBiosEntryPoint64 {
         state.serialize_field("major_version", &self.major_version())?;
         state.serialize_field("minor_version", &self.minor_version())?;
         state.serialize_field("docrev", &self.docrev())?;
+        state.serialize_field("entry_point_revision", &self.entry_point_revision())?;
         state.serialize_field(
             "structure_table_maximum_size",
             &self.structure_table_maximum_size(),
@@ -771,6 +880,43 @@ impl<'a> TryFrom<Vec<u8>> for SMBiosEntryPoint64 {
     }
 }
 
+impl From<&SMBiosEntryPoint64> for SMBiosVersion {
+    fn from(entry_point: &SMBiosEntryPoint64) -> Self {
+        SMBiosVersion::new(
+            entry_point.major_version(),
+            entry_point.minor_version(),
+            entry_point.docrev(),
+        )
+    }
+}
+
+/// Locates the structure table described by the raw `entry_point`
+///
+/// The 64-bit (_SM3_) entry point is tried first, followed by the 32-bit
+/// (_SM_) entry point. Returns the structure table address, the structure
+/// table length (the maximum size for a 64-bit entry point) and the
+/// [SMBiosVersion] described by the entry point.
+#[cfg(not(feature = "no_std"))]
+pub(crate) fn locate_structure_table(
+    entry_point: &[u8],
+) -> Result<(u64, usize, SMBiosVersion), Error> {
+    if entry_point.starts_with(&SMBiosEntryPoint64::SM3_ANCHOR) {
+        let entry_point = SMBiosEntryPoint64::try_from(entry_point.to_vec())?;
+        Ok((
+            entry_point.structure_table_address(),
+            entry_point.structure_table_maximum_size() as usize,
+            SMBiosVersion::from(&entry_point),
+        ))
+    } else {
+        let entry_point = SMBiosEntryPoint32::try_from(entry_point.to_vec())?;
+        Ok((
+            entry_point.structure_table_address() as u64,
+            entry_point.structure_table_length() as usize,
+            SMBiosVersion::from(&entry_point),
+        ))
+    }
+}
+
 /// Verifies EPS and IEPS Checksums
 ///
 /// The EPS and IEPS contain a checksum value.
@@ -779,10 +925,190 @@ impl<'a> TryFrom<Vec<u8>> for SMBiosEntryPoint64 {
 /// the value 00h (using 8-bit addition [Wrapping] calculations).
 /// Values in the EPS are summed starting at offset 00h, for 'entry_point_length'
 /// bytes.
-fn verify_checksum(data: &[u8]) -> bool {
+pub fn verify_checksum(data: &[u8]) -> bool {
     let mut sum = Wrapping(0u8);
 
     data.iter().for_each(|b| sum += Wrapping(*b));
 
     sum == Wrapping(0)
 }
+
+/// Repairs the checksum of an EPS or IEPS
+///
+/// Sets the byte at `checksum_offset` to the value which, when added to
+/// all other bytes of `data`, results in the value 00h (using 8-bit
+/// addition [Wrapping] calculations). `checksum_offset` must be within
+/// `data`, see [SMBiosEntryPoint32::fix_checksums] and
+/// [SMBiosEntryPoint64::fix_checksums] which check the bounds.
+fn fix_checksum(data: &mut [u8], checksum_offset: usize) {
+    let mut sum = Wrapping(0u8);
+
+    data[checksum_offset] = 0;
+    data.iter().for_each(|b| sum += Wrapping(*b));
+
+    data[checksum_offset] = (Wrapping(0u8) - sum).0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry_point_32() -> Vec<u8> {
+        vec![
+            0x5F, 0x53, 0x4D, 0x5F, 0x66, 0x1F, 0x02, 0x08, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x5F, 0x44, 0x4D, 0x49, 0x5F, 0x0C, 0x13, 0x00, 0x00, 0x10, 0x0F, 0x00,
+            0x02, 0x00, 0x28,
+        ]
+    }
+
+    #[test]
+    fn test_entry_point_32() {
+        let entry_point = SMBiosEntryPoint32::try_from(entry_point_32()).unwrap();
+
+        assert_eq!(entry_point.entry_point_structure_checksum(), 0x66);
+        assert_eq!(entry_point.entry_point_length(), 0x1F);
+        assert_eq!(entry_point.major_version(), 2);
+        assert_eq!(entry_point.minor_version(), 8);
+        assert_eq!(entry_point.maximum_structure_size(), 0x13);
+        assert_eq!(entry_point.entry_point_revision(), 0);
+        assert_eq!(entry_point.formatted_area(), [0; 5]);
+        assert_eq!(entry_point.intermediate_anchor(), SMBiosEntryPoint32::DMI_ANCHOR);
+        assert_eq!(entry_point.intermediate_checksum(), 0x0C);
+        assert_eq!(entry_point.structure_table_length(), 0x13);
+        assert_eq!(entry_point.structure_table_address(), 0x000F_1000);
+        assert_eq!(entry_point.number_of_smbios_structures(), 2);
+        assert_eq!(entry_point.bcd_revision(), 0x28);
+        assert_eq!(
+            SMBiosVersion::from(&entry_point),
+            SMBiosVersion::new(2, 8, 0)
+        );
+
+        // Trailing bytes (such as a full paragraph read from memory) are accepted
+        let mut padded = entry_point_32();
+        padded.push(0);
+        assert!(SMBiosEntryPoint32::try_from(padded).is_ok());
+    }
+
+    #[test]
+    fn test_entry_point_32_errors() {
+        assert!(matches!(
+            SMBiosEntryPoint32::try_from(entry_point_32()[..0x1E].to_vec()),
+            Err(SMBiosEntryPoint32Error::SliceTooSmall)
+        ));
+
+        let mut raw = entry_point_32();
+        raw[0] = b'-';
+        assert!(matches!(
+            SMBiosEntryPoint32::try_from(raw),
+            Err(SMBiosEntryPoint32Error::SMNotFound)
+        ));
+
+        let mut raw = entry_point_32();
+        raw[SMBiosEntryPoint32::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET] += 1;
+        assert!(matches!(
+            SMBiosEntryPoint32::try_from(raw),
+            Err(SMBiosEntryPoint32Error::EntryChecksumVerificationFailed)
+        ));
+
+        let mut raw = entry_point_32();
+        raw[SMBiosEntryPoint32::INTERMEDIATE_CHECKSUM_OFFSET] += 1;
+        raw[SMBiosEntryPoint32::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET] -= 1;
+        assert!(matches!(
+            SMBiosEntryPoint32::try_from(raw),
+            Err(SMBiosEntryPoint32Error::IntermediateChecksumVerificationFailed)
+        ));
+    }
+
+    fn entry_point_64() -> Vec<u8> {
+        vec![
+            0x5F, 0x53, 0x4D, 0x33, 0x5F, 0x20, 0x18, 0x03, 0x03, 0x00, 0x01, 0x00, 0x00, 0x10,
+            0x00, 0x00, 0x00, 0x10, 0x0F, 0x00, 0x01, 0x00, 0x00, 0x00,
+        ]
+    }
+
+    #[test]
+    fn test_entry_point_64() {
+        let entry_point = SMBiosEntryPoint64::try_from(entry_point_64()).unwrap();
+
+        assert_eq!(entry_point.entry_point_structure_checksum(), 0x20);
+        assert_eq!(entry_point.entry_point_length(), 0x18);
+        assert_eq!(entry_point.major_version(), 3);
+        assert_eq!(entry_point.minor_version(), 3);
+        assert_eq!(entry_point.docrev(), 0);
+        assert_eq!(entry_point.entry_point_revision(), 1);
+        assert_eq!(entry_point.structure_table_maximum_size(), 0x1000);
+        assert_eq!(entry_point.structure_table_address(), 0x0000_0001_000F_1000);
+        assert_eq!(
+            SMBiosVersion::from(&entry_point),
+            SMBiosVersion::new(3, 3, 0)
+        );
+    }
+
+    #[test]
+    fn test_entry_point_64_errors() {
+        assert!(matches!(
+            SMBiosEntryPoint64::try_from(entry_point_64()[..0x17].to_vec()),
+            Err(SMBiosEntryPoint64Error::SliceTooSmall)
+        ));
+
+        let mut raw = entry_point_64();
+        raw[3] = b'_';
+        assert!(matches!(
+            SMBiosEntryPoint64::try_from(raw),
+            Err(SMBiosEntryPoint64Error::SM3NotFound)
+        ));
+
+        let mut raw = entry_point_64();
+        raw[SMBiosEntryPoint64::DOCREV_OFFSET] = 1;
+        assert!(matches!(
+            SMBiosEntryPoint64::try_from(raw),
+            Err(SMBiosEntryPoint64Error::ChecksumVerificationFailed)
+        ));
+
+        let mut raw = entry_point_64();
+        raw[SMBiosEntryPoint64::ENTRY_POINT_LENGTH_OFFSET] = 0x20;
+        assert!(matches!(
+            SMBiosEntryPoint64::try_from(raw),
+            Err(SMBiosEntryPoint64Error::EntryPointLengthTooBig)
+        ));
+    }
+
+    #[test]
+    fn test_fix_checksums() {
+        let mut data = [0x10, 0x00, 0x20, 0x30];
+        fix_checksum(&mut data, 1);
+        assert_eq!(data[1], 0xA0);
+        assert!(verify_checksum(&data));
+
+        let mut raw = entry_point_32();
+        raw[SMBiosEntryPoint32::MINOR_VERSION_OFFSET] = 7;
+        raw[SMBiosEntryPoint32::STRUCTURE_TABLE_LENGTH_OFFSET] = 0x20;
+        assert!(!SMBiosEntryPoint32::has_valid_checksums(&raw));
+        SMBiosEntryPoint32::fix_checksums(&mut raw).unwrap();
+        assert!(SMBiosEntryPoint32::has_valid_checksums(&raw));
+        let entry_point = SMBiosEntryPoint32::try_from(raw).unwrap();
+        assert_eq!(entry_point.minor_version(), 7);
+        assert_eq!(entry_point.structure_table_length(), 0x20);
+
+        let mut raw = entry_point_64();
+        raw[SMBiosEntryPoint64::MINOR_VERSION_OFFSET] = 4;
+        assert!(!SMBiosEntryPoint64::has_valid_checksums(&raw));
+        SMBiosEntryPoint64::fix_checksums(&mut raw).unwrap();
+        assert!(SMBiosEntryPoint64::has_valid_checksums(&raw));
+        assert_eq!(
+            SMBiosEntryPoint64::try_from(raw).unwrap().minor_version(),
+            4
+        );
+
+        assert!(matches!(
+            SMBiosEntryPoint64::fix_checksums(&mut [0; 4]),
+            Err(SMBiosEntryPoint64Error::SliceTooSmall)
+        ));
+        let mut raw = entry_point_32();
+        raw[SMBiosEntryPoint32::ENTRY_POINT_LENGTH_OFFSET] = 0x40;
+        assert!(matches!(
+            SMBiosEntryPoint32::fix_checksums(&mut raw),
+            Err(SMBiosEntryPoint32Error::EntryPointLengthTooBig)
+        ));
+    }
+}
@@ -0,0 +1,303 @@
+use super::header::Handle;
+use super::struct_fields::StructFields;
+use super::undefined_struct::UndefinedStruct;
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::{vec, vec::Vec};
+
+/// A field of a structure referencing a string of its string-set or another
+/// structure, named after the DMTF SMBIOS specification in lowercase words
+/// separated by underscores
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ReferenceField {
+    /// A single field at the offset
+    Single(&'static str, usize),
+    /// A list of fields at the offsets
+    List(&'static str, Vec<usize>),
+}
+
+impl ReferenceField {
+    fn offsets(self) -> Vec<usize> {
+        match self {
+            ReferenceField::Single(_, offset) => vec![offset],
+            ReferenceField::List(_, offsets) => offsets,
+        }
+    }
+}
+
+/// Offsets of the fields of the structure referencing other structures by
+/// [Handle], see [crate::DefinedStructMut::handle_field_offsets]
+pub(crate) fn handle_field_offsets(parts: &UndefinedStruct) -> Vec<usize> {
+    handle_fields(parts)
+        .into_iter()
+        .flat_map(ReferenceField::offsets)
+        .collect()
+}
+
+/// Offsets of the fields of the structure referencing a string of its
+/// string-set, see [crate::DefinedStructMut::string_field_offsets]
+pub(crate) fn string_field_offsets(parts: &UndefinedStruct) -> Vec<usize> {
+    string_fields(parts)
+        .into_iter()
+        .flat_map(ReferenceField::offsets)
+        .collect()
+}
+
+/// The fields of the structure referencing other structures by [Handle]
+///
+/// Fields beyond the length of the structure are left out.
+pub(crate) fn handle_fields(parts: &UndefinedStruct) -> Vec<ReferenceField> {
+    use ReferenceField::*;
+
+    let fields = match parts.header.struct_type() {
+        SMBiosBaseboardInformation::STRUCT_TYPE => {
+            let mut fields = vec![Single("chassis_handle", 0x0B)];
+            if let Some(count) = parts.get_field_byte(0x0E) {
+                fields.push(List(
+                    "contained_object_handles",
+                    (0..count as usize)
+                        .map(|index| 0x0F + index * Handle::SIZE)
+                        .collect(),
+                ));
+            }
+            fields
+        }
+        SMBiosProcessorInformation::STRUCT_TYPE => vec![
+            Single("l1_cache_handle", 0x1A),
+            Single("l2_cache_handle", 0x1C),
+            Single("l3_cache_handle", 0x1E),
+        ],
+        SMBiosMemoryControllerInformation::STRUCT_TYPE => {
+            let count = parts.get_field_byte(0x0E).unwrap_or(0) as usize;
+            vec![List(
+                "memory_module_configuration_handles",
+                (0..count)
+                    .map(|index| 0x0F + index * Handle::SIZE)
+                    .collect(),
+            )]
+        }
+        SMBiosGroupAssociations::STRUCT_TYPE => {
+            // items of a type BYTE and a handle WORD
+            vec![List(
+                "item_handles",
+                (0x05..parts.fields.len())
+                    .step_by(3)
+                    .map(|item| item + 1)
+                    .collect(),
+            )]
+        }
+        SMBiosPhysicalMemoryArray::STRUCT_TYPE => {
+            vec![Single("memory_error_information_handle", 0x0B)]
+        }
+        SMBiosMemoryDevice::STRUCT_TYPE => vec![
+            Single("physical_memory_array_handle", 0x04),
+            Single("memory_error_information_handle", 0x06),
+        ],
+        SMBiosMemoryArrayMappedAddress::STRUCT_TYPE => vec![Single("memory_array_handle", 0x0C)],
+        SMBiosMemoryDeviceMappedAddress::STRUCT_TYPE => vec![
+            Single("memory_device_handle", 0x0C),
+            Single("memory_array_mapped_address_handle", 0x0E),
+        ],
+        SMBiosCoolingDevice::STRUCT_TYPE => vec![Single("temperature_probe_handle", 0x04)],
+        SMBiosManagementDeviceComponent::STRUCT_TYPE => vec![
+            Single("management_device_handle", 0x05),
+            Single("component_handle", 0x07),
+            Single("threshold_handle", 0x09),
+        ],
+        SMBiosMemoryChannel::STRUCT_TYPE => {
+            // pairs of a load BYTE and a handle WORD
+            vec![List(
+                "memory_device_handles",
+                (0x07..parts.fields.len())
+                    .step_by(3)
+                    .map(|pair| pair + 1)
+                    .collect(),
+            )]
+        }
+        SMBiosSystemPowerSupply::STRUCT_TYPE => vec![
+            Single("input_voltage_probe_handle", 0x10),
+            Single("cooling_device_handle", 0x12),
+            Single("input_current_probe_handle", 0x14),
+        ],
+        SMBiosAdditionalInformation::STRUCT_TYPE => {
+            // entries start with their length BYTE followed by the handle WORD
+            vec![List(
+                "referenced_handles",
+                additional_information_entries(parts)
+                    .into_iter()
+                    .map(|entry| entry + 1)
+                    .collect(),
+            )]
+        }
+        SMBiosProcessorAdditionalInformation::STRUCT_TYPE => {
+            vec![Single("referenced_handle", 0x04)]
+        }
+        _ => Vec::new(),
+    };
+
+    within_length(parts, fields, Handle::SIZE)
+}
+
+/// The fields of the structure referencing a string of its string-set
+///
+/// Fields beyond the length of the structure are left out.
+pub(crate) fn string_fields(parts: &UndefinedStruct) -> Vec<ReferenceField> {
+    use ReferenceField::*;
+
+    let fields = match parts.header.struct_type() {
+        SMBiosInformation::STRUCT_TYPE => vec![
+            Single("vendor", 0x04),
+            Single("bios_version", 0x05),
+            Single("bios_release_date", 0x08),
+        ],
+        SMBiosSystemInformation::STRUCT_TYPE => vec![
+            Single("manufacturer", 0x04),
+            Single("product_name", 0x05),
+            Single("version", 0x06),
+            Single("serial_number", 0x07),
+            Single("sku_number", 0x19),
+            Single("family", 0x1A),
+        ],
+        SMBiosBaseboardInformation::STRUCT_TYPE => vec![
+            Single("manufacturer", 0x04),
+            Single("product", 0x05),
+            Single("version", 0x06),
+            Single("serial_number", 0x07),
+            Single("asset_tag", 0x08),
+            Single("location_in_chassis", 0x0A),
+        ],
+        SMBiosSystemChassisInformation::STRUCT_TYPE => {
+            let mut fields = vec![
+                Single("manufacturer", 0x04),
+                Single("version", 0x06),
+                Single("serial_number", 0x07),
+                Single("asset_tag_number", 0x08),
+            ];
+            // SKU number follows the contained elements
+            if let (Some(count), Some(record_length)) =
+                (parts.get_field_byte(0x13), parts.get_field_byte(0x14))
+            {
+                fields.push(Single(
+                    "sku_number",
+                    0x15 + count as usize * record_length as usize,
+                ));
+            }
+            fields
+        }
+        SMBiosProcessorInformation::STRUCT_TYPE => vec![
+            Single("socket_designation", 0x04),
+            Single("processor_manufacturer", 0x07),
+            Single("processor_version", 0x10),
+            Single("serial_number", 0x20),
+            Single("asset_tag", 0x21),
+            Single("part_number", 0x22),
+        ],
+        SMBiosMemoryModuleInformation::STRUCT_TYPE => vec![Single("socket_designation", 0x04)],
+        SMBiosCacheInformation::STRUCT_TYPE => vec![Single("socket_designation", 0x04)],
+        SMBiosPortConnectorInformation::STRUCT_TYPE => vec![
+            Single("internal_reference_designator", 0x04),
+            Single("external_reference_designator", 0x06),
+        ],
+        SMBiosSystemSlot::STRUCT_TYPE => vec![Single("slot_designation", 0x04)],
+        SMBiosOnBoardDeviceInformation::STRUCT_TYPE => {
+            // devices of a type BYTE and a description string BYTE
+            vec![List(
+                "description_strings",
+                (0x04..parts.fields.len())
+                    .step_by(2)
+                    .map(|device| device + 1)
+                    .collect(),
+            )]
+        }
+        SMBiosBiosLanguageInformation::STRUCT_TYPE => vec![Single("current_language", 0x15)],
+        SMBiosGroupAssociations::STRUCT_TYPE => vec![Single("group_name", 0x04)],
+        SMBiosMemoryDevice::STRUCT_TYPE => vec![
+            Single("device_locator", 0x10),
+            Single("bank_locator", 0x11),
+            Single("manufacturer", 0x17),
+            Single("serial_number", 0x18),
+            Single("asset_tag", 0x19),
+            Single("part_number", 0x1A),
+            Single("firmware_version", 0x2B),
+        ],
+        SMBiosPortableBattery::STRUCT_TYPE => vec![
+            Single("location", 0x04),
+            Single("manufacturer", 0x05),
+            Single("manufacture_date", 0x06),
+            Single("serial_number", 0x07),
+            Single("device_name", 0x08),
+            Single("sbds_version_number", 0x0E),
+            Single("sbds_device_chemistry", 0x14),
+        ],
+        SMBiosVoltageProbe::STRUCT_TYPE => vec![Single("description", 0x04)],
+        SMBiosCoolingDevice::STRUCT_TYPE => vec![Single("description", 0x0E)],
+        SMBiosTemperatureProbe::STRUCT_TYPE => vec![Single("description", 0x04)],
+        SMBiosElectricalCurrentProbe::STRUCT_TYPE => vec![Single("description", 0x04)],
+        SMBiosOutOfBandRemoteAccess::STRUCT_TYPE => vec![Single("manufacturer_name", 0x04)],
+        SMBiosManagementDevice::STRUCT_TYPE => vec![Single("description", 0x04)],
+        SMBiosManagementDeviceComponent::STRUCT_TYPE => vec![Single("description", 0x04)],
+        SMBiosSystemPowerSupply::STRUCT_TYPE => vec![
+            Single("location", 0x05),
+            Single("device_name", 0x06),
+            Single("manufacturer", 0x07),
+            Single("serial_number", 0x08),
+            Single("asset_tag_number", 0x09),
+            Single("model_part_number", 0x0A),
+            Single("revision_level", 0x0B),
+        ],
+        SMBiosAdditionalInformation::STRUCT_TYPE => {
+            // entries hold their string BYTE at relative offset 4
+            vec![List(
+                "entry_strings",
+                additional_information_entries(parts)
+                    .into_iter()
+                    .map(|entry| entry + 4)
+                    .collect(),
+            )]
+        }
+        SMBiosOnboardDevicesExtendedInformation::STRUCT_TYPE => {
+            vec![Single("reference_designation", 0x04)]
+        }
+        SMBiosTpmDevice::STRUCT_TYPE => vec![Single("description", 0x12)],
+        _ => Vec::new(),
+    };
+
+    within_length(parts, fields, 1)
+}
+
+/// Offsets of the entries of an Additional Information structure
+fn additional_information_entries(parts: &UndefinedStruct) -> Vec<usize> {
+    let count = parts.get_field_byte(0x04).unwrap_or(0);
+    let mut entry = 0x05;
+    let mut entries = Vec::new();
+    for _ in 0..count {
+        match parts.get_field_byte(entry) {
+            Some(length) if length > 0 => {
+                entries.push(entry);
+                entry += length as usize;
+            }
+            _ => break,
+        }
+    }
+    entries
+}
+
+/// Leaves out the fields of `size` bytes beyond the length of the structure
+fn within_length(
+    parts: &UndefinedStruct,
+    fields: Vec<ReferenceField>,
+    size: usize,
+) -> Vec<ReferenceField> {
+    let within = |offset: &usize| offset + size <= parts.fields.len();
+    fields
+        .into_iter()
+        .filter_map(|field| match field {
+            ReferenceField::Single(_, offset) if !within(&offset) => None,
+            ReferenceField::List(name, offsets) => Some(ReferenceField::List(
+                name,
+                offsets.into_iter().filter(within).collect(),
+            )),
+            field => Some(field),
+        })
+        .collect()
+}
@@ -1,4 +1,4 @@
-use serde::{ser::SerializeStruct, Serialize, Serializer};
+use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use core::{convert::TryInto, fmt, ops::Deref, str::FromStr, any};
 
 /// # Structure Handle
@@ -7,7 +7,7 @@ use core::{convert::TryInto, fmt, ops::Deref, str::FromStr, any};
 /// Some structures will reference other structures by using this value.
 ///
 /// Dereference a handle (*handle) to access its u16 value.
-#[derive(Serialize, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 pub struct Handle(pub u16);
 
 impl Handle {
@@ -99,6 +99,30 @@ impl Serialize for Header {
     }
 }
 
+impl<'de> Deserialize<'de> for Header {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "Header")]
+        struct Fields {
+            struct_type: u8,
+            length: u8,
+            handle: Handle,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+        let handle = fields.handle.to_le_bytes();
+        Ok(Header([
+            fields.struct_type,
+            fields.length,
+            handle[0],
+            handle[1],
+        ]))
+    }
+}
+
 impl Header {
     /// Total size of a Header (4)
     ///
@@ -0,0 +1,221 @@
+use super::header::Handle;
+use super::struct_fields::StructFields;
+use super::undefined_struct::UndefinedStruct;
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+use serde::Serialize;
+
+/// # Maybe Value
+///
+/// The value of a field, told apart from the sentinels the specification
+/// reserves in place of a value and from a field the structure is too short
+/// to hold.
+///
+/// Many fields give meaning to raw values which are not values, such as
+/// 8000h for the unknown reading of a probe or 0 for an unknown processor
+/// speed. The typed field enumerations convert into a [MaybeValue] (for
+/// example `MaybeValue::from(probe.nominal_value()?)`) so that a sentinel
+/// is never mistaken for, or averaged with, a value. Fields split over a
+/// field and its extended field are merged by the `effective_*()`
+/// functions of their structures.
+///
+/// ```
+/// # use smbioslib::*;
+/// # fn nominal(probe: &SMBiosTemperatureProbe<'_>) -> Option<i16> {
+/// // 1/10th degrees C, None when the probe reports no nominal value
+/// MaybeValue::from(probe.nominal_value()?).value()
+/// # }
+/// ```
+///
+/// Structures grow with each version of the SMBIOS specification, new
+/// fields being appended to the formatted area. A structure written for an
+/// older version, or by firmware which truncates it, is shorter than the
+/// offset of the newer fields. The field functions of the defined structures
+/// and the `get_field_*()` functions of [UndefinedStruct] return None in
+/// that case as well as for a field holding no value; the `*_access()`
+/// field functions of the defined structures and the `access_field_*()`
+/// functions of [UndefinedStruct] give the reason.
+///
+/// ```
+/// # use smbioslib::*;
+/// # fn describe(processor: &SMBiosProcessorInformation<'_>) {
+/// match processor.core_count_2_access() {
+///     MaybeValue::Value(count) => println!("{:?}", count),
+///     MaybeValue::NotPresentInVersion => println!("not defined before SMBIOS 3.0"),
+///     _ => println!("unknown"),
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum MaybeValue<T> {
+    /// The value of the field
+    Value(T),
+    /// The field holds the sentinel for an unknown value
+    Unknown,
+    /// The field holds the sentinel for an absent value, such as the size
+    /// of an empty memory socket, or a string field holds no string
+    NotProvided,
+    /// The field holds a value reserved by the specification
+    Reserved,
+    /// The structure is too short to hold the field, which was introduced
+    /// by a later version of the specification
+    NotPresentInVersion,
+    /// A string field holds the given index of a string which is not in
+    /// the structure's string-set (often FFh)
+    InvalidIndex(u8),
+}
+
+impl<T> MaybeValue<T> {
+    /// The access of a field of `size` bytes at `offset` of `parts`, given
+    /// the `value` read from it
+    ///
+    /// NotPresentInVersion when the field does not fit in the formatted
+    /// area of `parts`, otherwise [MaybeValue::Value] of `value` or
+    /// [MaybeValue::NotProvided] when `value` is None.
+    pub fn of(parts: &UndefinedStruct, offset: usize, size: usize, value: Option<T>) -> Self {
+        if offset + size > parts.fields.len() {
+            return MaybeValue::NotPresentInVersion;
+        }
+
+        match value {
+            Some(value) => MaybeValue::Value(value),
+            None => MaybeValue::NotProvided,
+        }
+    }
+
+    /// The value, None for a sentinel
+    pub fn value(self) -> Option<T> {
+        match self {
+            MaybeValue::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Whether the field holds a value rather than a sentinel
+    pub fn is_value(&self) -> bool {
+        matches!(self, MaybeValue::Value(_))
+    }
+
+    /// Whether the field holds the sentinel for an unknown value
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, MaybeValue::Unknown)
+    }
+
+    /// Whether the structure is long enough to hold the field
+    pub fn is_present(&self) -> bool {
+        !matches!(self, MaybeValue::NotPresentInVersion)
+    }
+
+    /// Maps the value with `f`, keeping a sentinel
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> MaybeValue<U> {
+        match self {
+            MaybeValue::Value(value) => MaybeValue::Value(f(value)),
+            MaybeValue::Unknown => MaybeValue::Unknown,
+            MaybeValue::NotProvided => MaybeValue::NotProvided,
+            MaybeValue::Reserved => MaybeValue::Reserved,
+            MaybeValue::NotPresentInVersion => MaybeValue::NotPresentInVersion,
+            MaybeValue::InvalidIndex(index) => MaybeValue::InvalidIndex(index),
+        }
+    }
+}
+
+impl<T> From<MaybeValue<T>> for Option<T> {
+    fn from(value: MaybeValue<T>) -> Self {
+        value.value()
+    }
+}
+
+impl UndefinedStruct {
+    /// Access a byte at the given offset from the structure's data section
+    pub fn access_field_byte(&self, offset: usize) -> MaybeValue<u8> {
+        MaybeValue::of(self, offset, 1, self.get_field_byte(offset))
+    }
+
+    /// Access a WORD at the given offset from the structure's data section
+    pub fn access_field_word(&self, offset: usize) -> MaybeValue<u16> {
+        MaybeValue::of(self, offset, 2, self.get_field_word(offset))
+    }
+
+    /// Access a [Handle] at the given offset from the structure's data section
+    pub fn access_field_handle(&self, offset: usize) -> MaybeValue<Handle> {
+        MaybeValue::of(self, offset, Handle::SIZE, self.get_field_handle(offset))
+    }
+
+    /// Access a DWORD at the given offset from the structure's data section
+    pub fn access_field_dword(&self, offset: usize) -> MaybeValue<u32> {
+        MaybeValue::of(self, offset, 4, self.get_field_dword(offset))
+    }
+
+    /// Access a QWORD at the given offset from the structure's data section
+    pub fn access_field_qword(&self, offset: usize) -> MaybeValue<u64> {
+        MaybeValue::of(self, offset, 8, self.get_field_qword(offset))
+    }
+
+    /// Access a String of the given offset
+    ///
+    /// [MaybeValue::NotProvided] when the string index is 0 and
+    /// [MaybeValue::InvalidIndex] when it is past the end of the string-set,
+    /// which [UndefinedStruct::get_field_string] both return as None.
+    pub fn access_field_string(&self, offset: usize) -> MaybeValue<String> {
+        match self.get_field_byte(offset) {
+            Some(index) => self.strings.access_string(index),
+            None => MaybeValue::NotPresentInVersion,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maybe_value() {
+        assert_eq!(MaybeValue::Value(3u16).value(), Some(3));
+        assert_eq!(MaybeValue::<u16>::Unknown.value(), None);
+        assert!(MaybeValue::Value(3u16).is_value());
+        assert!(!MaybeValue::<u16>::Reserved.is_value());
+        assert_eq!(
+            MaybeValue::Value(3u16).map(u32::from),
+            MaybeValue::Value(3u32)
+        );
+        assert_eq!(
+            MaybeValue::<u16>::NotProvided.map(u32::from),
+            MaybeValue::NotProvided
+        );
+        assert_eq!(
+            MaybeValue::<u16>::InvalidIndex(0xFF).map(u32::from),
+            MaybeValue::InvalidIndex(0xFF)
+        );
+        assert_eq!(Option::from(MaybeValue::Value(3u16)), Some(3));
+    }
+
+    #[test]
+    fn test_access_field() {
+        // System Information of SMBIOS 2.0, with a product name index of 0
+        let parts = UndefinedStruct::new(&vec![
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x00, 0x03, 0x00, b'A', 0x00, 0x00,
+        ]);
+
+        assert_eq!(
+            parts.access_field_string(0x04),
+            MaybeValue::Value("A".to_string())
+        );
+        assert_eq!(parts.access_field_string(0x05), MaybeValue::NotProvided);
+        assert_eq!(parts.access_field_string(0x06), MaybeValue::InvalidIndex(3));
+        assert_eq!(parts.access_field_byte(0x07), MaybeValue::Value(0));
+        assert_eq!(
+            parts.access_field_word(0x07),
+            MaybeValue::NotPresentInVersion
+        );
+        assert_eq!(
+            parts.access_field_string(0x19),
+            MaybeValue::NotPresentInVersion
+        );
+        assert!(!parts.access_field_qword(0x08).is_present());
+        assert!(MaybeValue::<u8>::NotProvided.is_present());
+        assert_eq!(
+            parts.access_field_byte(0x07).map(u16::from).value(),
+            Some(0)
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use super::maybe_value::MaybeValue;
+use core::fmt;
+use serde::Serialize;
+
+/// # Memory Capacity
+///
+/// A size of memory in bytes, such as the size of a memory device merged
+/// from its Size and Extended Size fields (see
+/// [crate::SMBiosMemoryDevice::effective_size]) or the size of a mapped
+/// address range (see [crate::SMBiosMemoryArrayMappedAddress::range_size]).
+///
+/// The fields hold sizes in kilobytes or megabytes (of 1024 bytes); the
+/// capacity converts between the units so that the fields are not decoded
+/// by hand.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MemoryCapacity(u64);
+
+impl MemoryCapacity {
+    /// Creates a capacity of `bytes` bytes
+    pub fn new(bytes: u64) -> Self {
+        MemoryCapacity(bytes)
+    }
+
+    /// Capacity in bytes
+    pub fn as_bytes(&self) -> u64 {
+        self.0
+    }
+
+    /// Capacity in kibibytes (1024 bytes), rounded down
+    pub fn as_kibibytes(&self) -> u64 {
+        self.0 >> 10
+    }
+
+    /// Capacity in mebibytes (1024 kibibytes), rounded down
+    pub fn as_mebibytes(&self) -> u64 {
+        self.0 >> 20
+    }
+}
+
+impl fmt::Display for MemoryCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["TiB", "GiB", "MiB", "KiB"];
+
+        for (shift, unit) in (1..=4).rev().map(|n| n * 10).zip(UNITS.iter()) {
+            if self.0 != 0 && self.0.trailing_zeros() >= shift {
+                return write!(f, "{} {}", self.0 >> shift, unit);
+            }
+        }
+        write!(f, "{} bytes", self.0)
+    }
+}
+
+impl MaybeValue<MemoryCapacity> {
+    /// Size in bytes, None when not installed, unknown or absent
+    pub fn as_bytes(&self) -> Option<u64> {
+        self.value().map(|capacity| capacity.as_bytes())
+    }
+
+    /// Size in mebibytes (1024 kibibytes) rounded down, None when not
+    /// installed, unknown or absent
+    pub fn as_mebibytes(&self) -> Option<u64> {
+        self.value().map(|capacity| capacity.as_mebibytes())
+    }
+
+    /// Whether memory is installed, including memory of an unknown size
+    pub fn is_installed(&self) -> bool {
+        matches!(self, MaybeValue::Value(_) | MaybeValue::Unknown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_capacity() {
+        let capacity = MemoryCapacity::new(8 << 30);
+        assert_eq!(capacity.as_mebibytes(), 8192);
+        assert_eq!(capacity.as_kibibytes(), 8 << 20);
+        assert_eq!(capacity.to_string(), "8 GiB");
+        assert_eq!(MemoryCapacity::new(256 << 10).to_string(), "256 KiB");
+        assert_eq!(MemoryCapacity::new(1536 << 20).to_string(), "1536 MiB");
+        assert_eq!(MemoryCapacity::new(4 << 40).to_string(), "4 TiB");
+        assert_eq!(MemoryCapacity::new(1000).to_string(), "1000 bytes");
+        assert_eq!(MemoryCapacity::new(0).to_string(), "0 bytes");
+
+        let size = MaybeValue::Value(MemoryCapacity::new(256 << 10));
+        assert_eq!(size.as_bytes(), Some(256 << 10));
+        assert_eq!(size.as_mebibytes(), Some(0));
+        assert!(size.is_installed() && !size.is_unknown());
+        let size = MaybeValue::<MemoryCapacity>::Unknown;
+        assert!(size.is_installed() && size.is_unknown());
+        assert_eq!(size.as_bytes(), None);
+        let size = MaybeValue::<MemoryCapacity>::NotProvided;
+        assert!(!size.is_installed() && !size.is_unknown());
+        assert!(!MaybeValue::<MemoryCapacity>::NotPresentInVersion.is_installed());
+    }
+}
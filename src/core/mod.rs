@@ -1,11 +1,45 @@
+mod conformance;
+mod content_hash;
 mod entry_point;
+mod field_offsets;
 mod header;
+mod maybe_value;
+mod memory_capacity;
+mod nul_scan;
+mod parse_options;
+mod serialization_filter;
 mod smbios_data;
+mod speed;
+mod stream_parser;
 mod strings;
+mod struct_fields;
+mod table_integrity;
+mod table_ref;
 mod undefined_struct;
+mod validation;
 
+pub use conformance::*;
+pub use content_hash::*;
 pub use entry_point::*;
 pub use header::*;
+pub use maybe_value::*;
+pub use memory_capacity::*;
+pub use parse_options::*;
+pub use serialization_filter::*;
 pub use smbios_data::*;
+pub use speed::*;
+pub use stream_parser::*;
 pub use strings::*;
+pub use struct_fields::*;
+pub use table_integrity::*;
+pub use table_ref::*;
 pub use undefined_struct::*;
+pub use validation::*;
+
+#[cfg(not(feature = "no_std"))]
+pub(crate) use entry_point::locate_structure_table;
+pub(crate) use field_offsets::{handle_field_offsets, string_field_offsets};
+#[cfg(feature = "serde_json")]
+pub(crate) use field_offsets::{handle_fields, string_fields, ReferenceField};
+pub(crate) use strings::trim_padding;
+pub(crate) use validation::minimum_length;
@@ -0,0 +1,84 @@
+//! Scanning for the NUL terminators of string-sets
+//!
+//! With the `memchr` feature the scans use the vectorized searches of the
+//! memchr crate, which pay off for structures embedding kilobytes of
+//! strings; otherwise the bytes are compared one at a time.
+
+/// Position of the first NUL byte of `data`
+fn find_nul(data: &[u8]) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memchr(0, data)
+    }
+
+    #[cfg(not(feature = "memchr"))]
+    data.iter().position(|byte| *byte == 0)
+}
+
+/// Position of the first pair of NUL bytes of `data`, such as the
+/// terminator of a string-set
+pub(crate) fn find_double_nul(data: &[u8]) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memmem::find(data, &[0, 0])
+    }
+
+    #[cfg(not(feature = "memchr"))]
+    data.windows(2).position(|pair| pair == [0, 0])
+}
+
+/// Splits `data` at each NUL byte, as `data.split(|byte| *byte == 0)`
+pub(crate) fn split_nul(data: &[u8]) -> NulSplit<'_> {
+    NulSplit {
+        remaining: Some(data),
+    }
+}
+
+/// Iterates the NUL separated parts of a slice, see [split_nul]
+pub(crate) struct NulSplit<'a> {
+    remaining: Option<&'a [u8]>,
+}
+
+impl<'a> Iterator for NulSplit<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+        match find_nul(remaining) {
+            Some(position) => {
+                self.remaining = Some(&remaining[position + 1..]);
+                Some(&remaining[..position])
+            }
+            None => self.remaining.take(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nul_scan() {
+        let samples: [&[u8]; 6] = [
+            b"",
+            b"\0",
+            b"Vendor\0Version",
+            b"Vendor\0\0Version\0",
+            b"\0Vendor\0",
+            &[b'A'; 300],
+        ];
+        for sample in samples.iter() {
+            assert!(split_nul(sample).eq(sample.split(|byte| *byte == 0)));
+            assert_eq!(
+                find_double_nul(sample),
+                sample.windows(2).position(|pair| pair == [0, 0])
+            );
+        }
+
+        let mut oem_strings = vec![b'A'; 4096];
+        oem_strings.extend_from_slice(b"\0B\0\0");
+        assert_eq!(find_double_nul(&oem_strings), Some(4098));
+        assert_eq!(split_nul(&oem_strings).count(), 4);
+    }
+}
@@ -0,0 +1,142 @@
+use super::header::Handle;
+use super::strings::StringDecoding;
+#[cfg(feature = "no_std")]
+use alloc::format;
+use core::fmt;
+use serde::Serialize;
+
+/// # Parse Options
+///
+/// Limits applied while parsing a structure table, bounding the memory and
+/// CPU spent on untrusted (possibly malformed or hostile) data, and the
+/// decoding of its strings.
+///
+/// A limit of [None] is unbounded; [ParseOptions::default] applies no limits,
+/// parses leniently and decodes strings as ISO-8859-1.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Maximum number of structures in the table
+    pub max_structure_count: Option<usize>,
+    /// Maximum length of a single string, in bytes
+    pub max_string_length: Option<usize>,
+    /// Maximum size of the table, in bytes
+    pub max_table_size: Option<usize>,
+    /// Fails with [ParseLimitError::MalformedStructure] on a malformed
+    /// structure
+    ///
+    /// When false (lenient), parsing stops at a malformed structure and
+    /// records a [TableWarning](super::TableWarning), keeping the
+    /// structures parsed so far.
+    pub strict: bool,
+    /// Skips a structure whose header reports a length smaller than the
+    /// header itself, rather than stopping at it, when parsing leniently
+    ///
+    /// The formatted area of such a structure is taken to be its header,
+    /// followed by its strings, and the structure is recorded as a
+    /// [TableWarning::SkippedStructure](super::TableWarning::SkippedStructure).
+    /// Parsing stops as without this option when no string-set terminator
+    /// follows the header.
+    pub skip_invalid_lengths: bool,
+    /// The decoding of the strings of the structures, see
+    /// [Strings::decoding](super::Strings::decoding)
+    pub string_decoding: StringDecoding,
+}
+
+/// # Structure Defect
+///
+/// The reason a structure is malformed, see [ParseOptions::strict]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum StructureDefect {
+    /// The table ends within the header or the formatted area
+    Truncated,
+    /// The header reports a length smaller than the header itself
+    InvalidLength(u8),
+    /// The table ends before the double zero terminating the strings
+    UnterminatedStrings,
+}
+
+impl fmt::Display for StructureDefect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StructureDefect::Truncated => f.write_str("the structure is truncated"),
+            StructureDefect::InvalidLength(length) => {
+                write!(f, "the header reports an invalid length of {}", length)
+            }
+            StructureDefect::UnterminatedStrings => f.write_str("the strings are not terminated"),
+        }
+    }
+}
+
+/// Parse limit errors
+pub enum ParseLimitError {
+    /// The table size exceeds [ParseOptions::max_table_size]
+    TableTooLarge {
+        /// Size of the table
+        size: usize,
+        /// The limit exceeded
+        limit: usize,
+    },
+    /// The number of structures exceeds [ParseOptions::max_structure_count]
+    TooManyStructures {
+        /// The limit exceeded
+        limit: usize,
+    },
+    /// A string exceeds [ParseOptions::max_string_length]
+    StringTooLong {
+        /// Handle of the structure containing the string
+        handle: Handle,
+        /// Length of the string
+        length: usize,
+        /// The limit exceeded
+        limit: usize,
+    },
+    /// A structure is malformed, when [ParseOptions::strict]
+    MalformedStructure {
+        /// Offset of the structure within the table
+        offset: usize,
+        /// The reason the structure is malformed
+        defect: StructureDefect,
+    },
+}
+
+impl fmt::Debug for ParseLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ParseLimitError")
+            .field(&format!("{}", &self))
+            .finish()
+    }
+}
+
+impl fmt::Display for ParseLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseLimitError::TableTooLarge { size, limit } => write!(
+                f,
+                "The table size of {} bytes exceeds the limit of {} bytes",
+                size, limit
+            ),
+            ParseLimitError::TooManyStructures { limit } => write!(
+                f,
+                "The table contains more than the limit of {} structures",
+                limit
+            ),
+            ParseLimitError::StringTooLong {
+                handle,
+                length,
+                limit,
+            } => write!(
+                f,
+                "A string of {} bytes in structure {:#06X} exceeds the limit of {} bytes",
+                length, **handle, limit
+            ),
+            ParseLimitError::MalformedStructure { offset, defect } => write!(
+                f,
+                "The structure at offset {} is malformed: {}",
+                offset, defect
+            ),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for ParseLimitError {}
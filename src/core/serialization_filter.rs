@@ -0,0 +1,497 @@
+use crate::core::SMBiosData;
+use crate::structs::DefinedStruct;
+#[cfg(feature = "no_std")]
+use alloc::{string::String, vec::Vec};
+use serde::ser::{
+    Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+
+/// # Serialization Filter
+///
+/// Selects the structures and fields of the serde serialization of a table
+/// (see [SMBiosData::filtered]), for example to leave out identifying
+/// information:
+///
+/// ```
+/// # use smbioslib::*;
+/// let filter = SerializationFilter::new()
+///     .exclude_field("serial_number")
+///     .exclude_field("uuid")
+///     .exclude_type(11);
+/// ```
+///
+/// Structure types are selected by [SerializationFilter::include_type] and
+/// [SerializationFilter::exclude_type]: when any type is included only the
+/// included types are kept, and excluded types are always left out.
+///
+/// Fields are named as in the serialization of the structures (for example
+/// `serial_number` or `uuid`). Included fields select the fields of each
+/// structure, keeping the whole value of a selected field, while excluded
+/// fields are left out at any depth.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SerializationFilter {
+    include_types: Vec<u8>,
+    exclude_types: Vec<u8>,
+    include_fields: Vec<String>,
+    exclude_fields: Vec<String>,
+}
+
+impl SerializationFilter {
+    /// Creates a filter keeping all structures and fields
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps structures of `struct_type`
+    pub fn include_type(mut self, struct_type: u8) -> Self {
+        self.include_types.push(struct_type);
+        self
+    }
+
+    /// Leaves out structures of `struct_type`
+    pub fn exclude_type(mut self, struct_type: u8) -> Self {
+        self.exclude_types.push(struct_type);
+        self
+    }
+
+    /// Keeps the field `name` of the structures
+    pub fn include_field(mut self, name: &str) -> Self {
+        self.include_fields.push(name.into());
+        self
+    }
+
+    /// Leaves out fields named `name`
+    pub fn exclude_field(mut self, name: &str) -> Self {
+        self.exclude_fields.push(name.into());
+        self
+    }
+
+    /// Whether structures of `struct_type` are kept
+    pub fn keeps_type(&self, struct_type: u8) -> bool {
+        (self.include_types.is_empty() || self.include_types.contains(&struct_type))
+            && !self.exclude_types.contains(&struct_type)
+    }
+
+    /// Whether a field `name` is kept, `structure_field` when it is a field
+    /// of a structure rather than of a nested value
+    fn keeps_field(&self, name: &str, structure_field: bool) -> bool {
+        let included = !structure_field
+            || self.include_fields.is_empty()
+            || self.include_fields.iter().any(|field| field == name);
+        included && !self.exclude_fields.iter().any(|field| field == name)
+    }
+}
+
+impl SMBiosData {
+    /// The table with the structures and fields selected by `filter`, to
+    /// be serialized in place of the table
+    ///
+    /// The serialization holds the `version` and the `table` of the
+    /// selected structures. Unlike that of [SMBiosData::with_raw_table] it
+    /// has no `raw_table`, which would hold every field.
+    pub fn filtered<'a>(&'a self, filter: &'a SerializationFilter) -> FilteredSMBiosData<'a> {
+        FilteredSMBiosData { data: self, filter }
+    }
+}
+
+/// # Filtered SMBIOS Data
+///
+/// A table serialized with a [SerializationFilter], see
+/// [SMBiosData::filtered].
+pub struct FilteredSMBiosData<'a> {
+    data: &'a SMBiosData,
+    filter: &'a SerializationFilter,
+}
+
+impl Serialize for FilteredSMBiosData<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("SMBiosData", 2)?;
+        state.serialize_field("version", &self.data.version)?;
+        state.serialize_field("table", &FilteredTable(self))?;
+        state.end()
+    }
+}
+
+struct FilteredTable<'a, 'b>(&'b FilteredSMBiosData<'a>);
+
+impl Serialize for FilteredTable<'_, '_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let filter = self.0.filter;
+        let mut seq = serializer.serialize_seq(None)?;
+        for undefined_struct in self
+            .0
+            .data
+            .iter()
+            .filter(|undefined_struct| filter.keeps_type(undefined_struct.header.struct_type()))
+        {
+            seq.serialize_element(&Filtered {
+                value: &DefinedStruct::from(undefined_struct),
+                filter,
+                structure: true,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+/// A value serialized with the fields selected by a filter
+///
+/// `structure` is set for a structure, whose fields are selected by the
+/// included fields.
+struct Filtered<'a, T: ?Sized> {
+    value: &'a T,
+    filter: &'a SerializationFilter,
+    structure: bool,
+}
+
+impl<T: Serialize + ?Sized> Serialize for Filtered<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(FilterSerializer {
+            inner: serializer,
+            filter: self.filter,
+            structure: self.structure,
+        })
+    }
+}
+
+/// A serializer leaving out the fields of structs not selected by a filter
+struct FilterSerializer<'a, S> {
+    inner: S,
+    filter: &'a SerializationFilter,
+    structure: bool,
+}
+
+impl<'a, S> FilterSerializer<'a, S> {
+    fn new(inner: S, filter: &'a SerializationFilter, structure: bool) -> Self {
+        FilterSerializer {
+            inner,
+            filter,
+            structure,
+        }
+    }
+}
+
+macro_rules! forward {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method(self, $($arg: $ty),*) -> Result<Self::Ok, Self::Error> {
+                self.inner.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<'a, S: Serializer> Serializer for FilterSerializer<'a, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = FilterSerializer<'a, S::SerializeSeq>;
+    type SerializeTuple = FilterSerializer<'a, S::SerializeTuple>;
+    type SerializeTupleStruct = FilterSerializer<'a, S::SerializeTupleStruct>;
+    type SerializeTupleVariant = FilterSerializer<'a, S::SerializeTupleVariant>;
+    type SerializeMap = FilterSerializer<'a, S::SerializeMap>;
+    type SerializeStruct = FilterSerializer<'a, S::SerializeStruct>;
+    type SerializeStructVariant = FilterSerializer<'a, S::SerializeStructVariant>;
+
+    forward! {
+        serialize_bool(v: bool);
+        serialize_i8(v: i8);
+        serialize_i16(v: i16);
+        serialize_i32(v: i32);
+        serialize_i64(v: i64);
+        serialize_u8(v: u8);
+        serialize_u16(v: u16);
+        serialize_u32(v: u32);
+        serialize_u64(v: u64);
+        serialize_f32(v: f32);
+        serialize_f64(v: f64);
+        serialize_char(v: char);
+        serialize_str(v: &str);
+        serialize_bytes(v: &[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(name: &'static str);
+        serialize_unit_variant(name: &'static str, variant_index: u32, variant: &'static str);
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_some(&Filtered {
+            value,
+            filter: self.filter,
+            structure: false,
+        })
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        // the wrapped value stands for the newtype itself
+        self.inner.serialize_newtype_struct(
+            name,
+            &Filtered {
+                value,
+                filter: self.filter,
+                structure: self.structure,
+            },
+        )
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        // a DefinedStruct is a variant holding the structure
+        self.inner.serialize_newtype_variant(
+            name,
+            variant_index,
+            variant,
+            &Filtered {
+                value,
+                filter: self.filter,
+                structure: self.structure,
+            },
+        )
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        let inner = self.inner.serialize_seq(len)?;
+        Ok(FilterSerializer::new(inner, self.filter, false))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        let inner = self.inner.serialize_tuple(len)?;
+        Ok(FilterSerializer::new(inner, self.filter, false))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        let inner = self.inner.serialize_tuple_struct(name, len)?;
+        Ok(FilterSerializer::new(inner, self.filter, false))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        let inner = self
+            .inner
+            .serialize_tuple_variant(name, variant_index, variant, len)?;
+        Ok(FilterSerializer::new(inner, self.filter, false))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        let inner = self.inner.serialize_map(len)?;
+        Ok(FilterSerializer::new(inner, self.filter, false))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        let inner = self.inner.serialize_struct(name, len)?;
+        Ok(FilterSerializer::new(inner, self.filter, self.structure))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        let inner = self
+            .inner
+            .serialize_struct_variant(name, variant_index, variant, len)?;
+        Ok(FilterSerializer::new(inner, self.filter, self.structure))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! filter_elements {
+    ($($trait:ident::$method:ident;)*) => {
+        $(
+            impl<S: $trait> $trait for FilterSerializer<'_, S> {
+                type Ok = S::Ok;
+                type Error = S::Error;
+
+                fn $method<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+                    self.inner.$method(&Filtered {
+                        value,
+                        filter: self.filter,
+                        structure: false,
+                    })
+                }
+
+                fn end(self) -> Result<Self::Ok, Self::Error> {
+                    self.inner.end()
+                }
+            }
+        )*
+    };
+}
+
+filter_elements! {
+    SerializeSeq::serialize_element;
+    SerializeTuple::serialize_element;
+    SerializeTupleStruct::serialize_field;
+    SerializeTupleVariant::serialize_field;
+}
+
+impl<S: SerializeMap> SerializeMap for FilterSerializer<'_, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.inner.serialize_key(&Filtered {
+            value: key,
+            filter: self.filter,
+            structure: false,
+        })
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.inner.serialize_value(&Filtered {
+            value,
+            filter: self.filter,
+            structure: false,
+        })
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+macro_rules! filter_fields {
+    ($($trait:ident;)*) => {
+        $(
+            impl<S: $trait> $trait for FilterSerializer<'_, S> {
+                type Ok = S::Ok;
+                type Error = S::Error;
+
+                fn serialize_field<T: Serialize + ?Sized>(
+                    &mut self,
+                    key: &'static str,
+                    value: &T,
+                ) -> Result<(), Self::Error> {
+                    if !self.filter.keeps_field(key, self.structure) {
+                        return self.inner.skip_field(key);
+                    }
+
+                    self.inner.serialize_field(
+                        key,
+                        &Filtered {
+                            value,
+                            filter: self.filter,
+                            structure: false,
+                        },
+                    )
+                }
+
+                fn end(self) -> Result<Self::Ok, Self::Error> {
+                    self.inner.end()
+                }
+            }
+        )*
+    };
+}
+
+filter_fields! {
+    SerializeStruct;
+    SerializeStructVariant;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde_json")]
+    fn test_data() -> SMBiosData {
+        use crate::builder::{SMBiosTableBuilder, StructBuilder, SystemInformationBuilder};
+        use crate::core::SMBiosVersion;
+
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add(
+                SystemInformationBuilder::new()
+                    .manufacturer("Manufacturer")
+                    .serial_number("Serial"),
+            )
+            .unwrap();
+        builder
+            .add(StructBuilder::new(11).byte(1).string("OEM"))
+            .unwrap();
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_serialization_filter() {
+        let filter = SerializationFilter::new()
+            .exclude_type(11)
+            .exclude_field("uuid");
+        assert!(filter.keeps_type(1));
+        assert!(!filter.keeps_type(11));
+        assert!(!SerializationFilter::new().include_type(1).keeps_type(127));
+        assert!(filter.keeps_field("serial_number", true));
+        assert!(!filter.keeps_field("uuid", false));
+
+        let filter = filter
+            .include_field("header")
+            .include_field("serial_number");
+        assert!(filter.keeps_field("serial_number", true));
+        assert!(!filter.keeps_field("manufacturer", true));
+        assert!(filter.keeps_field("manufacturer", false));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_filtered_serialization() {
+        let data = test_data();
+        let filter = SerializationFilter::new()
+            .exclude_type(127)
+            .exclude_field("serial_number")
+            .exclude_field("uuid");
+        let json = serde_json::to_value(data.filtered(&filter)).unwrap();
+        let table = json["table"].as_array().unwrap();
+        assert_eq!(table.len(), 2);
+        let system = &table[0]["SystemInformation"];
+        assert_eq!(system["manufacturer"], "Manufacturer");
+        assert!(system.get("serial_number").is_none());
+        assert!(system.get("uuid").is_none());
+        assert_eq!(system["header"]["handle"], 0);
+        assert!(json.get("raw_table").is_none());
+
+        let filter = SerializationFilter::new()
+            .include_type(1)
+            .include_field("manufacturer");
+        let json = serde_json::to_value(data.filtered(&filter)).unwrap();
+        assert_eq!(
+            json["table"],
+            serde_json::json!([{ "SystemInformation": { "manufacturer": "Manufacturer" } }])
+        );
+    }
+}
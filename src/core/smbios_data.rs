@@ -1,20 +1,148 @@
-use super::header::Handle;
-use super::undefined_struct::{UndefinedStruct, UndefinedStructTable};
-use crate::structs::{DefinedStructTable, SMBiosStruct};
-use serde::{ser::SerializeStruct, Serialize, Serializer};
 #[cfg(not(feature = "no_std"))]
-use std::io::Error;
+use super::locate_structure_table;
+#[cfg(not(feature = "no_std"))]
+use super::entry_point::{SMBiosEntryPoint32, SMBiosEntryPoint64};
+use super::header::{Handle, Header};
+use super::parse_options::{ParseLimitError, ParseOptions};
+use super::strings::{StringDecoding, Strings};
+use super::struct_fields::StructFields;
+use super::table_integrity::TableIntegrity;
+use super::undefined_struct::{TableWarning, UndefinedStruct, UndefinedStructTable};
+use super::handle_field_offsets;
+use crate::structs::{DefinedStruct, DefinedStructTable, SMBiosStruct};
+use serde::{
+    de::{self, Error as _, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+#[cfg(not(feature = "no_std"))]
+use std::{convert::TryFrom, ops::Range};
+#[cfg(not(feature = "no_std"))]
+use crate::error::Error;
 use core::{cmp::Ordering, slice::Iter, fmt, any};
 #[cfg(not(feature = "no_std"))]
 use std::fs::read;
+#[cfg(feature = "no_std")]
+use alloc::{collections::BTreeMap, vec::{Vec, IntoIter}, string::String, format};
+#[cfg(not(feature = "no_std"))]
+use std::vec::IntoIter;
+#[cfg(not(feature = "no_std"))]
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::OnceLock,
+};
 
 /// # SMBIOS Data
 ///
 /// Contains an optional SMBIOS version and a collection of SMBIOS structures.
+///
+/// Serializes to its version and the defined structures, see
+/// [SMBiosData::with_raw_table] for a serialization which deserializes back
+/// into the same table.
 pub struct SMBiosData {
     table: UndefinedStructTable,
     /// Version of the contained SMBIOS structures.
     pub version: Option<SMBiosVersion>,
+    handle_index: HandleIndex,
+    offset_index: OffsetIndex,
+    integrity: Option<TableIntegrity>,
+}
+
+/// Positions of the structures in the table by handle, built on the first
+/// lookup
+///
+/// Without std, lookups scan the table.
+#[derive(Default)]
+struct HandleIndex {
+    #[cfg(not(feature = "no_std"))]
+    positions: OnceLock<HashMap<u16, usize>>,
+}
+
+impl HandleIndex {
+    fn find<'a>(
+        &self,
+        table: &'a UndefinedStructTable,
+        handle: &Handle,
+    ) -> Option<&'a UndefinedStruct> {
+        #[cfg(not(feature = "no_std"))]
+        {
+            let positions = self.positions.get_or_init(|| {
+                let mut positions = HashMap::new();
+                for (position, undefined_struct) in table.iter().enumerate() {
+                    // the first of structures sharing a handle is found
+                    positions
+                        .entry(undefined_struct.header.handle().0)
+                        .or_insert(position);
+                }
+                positions
+            });
+            positions
+                .get(&handle.0)
+                .and_then(|position| table.iter().nth(*position))
+        }
+
+        #[cfg(feature = "no_std")]
+        table.find_by_handle(handle)
+    }
+}
+
+/// # Structure Offset
+///
+/// The location of a structure within the raw table, see
+/// [SMBiosData::structure_offsets]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct StructureOffset {
+    /// Type of the structure
+    pub struct_type: u8,
+    /// Handle of the structure
+    pub handle: Handle,
+    /// Offset of the first byte of the structure within the table
+    pub start: usize,
+    /// Offset following the string-set terminator of the structure
+    pub end: usize,
+}
+
+/// Offsets of the structures in the table, built on the first use
+///
+/// Without std, the offsets are computed on each use.
+#[derive(Default)]
+struct OffsetIndex {
+    #[cfg(not(feature = "no_std"))]
+    offsets: OnceLock<Vec<StructureOffset>>,
+}
+
+impl OffsetIndex {
+    fn offsets(table: &UndefinedStructTable) -> Vec<StructureOffset> {
+        table
+            .iter()
+            .scan(0, |offset, undefined_struct| {
+                let start = *offset;
+                *offset += undefined_struct.raw_len();
+                Some(StructureOffset {
+                    struct_type: undefined_struct.header.struct_type(),
+                    handle: undefined_struct.header.handle(),
+                    start,
+                    end: *offset,
+                })
+            })
+            .collect()
+    }
+
+    fn iter<'a>(
+        &'a self,
+        table: &UndefinedStructTable,
+    ) -> impl ExactSizeIterator<Item = StructureOffset> + 'a {
+        #[cfg(not(feature = "no_std"))]
+        {
+            self.offsets
+                .get_or_init(|| OffsetIndex::offsets(table))
+                .iter()
+                .copied()
+        }
+
+        #[cfg(feature = "no_std")]
+        OffsetIndex::offsets(table).into_iter()
+    }
 }
 
 impl<'a> SMBiosData {
@@ -23,7 +151,13 @@ impl<'a> SMBiosData {
     /// `table` is iterable table data.
     /// `version` is optional and represents the DMTF SMBIOS Standard version of the bytes in `data`.
     pub fn new(table: UndefinedStructTable, version: Option<SMBiosVersion>) -> Self {
-        Self { table, version }
+        Self {
+            table,
+            version,
+            handle_index: HandleIndex::default(),
+            offset_index: OffsetIndex::default(),
+            integrity: None,
+        }
     }
 
     /// Creates an SMBIOS table parser which can be iterated
@@ -34,9 +168,50 @@ impl<'a> SMBiosData {
         Self {
             table: UndefinedStructTable::from(data),
             version,
+            handle_index: HandleIndex::default(),
+            offset_index: OffsetIndex::default(),
+            integrity: None,
         }
     }
 
+    /// Creates an SMBIOS table parser which can be iterated
+    ///
+    /// `data` is a block of bytes representing the raw table data, such as a
+    /// memory mapping. The structures are copied out of `data`, which need
+    /// not outlive the result.
+    /// `version` is optional and represents the DMTF SMBIOS Standard version of the bytes in `data`.
+    pub fn from_slice_and_version(data: &[u8], version: Option<SMBiosVersion>) -> Self {
+        Self {
+            table: UndefinedStructTable::from(data),
+            version,
+            handle_index: HandleIndex::default(),
+            offset_index: OffsetIndex::default(),
+            integrity: None,
+        }
+    }
+
+    /// Creates an SMBIOS table parser which can be iterated, enforcing parse limits
+    ///
+    /// `data` is a block of bytes representing the raw table data.
+    /// `version` is optional and represents the DMTF SMBIOS Standard version of the bytes in `data`.
+    /// `options` bounds the table size, structure count and string length accepted,
+    /// for use with untrusted data, and selects strict or lenient handling of
+    /// malformed structures (see [ParseOptions::strict] and
+    /// [ParseOptions::skip_invalid_lengths]).
+    pub fn try_from_vec_with_options(
+        data: Vec<u8>,
+        version: Option<SMBiosVersion>,
+        options: &ParseOptions,
+    ) -> Result<Self, ParseLimitError> {
+        Ok(Self {
+            table: UndefinedStructTable::try_from_slice_with_options(&data, options)?,
+            version,
+            handle_index: HandleIndex::default(),
+            offset_index: OffsetIndex::default(),
+            integrity: None,
+        })
+    }
+
     /// Loads raw SMBios table data from a file
     #[cfg(not(feature = "no_std"))]
     pub fn try_load_from_file(
@@ -47,15 +222,161 @@ impl<'a> SMBiosData {
         let result = Self {
             table: UndefinedStructTable::from(data),
             version,
+            handle_index: HandleIndex::default(),
+            offset_index: OffsetIndex::default(),
+            integrity: None,
         };
         Ok(result)
     }
 
+    /// Creates an SMBIOS table parser from a raw entry point and the table it describes
+    ///
+    /// `entry_point` is the raw 64-bit (_SM3_) or 32-bit (_SM_) entry point,
+    /// such as the one exported next to the table by the operating system.
+    /// `data` is a block of bytes representing the raw table data.
+    /// The [SMBiosVersion] is taken from the entry point, which is also
+    /// reported against by [SMBiosData::integrity].
+    #[cfg(not(feature = "no_std"))]
+    pub fn try_from_entry_point_and_vec(
+        entry_point: &[u8],
+        data: Vec<u8>,
+    ) -> Result<SMBiosData, Error> {
+        let (_, _, version) = locate_structure_table(entry_point)?;
+        Ok(Self::from_vec_and_version(data, Some(version)).with_entry_point(entry_point))
+    }
+
+    /// Loads SMBios table data from a `dmidecode --dump-bin` file
+    ///
+    /// See [SMBiosData::try_from_dump_bin] for the layout of the file.
+    #[cfg(not(feature = "no_std"))]
+    pub fn try_load_from_dump_bin(filename: &str) -> Result<SMBiosData, Error> {
+        Self::try_from_dump_bin(&read(filename)?)
+    }
+
+    /// Parses SMBios table data from a `dmidecode --dump-bin` image
+    ///
+    /// The image begins with an entry point (_SM3_ or _SM_), followed by padding
+    /// and the structure table. The structure table address of the entry point
+    /// is the offset of the structure table within the image (20h when written
+    /// by dmidecode). The [SMBiosVersion] is taken from the entry point, which
+    /// is also reported against by [SMBiosData::integrity].
+    #[cfg(not(feature = "no_std"))]
+    pub fn try_from_dump_bin(image: &[u8]) -> Result<SMBiosData, Error> {
+        let (table_range, version) = locate_dump_bin_table(image)?;
+        Ok(Self::from_vec_and_version(image[table_range].to_vec(), Some(version))
+            .with_entry_point(image))
+    }
+
+    /// Returns the raw structure table of a `dmidecode --dump-bin` image
+    ///
+    /// See [SMBiosData::try_from_dump_bin] for the layout of the image.
+    #[cfg(not(feature = "no_std"))]
+    pub fn raw_table_from_dump_bin(image: &[u8]) -> Result<&[u8], Error> {
+        let (table_range, _) = locate_dump_bin_table(image)?;
+        Ok(&image[table_range])
+    }
+
+    /// The raw bytes of the structure table
+    ///
+    /// The structures are emitted in order with their original bytes
+    /// (header, formatted area, strings and string-set terminator).
+    pub fn raw_bytes(&self) -> Vec<u8> {
+        self.iter().flat_map(|undefined_struct| undefined_struct.raw()).collect()
+    }
+
+    /// The table serialized as CBOR (RFC 8949)
+    ///
+    /// A map of the `version` and `raw_table`, the table bytes
+    /// ([SMBiosData::raw_bytes]) as a byte string, which deserializes back
+    /// into a [SMBiosData]. The decoded structures are left out.
+    #[cfg(all(feature = "ciborium", not(feature = "no_std")))]
+    pub fn to_cbor(&self) -> Result<Vec<u8>, ciborium::ser::Error<std::io::Error>> {
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&RawSMBiosData::from(self), &mut cbor)?;
+        Ok(cbor)
+    }
+
+    /// The table serialized as MessagePack
+    ///
+    /// A map of the `version` and `raw_table`, the table bytes
+    /// ([SMBiosData::raw_bytes]) as binary, which deserializes back into a
+    /// [SMBiosData]. The decoded structures are left out.
+    #[cfg(all(feature = "rmp-serde", not(feature = "no_std")))]
+    pub fn to_msgpack(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(&RawSMBiosData::from(self))
+    }
+
+    /// Consistency of the table with the entry point it was loaded by
+    ///
+    /// Set by the device loaders (sysfs, /dev/mem, the BSDs, macOS and
+    /// UEFI), when loading a `dmidecode --dump-bin` image or from an entry
+    /// point and table, or by [SMBiosData::with_entry_point]. None when the
+    /// table was loaded without its entry point.
+    pub fn integrity(&self) -> Option<&TableIntegrity> {
+        self.integrity.as_ref()
+    }
+
+    /// Reports the integrity of the table against the raw `entry_point` it
+    /// was located by, see [SMBiosData::integrity]
+    pub fn with_entry_point(mut self, entry_point: &[u8]) -> Self {
+        self.integrity = TableIntegrity::new(entry_point, &self);
+        self
+    }
+
+    /// Decodes the strings of the structures with `decoding`, in place of
+    /// the ISO-8859-1 default
+    ///
+    /// Honored by the string fields of the structures, their serialization
+    /// (including JSON) and [SMBiosData::validate]. A table parsed with
+    /// [SMBiosData::try_from_vec_with_options] is decoded with
+    /// [ParseOptions::string_decoding] instead.
+    ///
+    /// ```
+    /// # use smbioslib::*;
+    /// # fn utf8(data: SMBiosData) -> SMBiosData {
+    /// data.with_string_decoding(StringDecoding::Utf8OrLatin1)
+    /// # }
+    /// ```
+    pub fn with_string_decoding(mut self, decoding: StringDecoding) -> Self {
+        self.table.set_string_decoding(decoding);
+        self
+    }
+
+    /// Non-fatal irregularities found while parsing the table
+    ///
+    /// Such as a missing End-of-Table (Type 127) structure, a table
+    /// region padded with 00h or FFh bytes, a truncated structure or
+    /// unterminated string-set ending the table, or a structure skipped for
+    /// its invalid length (see [ParseOptions::skip_invalid_lengths]).
+    /// Warnings are collected when parsing leniently; see
+    /// [ParseOptions::strict] to fail instead.
+    pub fn warnings(&self) -> &[TableWarning] {
+        self.table.warnings()
+    }
+
     /// Iterator of the contained [UndefinedStruct] items
     pub fn iter(&self) -> Iter<'_, UndefinedStruct> {
         self.table.iter()
     }
 
+    /// Number of structures in the table
+    pub fn len(&self) -> usize {
+        self.table.iter().len()
+    }
+
+    /// Whether the table contains no structures
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The location of each structure within the raw table, in table order
+    ///
+    /// The offsets are those of [SMBiosData::raw_bytes]. The first call
+    /// indexes the structures, subsequent calls iterate the index.
+    pub fn structure_offsets(&self) -> impl ExactSizeIterator<Item = StructureOffset> + '_ {
+        self.offset_index.iter(&self.table)
+    }
+
     /// An iterator over the defined type instances within the table.
     pub fn defined_struct_iter<T: 'a>(&'a self) -> impl Iterator<Item = T> + 'a
     where
@@ -64,6 +385,63 @@ impl<'a> SMBiosData {
         self.table.defined_struct_iter()
     }
 
+    /// An iterator over the structures of type `struct_type`, as [DefinedStruct]
+    ///
+    /// Unlike [SMBiosData::defined_struct_iter] the type is a number, which
+    /// also selects OEM-specific types (128 through 255) as
+    /// [DefinedStruct::Undefined]. Structures following the End-of-Table
+    /// (Type 127) structure are not visited.
+    ///
+    /// ```
+    /// # use smbioslib::*;
+    /// # fn oem_structures(data: &SMBiosData) {
+    /// for oem_struct in data.defined_structs_of_type(0xDD) {
+    ///     if let DefinedStruct::Undefined(unknown) = oem_struct {
+    ///         println!("{:?}", unknown.parts().fields);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn defined_structs_of_type(
+        &'a self,
+        struct_type: u8,
+    ) -> impl Iterator<Item = DefinedStruct<'a>> + 'a {
+        self.table.defined_structs_of_type(struct_type)
+    }
+
+    /// An iterator over the structures in table order, as tuples of the
+    /// handle, type, byte offset in the raw table and [DefinedStruct] of
+    /// each structure
+    ///
+    /// The offset is relative to the start of the structure table, as in
+    /// [SMBiosData::raw_bytes]. For a table parsed from raw data it locates
+    /// the structure in that data. Every structure is visited, including
+    /// structures following the End-of-Table (Type 127) structure. The
+    /// offsets are those of [SMBiosData::structure_offsets].
+    ///
+    /// ```
+    /// # use smbioslib::*;
+    /// # fn report(data: &SMBiosData) {
+    /// for (handle, struct_type, offset, _) in data.iter_with_offsets() {
+    ///     println!("{:#06X}: type {} at offset {:#X}", *handle, struct_type, offset);
+    /// }
+    /// # }
+    /// ```
+    pub fn iter_with_offsets(
+        &'a self,
+    ) -> impl ExactSizeIterator<Item = (Handle, u8, usize, DefinedStruct<'a>)> + 'a {
+        self.structure_offsets()
+            .zip(self.table.iter())
+            .map(|(offset, undefined_struct)| {
+                (
+                    offset.handle,
+                    offset.struct_type,
+                    offset.start,
+                    DefinedStruct::from(undefined_struct),
+                )
+            })
+    }
+
     /// Tests if every element of the defined struct iterator matches a predicate.
     pub fn all<T, F>(&'a self, f: F) -> bool
     where
@@ -83,6 +461,11 @@ impl<'a> SMBiosData {
     }
 
     /// Finds the first occurance of the structure
+    ///
+    /// The structure type is that of `T` ([SMBiosStruct::STRUCT_TYPE]), for
+    /// example `data.first::<SMBiosSystemInformation<'_>>()`. Like the other
+    /// typed lookups, structures following the End-of-Table (Type 127)
+    /// structure are not searched.
     pub fn first<T>(&'a self) -> Option<T>
     where
         T: SMBiosStruct<'a>,
@@ -136,11 +519,74 @@ impl<'a> SMBiosData {
     }
 
     /// Finds the structure matching the given handle
+    ///
+    /// The first lookup indexes the structures by handle, subsequent
+    /// lookups take constant time. When structures share a handle, the
+    /// first one is found.
     pub fn find_by_handle(&'a self, handle: &Handle) -> Option<&UndefinedStruct> {
-        self.table.find_by_handle(handle)
+        self.handle_index.find(&self.table, handle)
+    }
+
+    /// Finds the structure of type `T` the given handle refers to
+    ///
+    /// The structure type is that of `T` ([SMBiosStruct::STRUCT_TYPE]), for
+    /// example `data.resolve::<SMBiosCacheInformation<'_>>(&handle)`. [None]
+    /// when no structure has the handle, when the structure found is of
+    /// another type, or for FFFEh and FFFFh, which stand for no structure.
+    pub fn resolve<T>(&'a self, handle: &Handle) -> Option<T>
+    where
+        T: SMBiosStruct<'a>,
+    {
+        self.resolve_parts(handle, T::STRUCT_TYPE).map(T::new)
+    }
+
+    /// Finds the structure of type `struct_type` the given handle refers to,
+    /// see [SMBiosData::resolve]
+    pub(crate) fn resolve_parts(
+        &'a self,
+        handle: &Handle,
+        struct_type: u8,
+    ) -> Option<&'a UndefinedStruct> {
+        self.find_referenced(handle)
+            .filter(|parts| parts.header.struct_type() == struct_type)
     }
 
-    /// Finds all occurances of the structure
+    /// Finds the structure the given handle refers to, of any type
+    ///
+    /// [None] for FFFEh and FFFFh, which stand for no structure.
+    pub(crate) fn find_referenced(&'a self, handle: &Handle) -> Option<&'a UndefinedStruct> {
+        match **handle {
+            0xFFFE | 0xFFFF => None,
+            _ => self.find_by_handle(handle),
+        }
+    }
+
+    /// Iterates over the structures referencing the given handle, in table
+    /// order
+    ///
+    /// A structure references the handle when one of its handle fields (see
+    /// [crate::DefinedStructMut::handle_field_offsets]) holds it, such as the
+    /// Physical Memory Array Handle and Memory Error Information Handle of
+    /// a Memory Device (Type 17) or the contained object handles of a
+    /// Baseboard Information (Type 2). Nothing references FFFEh and FFFFh,
+    /// which stand for no structure.
+    pub fn referencing(
+        &'a self,
+        handle: &Handle,
+    ) -> impl Iterator<Item = &'a UndefinedStruct> + 'a {
+        let handle = *handle;
+        self.iter().filter(move |undefined_struct| {
+            *handle < 0xFFFE
+                && handle_field_offsets(undefined_struct)
+                    .into_iter()
+                    .any(|offset| undefined_struct.get_field_handle(offset) == Some(handle))
+        })
+    }
+
+    /// Finds all occurances of the structure, in table order
+    ///
+    /// The structure type is that of `T` ([SMBiosStruct::STRUCT_TYPE]), for
+    /// example `data.collect::<SMBiosMemoryDevice<'_>>()`.
     pub fn collect<T>(&'a self) -> Vec<T>
     where
         T: SMBiosStruct<'a>,
@@ -151,7 +597,7 @@ impl<'a> SMBiosData {
 
 impl IntoIterator for SMBiosData {
     type Item = UndefinedStruct;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.table.into_iter()
@@ -185,14 +631,278 @@ impl Serialize for SMBiosData {
     }
 }
 
+impl SMBiosData {
+    /// The table along with its raw bytes, to be serialized in place of the
+    /// table
+    ///
+    /// The serialization holds the `version` and the `table` of
+    /// [SMBiosData], followed by `raw_table`, the table bytes
+    /// ([SMBiosData::raw_bytes]) as a hexadecimal string. Most defined
+    /// structures are serialized with decoded fields, from which their bytes
+    /// cannot be rebuilt; `raw_table` deserializes back into the same table.
+    pub fn with_raw_table(&self) -> SMBiosDataWithRawTable<'_> {
+        SMBiosDataWithRawTable { data: self }
+    }
+}
+
+/// # SMBIOS Data with its Raw Table
+///
+/// A table serialized along with its raw bytes, see
+/// [SMBiosData::with_raw_table].
+pub struct SMBiosDataWithRawTable<'a> {
+    data: &'a SMBiosData,
+}
+
+impl Serialize for SMBiosDataWithRawTable<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let defined_table: DefinedStructTable<'_> = self.data.table.iter().collect();
+
+        let mut state = serializer.serialize_struct("SMBiosData", 3)?;
+        state.serialize_field("version", &self.data.version)?;
+        state.serialize_field("table", &defined_table)?;
+        state.serialize_field("raw_table", &encode_hex(&self.data.raw_bytes()))?;
+        state.end()
+    }
+}
+
+/// The version and bytes of a table, serialized by [SMBiosData::to_cbor]
+/// and [SMBiosData::to_msgpack]
+#[cfg(all(
+    any(feature = "ciborium", feature = "rmp-serde"),
+    not(feature = "no_std")
+))]
+struct RawSMBiosData {
+    version: Option<SMBiosVersion>,
+    raw_table: Vec<u8>,
+}
+
+#[cfg(all(
+    any(feature = "ciborium", feature = "rmp-serde"),
+    not(feature = "no_std")
+))]
+impl From<&SMBiosData> for RawSMBiosData {
+    fn from(data: &SMBiosData) -> Self {
+        Self {
+            version: data.version,
+            raw_table: data.raw_bytes(),
+        }
+    }
+}
+
+#[cfg(all(
+    any(feature = "ciborium", feature = "rmp-serde"),
+    not(feature = "no_std")
+))]
+impl Serialize for RawSMBiosData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Bytes<'a>(&'a [u8]);
+
+        impl Serialize for Bytes<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_bytes(self.0)
+            }
+        }
+
+        let mut state = serializer.serialize_struct("SMBiosData", 2)?;
+        state.serialize_field("version", &self.version)?;
+        state.serialize_field("raw_table", &Bytes(&self.raw_table))?;
+        state.end()
+    }
+}
+
+/// The `raw_table` of a serialized table, either a hexadecimal string or
+/// a byte string
+struct RawTable(Vec<u8>);
+
+impl<'de> Deserialize<'de> for RawTable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RawTableVisitor;
+
+        impl<'de> Visitor<'de> for RawTableVisitor {
+            type Value = RawTable;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a hexadecimal string or a byte string")
+            }
+
+            fn visit_str<E: de::Error>(self, hex: &str) -> Result<Self::Value, E> {
+                decode_hex(hex)
+                    .map(RawTable)
+                    .ok_or_else(|| E::custom("raw_table is not a hexadecimal string"))
+            }
+
+            fn visit_bytes<E: de::Error>(self, data: &[u8]) -> Result<Self::Value, E> {
+                Ok(RawTable(data.to_vec()))
+            }
+
+            fn visit_byte_buf<E: de::Error>(self, data: Vec<u8>) -> Result<Self::Value, E> {
+                Ok(RawTable(data))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut data = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element()? {
+                    data.push(byte);
+                }
+                Ok(RawTable(data))
+            }
+        }
+
+        deserializer.deserialize_any(RawTableVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for SMBiosData {
+    /// Deserializes a table from its serialized form
+    ///
+    /// When present, the structures are parsed from `raw_table`, the table
+    /// bytes as a hexadecimal string (see [SMBiosData::with_raw_table]) or
+    /// a byte string (see [SMBiosData::to_cbor]). Otherwise the table is rebuilt from the
+    /// structures of `table` which are serialized with their fields and
+    /// strings, as those of a type not defined by this library are, or which
+    /// have no fields past the header, such as End-of-Table (Type 127).
+    /// Other defined structures are serialized with decoded fields, which
+    /// cannot be rebuilt into bytes, and fail without `raw_table`.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "SMBiosData")]
+        struct Parts {
+            version: Option<SMBiosVersion>,
+            #[serde(default)]
+            table: Vec<BTreeMap<String, SerializedStruct>>,
+            #[serde(default)]
+            raw_table: Option<RawTable>,
+        }
+
+        /// A defined structure, of which only the members holding the
+        /// header, fields and strings are read
+        #[derive(Deserialize)]
+        struct SerializedStruct {
+            header: Header,
+            fields: Option<Vec<u8>>,
+            strings: Option<Strings>,
+        }
+
+        let parts = Parts::deserialize(deserializer)?;
+        if let Some(RawTable(data)) = parts.raw_table {
+            return Ok(SMBiosData::from_vec_and_version(data, parts.version));
+        }
+
+        let mut data = Vec::new();
+        for serialized in parts.table.into_iter().flat_map(BTreeMap::into_values) {
+            let header = serialized.header;
+            let mut fields = header.to_vec();
+            match serialized.fields {
+                Some(formatted) => fields.extend(formatted),
+                None if header.length() as usize == Header::SIZE => (),
+                None => {
+                    return Err(D::Error::custom(format!(
+                        "structure {:04X}h of type {} cannot be rebuilt without raw_table",
+                        *header.handle(),
+                        header.struct_type()
+                    )))
+                }
+            }
+            if fields.len() != header.length() as usize {
+                return Err(D::Error::custom(
+                    "the header does not match the fields of the structure",
+                ));
+            }
+
+            data.extend(
+                UndefinedStruct {
+                    header,
+                    fields,
+                    strings: serialized
+                        .strings
+                        .unwrap_or_else(|| Strings::new(Vec::new())),
+                }
+                .raw(),
+            );
+        }
+
+        Ok(SMBiosData::from_vec_and_version(data, parts.version))
+    }
+}
+
+/// Encodes bytes as a string of hexadecimal digits
+fn encode_hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+/// Decodes a string of hexadecimal digits
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [high, low] => {
+                let high = (*high as char).to_digit(16)?;
+                let low = (*low as char).to_digit(16)?;
+                Some((high << 4 | low) as u8)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Locates the structure table within a `dmidecode --dump-bin` image
+///
+/// Returns the range of the structure table within `image` and the
+/// [SMBiosVersion] described by the entry point.
+#[cfg(not(feature = "no_std"))]
+fn locate_dump_bin_table(image: &[u8]) -> Result<(Range<usize>, SMBiosVersion), Error> {
+    let entry_point_bytes = |size: usize| {
+        image.get(..size).ok_or(Error::TruncatedStructure {
+            offset: 0,
+            needed: size,
+        })
+    };
+
+    let entry_point = if image.starts_with(&SMBiosEntryPoint64::SM3_ANCHOR) {
+        entry_point_bytes(SMBiosEntryPoint64::MINIMUM_SIZE)?
+    } else {
+        entry_point_bytes(SMBiosEntryPoint32::MINIMUM_SIZE)?
+    };
+    let (table_offset, table_length, version) = locate_structure_table(entry_point)?;
+
+    if table_offset >= image.len() as u64 {
+        return Err(Error::TruncatedStructure {
+            offset: usize::try_from(table_offset).unwrap_or(usize::MAX),
+            needed: table_length,
+        });
+    }
+
+    // The table maximum size of a 64 bit entry point may exceed the table actually present
+    let table_offset = table_offset as usize;
+    let table_length = table_length.min(image.len() - table_offset);
+
+    Ok((table_offset..table_offset + table_length, version))
+}
+
 /// # Version of SMBIOS Structure
-#[derive(Debug, Eq, PartialEq, Serialize, Clone, Copy)]
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Clone, Copy)]
 pub struct SMBiosVersion {
     /// SMBIOS major version
     pub major: u8,
     /// SMBIOS minor version
     pub minor: u8,
     /// SMBIOS version revision
+    #[serde(default)]
     pub revision: u8,
 }
 
@@ -232,3 +942,471 @@ impl PartialOrd for SMBiosVersion {
         Some(self.cmp(other))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_entry_point_and_vec() {
+        let table = vec![
+            0x00, 0x04, 0x00, 0x00, b'B', b'I', b'O', b'S', 0x00, 0x00, 0x7F, 0x04, 0x01, 0x00,
+            0x00, 0x00,
+        ];
+
+        // _SM3_ entry point, as exported by the SMBIOS-EPS property of macOS
+        let mut entry_point = vec![
+            0x5F, 0x53, 0x4D, 0x33, 0x5F, 0x00, 0x18, 0x03, 0x03, 0x00, 0x01, 0x00, 0x10, 0x00,
+            0x00, 0x00, 0x00, 0x20, 0xB0, 0x7B, 0x00, 0x00, 0x00, 0x00,
+        ];
+        SMBiosEntryPoint64::fix_checksums(&mut entry_point).unwrap();
+
+        let data = SMBiosData::try_from_entry_point_and_vec(&entry_point, table.clone()).unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 3, 0)));
+        assert_eq!(data.iter().count(), 2);
+        assert!(data.integrity().unwrap().is_consistent());
+
+        // _SM_ entry point
+        let mut entry_point = vec![
+            0x5F, 0x53, 0x4D, 0x5F, 0x00, 0x1F, 0x02, 0x08, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x5F, 0x44, 0x4D, 0x49, 0x5F, 0x00, 0x10, 0x00, 0x00, 0x10, 0x0F, 0x00,
+            0x02, 0x00, 0x28,
+        ];
+        SMBiosEntryPoint32::fix_checksums(&mut entry_point).unwrap();
+
+        let data = SMBiosData::try_from_entry_point_and_vec(&entry_point, table.clone()).unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(2, 8, 0)));
+        assert_eq!(data.integrity().unwrap().declared_structure_count, Some(2));
+
+        // Corrupted checksum
+        entry_point[SMBiosEntryPoint32::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET] ^= 0xFF;
+        assert!(matches!(
+            SMBiosData::try_from_entry_point_and_vec(&entry_point, table).unwrap_err(),
+            Error::InvalidEntryPoint32(
+                crate::core::SMBiosEntryPoint32Error::EntryChecksumVerificationFailed
+            )
+        ));
+    }
+
+    #[test]
+    fn test_try_from_dump_bin() {
+        // _SM3_ entry point, table at offset 20h
+        let mut image = vec![
+            0x5F, 0x53, 0x4D, 0x33, 0x5F, 0x00, 0x18, 0x03, 0x02, 0x00, 0x01, 0x00, 0x20, 0x00,
+            0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        SMBiosEntryPoint64::fix_checksums(&mut image).unwrap();
+        image.resize(0x20, 0);
+        let table = [
+            0x00, 0x04, 0x00, 0x00, b'B', b'I', b'O', b'S', 0x00, 0x00, 0x7F, 0x04, 0x01, 0x00,
+            0x00, 0x00,
+        ];
+        image.extend_from_slice(&table);
+
+        assert_eq!(SMBiosData::raw_table_from_dump_bin(&image).unwrap(), &table);
+
+        let data = SMBiosData::try_from_dump_bin(&image).unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 2, 0)));
+        assert_eq!(data.iter().count(), 2);
+
+        // _SM_ entry point, table at offset 20h
+        let mut image = vec![
+            0x5F, 0x53, 0x4D, 0x5F, 0x00, 0x1F, 0x02, 0x07, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x5F, 0x44, 0x4D, 0x49, 0x5F, 0x00, 0x10, 0x00, 0x20, 0x00, 0x00, 0x00,
+            0x02, 0x00, 0x27,
+        ];
+        SMBiosEntryPoint32::fix_checksums(&mut image).unwrap();
+        image.resize(0x20, 0);
+        image.extend_from_slice(&table);
+
+        let data = SMBiosData::try_from_dump_bin(&image).unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(2, 7, 0)));
+        assert_eq!(data.iter().count(), 2);
+        let integrity = data.integrity().unwrap();
+        assert_eq!(integrity.declared_table_length, 0x10);
+        assert!(integrity.is_consistent());
+
+        // Table offset beyond the end of the image
+        image.truncate(0x20);
+        assert!(matches!(
+            SMBiosData::try_from_dump_bin(&image).unwrap_err(),
+            Error::TruncatedStructure {
+                offset: 0x20,
+                needed: 0x10
+            }
+        ));
+        assert!(matches!(
+            SMBiosData::try_from_dump_bin(&image[..0x10]).unwrap_err(),
+            Error::TruncatedStructure {
+                offset: 0,
+                needed: 0x1F
+            }
+        ));
+    }
+
+    #[test]
+    fn test_try_from_smbios_device_image() {
+        // /dev/smbios of Solaris and illumos: the table directly follows the
+        // entry point, whose structure table address is the offset of the table
+        let table = [
+            0x00, 0x04, 0x00, 0x00, b'B', b'I', b'O', b'S', 0x00, 0x00, 0x7F, 0x04, 0x01, 0x00,
+            0x00, 0x00,
+        ];
+
+        let mut image = vec![
+            0x5F, 0x53, 0x4D, 0x33, 0x5F, 0x00, 0x18, 0x03, 0x00, 0x00, 0x01, 0x00, 0x10, 0x00,
+            0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        SMBiosEntryPoint64::fix_checksums(&mut image).unwrap();
+        image.extend_from_slice(&table);
+
+        assert_eq!(SMBiosData::raw_table_from_dump_bin(&image).unwrap(), &table);
+        let data = SMBiosData::try_from_dump_bin(&image).unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 0, 0)));
+        assert!(data.integrity().unwrap().is_consistent());
+
+        let mut image = vec![
+            0x5F, 0x53, 0x4D, 0x5F, 0x00, 0x1F, 0x02, 0x06, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x5F, 0x44, 0x4D, 0x49, 0x5F, 0x00, 0x10, 0x00, 0x1F, 0x00, 0x00, 0x00,
+            0x02, 0x00, 0x26,
+        ];
+        SMBiosEntryPoint32::fix_checksums(&mut image).unwrap();
+        image.extend_from_slice(&table);
+
+        assert_eq!(SMBiosData::raw_table_from_dump_bin(&image).unwrap(), &table);
+        let data = SMBiosData::try_from_dump_bin(&image).unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(2, 6, 0)));
+        assert_eq!(data.iter().count(), 2);
+        assert!(data.integrity().unwrap().is_consistent());
+    }
+
+    #[test]
+    fn test_collect() {
+        use crate::structs::{
+            SMBiosMemoryDevice, SMBiosProcessorInformation, SMBiosStruct, SMBiosSystemInformation,
+        };
+
+        // Windows dump, the table follows an 8 byte header
+
+        let raw = std::fs::read("tests/jeffgerlap_3_2_0.dat").unwrap()[8..].to_vec();
+        let data = SMBiosData::from_vec_and_version(raw, None);
+        let memory_devices = data.collect::<SMBiosMemoryDevice<'_>>();
+        assert_eq!(memory_devices.len(), 2);
+        assert_eq!(data.collect::<SMBiosProcessorInformation<'_>>().len(), 1);
+        assert_eq!(
+            data.first::<SMBiosMemoryDevice<'_>>().unwrap().parts().header.handle(),
+            memory_devices[0].parts().header.handle()
+        );
+        assert!(data.first::<SMBiosSystemInformation<'_>>().is_some());
+    }
+
+    #[test]
+    fn test_find_by_handle() {
+        // Windows dump, the table follows an 8 byte header
+        let raw = std::fs::read("tests/jeffgerlap_3_2_0.dat").unwrap()[8..].to_vec();
+        let data = SMBiosData::from_vec_and_version(raw, None);
+        for undefined_struct in data.iter() {
+            let handle = undefined_struct.header.handle();
+            let found = data.find_by_handle(&handle).unwrap();
+            assert!(core::ptr::eq(found, undefined_struct));
+        }
+        assert!(data.find_by_handle(&Handle(0xFFF0)).is_none());
+
+        // the first of structures sharing a handle is found
+        let table = vec![
+            0x0B, 0x05, 0x01, 0x00, 0x00, 0x00, 0x00, // handle 1
+            0x0B, 0x05, 0x01, 0x00, 0x01, 0x00, 0x00, // handle 1
+            0x7F, 0x04, 0x02, 0x00, 0x00, 0x00,
+        ];
+        let data = SMBiosData::from_vec_and_version(table, None);
+        assert_eq!(data.find_by_handle(&Handle(1)).unwrap().fields[4], 0);
+    }
+
+    #[test]
+    fn test_resolve() {
+        use crate::structs::{SMBiosEndOfTable, SMBiosOemStrings};
+
+        let table = vec![
+            0x0B, 0x05, 0x01, 0x00, 0x00, 0x00, 0x00, // OEM Strings, handle 1
+            0x0B, 0x05, 0xFF, 0xFF, 0x00, 0x00, 0x00, // OEM Strings, handle FFFFh
+            0x7F, 0x04, 0x02, 0x00, 0x00, 0x00,
+        ];
+        let data = SMBiosData::from_vec_and_version(table, None);
+        let oem_strings = data.resolve::<SMBiosOemStrings<'_>>(&Handle(1)).unwrap();
+        assert_eq!(oem_strings.parts().header.handle(), Handle(1));
+        assert!(data.resolve::<SMBiosEndOfTable<'_>>(&Handle(1)).is_none());
+        assert!(data.resolve::<SMBiosOemStrings<'_>>(&Handle(3)).is_none());
+        assert!(data
+            .resolve::<SMBiosOemStrings<'_>>(&Handle(0xFFFF))
+            .is_none());
+        assert!(data.find_by_handle(&Handle(0xFFFF)).is_some());
+    }
+
+    #[test]
+    fn test_referencing() {
+        let table = vec![
+            // Physical Memory Array 0x0010, error information 0x0012
+            0x10, 0x0F, 0x10, 0x00, 0x03, 0x03, 0x03, 0x00, 0x00, 0x00, 0x02, 0x12, 0x00, 0x01,
+            0x00, 0x00, 0x00,
+            // Memory Device 0x0011 of array 0x0010, no error information
+            0x11, 0x15, 0x11, 0x00, 0x10, 0x00, 0xFE, 0xFF, 0x48, 0x00, 0x40, 0x00, 0x00, 0x40,
+            0x09, 0x00, 0x00, 0x00, 0x1A, 0x00, 0x00, 0x00, 0x00,
+            // OEM-specific 0x0012, a WORD 0x0010 not known to be a handle
+            0xDD, 0x06, 0x12, 0x00, 0x10, 0x00, 0x00, 0x00,
+            // Memory Array Mapped Address 0x0013 of array 0x0010
+            0x13, 0x0F, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x3F, 0x00, 0x10, 0x00,
+            0x01, 0x00, 0x00,
+        ];
+        let data = SMBiosData::from_vec_and_version(table, None);
+
+        let handles: Vec<Handle> = data
+            .referencing(&Handle(0x0010))
+            .map(|undefined_struct| undefined_struct.header.handle())
+            .collect();
+        assert_eq!(handles, vec![Handle(0x0011), Handle(0x0013)]);
+
+        let handles: Vec<Handle> = data
+            .referencing(&Handle(0x0012))
+            .map(|undefined_struct| undefined_struct.header.handle())
+            .collect();
+        assert_eq!(handles, vec![Handle(0x0010)]);
+
+        assert_eq!(data.referencing(&Handle(0xFFFE)).count(), 0);
+        assert_eq!(data.referencing(&Handle(0x0013)).count(), 0);
+    }
+
+    #[test]
+    fn test_iter_with_offsets() {
+        // Windows dump, the table follows an 8 byte header
+        let raw = std::fs::read("tests/jeffgerlap_3_2_0.dat").unwrap()[8..].to_vec();
+        let data = SMBiosData::from_vec_and_version(raw.clone(), None);
+
+        let mut count = 0;
+        for ((handle, struct_type, offset, defined_struct), undefined_struct) in
+            data.iter_with_offsets().zip(data.iter())
+        {
+            let struct_raw = undefined_struct.raw();
+            assert_eq!(handle, undefined_struct.header.handle());
+            assert_eq!(struct_type, undefined_struct.header.struct_type());
+            assert_eq!(&raw[offset..offset + struct_raw.len()], struct_raw.as_slice());
+            if struct_type == 17 {
+                assert!(matches!(defined_struct, DefinedStruct::MemoryDevice(_)));
+            }
+            count += 1;
+        }
+        assert_eq!(count, data.iter().len());
+
+        // structures following End-of-Table are visited
+        let table = vec![
+            0x7F, 0x04, 0x01, 0x00, 0x00, 0x00, // End-of-Table
+            0x0B, 0x05, 0x02, 0x00, 0x01, 0x41, 0x00, 0x00, // OEM Strings
+        ];
+        let data = SMBiosData::from_vec_and_version(table, None);
+        let offsets: Vec<(Handle, u8, usize)> = data
+            .iter_with_offsets()
+            .map(|(handle, struct_type, offset, _)| (handle, struct_type, offset))
+            .collect();
+        assert_eq!(offsets, vec![(Handle(1), 127, 0), (Handle(2), 11, 6)]);
+    }
+
+    #[test]
+    fn test_structure_offsets() {
+        let table = vec![
+            0x0B, 0x05, 0x01, 0x00, 0x01, 0x41, 0x00, 0x00, // OEM Strings
+            0x7F, 0x04, 0x02, 0x00, 0x00, 0x00, // End-of-Table
+        ];
+        let data = SMBiosData::from_vec_and_version(table, None);
+        assert_eq!(data.len(), 2);
+        assert!(!data.is_empty());
+
+        let offsets = data.structure_offsets();
+        assert_eq!(offsets.len(), 2);
+        assert_eq!(
+            offsets.collect::<Vec<_>>(),
+            vec![
+                StructureOffset {
+                    struct_type: 11,
+                    handle: Handle(1),
+                    start: 0,
+                    end: 8,
+                },
+                StructureOffset {
+                    struct_type: 127,
+                    handle: Handle(2),
+                    start: 8,
+                    end: 14,
+                },
+            ]
+        );
+        // repeated iterations read the index
+        assert!(data.structure_offsets().eq(data.structure_offsets()));
+        assert_eq!(data.iter_with_offsets().len(), 2);
+
+        assert!(SMBiosData::from_vec_and_version(vec![], None).is_empty());
+    }
+
+    #[test]
+    fn test_defined_structs_of_type() {
+        let table = vec![
+            0xDD, 0x05, 0x01, 0x00, 0x2A, 0x00, 0x00, // OEM-specific
+            0x0B, 0x05, 0x02, 0x00, 0x00, 0x00, 0x00, // OEM Strings
+            0xDD, 0x05, 0x03, 0x00, 0x2B, 0x00, 0x00, // OEM-specific
+            0x7F, 0x04, 0x04, 0x00, 0x00, 0x00, // End-of-Table
+            0xDD, 0x05, 0x05, 0x00, 0x2C, 0x00, 0x00, // after End-of-Table
+        ];
+        let data = SMBiosData::from_vec_and_version(table, None);
+
+        let handles: Vec<Handle> = data
+            .defined_structs_of_type(0xDD)
+            .map(|defined_struct| match defined_struct {
+                DefinedStruct::Undefined(unknown) => unknown.parts().header.handle(),
+                _ => panic!("OEM-specific structures are undefined"),
+            })
+            .collect();
+        assert_eq!(handles, vec![Handle(1), Handle(3)]);
+        assert!(matches!(
+            data.defined_structs_of_type(0x0B).next(),
+            Some(DefinedStruct::OemStrings(_))
+        ));
+        assert_eq!(data.defined_structs_of_type(0x7F).count(), 1);
+        assert_eq!(data.defined_structs_of_type(0x01).count(), 0);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_serde_round_trip() {
+        let raw = std::fs::read("tests/jeffgerlap_3_2_0.dat").unwrap()[8..].to_vec();
+        let data = SMBiosData::from_vec_and_version(raw.clone(), Some(SMBiosVersion::new(3, 2, 0)));
+
+        let json = serde_json::to_string(&data.with_raw_table()).unwrap();
+        let loaded: SMBiosData = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.version, data.version);
+        assert_eq!(loaded.raw_bytes(), raw);
+        assert_eq!(
+            serde_json::to_string(&loaded.with_raw_table()).unwrap(),
+            json
+        );
+
+        // the serialization of the table alone has no raw_table, and its
+        // defined structures cannot be rebuilt
+        let value = serde_json::to_value(&data).unwrap();
+        assert_eq!(
+            value.as_object().unwrap().keys().collect::<Vec<_>>(),
+            vec!["table", "version"]
+        );
+        assert!(serde_json::from_value::<SMBiosData>(value).is_err());
+
+        // structures of an undefined type and End-of-Table are rebuilt
+        let table = vec![
+            0x80, 0x06, 0x10, 0x00, 0x01, 0x02, b'O', b'E', b'M', 0x00, 0xC6, 0x00, 0x00, 0x7F,
+            0x04, 0x11, 0x00, 0x00, 0x00,
+        ];
+        let data = SMBiosData::from_vec_and_version(table.clone(), None);
+        let json = serde_json::to_string(&data).unwrap();
+        let loaded: SMBiosData = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.raw_bytes(), table);
+        assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+
+        let loaded: SMBiosData =
+            serde_json::from_str(r#"{ "version": null, "raw_table": "7F0400000000" }"#).unwrap();
+        assert_eq!(loaded.version, None);
+        assert_eq!(loaded.iter().count(), 1);
+        assert!(serde_json::from_str::<SMBiosData>(
+            r#"{ "version": null, "raw_table": "7F04 0000" }"#
+        )
+        .is_err());
+        let loaded: SMBiosData =
+            serde_json::from_str(r#"{ "version": null, "raw_table": [127, 4, 0, 0, 0, 0] }"#)
+                .unwrap();
+        assert_eq!(loaded.iter().count(), 1);
+        let loaded: SMBiosData = serde_json::from_str(r#"{ "version": null }"#).unwrap();
+        assert_eq!(loaded.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_string_decoding() {
+        use crate::structs::SMBiosSystemInformation;
+
+        // System Information with a UTF-8 manufacturer and an OEM-specific
+        // structure with a UTF-8 string
+        let table = vec![
+            0x01, 0x05, 0x00, 0x00, 0x01, b'C', 0xC3, 0xA9, 0x00, 0x00, 0x80, 0x05, 0x01, 0x00,
+            0x01, 0xE2, 0x82, 0xAC, 0x00, 0x00, 0x7F, 0x04, 0x02, 0x00, 0x00, 0x00,
+        ];
+        let manufacturer = |data: &SMBiosData| {
+            data.first::<SMBiosSystemInformation<'_>>()
+                .unwrap()
+                .manufacturer()
+        };
+
+        let data = SMBiosData::from_vec_and_version(table.clone(), None);
+        assert_eq!(manufacturer(&data), Some("CÃ©".to_string()));
+
+        let options = ParseOptions {
+            string_decoding: StringDecoding::Utf8,
+            ..Default::default()
+        };
+        let parsed = SMBiosData::try_from_vec_with_options(table.clone(), None, &options).unwrap();
+        assert_eq!(manufacturer(&parsed), Some("Cé".to_string()));
+        assert_eq!(
+            parsed.iter().nth(1).unwrap().strings.decoding(),
+            StringDecoding::Utf8
+        );
+
+        let data = data.with_string_decoding(StringDecoding::Utf8OrLatin1);
+        assert_eq!(manufacturer(&data), Some("Cé".to_string()));
+        assert_eq!(
+            (&data.iter().nth(1).unwrap().strings)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["€".to_string()]
+        );
+
+        #[cfg(feature = "serde_json")]
+        {
+            let json = serde_json::to_string(&data).unwrap();
+            assert!(json.contains("\"Cé\""));
+            assert!(json.contains("\"€\""));
+
+            // the UTF-8 string of the OEM-specific structure is rebuilt
+            let oem = SMBiosData::from_vec_and_version(table[10..].to_vec(), None)
+                .with_string_decoding(StringDecoding::Utf8);
+            let loaded: SMBiosData =
+                serde_json::from_str(&serde_json::to_string(&oem).unwrap()).unwrap();
+            assert_eq!(loaded.raw_bytes(), table[10..].to_vec());
+        }
+    }
+
+    #[cfg(all(feature = "ciborium", not(feature = "no_std")))]
+    #[test]
+    fn test_to_cbor() {
+        let raw = std::fs::read("tests/jeffgerlap_3_2_0.dat").unwrap()[8..].to_vec();
+        let data = SMBiosData::from_vec_and_version(raw.clone(), Some(SMBiosVersion::new(3, 2, 0)));
+
+        let cbor = data.to_cbor().unwrap();
+        let loaded: SMBiosData = ciborium::from_reader(cbor.as_slice()).unwrap();
+        assert_eq!(loaded.version, data.version);
+        assert_eq!(loaded.raw_bytes(), raw);
+
+        // the table is held as a byte string, without the decoded structures
+        let value: ciborium::value::Value = ciborium::from_reader(cbor.as_slice()).unwrap();
+        let map = value.as_map().unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[1].0.as_text(), Some("raw_table"));
+        assert_eq!(map[1].1.as_bytes(), Some(&raw));
+    }
+
+    #[cfg(all(feature = "rmp-serde", not(feature = "no_std")))]
+    #[test]
+    fn test_to_msgpack() {
+        let raw = std::fs::read("tests/jeffgerlap_3_2_0.dat").unwrap()[8..].to_vec();
+        let data = SMBiosData::from_vec_and_version(raw.clone(), Some(SMBiosVersion::new(3, 2, 0)));
+
+        let msgpack = data.to_msgpack().unwrap();
+        let loaded: SMBiosData = rmp_serde::from_slice(&msgpack).unwrap();
+        assert_eq!(loaded.version, data.version);
+        assert_eq!(loaded.raw_bytes(), raw);
+        // a map of version and raw_table, the table as binary
+        assert!(msgpack.len() < raw.len() + 64);
+    }
+}
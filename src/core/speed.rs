@@ -0,0 +1,88 @@
+use core::fmt;
+use serde::Serialize;
+
+/// # Megahertz Speed
+///
+/// A clock frequency in megahertz (MHz), such as the speed of a processor
+/// (see [crate::ProcessorSpeed::megahertz]).
+///
+/// A frequency is kept apart from a [MegatransferSpeed], the data rate of
+/// a memory device, which is not a clock frequency: a DDR device transfers
+/// data twice per clock cycle.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MegahertzSpeed(u32);
+
+impl MegahertzSpeed {
+    /// Creates a speed of `megahertz` MHz
+    pub fn new(megahertz: u32) -> Self {
+        MegahertzSpeed(megahertz)
+    }
+
+    /// Speed in megahertz (MHz)
+    pub fn as_megahertz(&self) -> u32 {
+        self.0
+    }
+
+    /// Speed in hertz (Hz)
+    pub fn as_hertz(&self) -> u64 {
+        u64::from(self.0) * 1_000_000
+    }
+}
+
+impl fmt::Display for MegahertzSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} MHz", self.0)
+    }
+}
+
+/// # Megatransfer Speed
+///
+/// A data rate in megatransfers per second (MT/s), such as the speed of a
+/// memory device (see [crate::SMBiosMemoryDevice::effective_speed]).
+///
+/// A data rate is kept apart from a [MegahertzSpeed], a clock frequency.
+/// SMBIOS versions before 3.1.0 named the unit of memory speeds MHz while
+/// holding the data rate.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MegatransferSpeed(u32);
+
+impl MegatransferSpeed {
+    /// Creates a speed of `megatransfers` MT/s
+    pub fn new(megatransfers: u32) -> Self {
+        MegatransferSpeed(megatransfers)
+    }
+
+    /// Speed in megatransfers per second (MT/s)
+    pub fn as_megatransfers(&self) -> u32 {
+        self.0
+    }
+
+    /// Speed in transfers per second
+    pub fn as_transfers_per_second(&self) -> u64 {
+        u64::from(self.0) * 1_000_000
+    }
+}
+
+impl fmt::Display for MegatransferSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} MT/s", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speed() {
+        let speed = MegahertzSpeed::new(3600);
+        assert_eq!(speed.as_hertz(), 3_600_000_000);
+        assert_eq!(speed.to_string(), "3600 MHz");
+
+        let speed = MegatransferSpeed::new(4800);
+        assert_eq!(speed.as_megatransfers(), 4800);
+        assert_eq!(speed.as_transfers_per_second(), 4_800_000_000);
+        assert_eq!(speed.to_string(), "4800 MT/s");
+        assert!(speed > MegatransferSpeed::new(3200));
+    }
+}
@@ -0,0 +1,183 @@
+use super::header::Header;
+use super::undefined_struct::{complete_struct_length, UndefinedStruct};
+use crate::structs::{SMBiosEndOfTable, SMBiosStruct};
+#[cfg(feature = "no_std")]
+use alloc::{format, vec::Vec};
+use core::fmt;
+
+/// SMBiosStreamParser parse errors
+pub enum SMBiosStreamError {
+    /// A structure header reported a length smaller than the header itself
+    InvalidStructLength {
+        /// Offset of the structure within the table
+        offset: usize,
+        /// The length reported by the header
+        length: u8,
+    },
+}
+
+impl fmt::Debug for SMBiosStreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SMBiosStreamError")
+            .field(&format!("{}", &self))
+            .finish()
+    }
+}
+
+impl fmt::Display for SMBiosStreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SMBiosStreamError::InvalidStructLength { offset, length } => write!(
+                f,
+                "Structure length {} at offset {} is smaller than the structure header",
+                length, offset
+            ),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for SMBiosStreamError {}
+
+/// # SMBIOS Stream Parser
+///
+/// Incrementally parses an SMBIOS structure table which arrives in chunks
+/// (for example from a socket or a BMC transfer) rather than all at once.
+///
+/// Chunks are added with [SMBiosStreamParser::push] and complete structures
+/// are taken with [SMBiosStreamParser::next_struct] as soon as all of their
+/// bytes, including the string-set terminator, have arrived. Parsing stops at
+/// the End-of-Table (Type 127) structure; any bytes after it are ignored.
+#[derive(Default)]
+pub struct SMBiosStreamParser {
+    buffer: Vec<u8>,
+    position: usize,
+    offset: usize,
+    end_of_table: bool,
+}
+
+impl SMBiosStreamParser {
+    /// Creates a parser awaiting the first chunk of the table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a chunk of table bytes
+    pub fn push(&mut self, chunk: &[u8]) {
+        if self.end_of_table {
+            return;
+        }
+
+        // Discard the bytes of structures already taken
+        self.buffer.drain(..self.position);
+        self.position = 0;
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Takes the next complete structure
+    ///
+    /// Returns `Ok(None)` when more bytes are needed or the End-of-Table
+    /// structure has been taken.
+    ///
+    /// A structure whose header reports a length smaller than the header
+    /// cannot be delimited, so the table cannot be parsed past it: the same
+    /// [SMBiosStreamError::InvalidStructLength] is returned on each call.
+    pub fn next_struct(&mut self) -> Result<Option<UndefinedStruct>, SMBiosStreamError> {
+        if self.end_of_table {
+            return Ok(None);
+        }
+
+        let pending = &self.buffer[self.position..];
+
+        if let Some(&length) = pending.get(Header::LENGTH_OFFSET) {
+            if (length as usize) < Header::SIZE {
+                return Err(SMBiosStreamError::InvalidStructLength {
+                    offset: self.offset,
+                    length,
+                });
+            }
+        }
+
+        match complete_struct_length(pending) {
+            Some(struct_len) => {
+                let undefined_struct = UndefinedStruct::from_slice(&pending[..struct_len]);
+                self.position += struct_len;
+                self.offset += struct_len;
+
+                if undefined_struct.header.struct_type() == SMBiosEndOfTable::STRUCT_TYPE {
+                    self.end_of_table = true;
+                    self.buffer = Vec::new();
+                    self.position = 0;
+                }
+
+                Ok(Some(undefined_struct))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Whether the End-of-Table (Type 127) structure has been taken
+    pub fn is_end_of_table(&self) -> bool {
+        self.end_of_table
+    }
+
+    /// Number of bytes received which are not yet part of a taken structure
+    pub fn pending_len(&self) -> usize {
+        self.buffer.len() - self.position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_parser() {
+        let table = [
+            0x00, 0x04, 0x00, 0x00, b'B', b'I', b'O', b'S', 0x00, 0x00, 0x01, 0x04, 0x01, 0x00,
+            0x00, 0x00, 0x7F, 0x04, 0x02, 0x00, 0x00, 0x00, 0xFF, 0xFF,
+        ];
+
+        let mut parser = SMBiosStreamParser::new();
+        let mut structs = Vec::new();
+
+        for chunk in table.chunks(3) {
+            parser.push(chunk);
+            while let Some(undefined_struct) = parser.next_struct().unwrap() {
+                structs.push(undefined_struct);
+            }
+        }
+
+        assert_eq!(structs.len(), 3);
+        assert_eq!(structs[0].header.struct_type(), 0);
+        assert_eq!(structs[0].strings.iter().next().unwrap(), b"BIOS");
+        assert_eq!(structs[1].header.struct_type(), 1);
+        assert_eq!(structs[2].header.struct_type(), 127);
+        assert!(parser.is_end_of_table());
+        assert_eq!(parser.pending_len(), 0);
+    }
+
+    #[test]
+    fn test_stream_parser_partial() {
+        let mut parser = SMBiosStreamParser::new();
+
+        parser.push(&[0x00, 0x04, 0x00, 0x00, b'A', 0x00]);
+        assert!(parser.next_struct().unwrap().is_none());
+        assert_eq!(parser.pending_len(), 6);
+
+        parser.push(&[0x00]);
+        assert!(parser.next_struct().unwrap().is_some());
+        assert!(!parser.is_end_of_table());
+        assert_eq!(parser.pending_len(), 0);
+
+        parser.push(&[0x01, 0x02, 0x00, 0x00, 0x00, 0x00]);
+        assert!(matches!(
+            parser.next_struct(),
+            Err(SMBiosStreamError::InvalidStructLength {
+                offset: 7,
+                length: 2
+            })
+        ));
+        assert!(parser.next_struct().is_err());
+    }
+}
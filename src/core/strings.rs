@@ -1,68 +1,130 @@
-use serde::{ser::SerializeSeq, Serialize, Serializer};
+use super::maybe_value::MaybeValue;
+use super::nul_scan::split_nul;
+use serde::{de::Error, ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
 use core::fmt;
 #[cfg(feature = "no_std")]
-use alloc::{vec::Vec, string::String, vec};
+use alloc::{borrow::Cow, vec::Vec, string::String, vec, format};
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
 
 /// # SMBIOS Strings
 ///
 /// The strings part/section of a structure
+///
+/// The string-set is split at its NUL terminators once, when the structure
+/// is parsed, so that string fields are looked up by index without scanning.
+///
+/// Strings are decoded with their [StringDecoding], ISO-8859-1 (Latin-1) by
+/// default, see [Strings::decoding].
 pub struct Strings {
     strings: Vec<Vec<u8>>,
     current_string_index: usize,
+    decoding: StringDecoding,
 }
 
 impl Strings {
     /// Creates a new strings section of a structure
     pub fn new(string_area: Vec<u8>) -> Strings {
+        Strings::from_area(&string_area)
+    }
+
+    /// Creates the strings section of a structure from its string area,
+    /// copying each string once
+    pub(crate) fn from_area(string_area: &[u8]) -> Strings {
         Strings {
             strings: {
-                if string_area == &[] {
+                if string_area.is_empty() {
                     vec![]
                 } else {
-                    string_area
-                        .split(|num| *num == 0)
-                        .into_iter()
+                    split_nul(string_area)
                         .map(|string_slice| string_slice.to_vec())
                         .collect()
                 }
             },
             current_string_index: 0,
+            decoding: StringDecoding::default(),
         }
     }
 
+    /// The decoding of the strings
+    ///
+    /// Used by [Strings::get_string], [Strings::get_str], iteration and
+    /// serialization, and set by
+    /// [ParseOptions::string_decoding](super::ParseOptions::string_decoding)
+    /// or [SMBiosData::with_string_decoding](super::SMBiosData::with_string_decoding).
+    pub fn decoding(&self) -> StringDecoding {
+        self.decoding
+    }
+
+    /// Sets the decoding of the strings, see [Strings::decoding]
+    pub fn set_decoding(&mut self, decoding: StringDecoding) {
+        self.decoding = decoding;
+    }
+
+    /// Decodes `string` with the decoding of the strings, lossily when the
+    /// decoding fails on it
+    fn decode_or_lossy<'b>(&self, string: &'b [u8]) -> Cow<'b, str> {
+        self.decoding
+            .decode(string)
+            .unwrap_or_else(|| String::from_utf8_lossy(string))
+    }
+
     fn reset(&mut self) {
         self.current_string_index = 0;
     }
 
-    /// Returns a [String] at the given `index`
+    /// Returns a [String] at the given `index`, decoded with
+    /// [Strings::decoding]
     ///
     /// BIOS strings are 1 based indexing
     pub fn get_string(&self, index: u8) -> Option<String> {
-        let index_usize = index as usize;
+        self.get_str(index).map(Cow::into_owned)
+    }
 
-        if index_usize == 0 || index_usize > self.strings.len() {
-            // BIOS strings are 1 based indexing, ignore bad input
-            return None;
-        }
+    /// Returns the string at the given `index`, borrowed from the structure
+    /// when it is ASCII
+    ///
+    /// Returns the same value as [Strings::get_string] without allocating
+    /// for the usual ASCII strings. Strings are decoded with
+    /// [Strings::decoding], which is ISO-8859-1 unless configured otherwise.
+    ///
+    /// BIOS strings are 1 based indexing
+    pub fn get_str(&self, index: u8) -> Option<Cow<'_, str>> {
+        self.get_str_with(index, self.decoding)
+    }
+
+    /// Returns the string at the given `index`, decoded with `decoding`
+    ///
+    /// Returns None when there is no such string, or when `decoding` is
+    /// [StringDecoding::Utf8] and the string is not UTF-8.
+    ///
+    /// BIOS strings are 1 based indexing
+    pub fn get_str_with(&self, index: u8, decoding: StringDecoding) -> Option<Cow<'_, str>> {
+        decoding.decode(self.get_bytes(index)?)
+    }
+
+    /// Returns the raw bytes of the string at the given `index`, without
+    /// the terminating 0
+    ///
+    /// BIOS strings are 1 based indexing
+    pub fn get_bytes(&self, index: u8) -> Option<&[u8]> {
+        // BIOS strings are 1 based indexing, ignore bad input
+        let index_usize = (index as usize).checked_sub(1)?;
+        self.strings.get(index_usize).map(Vec::as_slice)
+    }
 
-        // Create an ISO-8859-1 String.  Each `u8 as char` operation maps a u8
-        // value (0xNN) to a Unicode code point (0x00NN).
-        //
-        // SMBIOS specification does not state that a BIOS string is ISO-8859-1 or
-        // ASCII (or anything else).  The reason it is important to use ISO-8859-1
-        // is that every u8 value (0-255) is represented and mapped 1:1 with a Unicode
-        // value.  Therefore, it is possible to reverse the process, starting from a
-        // Rust String or str and produce the original u8 array of values.
-        //
-        // Presently there is no need to convert back to a u8 array. If there were,
-        // the Rust char functions len_utf8() and encode_utf8() can be used.  If len_utf8()
-        // == 2 then the original u8 can be arrived at by combining bits from the two bytes.
-        Some(
-            self.strings[index_usize - 1]
-                .iter()
-                .map(|x| *x as char)
-                .collect(),
-        )
+    /// Access the string of the given `index`
+    ///
+    /// Unlike [Strings::get_string], an index of 0 (no string,
+    /// [MaybeValue::NotProvided]) is told apart from an index past the end
+    /// of the string-set ([MaybeValue::InvalidIndex]). A string which
+    /// [Strings::decoding] fails on is decoded lossily, as when iterating.
+    pub fn access_string(&self, index: u8) -> MaybeValue<String> {
+        match self.get_bytes(index) {
+            Some(string) => MaybeValue::Value(self.decode_or_lossy(string).into_owned()),
+            None if index == 0 => MaybeValue::NotProvided,
+            None => MaybeValue::InvalidIndex(index),
+        }
     }
 
     /// Iterates the raw bytes of the strings. The terminating 0 is not included in each string.
@@ -80,11 +142,9 @@ impl Iterator for Strings {
             return None;
         }
 
-        // "*x as char" is ISO-8859-1.
-        let result: String = self.strings[self.current_string_index]
-            .iter()
-            .map(|x| *x as char)
-            .collect();
+        let result = self
+            .decode_or_lossy(&self.strings[self.current_string_index])
+            .into_owned();
         self.current_string_index = self.current_string_index + 1;
 
         Some(result)
@@ -99,13 +159,18 @@ impl IntoIterator for &Strings {
         Strings {
             strings: self.strings.clone(),
             current_string_index: 0,
+            decoding: self.decoding,
         }
     }
 }
 
 impl fmt::Debug for Strings {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_list().entries(self.into_iter()).finish()
+        // Always ISO-8859-1, so that the bytes can be recovered from the
+        // serialized form of an UndefinedStruct (see Strings::parse_debug)
+        fmt.debug_list()
+            .entries(self.strings.iter().map(|string| iso_8859_1(string)))
+            .finish()
     }
 }
 
@@ -114,11 +179,203 @@ impl Serialize for Strings {
     where
         S: Serializer,
     {
-        let strings: Vec<String> = self.into_iter().collect();
-        let mut seq = serializer.serialize_seq(Some(strings.len()))?;
-        for e in strings {
-            seq.serialize_element(&e)?;
+        let mut seq = serializer.serialize_seq(Some(self.strings.len()))?;
+        for string in self.strings.iter() {
+            seq.serialize_element(&self.decode_or_lossy(string))?;
         }
         seq.end()
     }
 }
+
+impl<'de> Deserialize<'de> for Strings {
+    /// Deserializes the strings from a sequence of strings
+    ///
+    /// A string whose characters are all within ISO-8859-1 is read as
+    /// ISO-8859-1 bytes (see [StringDecoding::Latin1]), any other string as
+    /// UTF-8.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+        Strings::try_from_decoded(&strings)
+            .map_err(|c| D::Error::custom(format!("invalid character {:?} in SMBIOS string", c)))
+    }
+}
+
+impl Strings {
+    /// Creates the strings of a sequence of strings, each character being
+    /// an ISO-8859-1 byte
+    ///
+    /// Fails with the first character which is NUL or outside of ISO-8859-1.
+    pub(crate) fn try_from_iso_8859_1(strings: &[String]) -> Result<Strings, char> {
+        let strings = strings
+            .iter()
+            .map(|string| {
+                string
+                    .chars()
+                    .map(|c| match c as u32 {
+                        0x01..=0xFF => Ok(c as u8),
+                        _ => Err(c),
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<u8>>, char>>()?;
+
+        Ok(Strings {
+            strings,
+            current_string_index: 0,
+            decoding: StringDecoding::default(),
+        })
+    }
+
+    /// Creates the strings of a sequence of decoded strings
+    ///
+    /// A string whose characters are all within ISO-8859-1 is encoded as
+    /// such, recovering the bytes of a string decoded with the default
+    /// [StringDecoding::Latin1]. Other strings, decoded from UTF-8, are
+    /// encoded as UTF-8. Fails with the first NUL character.
+    fn try_from_decoded(strings: &[String]) -> Result<Strings, char> {
+        let strings = strings
+            .iter()
+            .map(|string| {
+                if string.contains('\0') {
+                    Err('\0')
+                } else if string.chars().all(|c| (c as u32) <= 0xFF) {
+                    Ok(string.chars().map(|c| c as u8).collect())
+                } else {
+                    Ok(string.as_bytes().to_vec())
+                }
+            })
+            .collect::<Result<Vec<Vec<u8>>, char>>()?;
+
+        Ok(Strings {
+            strings,
+            current_string_index: 0,
+            decoding: StringDecoding::default(),
+        })
+    }
+
+    /// Parses the [fmt::Debug] form of the strings, such as `["OEM", "\u{1}"]`
+    pub(crate) fn parse_debug(debug: &str) -> Option<Vec<String>> {
+        let mut chars = debug.strip_prefix('[')?.strip_suffix(']')?.chars();
+        let mut strings = Vec::new();
+        let mut next = chars.next();
+        while next.is_some() {
+            if next != Some('"') {
+                return None;
+            }
+
+            let mut string = String::new();
+            loop {
+                match chars.next()? {
+                    '"' => break,
+                    '\\' => string.push(match chars.next()? {
+                        '0' => '\0',
+                        't' => '\t',
+                        'r' => '\r',
+                        'n' => '\n',
+                        'u' => {
+                            let hex: String = chars
+                                .by_ref()
+                                .skip_while(|c| *c == '{')
+                                .take_while(|c| *c != '}')
+                                .collect();
+                            core::char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+                        }
+                        c => c,
+                    }),
+                    c => string.push(c),
+                }
+            }
+            strings.push(string);
+
+            next = match chars.next() {
+                Some(',') if chars.next() == Some(' ') => chars.next(),
+                None => None,
+                _ => return None,
+            };
+        }
+
+        Some(strings)
+    }
+}
+
+/// # String Decoding
+///
+/// How the bytes of a string are decoded, see [Strings::decoding] and
+/// [Strings::get_str_with]
+///
+/// The specification leaves the encoding of strings open. Most firmware
+/// writes ASCII, which every decoding reads alike; some vendors write
+/// UTF-8 or ISO-8859-1 (Latin-1) text, or garbage bytes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StringDecoding {
+    /// Each byte is an ISO-8859-1 (Latin-1) character
+    ///
+    /// The default decoding of [Strings]. It never fails and maps each
+    /// byte to one character, so the bytes can be recovered from the
+    /// string, but UTF-8 text is decoded as several characters per
+    /// character.
+    #[default]
+    Latin1,
+    /// UTF-8, failing on a string which is not UTF-8
+    ///
+    /// Accessors return None for such a string, while
+    /// [Strings::access_string], iterating or serializing [Strings] decodes
+    /// it as [StringDecoding::Utf8Lossy].
+    Utf8,
+    /// UTF-8, replacing invalid sequences with U+FFFD
+    Utf8Lossy,
+    /// UTF-8, or ISO-8859-1 for a string which is not UTF-8
+    Utf8OrLatin1,
+}
+
+impl StringDecoding {
+    /// Decodes the raw bytes of a string, borrowing them when they are
+    /// ASCII
+    ///
+    /// Returns None for [StringDecoding::Utf8] when the bytes are not UTF-8.
+    pub fn decode(self, string: &[u8]) -> Option<Cow<'_, str>> {
+        match self {
+            StringDecoding::Latin1 => Some(iso_8859_1(string)),
+            StringDecoding::Utf8 => core::str::from_utf8(string).ok().map(Cow::Borrowed),
+            StringDecoding::Utf8Lossy => Some(String::from_utf8_lossy(string)),
+            StringDecoding::Utf8OrLatin1 => Some(match core::str::from_utf8(string) {
+                Ok(utf8) => Cow::Borrowed(utf8),
+                Err(_) => iso_8859_1(string),
+            }),
+        }
+    }
+}
+
+/// Trims the padding found in many tables from a string, an empty string
+/// being None
+pub(crate) fn trim_padding(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| String::from(value.trim()))
+        .filter(|value| !value.is_empty())
+}
+
+/// Decodes a BIOS string as ISO-8859-1, borrowing it when it is ASCII
+fn iso_8859_1(string: &[u8]) -> Cow<'_, str> {
+    // Create an ISO-8859-1 String.  Each `u8 as char` operation maps a u8
+    // value (0xNN) to a Unicode code point (0x00NN).
+    //
+    // SMBIOS specification does not state that a BIOS string is ISO-8859-1 or
+    // ASCII (or anything else).  The reason it is important to use ISO-8859-1
+    // is that every u8 value (0-255) is represented and mapped 1:1 with a Unicode
+    // value.  Therefore, it is possible to reverse the process, starting from a
+    // Rust String or str and produce the original u8 array of values.
+    //
+    // Presently there is no need to convert back to a u8 array. If there were,
+    // the Rust char functions len_utf8() and encode_utf8() can be used.  If len_utf8()
+    // == 2 then the original u8 can be arrived at by combining bits from the two bytes.
+    //
+    // ASCII is the same in ISO-8859-1 and UTF-8 and needs no conversion.
+    if string.is_ascii() {
+        Cow::Borrowed(core::str::from_utf8(string).expect("ASCII is UTF-8"))
+    } else {
+        Cow::Owned(string.iter().map(|x| *x as char).collect())
+    }
+}
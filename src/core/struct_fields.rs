@@ -0,0 +1,69 @@
+use super::header::Handle;
+use super::table_ref::UndefinedStructRef;
+use super::undefined_struct::UndefinedStruct;
+use core::convert::TryInto;
+
+/// # Structure Fields
+///
+/// The `get_field_*()` functions reading the formatted area of a structure,
+/// shared by the owned [UndefinedStruct] and the borrowed
+/// [UndefinedStructRef].
+///
+/// The formatted area includes the header, so that offsets are those given
+/// by the SMBIOS specification. A field which the formatted area is too
+/// short to hold is None.
+pub trait StructFields<'a>: Sized {
+    /// The raw data for the header and fields
+    fn field_bytes(self) -> &'a [u8];
+
+    /// Retrieve a byte at the given offset from the structure's data section
+    fn get_field_byte(self, offset: usize) -> Option<u8> {
+        self.field_bytes().get(offset).copied()
+    }
+
+    /// Retrieve a WORD at the given offset from the structure's data section
+    fn get_field_word(self, offset: usize) -> Option<u16> {
+        let bytes = self.field_bytes().get(offset..offset + 2)?;
+        Some(u16::from_le_bytes(
+            bytes.try_into().expect("u16 is 2 bytes"),
+        ))
+    }
+
+    /// Retrieve a [Handle] at the given offset from the structure's data section
+    fn get_field_handle(self, offset: usize) -> Option<Handle> {
+        self.get_field_word(offset).map(Handle)
+    }
+
+    /// Retrieve a DWORD at the given offset from the structure's data section
+    fn get_field_dword(self, offset: usize) -> Option<u32> {
+        let bytes = self.field_bytes().get(offset..offset + 4)?;
+        Some(u32::from_le_bytes(
+            bytes.try_into().expect("u32 is 4 bytes"),
+        ))
+    }
+
+    /// Retrieve a QWORD at the given offset from the structure's data section
+    fn get_field_qword(self, offset: usize) -> Option<u64> {
+        let bytes = self.field_bytes().get(offset..offset + 8)?;
+        Some(u64::from_le_bytes(
+            bytes.try_into().expect("u64 is 8 bytes"),
+        ))
+    }
+
+    /// Retrieve a block of bytes from the structure's data section
+    fn get_field_data(self, start_index: usize, end_index: usize) -> Option<&'a [u8]> {
+        self.field_bytes().get(start_index..end_index)
+    }
+}
+
+impl<'a> StructFields<'a> for &'a UndefinedStruct {
+    fn field_bytes(self) -> &'a [u8] {
+        &self.fields
+    }
+}
+
+impl<'a> StructFields<'a> for UndefinedStructRef<'a> {
+    fn field_bytes(self) -> &'a [u8] {
+        self.fields
+    }
+}
@@ -0,0 +1,169 @@
+use super::entry_point::{verify_checksum, SMBiosEntryPoint32, SMBiosEntryPoint64};
+use super::smbios_data::SMBiosData;
+use core::convert::TryInto;
+use serde::Serialize;
+
+/// # Table Integrity
+///
+/// Consistency of a structure table with the entry point it was located
+/// by, see [SMBiosData::integrity].
+///
+/// The report is made from the raw entry point, so that a checksum failure
+/// is reported rather than preventing the report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct TableIntegrity {
+    /// Whether the Entry Point Structure (EPS) checksum is valid
+    pub entry_point_checksum_valid: bool,
+    /// Whether the intermediate anchor string is "_DMI_", None for a
+    /// 64-bit (_SM3_) entry point
+    pub intermediate_anchor_valid: Option<bool>,
+    /// Whether the Intermediate Entry Point Structure (IEPS) checksum is
+    /// valid, None for a 64-bit (_SM3_) entry point
+    pub intermediate_checksum_valid: Option<bool>,
+    /// Table length declared by the entry point, in bytes
+    ///
+    /// The exact length for a 32-bit (_SM_) entry point, the maximum size
+    /// for a 64-bit (_SM3_) entry point.
+    pub declared_table_length: usize,
+    /// Length of the structures of the table, in bytes
+    pub actual_table_length: usize,
+    /// Number of structures declared by the entry point, None for a 64-bit
+    /// (_SM3_) entry point
+    pub declared_structure_count: Option<u16>,
+    /// Number of structures of the table
+    pub structure_count: usize,
+}
+
+impl TableIntegrity {
+    /// Reports the integrity of `data` against the raw `entry_point`
+    ///
+    /// Returns None when `entry_point` starts with neither the _SM3_ nor
+    /// the _SM_ anchor, or is shorter than the entry point it announces.
+    pub fn new(entry_point: &[u8], data: &SMBiosData) -> Option<Self> {
+        let word = |offset: usize| {
+            u16::from_le_bytes(entry_point[offset..offset + 2].try_into().expect("2 bytes"))
+        };
+        let actual_table_length = data.raw_bytes().len();
+        let structure_count = data.iter().count();
+
+        if entry_point.starts_with(&SMBiosEntryPoint64::SM3_ANCHOR) {
+            if entry_point.len() < SMBiosEntryPoint64::MINIMUM_SIZE {
+                return None;
+            }
+            let offset = SMBiosEntryPoint64::STRUCTURE_TABLE_MAXIMUM_SIZE_OFFSET;
+            let maximum_size =
+                u32::from_le_bytes(entry_point[offset..offset + 4].try_into().expect("4 bytes"));
+
+            Some(Self {
+                entry_point_checksum_valid: SMBiosEntryPoint64::has_valid_checksums(entry_point),
+                intermediate_anchor_valid: None,
+                intermediate_checksum_valid: None,
+                declared_table_length: maximum_size as usize,
+                actual_table_length,
+                declared_structure_count: None,
+                structure_count,
+            })
+        } else if entry_point.starts_with(&SMBiosEntryPoint32::SM_ANCHOR) {
+            if entry_point.len() < SMBiosEntryPoint32::MINIMUM_SIZE {
+                return None;
+            }
+            let length = entry_point[SMBiosEntryPoint32::ENTRY_POINT_LENGTH_OFFSET] as usize;
+            let intermediate = &entry_point[SMBiosEntryPoint32::INTERMEDIATE_ANCHOR_OFFSET
+                ..SMBiosEntryPoint32::INTERMEDIATE_ANCHOR_OFFSET + 0x0F];
+
+            Some(Self {
+                entry_point_checksum_valid: matches!(
+                    entry_point.get(..length),
+                    Some(eps) if verify_checksum(eps)
+                ),
+                intermediate_anchor_valid: Some(
+                    intermediate.starts_with(&SMBiosEntryPoint32::DMI_ANCHOR),
+                ),
+                intermediate_checksum_valid: Some(verify_checksum(intermediate)),
+                declared_table_length: word(SMBiosEntryPoint32::STRUCTURE_TABLE_LENGTH_OFFSET)
+                    as usize,
+                actual_table_length,
+                declared_structure_count: Some(word(
+                    SMBiosEntryPoint32::NUMBER_OF_SMBIOS_STRUCTURES_OFFSET,
+                )),
+                structure_count,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Whether the table is consistent with its entry point
+    ///
+    /// The checksums and anchor are valid, the structure count matches the
+    /// declared count, and the table length matches the declared length
+    /// (or does not exceed the maximum size of a 64-bit entry point).
+    pub fn is_consistent(&self) -> bool {
+        let table_length_valid = match self.declared_structure_count {
+            Some(_) => self.actual_table_length == self.declared_table_length,
+            None => self.actual_table_length <= self.declared_table_length,
+        };
+
+        self.entry_point_checksum_valid
+            && self.intermediate_anchor_valid != Some(false)
+            && self.intermediate_checksum_valid != Some(false)
+            && table_length_valid
+            && match self.declared_structure_count {
+                Some(count) => count as usize == self.structure_count,
+                None => true,
+            }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{SMBiosTableBuilder, SystemInformationBuilder};
+    use crate::core::SMBiosVersion;
+
+    #[test]
+    fn test_table_integrity() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(2, 8, 0));
+        builder
+            .add(SystemInformationBuilder::new().manufacturer("Manufacturer"))
+            .unwrap();
+        let data = builder.build().unwrap();
+        let table_length = data.raw_bytes().len();
+
+        let mut entry_point = data.entry_point_32(0x000F_1000).unwrap();
+        let integrity = TableIntegrity::new(&entry_point, &data).unwrap();
+        assert!(integrity.is_consistent());
+        assert_eq!(integrity.declared_table_length, table_length);
+        assert_eq!(integrity.declared_structure_count, Some(2));
+        assert_eq!(integrity.structure_count, 2);
+
+        // one structure and 4 bytes more than the table holds
+        entry_point[SMBiosEntryPoint32::STRUCTURE_TABLE_LENGTH_OFFSET] += 4;
+        entry_point[SMBiosEntryPoint32::NUMBER_OF_SMBIOS_STRUCTURES_OFFSET] += 1;
+        entry_point[SMBiosEntryPoint32::INTERMEDIATE_ANCHOR_OFFSET + 1] = b'X';
+        let integrity = TableIntegrity::new(&entry_point, &data).unwrap();
+        assert!(!integrity.is_consistent());
+        assert!(!integrity.entry_point_checksum_valid);
+        assert_eq!(integrity.intermediate_anchor_valid, Some(false));
+        assert_eq!(integrity.intermediate_checksum_valid, Some(false));
+        assert_eq!(integrity.declared_table_length, table_length + 4);
+        assert_eq!(integrity.declared_structure_count, Some(3));
+
+        let mut entry_point = data.entry_point_64(0x000F_1000).unwrap();
+        let integrity = TableIntegrity::new(&entry_point, &data).unwrap();
+        assert!(integrity.is_consistent());
+        assert_eq!(integrity.intermediate_checksum_valid, None);
+        assert_eq!(integrity.declared_structure_count, None);
+
+        // a maximum size smaller than the table
+        entry_point[SMBiosEntryPoint64::STRUCTURE_TABLE_MAXIMUM_SIZE_OFFSET] = 1;
+        entry_point[SMBiosEntryPoint64::STRUCTURE_TABLE_MAXIMUM_SIZE_OFFSET + 1] = 0;
+        SMBiosEntryPoint64::fix_checksums(&mut entry_point).unwrap();
+        let integrity = TableIntegrity::new(&entry_point, &data).unwrap();
+        assert!(integrity.entry_point_checksum_valid);
+        assert!(!integrity.is_consistent());
+
+        assert!(TableIntegrity::new(&entry_point[..0x10], &data).is_none());
+        assert!(TableIntegrity::new(b"_DMI_", &data).is_none());
+    }
+}
@@ -0,0 +1,330 @@
+use super::header::{Handle, Header};
+use super::nul_scan::split_nul;
+use super::struct_fields::StructFields;
+use super::undefined_struct::{complete_struct_length, is_padding, UndefinedStruct};
+#[cfg(all(feature = "mmap", not(feature = "no_std")))]
+use crate::error::Error;
+use core::convert::TryInto;
+use core::fmt;
+
+/// # SMBIOS Table Reference
+///
+/// A structure table borrowed from raw table data, such as a memory mapped
+/// firmware region, which is parsed as it is iterated without copying or
+/// allocating.
+///
+/// [SMBiosData](super::SMBiosData) copies each structure out of the raw
+/// table data, including when created from a borrowed slice by
+/// [SMBiosData::from_slice_and_version](super::SMBiosData::from_slice_and_version).
+/// [SMBiosTableRef] borrows them instead.
+///
+/// Structures are parsed leniently, as by [SMBiosData](super::SMBiosData):
+/// iteration ends at padding or at bytes which do not form a complete
+/// structure. The defined structures are read from an [UndefinedStruct],
+/// see [UndefinedStructRef::to_undefined_struct].
+///
+/// The boundary of each structure is found only when the iteration reaches
+/// it, so a search stops at the first match rather than walking the string
+/// sets of all the structures which follow it.
+///
+/// ```
+/// # use smbioslib::*;
+/// # fn system_uuid(raw_table: &[u8]) -> Option<SystemUuidData> {
+/// let table = SMBiosTableRef::new(raw_table);
+/// let system_information = table
+///     .first_of_type(SMBiosSystemInformation::STRUCT_TYPE)?
+///     .to_undefined_struct();
+/// SMBiosSystemInformation::new(&system_information).uuid()
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SMBiosTableRef<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> SMBiosTableRef<'a> {
+    /// Creates a table borrowing the raw table data `data`
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// The raw table data
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Iterates the structures of the table
+    pub fn iter(&self) -> UndefinedStructRefIter<'a> {
+        UndefinedStructRefIter {
+            data: self.data,
+            offset: 0,
+        }
+    }
+
+    /// Iterates the structures of type `struct_type`
+    pub fn structs_of_type(&self, struct_type: u8) -> impl Iterator<Item = UndefinedStructRef<'a>> {
+        self.iter()
+            .filter(move |undefined_struct| undefined_struct.header().struct_type() == struct_type)
+    }
+
+    /// Finds the first structure of type `struct_type`, parsing no further
+    /// than it
+    pub fn first_of_type(&self, struct_type: u8) -> Option<UndefinedStructRef<'a>> {
+        self.structs_of_type(struct_type).next()
+    }
+
+    /// Finds the first structure with the given [Handle]
+    pub fn find_by_handle(&self, handle: &Handle) -> Option<UndefinedStructRef<'a>> {
+        self.iter()
+            .find(|undefined_struct| undefined_struct.header().handle() == *handle)
+    }
+}
+
+impl<'a> IntoIterator for SMBiosTableRef<'a> {
+    type Item = UndefinedStructRef<'a>;
+    type IntoIter = UndefinedStructRefIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// # Memory Mapped SMBIOS Table
+///
+/// Raw table data memory mapped from a file, such as a table dump, which
+/// stays mapped for as long as the [SMBiosTableMmap] lives. The structures
+/// are borrowed from the mapping through [SMBiosTableMmap::table] rather
+/// than copied, so that processing many large dumps allocates no copy of
+/// them.
+///
+/// ```no_run
+/// # use smbioslib::*;
+/// # fn count(filename: &str) -> Result<usize, Error> {
+/// // Safety: the dump is not modified while it is mapped
+/// let mapped = unsafe { SMBiosTableMmap::try_load_from_file(filename)? };
+/// Ok(mapped.table().iter().count())
+/// # }
+/// ```
+#[cfg(all(feature = "mmap", not(feature = "no_std")))]
+#[derive(Debug)]
+pub struct SMBiosTableMmap {
+    mapping: memmap2::Mmap,
+}
+
+#[cfg(all(feature = "mmap", not(feature = "no_std")))]
+impl SMBiosTableMmap {
+    /// Maps the raw table data of the file `filename`
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or another
+    /// process, for as long as it is mapped.
+    pub unsafe fn try_load_from_file(filename: &str) -> Result<Self, Error> {
+        let file = std::fs::File::open(filename)?;
+        Ok(Self {
+            mapping: memmap2::Mmap::map(&file)?,
+        })
+    }
+
+    /// The table, borrowing the mapping
+    pub fn table(&self) -> SMBiosTableRef<'_> {
+        SMBiosTableRef::new(&self.mapping)
+    }
+}
+
+/// Iterates the structures of an [SMBiosTableRef]
+pub struct UndefinedStructRefIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for UndefinedStructRefIter<'a> {
+    type Item = UndefinedStructRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.data.get(self.offset..)?;
+        if is_padding(remaining) {
+            return None;
+        }
+
+        let struct_len = complete_struct_length(remaining)?;
+        let raw = &remaining[..struct_len];
+        let length = raw[Header::LENGTH_OFFSET] as usize;
+        let undefined_struct = UndefinedStructRef {
+            fields: &raw[..length],
+            raw,
+            offset: self.offset,
+        };
+
+        self.offset += struct_len;
+        Some(undefined_struct)
+    }
+}
+
+/// # Undefined Struct Reference
+///
+/// A structure borrowed from the raw table data of an [SMBiosTableRef], the
+/// borrowed counterpart of [UndefinedStruct].
+#[derive(Clone, Copy)]
+pub struct UndefinedStructRef<'a> {
+    /// The raw data for the header and fields, see [UndefinedStruct::fields]
+    pub fields: &'a [u8],
+    raw: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> UndefinedStructRef<'a> {
+    /// The [Header] of the structure
+    pub fn header(&self) -> Header {
+        Header::new(self.raw[..Header::SIZE].try_into().expect("4 bytes"))
+    }
+
+    /// Offset of the structure within the table
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Iterates the raw bytes of the strings. The terminating 0 is not
+    /// included in each string.
+    pub fn strings(&self) -> impl Iterator<Item = &'a [u8]> {
+        let string_area = &self.raw[self.fields.len()..self.raw.len() - 2];
+        (!string_area.is_empty())
+            .then(|| split_nul(string_area))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Retrieve the raw bytes of the string at the 1 based `index`
+    ///
+    /// The string-set is scanned on each call. Structures whose strings are
+    /// read repeatedly are better copied with
+    /// [UndefinedStructRef::to_undefined_struct], which splits the
+    /// string-set once.
+    pub fn get_string(&self, index: u8) -> Option<&'a [u8]> {
+        self.strings().nth((index as usize).checked_sub(1)?)
+    }
+
+    /// Retrieve the raw bytes of the string referenced by the string field
+    /// at the given offset
+    pub fn get_field_string(&self, offset: usize) -> Option<&'a [u8]> {
+        self.get_string(self.get_field_byte(offset)?)
+    }
+
+    /// The raw bytes of the structure, including the strings and the
+    /// string-set terminator
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Copies the structure into an [UndefinedStruct], through which the
+    /// defined structures are read
+    pub fn to_undefined_struct(&self) -> UndefinedStruct {
+        UndefinedStruct::from_slice(self.raw)
+    }
+}
+
+impl fmt::Debug for UndefinedStructRef<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("UndefinedStructRef")
+            .field("header", &self.header())
+            .field("fields", &&self.fields[Header::SIZE..])
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::UndefinedStructTable;
+
+    #[test]
+    fn test_table_ref() {
+        let raw = std::fs::read("tests/jeffgerlap_3_2_0.dat").unwrap()[8..].to_vec();
+        let table = SMBiosTableRef::new(&raw);
+        let owned = UndefinedStructTable::from(raw.as_slice());
+
+        assert_eq!(table.iter().count(), owned.iter().count());
+        for (borrowed, owned) in table.iter().zip(owned.iter()) {
+            assert_eq!(borrowed.header()[..], owned.header[..]);
+            assert_eq!(borrowed.fields, owned.fields.as_slice());
+            assert!(borrowed
+                .strings()
+                .eq(owned.strings.iter().map(Vec::as_slice)));
+            assert_eq!(borrowed.raw(), owned.raw().as_slice());
+            assert_eq!(
+                &raw[borrowed.offset()..][..borrowed.raw().len()],
+                borrowed.raw()
+            );
+        }
+
+        let system_information = table.find_by_handle(&Handle(14)).unwrap();
+        assert_eq!(system_information.header().struct_type(), 1);
+        assert_eq!(
+            system_information.get_field_string(0x04),
+            Some(&b"Microsoft Corporation"[..])
+        );
+        assert_eq!(system_information.get_string(0), None);
+        assert_eq!(
+            system_information
+                .to_undefined_struct()
+                .get_field_string(0x04),
+            Some("Microsoft Corporation".to_string())
+        );
+
+        // the search stops at the System Information, leaving the
+        // truncated structure after it unparsed
+        let mut truncated = raw[..system_information.offset()].to_vec();
+        truncated.extend_from_slice(system_information.raw());
+        truncated.extend_from_slice(&[0x11, 0x54, 0x20]);
+        let table = SMBiosTableRef::new(&truncated);
+        let mut structures = table.iter();
+        assert_eq!(
+            structures
+                .find(|undefined_struct| undefined_struct.header().struct_type() == 1)
+                .map(|undefined_struct| undefined_struct.offset()),
+            Some(system_information.offset())
+        );
+        assert_eq!(
+            table.first_of_type(1).unwrap().raw(),
+            system_information.raw()
+        );
+        assert!(structures.next().is_none());
+        assert_eq!(table.structs_of_type(17).count(), 2);
+
+        // the table ends at padding
+        let table = [
+            0x7F, 0x04, 0x00, 0x00, 0x00, 0x00, // End-of-Table
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        ];
+        let structures: Vec<_> = SMBiosTableRef::new(&table).into_iter().collect();
+        assert_eq!(structures.len(), 1);
+        assert_eq!(structures[0].strings().count(), 0);
+        assert_eq!(structures[0].raw(), &table[..6]);
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_table_mmap() {
+        let table = [
+            0x00, 0x04, 0x00, 0x00, b'B', b'I', b'O', b'S', 0x00, 0x00, 0x7F, 0x04, 0x01, 0x00,
+            0x00, 0x00,
+        ];
+        let path =
+            std::env::temp_dir().join(format!("smbioslib_test_mmap_{}.bin", std::process::id()));
+        std::fs::write(&path, table).unwrap();
+
+        let mapped =
+            unsafe { SMBiosTableMmap::try_load_from_file(path.to_str().unwrap()) }.unwrap();
+        let table_ref = mapped.table();
+        assert_eq!(table_ref.raw_bytes(), &table);
+        assert_eq!(table_ref.iter().count(), 2);
+        assert_eq!(
+            table_ref.iter().next().unwrap().get_string(1),
+            Some(&b"BIOS"[..])
+        );
+
+        drop(mapped);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
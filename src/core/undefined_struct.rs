@@ -1,13 +1,19 @@
 use super::header::{Handle, Header};
-use super::strings::Strings;
+use super::nul_scan::find_double_nul;
+use super::parse_options::{ParseLimitError, ParseOptions, StructureDefect};
+use super::strings::{StringDecoding, Strings};
+use super::struct_fields::StructFields;
 use crate::structs::{DefinedStruct, SMBiosEndOfTable, SMBiosStruct};
-use serde::{Serialize, Serializer};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 #[cfg(not(feature = "no_std"))]
 use std::{
+    borrow::Cow,
     fs::File,
-    io::{prelude::*, Error, ErrorKind, SeekFrom},
+    io::{prelude::*, SeekFrom},
     vec::IntoIter,
 };
+#[cfg(not(feature = "no_std"))]
+use crate::error::Error;
 use core::{
     convert::TryInto,
     slice::Iter,
@@ -15,7 +21,7 @@ use core::{
     any
 };
 #[cfg(feature = "no_std")]
-use alloc::{vec::{Vec, IntoIter}, format};
+use alloc::{borrow::Cow, vec::{Vec, IntoIter}, string::String, format};
 
 /// # Embodies the three basic parts of an SMBIOS structure
 ///
@@ -38,7 +44,8 @@ pub struct UndefinedStruct {
 
     /// The raw data for the header and fields
     ///
-    /// `fields` is used by the `get_field_*()` functions. `fields` does not
+    /// `fields` is used by the `get_field_*()` functions of [StructFields].
+    /// `fields` does not
     /// include _strings_; therefore, preventing accidentally retrieving
     /// data from the _strings_ area.  This avoids a need to check
     /// `header.length()` during field retrieval.
@@ -67,66 +74,80 @@ where
     serializer.serialize_str(format!("{:?}", data).as_str())
 }
 
-impl<'a> UndefinedStruct {
-    /// Creates a structure instance of the given byte array slice
-    pub fn new(raw: &Vec<u8>) -> Self {
-        match raw.get(Header::LENGTH_OFFSET) {
-            Some(&header_length) => UndefinedStruct {
-                header: Header::new(raw[..Header::SIZE].try_into().expect("4 bytes")),
-                fields: raw.get(..(header_length as usize)).unwrap_or(&[]).to_vec(),
-                strings: {
-                    Strings::new(
-                        raw.get((header_length as usize)..raw.len() - 2)
-                            .unwrap_or(&[])
-                            .to_vec(),
-                    )
-                },
-            },
-            None => UndefinedStruct {
-                ..Default::default()
-            },
+impl<'de> Deserialize<'de> for UndefinedStruct {
+    /// Deserializes a structure from its serialized form
+    ///
+    /// The header must match the first bytes of `fields`, whose length is
+    /// the length given by the header.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "UndefinedStruct")]
+        struct Parts {
+            header: Header,
+            fields: Vec<u8>,
+            strings: SerializedStrings,
         }
-    }
 
-    /// Retrieve a byte at the given offset from the structure's data section
-    pub fn get_field_byte(&self, offset: usize) -> Option<u8> {
-        match self.fields.get(offset..offset + 1) {
-            Some(val) => Some(val[0]),
-            None => None,
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum SerializedStrings {
+            Debug(String),
+            Sequence(Strings),
         }
-    }
 
-    /// Retrieve a WORD at the given offset from the structure's data section
-    pub fn get_field_word(&self, offset: usize) -> Option<u16> {
-        match self.fields.get(offset..offset + 2) {
-            Some(val) => Some(u16::from_le_bytes(val.try_into().expect("u16 is 2 bytes"))),
-            None => None,
+        let parts = Parts::deserialize(deserializer)?;
+        let strings = match parts.strings {
+            SerializedStrings::Debug(debug) => {
+                let strings = Strings::parse_debug(&debug)
+                    .ok_or_else(|| D::Error::custom("the strings are not a list of strings"))?;
+                Strings::try_from_iso_8859_1(&strings).map_err(|c| {
+                    D::Error::custom(format!("invalid character {:?} in SMBIOS string", c))
+                })?
+            }
+            SerializedStrings::Sequence(strings) => strings,
+        };
+        if parts.fields.get(..Header::SIZE) != Some(&parts.header[..])
+            || parts.fields.len() != parts.header.length() as usize
+        {
+            return Err(D::Error::custom(
+                "the header does not match the fields of the structure",
+            ));
         }
-    }
 
-    /// Retrieve a [Handle] at the given offset from the structure's data section
-    pub fn get_field_handle(&self, offset: usize) -> Option<Handle> {
-        match self.fields.get(offset..offset + Handle::SIZE) {
-            Some(val) => Some(Handle(u16::from_le_bytes(
-                val.try_into().expect("u16 is 2 bytes"),
-            ))),
-            None => None,
-        }
+        Ok(UndefinedStruct {
+            header: parts.header,
+            fields: parts.fields,
+            strings,
+        })
     }
+}
 
-    /// Retrieve a DWORD at the given offset from the structure's data section
-    pub fn get_field_dword(&self, offset: usize) -> Option<u32> {
-        match self.fields.get(offset..offset + 4) {
-            Some(val) => Some(u32::from_le_bytes(val.try_into().expect("u32 is 4 bytes"))),
-            None => None,
-        }
+impl<'a> UndefinedStruct {
+    /// Creates a structure instance of the given byte array slice
+    pub fn new(raw: &Vec<u8>) -> Self {
+        Self::from_slice(raw)
     }
 
-    /// Retrieve a QWORD at the given offset from the structure's data section
-    pub fn get_field_qword(&self, offset: usize) -> Option<u64> {
-        match self.fields.get(offset..offset + 8) {
-            Some(val) => Some(u64::from_le_bytes(val.try_into().expect("u64 is 8 bytes"))),
-            None => None,
+    /// Creates a structure instance of the raw bytes of a structure,
+    /// copying the formatted area and each string once
+    pub(crate) fn from_slice(raw: &[u8]) -> Self {
+        match raw.get(..Header::SIZE) {
+            Some(header) => {
+                let header_length = header[Header::LENGTH_OFFSET] as usize;
+                UndefinedStruct {
+                    header: Header::new(header.try_into().expect("4 bytes")),
+                    fields: raw.get(..header_length).unwrap_or(&[]).to_vec(),
+                    strings: Strings::from_area(
+                        raw.get(header_length..raw.len() - 2).unwrap_or(&[]),
+                    ),
+                }
+            }
+            None => UndefinedStruct {
+                ..Default::default()
+            },
         }
     }
 
@@ -143,12 +164,65 @@ impl<'a> UndefinedStruct {
         }
     }
 
-    // todo: learn how to pass an index range (SliceIndex?) rather than start/end indices.
-    // This would better conform to the Rust design look and feel.
+    /// Retrieve a String of the given offset, borrowed from the structure
+    /// when it is ASCII
+    ///
+    /// Returns the same value as [UndefinedStruct::get_field_string]
+    /// without allocating for ASCII strings, see [Strings::get_str].
+    pub fn get_field_str(&self, offset: usize) -> Option<Cow<'_, str>> {
+        self.strings.get_str(self.get_field_byte(offset)?)
+    }
+
+    /// Retrieve a String of the given offset, decoded with `decoding`
+    ///
+    /// See [Strings::get_str_with].
+    pub fn get_field_str_with(
+        &self,
+        offset: usize,
+        decoding: StringDecoding,
+    ) -> Option<Cow<'_, str>> {
+        self.strings
+            .get_str_with(self.get_field_byte(offset)?, decoding)
+    }
+
+    /// Retrieve the raw bytes of the string of the given offset
+    ///
+    /// The bytes are those of the string-set, whatever their encoding, see
+    /// [Strings::get_bytes].
+    pub fn get_field_string_bytes(&self, offset: usize) -> Option<&[u8]> {
+        self.strings.get_bytes(self.get_field_byte(offset)?)
+    }
+
+    /// The raw bytes of the structure
+    ///
+    /// Rebuilt from the header, the formatted area and the strings, followed
+    /// by the string-set terminator. For a structure of a well-formed table
+    /// these are its bytes in the table; a structure which was since changed
+    /// or came from a malformed table is given in its canonical encoding.
+    /// [crate::UndefinedStructRef::raw] borrows the bytes of the table itself.
+    pub fn raw(&self) -> Vec<u8> {
+        let mut raw = self.fields.clone();
+
+        if self.strings.iter().len() == 0 {
+            raw.extend_from_slice(&[0, 0]);
+        } else {
+            for string in self.strings.iter() {
+                raw.extend_from_slice(string);
+                raw.push(0);
+            }
+            raw.push(0);
+        }
+
+        raw
+    }
 
-    /// Retrieve a block of bytes from the structure's data section
-    pub fn get_field_data(&self, start_index: usize, end_index: usize) -> Option<&[u8]> {
-        return self.fields.get(start_index..end_index);
+    /// Length of [UndefinedStruct::raw], without building it
+    pub(crate) fn raw_len(&self) -> usize {
+        let strings = self.strings.iter();
+        match strings.len() {
+            0 => self.fields.len() + 2,
+            _ => self.fields.len() + strings.map(|string| string.len() + 1).sum::<usize>() + 1,
+        }
     }
 
     /// Cast to a given structure
@@ -178,7 +252,7 @@ impl<'a> UndefinedStruct {
 
 impl fmt::Debug for UndefinedStruct {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let fields = &self.fields[Header::SIZE..];
+        let fields = self.fields.get(Header::SIZE..).unwrap_or(&[]);
         fmt.debug_struct(any::type_name::<UndefinedStruct>())
             .field("header", &self.header)
             .field("fields", &fields)
@@ -198,15 +272,86 @@ impl Default for UndefinedStruct {
     }
 }
 
+/// # Table Warning
+///
+/// A non-fatal irregularity found while parsing a structure table.
+/// Parsing stops gracefully, or skips a structure when told to by the
+/// [ParseOptions], rather than producing garbage structures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TableWarning {
+    /// The table does not contain an End-of-Table (Type 127) structure
+    MissingEndOfTable,
+    /// The table ends with padding (00h or FFh bytes) instead of an End-of-Table structure
+    Padding {
+        /// Offset of the padding within the table
+        offset: usize,
+        /// Length of the padding
+        length: usize,
+    },
+    /// The table ends with bytes which do not form a complete structure
+    TrailingData {
+        /// Offset of the bytes within the table
+        offset: usize,
+        /// Number of bytes
+        length: usize,
+        /// The reason the bytes do not form a complete structure
+        defect: StructureDefect,
+    },
+    /// A malformed structure was skipped, see
+    /// [ParseOptions::skip_invalid_lengths]
+    SkippedStructure {
+        /// Offset of the structure within the table
+        offset: usize,
+        /// Number of bytes skipped
+        length: usize,
+        /// The reason the structure is malformed
+        defect: StructureDefect,
+    },
+}
+
+impl fmt::Display for TableWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableWarning::MissingEndOfTable => {
+                f.write_str("The table has no End-of-Table (Type 127) structure")
+            }
+            TableWarning::Padding { offset, length } => write!(
+                f,
+                "The table ends with {} bytes of padding at offset {}",
+                length, offset
+            ),
+            TableWarning::TrailingData {
+                offset,
+                length,
+                defect,
+            } => write!(
+                f,
+                "The table ends with {} bytes at offset {} which are not a structure: {}",
+                length, offset, defect
+            ),
+            TableWarning::SkippedStructure {
+                offset,
+                length,
+                defect,
+            } => write!(
+                f,
+                "The {} bytes of the structure at offset {} were skipped: {}",
+                length, offset, defect
+            ),
+        }
+    }
+}
+
 /// # Undefined Struct Table
 ///
 /// A collection of [UndefinedStruct] items.
 #[derive(Debug, Serialize)]
-pub struct UndefinedStructTable(Vec<UndefinedStruct>);
+#[serde(transparent)]
+pub struct UndefinedStructTable(Vec<UndefinedStruct>, #[serde(skip)] Vec<TableWarning>);
 
 impl<'a> UndefinedStructTable {
     fn new() -> UndefinedStructTable {
-        UndefinedStructTable(Vec::new())
+        UndefinedStructTable(Vec::new(), Vec::new())
     }
 
     fn add(&mut self, elem: UndefinedStruct) {
@@ -218,6 +363,19 @@ impl<'a> UndefinedStructTable {
         self.0.iter()
     }
 
+    /// Non-fatal irregularities found while parsing the table
+    pub fn warnings(&self) -> &[TableWarning] {
+        &self.1
+    }
+
+    /// Sets the decoding of the strings of every structure, see
+    /// [Strings::decoding]
+    pub fn set_string_decoding(&mut self, decoding: StringDecoding) {
+        for undefined_struct in self.0.iter_mut() {
+            undefined_struct.strings.set_decoding(decoding);
+        }
+    }
+
     /// An iterator over the defined type instances within the table.
     pub fn defined_struct_iter<T>(&'a self) -> impl Iterator<Item = T> + 'a
     where
@@ -236,6 +394,50 @@ impl<'a> UndefinedStructTable {
             })
     }
 
+    /// An iterator over the structures of type `struct_type`, as [DefinedStruct]
+    ///
+    /// Structures following the End-of-Table (Type 127) structure are not
+    /// visited.
+    pub fn defined_structs_of_type(
+        &'a self,
+        struct_type: u8,
+    ) -> impl Iterator<Item = DefinedStruct<'a>> + 'a {
+        let end = self
+            .iter()
+            .position(|undefined_struct| {
+                undefined_struct.header.struct_type() == SMBiosEndOfTable::STRUCT_TYPE
+            })
+            .map_or(self.0.len(), |position| position + 1);
+
+        self.0[..end]
+            .iter()
+            .filter(move |undefined_struct| undefined_struct.header.struct_type() == struct_type)
+            .map(DefinedStruct::from)
+    }
+
+    /// An iterator over the structures in table order, with the handle,
+    /// type and byte offset of each structure
+    ///
+    /// The offset is that of the structure in the raw table: the table data
+    /// the structures were parsed from or, for a table built or edited
+    /// since, the data written by [crate::SMBiosData::raw_bytes]. Every
+    /// structure is visited, including structures following the
+    /// End-of-Table (Type 127) structure.
+    pub fn iter_with_offsets(
+        &'a self,
+    ) -> impl Iterator<Item = (Handle, u8, usize, DefinedStruct<'a>)> + 'a {
+        self.iter().scan(0, |offset, undefined_struct| {
+            let struct_offset = *offset;
+            *offset += undefined_struct.raw_len();
+            Some((
+                undefined_struct.header.handle(),
+                undefined_struct.header.struct_type(),
+                struct_offset,
+                DefinedStruct::from(undefined_struct),
+            ))
+        })
+    }
+
     /// Tests if every element of the defined struct iterator matches a predicate.
     pub fn all<T, F>(&'a self, f: F) -> bool
     where
@@ -332,10 +534,7 @@ impl<'a> UndefinedStructTable {
         table_len: usize,
     ) -> Result<Self, Error> {
         if table_len < Header::SIZE + 2 {
-            return Err(Error::new(
-                ErrorKind::InvalidData,
-                format!("The table has an invalid size: {}", table_len),
-            ));
+            return Err(Error::InvalidTableLength(table_len));
         }
 
         file.seek(SeekFrom::Start(table_offset))?;
@@ -348,56 +547,220 @@ impl<'a> UndefinedStructTable {
 
 impl From<Vec<u8>> for UndefinedStructTable {
     fn from(data: Vec<u8>) -> Self {
-        const DOUBLE_ZERO_SIZE: usize = 2usize;
-        const MIN_STRUCT_SIZE: usize = Header::SIZE + DOUBLE_ZERO_SIZE;
+        data.as_slice().into()
+    }
+}
+
+impl From<&[u8]> for UndefinedStructTable {
+    fn from(data: &[u8]) -> Self {
+        Self::try_from_slice_with_options(data, &ParseOptions::default())
+            .expect("No limits are applied by default")
+    }
+}
+
+impl UndefinedStructTable {
+    /// Parses a table from raw table data, enforcing the limits of `options`
+    ///
+    /// Returns a [ParseLimitError] as soon as a limit is exceeded, or, when
+    /// [ParseOptions::strict], as soon as a malformed structure is found.
+    pub fn try_from_slice_with_options(
+        data: &[u8],
+        options: &ParseOptions,
+    ) -> Result<Self, ParseLimitError> {
+        if let Some(limit) = options.max_table_size {
+            if data.len() > limit {
+                return Err(ParseLimitError::TableTooLarge {
+                    size: data.len(),
+                    limit,
+                });
+            }
+        }
+
         let mut result = Self::new();
         let mut current_index = 0usize;
+        let mut end_of_table = false;
+
+        while current_index < data.len() {
+            let remaining = &data[current_index..];
+
+            // Firmware may pad the table region with 00h or FFh (in place of
+            // or after the End-of-Table structure)
+            if is_padding(remaining) {
+                if !end_of_table && options.strict {
+                    return Err(ParseLimitError::MalformedStructure {
+                        offset: current_index,
+                        defect: struct_defect(remaining),
+                    });
+                }
+                if !end_of_table {
+                    result.1.push(TableWarning::Padding {
+                        offset: current_index,
+                        length: remaining.len(),
+                    });
+                }
+                break;
+            }
+
+            match complete_struct_length(remaining) {
+                Some(struct_len) => {
+                    if let Some(limit) = options.max_structure_count {
+                        if result.0.len() == limit {
+                            return Err(ParseLimitError::TooManyStructures { limit });
+                        }
+                    }
 
-        loop {
-            // Is the next structure long enough?
-            match data.get(current_index..current_index + MIN_STRUCT_SIZE) {
-                Some(min_struct) => {
-                    // Read the structure's self-reported length in its header
-                    let struct_len = min_struct[Header::LENGTH_OFFSET] as usize;
+                    // Copy the current structure to the collection
+                    let mut undefined_struct =
+                        UndefinedStruct::from_slice(&remaining[..struct_len]);
+                    undefined_struct
+                        .strings
+                        .set_decoding(options.string_decoding);
 
-                    // Bad reported length
-                    if struct_len < Header::SIZE {
-                        break;
+                    // Verifies that the writer rebuilds the structure unchanged
+                    #[cfg(feature = "round_trip_assert")]
+                    if let Ok(raw) = crate::builder::StructBuilder::from(&undefined_struct)
+                        .to_raw(undefined_struct.header.handle())
+                    {
+                        debug_assert_eq!(
+                            raw.as_slice(),
+                            &remaining[..struct_len],
+                            "structure at offset {} does not round-trip",
+                            current_index
+                        );
                     }
 
-                    // Beyond the structure length are the structure's strings
-                    // Find the /0/0 which marks the end of this structure and the
-                    // beginning of the next.
-                    match data.get(current_index + struct_len..) {
-                        Some(strings_etc) => {
-                            match strings_etc
-                                .windows(DOUBLE_ZERO_SIZE)
-                                .position(|x| x[0] == x[1] && x[1] == 0)
-                            {
-                                Some(double_zero_position) => {
-                                    // The next structure will start at this index
-                                    let next_index = current_index
-                                        + struct_len
-                                        + double_zero_position
-                                        + DOUBLE_ZERO_SIZE;
-
-                                    // Copy the current structure to the collection
-                                    result.add(UndefinedStruct::new(
-                                        &data[current_index..next_index].to_vec(),
-                                    ));
-                                    current_index = next_index;
-                                }
-                                None => break,
-                            }
+                    if let Some(limit) = options.max_string_length {
+                        if let Some(string) =
+                            undefined_struct.strings.iter().find(|string| string.len() > limit)
+                        {
+                            return Err(ParseLimitError::StringTooLong {
+                                handle: undefined_struct.header.handle(),
+                                length: string.len(),
+                                limit,
+                            });
                         }
-                        None => break,
+                    }
+
+                    end_of_table |=
+                        undefined_struct.header.struct_type() == SMBiosEndOfTable::STRUCT_TYPE;
+                    result.add(undefined_struct);
+                    current_index += struct_len;
+                }
+                None if options.strict => {
+                    return Err(ParseLimitError::MalformedStructure {
+                        offset: current_index,
+                        defect: struct_defect(remaining),
+                    });
+                }
+                None => {
+                    let defect = struct_defect(remaining);
+                    let skipped_len = match defect {
+                        StructureDefect::InvalidLength(_) if options.skip_invalid_lengths => {
+                            invalid_struct_length(remaining)
+                        }
+                        _ => None,
                     };
+
+                    match skipped_len {
+                        Some(length) => {
+                            result.1.push(TableWarning::SkippedStructure {
+                                offset: current_index,
+                                length,
+                                defect,
+                            });
+                            current_index += length;
+                        }
+                        None => {
+                            result.1.push(TableWarning::TrailingData {
+                                offset: current_index,
+                                length: remaining.len(),
+                                defect,
+                            });
+                            break;
+                        }
+                    }
                 }
-                None => break,
             }
         }
 
-        result
+        if !end_of_table {
+            result.1.push(TableWarning::MissingEndOfTable);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Determines if `data` is padding (only 00h or FFh bytes)
+///
+/// Only a header which cannot start a valid structure (a length of 00h or
+/// FFh) is considered, avoiding a scan of the remaining data for every structure.
+pub(crate) fn is_padding(data: &[u8]) -> bool {
+    const PADDING: [u8; 2] = [0x00, 0xFF];
+
+    match data.get(..Header::SIZE) {
+        Some(header)
+            if PADDING.contains(&header[Header::STRUCT_TYPE_OFFSET])
+                && PADDING.contains(&header[Header::LENGTH_OFFSET]) =>
+        {
+            data.iter().all(|b| PADDING.contains(b))
+        }
+        Some(_) => false,
+        None => data.iter().all(|b| PADDING.contains(b)),
+    }
+}
+
+/// Determines the length of the structure at the start of `data`
+///
+/// The length spans the header, the formatted area and the strings,
+/// including the double zero which terminates the strings.
+///
+/// Returns [None] when `data` does not contain a complete structure
+/// or the structure reports a length smaller than its header.
+pub(crate) fn complete_struct_length(data: &[u8]) -> Option<usize> {
+    const DOUBLE_ZERO_SIZE: usize = 2usize;
+    const MIN_STRUCT_SIZE: usize = Header::SIZE + DOUBLE_ZERO_SIZE;
+
+    // Is the next structure long enough?
+    let min_struct = data.get(..MIN_STRUCT_SIZE)?;
+
+    // Read the structure's self-reported length in its header
+    let struct_len = min_struct[Header::LENGTH_OFFSET] as usize;
+
+    // Bad reported length
+    if struct_len < Header::SIZE {
+        return None;
+    }
+
+    // Beyond the structure length are the structure's strings
+    // Find the /0/0 which marks the end of this structure and the
+    // beginning of the next.
+    let double_zero_position = find_double_nul(data.get(struct_len..)?)?;
+
+    Some(struct_len + double_zero_position + DOUBLE_ZERO_SIZE)
+}
+
+/// Determines the length of the structure at the start of `data` whose
+/// header reports a length smaller than the header, taking its formatted
+/// area to be the header
+///
+/// Returns [None] when no double zero follows the header.
+fn invalid_struct_length(data: &[u8]) -> Option<usize> {
+    const DOUBLE_ZERO_SIZE: usize = 2usize;
+
+    let double_zero_position = find_double_nul(data.get(Header::SIZE..)?)?;
+    Some(Header::SIZE + double_zero_position + DOUBLE_ZERO_SIZE)
+}
+
+/// Determines why `data` does not start with a complete structure, see
+/// [complete_struct_length]
+fn struct_defect(data: &[u8]) -> StructureDefect {
+    match data.get(Header::LENGTH_OFFSET) {
+        Some(&length) if (length as usize) < Header::SIZE => StructureDefect::InvalidLength(length),
+        Some(&length) if data.len() >= Header::SIZE.max(length as usize) => {
+            StructureDefect::UnterminatedStrings
+        }
+        _ => StructureDefect::Truncated,
     }
 }
 
@@ -409,3 +772,407 @@ impl IntoIterator for UndefinedStructTable {
         self.0.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::MaybeValue;
+
+    #[test]
+    fn test_raw() {
+        let table = vec![
+            0x00, 0x05, 0x00, 0x00, 0x01, b'B', b'I', b'O', b'S', 0x00, b'1', 0x00, 0x00, 0x01,
+            0x04, 0x01, 0x00, 0x00, 0x00, 0x7F, 0x04, 0x02, 0x00, 0x00, 0x00,
+        ];
+
+        let structs: Vec<_> = UndefinedStructTable::from(table.clone()).into_iter().collect();
+        assert_eq!(structs.len(), 3);
+        assert_eq!(structs[0].raw(), table[..13].to_vec());
+        assert_eq!(structs[1].raw(), table[13..19].to_vec());
+        assert_eq!(structs[2].raw(), table[19..].to_vec());
+
+        let raw: Vec<u8> = structs.iter().flat_map(|s| s.raw()).collect();
+        assert_eq!(raw, table);
+    }
+
+    #[test]
+    fn test_short_struct() {
+        // Fewer bytes than a header
+        for raw in [vec![], vec![0x01], vec![0x01, 0x04, 0x00]].iter() {
+            let parts = UndefinedStruct::new(raw);
+            assert!(parts.fields.is_empty());
+            assert_eq!(parts.get_field_byte(0x00), None);
+            let _ = format!("{:?}", parts);
+            let _ = format!("{:?}", parts.defined_struct());
+        }
+
+        let _ = format!("{:?}", UndefinedStruct::default());
+    }
+
+    #[test]
+    fn test_get_field_str() {
+        // OEM Strings of an ASCII string and an ISO-8859-1 string
+        let parts = UndefinedStruct::new(&vec![
+            0x0B, 0x06, 0x00, 0x00, 0x01, 0x02, b'O', b'E', b'M', 0x00, b'C', 0xE9, 0x00, 0x00,
+        ]);
+
+        let ascii = parts.get_field_str(0x04).unwrap();
+        assert!(matches!(ascii, Cow::Borrowed("OEM")));
+        let latin = parts.get_field_str(0x05).unwrap();
+        assert!(matches!(latin, Cow::Owned(_)));
+        assert_eq!(latin, "Cé");
+        assert_eq!(Some(latin.into_owned()), parts.get_field_string(0x05));
+        assert_eq!(parts.get_field_str(0x06), None);
+    }
+
+    #[test]
+    fn test_string_decoding() {
+        // OEM Strings of UTF-8, ISO-8859-1 and invalid UTF-8 strings
+        let parts = UndefinedStruct::new(&vec![
+            0x0B, 0x05, 0x00, 0x00, 0x03, b'C', 0xC3, 0xA9, 0x00, b'C', 0xE9, 0x00, 0xFF, b'A',
+            0x00, 0x00,
+        ]);
+        let decodings = [
+            (1, StringDecoding::Latin1, Some("CÃ©")),
+            (1, StringDecoding::Utf8, Some("Cé")),
+            (1, StringDecoding::Utf8OrLatin1, Some("Cé")),
+            (2, StringDecoding::Latin1, Some("Cé")),
+            (2, StringDecoding::Utf8, None),
+            (2, StringDecoding::Utf8Lossy, Some("C\u{FFFD}")),
+            (2, StringDecoding::Utf8OrLatin1, Some("Cé")),
+            (3, StringDecoding::Utf8Lossy, Some("\u{FFFD}A")),
+            (4, StringDecoding::Latin1, None),
+        ];
+        for (index, decoding, expected) in decodings.iter() {
+            assert_eq!(
+                parts.strings.get_str_with(*index, *decoding).as_deref(),
+                *expected
+            );
+        }
+        assert_eq!(
+            StringDecoding::default().decode(b"ASCII"),
+            Some(Cow::Borrowed("ASCII"))
+        );
+
+        assert_eq!(parts.get_field_string_bytes(0x04), Some(&[0xFF, b'A'][..]));
+        assert_eq!(
+            parts.get_field_str_with(0x04, StringDecoding::Utf8OrLatin1),
+            Some(Cow::Borrowed("ÿA"))
+        );
+        assert_eq!(parts.strings.get_bytes(0), None);
+    }
+
+    #[test]
+    fn test_access_field_string() {
+        // BIOS Information with a vendor, no version and a release date
+        // index of FFh
+        let parts = UndefinedStruct::new(&vec![
+            0x00, 0x09, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xFF, b'V', 0x00, 0x00,
+        ]);
+
+        assert_eq!(
+            parts.access_field_string(0x04),
+            MaybeValue::Value("V".to_string())
+        );
+        assert_eq!(parts.access_field_string(0x05), MaybeValue::NotProvided);
+        assert_eq!(
+            parts.access_field_string(0x08),
+            MaybeValue::InvalidIndex(0xFF)
+        );
+        assert_eq!(
+            parts.access_field_string(0x09),
+            MaybeValue::NotPresentInVersion
+        );
+
+        assert_eq!(parts.get_field_string(0x05), None);
+        assert_eq!(parts.get_field_string(0x08), None);
+        assert_eq!(parts.access_field_string(0x08).value(), None);
+        assert_eq!(parts.strings.access_string(2), MaybeValue::InvalidIndex(2));
+    }
+
+    #[test]
+    fn test_table_warnings() {
+        let bios = [0x00, 0x04, 0x00, 0x00, b'B', 0x00, 0x00];
+        let end_of_table = [0x7F, 0x04, 0x01, 0x00, 0x00, 0x00];
+
+        let mut table = bios.to_vec();
+        table.extend_from_slice(&end_of_table);
+        table.extend_from_slice(&[0xFF; 16]);
+        let parsed = UndefinedStructTable::from(table);
+        assert_eq!(parsed.iter().count(), 2);
+        assert!(parsed.warnings().is_empty());
+
+        let mut table = bios.to_vec();
+        table.extend_from_slice(&[0xFF; 8]);
+        table.extend_from_slice(&[0x00; 8]);
+        let parsed = UndefinedStructTable::from(table);
+        assert_eq!(parsed.iter().count(), 1);
+        assert_eq!(
+            parsed.warnings(),
+            &[
+                TableWarning::Padding {
+                    offset: 7,
+                    length: 16
+                },
+                TableWarning::MissingEndOfTable
+            ]
+        );
+
+        let mut table = bios.to_vec();
+        table.extend_from_slice(&[0x01, 0x08, 0x02, 0x00, 0x01]);
+        let parsed = UndefinedStructTable::from(table);
+        assert_eq!(parsed.iter().count(), 1);
+        assert_eq!(
+            parsed.warnings(),
+            &[
+                TableWarning::TrailingData {
+                    offset: 7,
+                    length: 5,
+                    defect: StructureDefect::Truncated
+                },
+                TableWarning::MissingEndOfTable
+            ]
+        );
+        assert_eq!(
+            parsed.warnings()[0].to_string(),
+            "The table ends with 5 bytes at offset 7 which are not a structure: \
+             the structure is truncated"
+        );
+
+        let mut table = bios.to_vec();
+        table.extend_from_slice(&[0x01, 0x04, 0x02, 0x00, b'A', 0x00, b'B']);
+        let parsed = UndefinedStructTable::from(table);
+        assert_eq!(
+            parsed.warnings()[0],
+            TableWarning::TrailingData {
+                offset: 7,
+                length: 7,
+                defect: StructureDefect::UnterminatedStrings
+            }
+        );
+
+        let mut table = bios.to_vec();
+        table.extend_from_slice(&[0x01, 0x02, 0x02, 0x00, 0x00, 0x00]);
+        let parsed = UndefinedStructTable::from(table);
+        assert_eq!(
+            parsed.warnings()[0],
+            TableWarning::TrailingData {
+                offset: 7,
+                length: 6,
+                defect: StructureDefect::InvalidLength(2)
+            }
+        );
+    }
+
+    #[test]
+    fn test_skip_invalid_lengths() {
+        let skip = ParseOptions {
+            skip_invalid_lengths: true,
+            ..Default::default()
+        };
+
+        let mut table = vec![0x00, 0x04, 0x00, 0x00, b'B', 0x00, 0x00];
+        table.extend_from_slice(&[0x01, 0x02, 0x02, 0x00, b'X', 0x00, 0x00]);
+        table.extend_from_slice(&[0x7F, 0x04, 0x03, 0x00, 0x00, 0x00]);
+
+        let parsed = UndefinedStructTable::try_from_slice_with_options(&table, &skip).unwrap();
+        let handles: Vec<_> = parsed.iter().map(|s| *s.header.handle()).collect();
+        assert_eq!(handles, vec![0, 3]);
+        assert_eq!(
+            parsed.warnings(),
+            &[TableWarning::SkippedStructure {
+                offset: 7,
+                length: 7,
+                defect: StructureDefect::InvalidLength(2)
+            }]
+        );
+        assert_eq!(
+            parsed.warnings()[0].to_string(),
+            "The 7 bytes of the structure at offset 7 were skipped: \
+             the header reports an invalid length of 2"
+        );
+
+        // without the option parsing stops at the structure
+        let parsed = UndefinedStructTable::from(table.as_slice());
+        assert_eq!(parsed.iter().count(), 1);
+        assert!(matches!(
+            parsed.warnings()[0],
+            TableWarning::TrailingData { offset: 7, .. }
+        ));
+
+        // strict parsing still fails
+        let strict = ParseOptions {
+            strict: true,
+            ..skip
+        };
+        assert!(matches!(
+            UndefinedStructTable::try_from_slice_with_options(&table, &strict),
+            Err(ParseLimitError::MalformedStructure {
+                offset: 7,
+                defect: StructureDefect::InvalidLength(2)
+            })
+        ));
+
+        // a structure with no string-set terminator is not skipped
+        let table = [0x01, 0x00, 0x02, 0x00, b'X', 0x00];
+        let parsed = UndefinedStructTable::try_from_slice_with_options(&table, &skip).unwrap();
+        assert_eq!(parsed.iter().count(), 0);
+        assert_eq!(
+            parsed.warnings(),
+            &[
+                TableWarning::TrailingData {
+                    offset: 0,
+                    length: 6,
+                    defect: StructureDefect::InvalidLength(0)
+                },
+                TableWarning::MissingEndOfTable
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let table = [
+            0x00, 0x04, 0x05, 0x00, b'B', b'I', b'O', b'S', 0x00, 0x00, 0x7F, 0x04, 0x06, 0x00,
+            0x00, 0x00,
+        ];
+
+        let parsed =
+            UndefinedStructTable::try_from_slice_with_options(&table, &ParseOptions::default())
+                .unwrap();
+        assert_eq!(parsed.iter().count(), 2);
+
+        let options = ParseOptions {
+            max_table_size: Some(15),
+            ..Default::default()
+        };
+        assert!(matches!(
+            UndefinedStructTable::try_from_slice_with_options(&table, &options),
+            Err(ParseLimitError::TableTooLarge {
+                size: 16,
+                limit: 15
+            })
+        ));
+
+        let options = ParseOptions {
+            max_structure_count: Some(1),
+            ..Default::default()
+        };
+        assert!(matches!(
+            UndefinedStructTable::try_from_slice_with_options(&table, &options),
+            Err(ParseLimitError::TooManyStructures { limit: 1 })
+        ));
+
+        let options = ParseOptions {
+            max_string_length: Some(3),
+            ..Default::default()
+        };
+        match UndefinedStructTable::try_from_slice_with_options(&table, &options) {
+            Err(ParseLimitError::StringTooLong {
+                handle,
+                length,
+                limit,
+            }) => {
+                assert_eq!(*handle, 5);
+                assert_eq!(length, 4);
+                assert_eq!(limit, 3);
+            }
+            other => panic!("Expected StringTooLong, found {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_strict_parsing() {
+        let strict = ParseOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let parse = |table: &[u8]| {
+            UndefinedStructTable::try_from_slice_with_options(table, &strict).map(|_| ())
+        };
+
+        // padding following the End-of-Table structure is not malformed
+        let table = [0x7F, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        assert!(parse(&table).is_ok());
+
+        let table = [
+            0x00, 0x04, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        assert!(matches!(
+            parse(&table),
+            Err(ParseLimitError::MalformedStructure {
+                offset: 6,
+                defect: StructureDefect::InvalidLength(0)
+            })
+        ));
+
+        let table = [0x00, 0x03, 0x05, 0x00, 0x00, 0x00];
+        assert!(matches!(
+            parse(&table),
+            Err(ParseLimitError::MalformedStructure {
+                offset: 0,
+                defect: StructureDefect::InvalidLength(3)
+            })
+        ));
+
+        let table = [0x00, 0x04, 0x05, 0x00, b'B', b'I', b'O', b'S'];
+        assert!(matches!(
+            parse(&table),
+            Err(ParseLimitError::MalformedStructure {
+                offset: 0,
+                defect: StructureDefect::UnterminatedStrings
+            })
+        ));
+
+        let table = [0x00, 0x18, 0x05, 0x00, 0x00, 0x00, 0x00];
+        assert!(matches!(
+            parse(&table),
+            Err(ParseLimitError::MalformedStructure {
+                offset: 0,
+                defect: StructureDefect::Truncated
+            })
+        ));
+
+        // the same table parses leniently, recording a warning
+        let parsed =
+            UndefinedStructTable::try_from_slice_with_options(&table, &ParseOptions::default())
+                .unwrap();
+        assert_eq!(parsed.iter().count(), 0);
+        assert_eq!(parsed.warnings().len(), 2);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_serde_round_trip() {
+        let raw = vec![
+            0x80, 0x06, 0x10, 0x00, 0x01, 0x02, b'O', b'E', b'M', 0x00, 0xC6, 0x00, 0x00,
+        ];
+        let undefined_struct = UndefinedStruct::new(&raw);
+
+        let json = serde_json::to_string(&undefined_struct).unwrap();
+        assert_eq!(
+            json,
+            r#"{"header":{"struct_type":128,"length":6,"handle":16},"fields":[128,6,16,0,1,2],"strings":"[\"OEM\", \"Æ\"]"}"#
+        );
+        let loaded: UndefinedStruct = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.raw(), raw);
+
+        // the header must match the fields
+        let json = json.replace(r#""length":6"#, r#""length":7"#);
+        assert!(serde_json::from_str::<UndefinedStruct>(&json).is_err());
+        let json = r#"{"header":{"struct_type":128,"length":4,"handle":16},"fields":[128,4,16,0],"strings":"[\"☺\"]"}"#;
+        assert!(serde_json::from_str::<UndefinedStruct>(json).is_err());
+        let json = r#"{"header":{"struct_type":128,"length":4,"handle":16},"fields":[128,4,16,0],"strings":"[\"OEM\""}"#;
+        assert!(serde_json::from_str::<UndefinedStruct>(json).is_err());
+
+        // strings escaped by their debug form, or given as a sequence
+        let raw = vec![0x80, 0x05, 0x10, 0x00, 0x01, b'"', b'\\', 0x01, 0x00, 0x00];
+        let undefined_struct = UndefinedStruct::new(&raw);
+        let json = serde_json::to_string(&undefined_struct).unwrap();
+        let loaded: UndefinedStruct = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.raw(), raw);
+        let json = r#"{"header":{"struct_type":128,"length":4,"handle":16},"fields":[128,4,16,0],"strings":["OEM"]}"#;
+        let loaded: UndefinedStruct = serde_json::from_str(json).unwrap();
+        assert_eq!(loaded.strings.get_string(1), Some("OEM".to_string()));
+    }
+}
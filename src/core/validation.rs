@@ -0,0 +1,696 @@
+use super::field_offsets::{handle_field_offsets, string_field_offsets};
+use super::header::Handle;
+use super::maybe_value::MaybeValue;
+use super::smbios_data::{SMBiosData, SMBiosVersion};
+use super::struct_fields::StructFields;
+use super::undefined_struct::UndefinedStruct;
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use serde::Serialize;
+#[cfg(not(feature = "no_std"))]
+use std::collections::BTreeMap;
+
+/// Structure types every table is required to hold, see
+/// [ValidationFinding::MissingStructType]
+pub const REQUIRED_STRUCT_TYPES: [u8; 6] = [
+    SMBiosInformation::STRUCT_TYPE,
+    SMBiosSystemInformation::STRUCT_TYPE,
+    SMBiosSystemChassisInformation::STRUCT_TYPE,
+    SMBiosProcessorInformation::STRUCT_TYPE,
+    SMBiosPhysicalMemoryArray::STRUCT_TYPE,
+    SMBiosMemoryDevice::STRUCT_TYPE,
+];
+
+/// Placeholder strings left in place of a value by firmware vendors, see
+/// [ValidationOptions::placeholder_strings]
+pub const DEFAULT_PLACEHOLDER_STRINGS: [&str; 7] = [
+    "To Be Filled By O.E.M.",
+    "Default string",
+    "None",
+    "Not Specified",
+    "System Product Name",
+    "System manufacturer",
+    "0123456789",
+];
+
+/// # Validation Options
+///
+/// Configures the checks of [SMBiosData::validate_with_options].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationOptions {
+    /// Strings reported as [ValidationFinding::PlaceholderString]
+    ///
+    /// Compared ignoring ASCII case and surrounding whitespace. Defaults to
+    /// the [DEFAULT_PLACEHOLDER_STRINGS].
+    pub placeholder_strings: Vec<String>,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            placeholder_strings: DEFAULT_PLACEHOLDER_STRINGS
+                .iter()
+                .map(|placeholder| placeholder.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Start of the handle range reserved by the specification
+const RESERVED_HANDLES: u16 = 0xFF00;
+
+/// Start of the structure types available for OEM-specific information
+const OEM_STRUCT_TYPES: u8 = 128;
+
+/// # Validation Finding
+///
+/// A departure of a table from the DMTF SMBIOS specification, see
+/// [SMBiosData::validate].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum ValidationFinding {
+    /// The structure is shorter than the version of the table requires
+    StructTooShort {
+        /// Handle of the structure
+        handle: Handle,
+        /// Type of the structure
+        struct_type: u8,
+        /// Length of the formatted area, including the header
+        length: u8,
+        /// Length required by the version of the table
+        minimum: usize,
+    },
+    /// A string field references a string past the end of the string-set
+    InvalidStringIndex {
+        /// Handle of the structure
+        handle: Handle,
+        /// Type of the structure
+        struct_type: u8,
+        /// Offset of the string field
+        offset: usize,
+        /// 1 based index of the string
+        index: u8,
+        /// Number of strings of the string-set
+        string_count: usize,
+    },
+    /// A handle field references no structure of the table
+    DanglingHandle {
+        /// Handle of the structure
+        handle: Handle,
+        /// Type of the structure
+        struct_type: u8,
+        /// Offset of the handle field
+        offset: usize,
+        /// The referenced handle
+        reference: Handle,
+    },
+    /// A structure of a type defined by the specification (0 to 127) has a
+    /// handle in the reserved range FF00h to FFFFh
+    ReservedHandle {
+        /// Handle of the structure
+        handle: Handle,
+        /// Type of the structure
+        struct_type: u8,
+    },
+    /// The handle of the structure is lower than that of the structure
+    /// preceding it, rather than ascending in table order
+    HandleOutOfOrder {
+        /// Handle of the structure
+        handle: Handle,
+        /// Handle of the preceding structure
+        previous: Handle,
+    },
+    /// A string contains control characters, or cannot be decoded
+    ///
+    /// Strings are decoded with the decoding of the table (see
+    /// [SMBiosData::with_string_decoding]). With the default
+    /// [StringDecoding::Latin1](super::StringDecoding::Latin1), any byte
+    /// other than a C0 or C1 control character is printable; with
+    /// [StringDecoding::Utf8](super::StringDecoding::Utf8), a string which
+    /// is not UTF-8 is reported as well.
+    NonPrintableString {
+        /// Handle of the structure
+        handle: Handle,
+        /// Type of the structure
+        struct_type: u8,
+        /// 1 based index of the string
+        index: u8,
+    },
+    /// A string is a placeholder (see
+    /// [ValidationOptions::placeholder_strings]) rather than a value
+    PlaceholderString {
+        /// Handle of the structure
+        handle: Handle,
+        /// Type of the structure
+        struct_type: u8,
+        /// 1 based index of the string
+        index: u8,
+        /// The string
+        value: String,
+    },
+    /// Several structures share the handle
+    DuplicateHandle {
+        /// The shared handle
+        handle: Handle,
+        /// Number of structures of the handle
+        count: usize,
+    },
+    /// The table does not contain an End-of-Table (Type 127) structure
+    MissingEndOfTable,
+    /// The table contains no structure of a required type (see
+    /// [REQUIRED_STRUCT_TYPES])
+    MissingStructType(u8),
+}
+
+impl fmt::Display for ValidationFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationFinding::StructTooShort {
+                handle,
+                struct_type,
+                length,
+                minimum,
+            } => write!(
+                f,
+                "Handle {:#06X}, type {}: length {} is shorter than the required {}",
+                **handle, struct_type, length, minimum
+            ),
+            ValidationFinding::InvalidStringIndex {
+                handle,
+                struct_type,
+                offset,
+                index,
+                string_count,
+            } => write!(
+                f,
+                "Handle {:#06X}, type {}: string field at offset {:#04X} references string {} of {}",
+                **handle, struct_type, offset, index, string_count
+            ),
+            ValidationFinding::DanglingHandle {
+                handle,
+                struct_type,
+                offset,
+                reference,
+            } => write!(
+                f,
+                "Handle {:#06X}, type {}: handle field at offset {:#04X} references missing handle {:#06X}",
+                **handle, struct_type, offset, **reference
+            ),
+            ValidationFinding::ReservedHandle {
+                handle,
+                struct_type,
+            } => write!(
+                f,
+                "Handle {:#06X}, type {}: the handle is in the reserved range FF00h to FFFFh",
+                **handle, struct_type
+            ),
+            ValidationFinding::HandleOutOfOrder { handle, previous } => write!(
+                f,
+                "Handle {:#06X} follows the higher handle {:#06X}",
+                **handle, **previous
+            ),
+            ValidationFinding::NonPrintableString {
+                handle,
+                struct_type,
+                index,
+            } => write!(
+                f,
+                "Handle {:#06X}, type {}: string {} is not printable",
+                **handle, struct_type, index
+            ),
+            ValidationFinding::PlaceholderString {
+                handle,
+                struct_type,
+                index,
+                value,
+            } => write!(
+                f,
+                "Handle {:#06X}, type {}: string {} is the placeholder \"{}\"",
+                **handle, struct_type, index, value
+            ),
+            ValidationFinding::DuplicateHandle { handle, count } => write!(
+                f,
+                "Handle {:#06X} is shared by {} structures",
+                **handle, count
+            ),
+            ValidationFinding::MissingEndOfTable => {
+                f.write_str("The table has no End-of-Table (Type 127) structure")
+            }
+            ValidationFinding::MissingStructType(struct_type) => write!(
+                f,
+                "The table has no structure of the required type {}",
+                struct_type
+            ),
+        }
+    }
+}
+
+impl SMBiosData {
+    /// Checks the table against the DMTF SMBIOS specification
+    ///
+    /// Returns the findings, empty for a conforming table, in this order:
+    /// - For each structure in table order, a handle in the reserved range
+    ///   of a non-OEM structure, a handle lower than that of the preceding
+    ///   structure, a length shorter than required
+    ///   by the version of the table ([ValidationFinding::StructTooShort]),
+    ///   string fields referencing strings past the end of the string-set
+    ///   and handle fields referencing no structure. Lengths are checked
+    ///   when the version is known, for the types 0, 1, 2, 3, 4, 7, 8, 9,
+    ///   11, 12, 13, 16, 17, 19, 20 and 127. Handles FFFEh and FFFFh, which
+    ///   stand for no structure, are not references. Then strings which
+    ///   are not printable or are placeholders, in string-set order.
+    /// - Handles shared by several structures.
+    /// - A missing End-of-Table (Type 127) structure.
+    /// - Missing structures of the [REQUIRED_STRUCT_TYPES].
+    ///
+    /// Placeholder strings are those of [ValidationOptions::default].
+    pub fn validate(&self) -> Vec<ValidationFinding> {
+        self.validate_with_options(&ValidationOptions::default())
+    }
+
+    /// Checks the table against the DMTF SMBIOS specification, configured
+    /// by `options`
+    ///
+    /// See [SMBiosData::validate] for the findings.
+    pub fn validate_with_options(&self, options: &ValidationOptions) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        let mut handle_counts = BTreeMap::new();
+
+        let mut previous: Option<Handle> = None;
+
+        for undefined_struct in self.iter() {
+            let handle = undefined_struct.header.handle();
+            let struct_type = undefined_struct.header.struct_type();
+            *handle_counts.entry(*handle).or_insert(0) += 1;
+
+            if *handle >= RESERVED_HANDLES && struct_type < OEM_STRUCT_TYPES {
+                findings.push(ValidationFinding::ReservedHandle {
+                    handle,
+                    struct_type,
+                });
+            }
+            if let Some(previous) = previous.filter(|previous| *handle < **previous) {
+                findings.push(ValidationFinding::HandleOutOfOrder { handle, previous });
+            }
+            previous = Some(handle);
+
+            if let Some(minimum) = self
+                .version
+                .and_then(|version| minimum_length(undefined_struct, version))
+            {
+                let length = undefined_struct.header.length();
+                if (length as usize) < minimum {
+                    findings.push(ValidationFinding::StructTooShort {
+                        handle,
+                        struct_type,
+                        length,
+                        minimum,
+                    });
+                }
+            }
+
+            let string_count = undefined_struct.strings.iter().len();
+            for offset in string_field_offsets(undefined_struct) {
+                if let MaybeValue::InvalidIndex(index) =
+                    undefined_struct.access_field_string(offset)
+                {
+                    findings.push(ValidationFinding::InvalidStringIndex {
+                        handle,
+                        struct_type,
+                        offset,
+                        index,
+                        string_count,
+                    })
+                }
+            }
+
+            for (index, string) in (1..=u8::MAX).zip(undefined_struct.strings.iter()) {
+                match undefined_struct.strings.decoding().decode(string) {
+                    Some(value) if !value.chars().any(char::is_control) => {
+                        if options
+                            .placeholder_strings
+                            .iter()
+                            .any(|placeholder| placeholder.eq_ignore_ascii_case(value.trim()))
+                        {
+                            findings.push(ValidationFinding::PlaceholderString {
+                                handle,
+                                struct_type,
+                                index,
+                                value: value.to_string(),
+                            });
+                        }
+                    }
+                    _ => findings.push(ValidationFinding::NonPrintableString {
+                        handle,
+                        struct_type,
+                        index,
+                    }),
+                }
+            }
+
+            for offset in handle_field_offsets(undefined_struct) {
+                match undefined_struct.get_field_handle(offset) {
+                    Some(reference)
+                        if *reference < 0xFFFE && self.find_by_handle(&reference).is_none() =>
+                    {
+                        findings.push(ValidationFinding::DanglingHandle {
+                            handle,
+                            struct_type,
+                            offset,
+                            reference,
+                        })
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        findings.extend(
+            handle_counts
+                .into_iter()
+                .filter(|(_, count)| *count > 1)
+                .map(|(handle, count)| ValidationFinding::DuplicateHandle {
+                    handle: Handle(handle),
+                    count,
+                }),
+        );
+
+        let has_type = |struct_type: u8| {
+            self.iter()
+                .any(|undefined_struct| undefined_struct.header.struct_type() == struct_type)
+        };
+        if !has_type(SMBiosEndOfTable::STRUCT_TYPE) {
+            findings.push(ValidationFinding::MissingEndOfTable);
+        }
+        findings.extend(
+            REQUIRED_STRUCT_TYPES
+                .iter()
+                .filter(|struct_type| !has_type(**struct_type))
+                .map(|struct_type| ValidationFinding::MissingStructType(*struct_type)),
+        );
+
+        findings
+    }
+}
+
+/// Length of the formatted area, including the header, that the version
+/// requires of the structure, None for types not checked
+pub(crate) fn minimum_length(
+    undefined_struct: &UndefinedStruct,
+    version: SMBiosVersion,
+) -> Option<usize> {
+    let at_least = |major: u8, minor: u8| version >= SMBiosVersion::new(major, minor, 0);
+
+    Some(match undefined_struct.header.struct_type() {
+        SMBiosInformation::STRUCT_TYPE if at_least(3, 1) => 0x1A,
+        SMBiosInformation::STRUCT_TYPE if at_least(2, 4) => 0x18,
+        SMBiosInformation::STRUCT_TYPE => 0x12,
+        SMBiosSystemInformation::STRUCT_TYPE if at_least(2, 4) => 0x1B,
+        SMBiosSystemInformation::STRUCT_TYPE if at_least(2, 1) => 0x19,
+        SMBiosSystemInformation::STRUCT_TYPE => 0x08,
+        SMBiosBaseboardInformation::STRUCT_TYPE => 0x08,
+        SMBiosSystemChassisInformation::STRUCT_TYPE if at_least(2, 3) => {
+            // contained elements of n records of m bytes follow the
+            // fields of version 2.3
+            let count = undefined_struct.get_field_byte(0x13).unwrap_or(0) as usize;
+            let record_length = undefined_struct.get_field_byte(0x14).unwrap_or(0) as usize;
+            let sku_number = if at_least(2, 7) { 1 } else { 0 };
+            0x15 + count * record_length + sku_number
+        }
+        SMBiosSystemChassisInformation::STRUCT_TYPE if at_least(2, 1) => 0x0D,
+        SMBiosSystemChassisInformation::STRUCT_TYPE => 0x09,
+        SMBiosProcessorInformation::STRUCT_TYPE if at_least(3, 6) => 0x32,
+        SMBiosProcessorInformation::STRUCT_TYPE if at_least(3, 0) => 0x30,
+        SMBiosProcessorInformation::STRUCT_TYPE if at_least(2, 6) => 0x2A,
+        SMBiosProcessorInformation::STRUCT_TYPE if at_least(2, 5) => 0x28,
+        SMBiosProcessorInformation::STRUCT_TYPE if at_least(2, 3) => 0x23,
+        SMBiosProcessorInformation::STRUCT_TYPE if at_least(2, 1) => 0x20,
+        SMBiosProcessorInformation::STRUCT_TYPE => 0x1A,
+        SMBiosCacheInformation::STRUCT_TYPE if at_least(3, 1) => 0x1B,
+        SMBiosCacheInformation::STRUCT_TYPE if at_least(2, 1) => 0x13,
+        SMBiosCacheInformation::STRUCT_TYPE => 0x0F,
+        SMBiosPortConnectorInformation::STRUCT_TYPE => 0x09,
+        SMBiosSystemSlot::STRUCT_TYPE if at_least(2, 6) => 0x11,
+        SMBiosSystemSlot::STRUCT_TYPE if at_least(2, 1) => 0x0D,
+        SMBiosSystemSlot::STRUCT_TYPE => 0x0C,
+        SMBiosOemStrings::STRUCT_TYPE => 0x05,
+        SMBiosSystemConfigurationOptions::STRUCT_TYPE => 0x05,
+        SMBiosBiosLanguageInformation::STRUCT_TYPE => 0x16,
+        SMBiosPhysicalMemoryArray::STRUCT_TYPE if at_least(2, 7) => 0x17,
+        SMBiosPhysicalMemoryArray::STRUCT_TYPE => 0x0F,
+        SMBiosMemoryDevice::STRUCT_TYPE if at_least(3, 7) => 0x64,
+        SMBiosMemoryDevice::STRUCT_TYPE if at_least(3, 3) => 0x5C,
+        SMBiosMemoryDevice::STRUCT_TYPE if at_least(3, 2) => 0x54,
+        SMBiosMemoryDevice::STRUCT_TYPE if at_least(2, 8) => 0x28,
+        SMBiosMemoryDevice::STRUCT_TYPE if at_least(2, 7) => 0x22,
+        SMBiosMemoryDevice::STRUCT_TYPE if at_least(2, 6) => 0x1C,
+        SMBiosMemoryDevice::STRUCT_TYPE if at_least(2, 3) => 0x1B,
+        SMBiosMemoryDevice::STRUCT_TYPE => 0x15,
+        SMBiosMemoryArrayMappedAddress::STRUCT_TYPE if at_least(2, 7) => 0x1F,
+        SMBiosMemoryArrayMappedAddress::STRUCT_TYPE => 0x0F,
+        SMBiosMemoryDeviceMappedAddress::STRUCT_TYPE if at_least(2, 7) => 0x23,
+        SMBiosMemoryDeviceMappedAddress::STRUCT_TYPE => 0x13,
+        SMBiosEndOfTable::STRUCT_TYPE => 0x04,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{SMBiosTableBuilder, StructBuilder, SystemInformationBuilder};
+
+    #[test]
+    fn test_validate() {
+        // Windows dump of 2.8 Memory Devices in a table declaring 3.2
+        let raw = std::fs::read("tests/jeffgerlap_3_2_0.dat").unwrap()[8..].to_vec();
+        let data = SMBiosData::from_vec_and_version(raw, Some(SMBiosVersion::new(3, 2, 0)));
+        assert_eq!(
+            data.validate(),
+            vec![
+                ValidationFinding::StructTooShort {
+                    handle: Handle(8),
+                    struct_type: 17,
+                    length: 0x28,
+                    minimum: 0x54,
+                },
+                ValidationFinding::StructTooShort {
+                    handle: Handle(11),
+                    struct_type: 17,
+                    length: 0x28,
+                    minimum: 0x54,
+                },
+                ValidationFinding::InvalidStringIndex {
+                    handle: Handle(15),
+                    struct_type: 3,
+                    offset: 0x08,
+                    index: 3,
+                    string_count: 2,
+                },
+            ]
+        );
+
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add_with_handle(
+                Handle(0),
+                SystemInformationBuilder::new().manufacturer("Manufacturer"),
+            )
+            .unwrap();
+        // Memory Device of a missing array, a product string 3 of 1
+        builder
+            .add_with_handle(
+                Handle(1),
+                StructBuilder::new(17)
+                    .word(0x1000)
+                    .word(0xFFFE)
+                    .word(72)
+                    .word(64)
+                    .word(0x4000)
+                    .byte(0x09)
+                    .byte(0)
+                    .string("DIMM")
+                    .byte(3)
+                    .byte(0x1A)
+                    .word(0),
+            )
+            .unwrap();
+        let data = builder.build().unwrap();
+
+        let findings = data.validate();
+        assert_eq!(
+            findings,
+            vec![
+                ValidationFinding::StructTooShort {
+                    handle: Handle(1),
+                    struct_type: 17,
+                    length: 0x15,
+                    minimum: 0x5C,
+                },
+                ValidationFinding::InvalidStringIndex {
+                    handle: Handle(1),
+                    struct_type: 17,
+                    offset: 0x11,
+                    index: 3,
+                    string_count: 1,
+                },
+                ValidationFinding::DanglingHandle {
+                    handle: Handle(1),
+                    struct_type: 17,
+                    offset: 0x04,
+                    reference: Handle(0x1000),
+                },
+                ValidationFinding::MissingStructType(0),
+                ValidationFinding::MissingStructType(3),
+                ValidationFinding::MissingStructType(4),
+                ValidationFinding::MissingStructType(16),
+            ]
+        );
+        assert_eq!(
+            findings[2].to_string(),
+            "Handle 0x0001, type 17: handle field at offset 0x04 references missing handle 0x1000"
+        );
+
+        let table = vec![
+            0x0B, 0x05, 0x01, 0x00, 0x00, 0x00, 0x00, // OEM Strings 1
+            0x0B, 0x05, 0x01, 0x00, 0x00, 0x00, 0x00, // OEM Strings 1
+        ];
+        let findings = SMBiosData::from_vec_and_version(table, None).validate();
+        assert_eq!(
+            findings[..2],
+            [
+                ValidationFinding::DuplicateHandle {
+                    handle: Handle(1),
+                    count: 2
+                },
+                ValidationFinding::MissingEndOfTable,
+            ]
+        );
+    }
+    #[test]
+    fn test_validate_handles() {
+        let table = vec![
+            0x0B, 0x05, 0x02, 0x00, 0x00, 0x00, 0x00, // OEM Strings 2
+            0x0B, 0x05, 0x00, 0xFF, 0x00, 0x00, 0x00, // OEM Strings FF00
+            0x80, 0x04, 0x01, 0xFF, 0x00, 0x00, // OEM-specific FF01
+            0x0B, 0x05, 0x01, 0x00, 0x00, 0x00, 0x00, // OEM Strings 1
+            0x7F, 0x04, 0x03, 0x00, 0x00, 0x00, // End-of-Table 3
+        ];
+        let findings = SMBiosData::from_vec_and_version(table, None).validate();
+        assert_eq!(
+            findings[..2],
+            [
+                ValidationFinding::ReservedHandle {
+                    handle: Handle(0xFF00),
+                    struct_type: 11,
+                },
+                ValidationFinding::HandleOutOfOrder {
+                    handle: Handle(1),
+                    previous: Handle(0xFF01),
+                },
+            ]
+        );
+        assert_eq!(
+            findings[1].to_string(),
+            "Handle 0x0001 follows the higher handle 0xFF01"
+        );
+        assert_eq!(findings[2], ValidationFinding::MissingStructType(0));
+    }
+
+    #[test]
+    fn test_validate_strings() {
+        let table = vec![
+            0x0B, 0x05, 0x00, 0x00, 0x05, // OEM Strings of 5 strings
+            b' ', b'T', b'O', b' ', b'B', b'E', b' ', b'F', b'I', b'L', b'L', b'E', b'D', b' ',
+            b'B', b'Y', b' ', b'O', b'.', b'E', b'.', b'M', b'.', 0x00, // string 1
+            b'X', b'1', 0x00, // string 2
+            b'A', 0x07, 0x00, // string 3
+            b'A', 0x9B, 0x00, // string 4
+            b'M', 0xFC, b'n', b'c', b'h', b'e', b'n', 0x00, // string 5
+            0x00, 0x7F, 0x04, 0x01, 0x00, 0x00, 0x00,
+        ];
+        let data = SMBiosData::from_vec_and_version(table, None);
+
+        let findings = data.validate();
+        assert_eq!(
+            findings[..3],
+            [
+                ValidationFinding::PlaceholderString {
+                    handle: Handle(0),
+                    struct_type: 11,
+                    index: 1,
+                    value: " TO BE FILLED BY O.E.M.".to_string(),
+                },
+                ValidationFinding::NonPrintableString {
+                    handle: Handle(0),
+                    struct_type: 11,
+                    index: 3,
+                },
+                ValidationFinding::NonPrintableString {
+                    handle: Handle(0),
+                    struct_type: 11,
+                    index: 4,
+                },
+            ]
+        );
+        assert_eq!(findings[3], ValidationFinding::MissingStructType(0));
+
+        let options = ValidationOptions {
+            placeholder_strings: vec!["x1".to_string()],
+        };
+        let findings = data.validate_with_options(&options);
+        assert_eq!(
+            findings[0],
+            ValidationFinding::PlaceholderString {
+                handle: Handle(0),
+                struct_type: 11,
+                index: 2,
+                value: "X1".to_string(),
+            }
+        );
+        assert_eq!(
+            findings[0].to_string(),
+            "Handle 0x0000, type 11: string 2 is the placeholder \"X1\""
+        );
+
+        // Strings 4 and 5 are not UTF-8
+        let data = data.with_string_decoding(crate::core::StringDecoding::Utf8);
+        let non_printable: Vec<u8> = data
+            .validate()
+            .into_iter()
+            .filter_map(|finding| match finding {
+                ValidationFinding::NonPrintableString { index, .. } => Some(index),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(non_printable, [3, 4, 5]);
+
+        // a string which is not UTF-8 is in range of the string-set
+        let table = vec![
+            0x01, 0x05, 0x00, 0x00, 0x01, 0xE9, b'A', 0x00, 0x00, 0x7F, 0x04, 0x01, 0x00, 0x00,
+            0x00,
+        ];
+        let data = SMBiosData::from_vec_and_version(table, None)
+            .with_string_decoding(crate::core::StringDecoding::Utf8);
+        let system_information = data.iter().next().unwrap();
+        assert_eq!(system_information.get_field_string(0x04), None);
+        assert_eq!(
+            system_information.access_field_string(0x04),
+            crate::core::MaybeValue::Value("\u{FFFD}A".to_string())
+        );
+        let findings = data.validate();
+        assert!(!findings
+            .iter()
+            .any(|finding| matches!(finding, ValidationFinding::InvalidStringIndex { .. })));
+        assert!(findings.contains(&ValidationFinding::NonPrintableString {
+            handle: Handle(0),
+            struct_type: 1,
+            index: 1,
+        }));
+    }
+}
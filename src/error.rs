@@ -0,0 +1,273 @@
+use crate::builder::BuildError;
+use crate::core::{Header, ParseLimitError, SMBiosEntryPoint32Error, SMBiosEntryPoint64Error};
+use crate::windows::WinSMBiosDataError;
+use std::{fmt, io};
+
+/// SMBIOS loading and parsing errors
+///
+/// Returned by the functions which load SMBIOS data from a device or a file
+/// and parse its entry point, so that failures can be handled by their
+/// cause. Errors of the operating system are kept as [io::Error], see
+/// [Error::io_error]; [load](crate::load) tells apart those caused by
+/// missing privileges ([Error::PermissionDenied]) or a missing table
+/// ([Error::TableNotFound]) so that an actionable message can be presented.
+pub enum Error {
+    /// No entry point was found where one was searched for
+    EntryPointNotFound,
+    /// The 32-bit (_SM_) entry point is invalid
+    InvalidEntryPoint32(SMBiosEntryPoint32Error),
+    /// The 64-bit (_SM3_) entry point is invalid
+    InvalidEntryPoint64(SMBiosEntryPoint64Error),
+    /// The data ends before the structure table or entry point it holds
+    TruncatedStructure {
+        /// Offset at which the structure table or entry point starts
+        offset: usize,
+        /// Number of bytes it needs at least
+        needed: usize,
+    },
+    /// The structure table length given by the entry point, in bytes, is
+    /// too short to hold a structure
+    InvalidTableLength(usize),
+    /// The table exceeds a limit of the [ParseOptions](crate::ParseOptions)
+    /// or, when parsing strictly, holds a malformed structure
+    ParseLimit(ParseLimitError),
+    /// The raw Windows SMBIOS data is invalid
+    InvalidWinSMBiosData(WinSMBiosDataError),
+    /// Loading SMBIOS from the device is not supported on this platform
+    UnsupportedPlatform,
+    /// Access to the SMBIOS table was denied (root or Administrator privileges are required)
+    PermissionDenied(io::Error),
+    /// The firmware does not expose an SMBIOS table (or the OS interface to it is absent)
+    TableNotFound(io::Error),
+    /// The operating system failed to read the data
+    OsError(io::Error),
+    /// The table cannot be written, such as to a `dmidecode --dump-bin` file
+    Build(BuildError),
+}
+
+impl Error {
+    /// The underlying [io::Error] reported by the operating system
+    pub fn io_error(&self) -> Option<&io::Error> {
+        match self {
+            Error::PermissionDenied(error)
+            | Error::TableNotFound(error)
+            | Error::OsError(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Classifies an [Error::OsError] reading the table of the device as
+    /// [Error::PermissionDenied] or [Error::TableNotFound] by its
+    /// [io::ErrorKind]
+    pub(crate) fn classify_device_error(self) -> Self {
+        match self {
+            Error::OsError(error) => match error.kind() {
+                io::ErrorKind::PermissionDenied => Error::PermissionDenied(error),
+                io::ErrorKind::NotFound => Error::TableNotFound(error),
+                _ => Error::OsError(error),
+            },
+            error => error,
+        }
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Error").field(&format!("{}", &self)).finish()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EntryPointNotFound => f.write_str("Entry Point not found"),
+            Error::InvalidEntryPoint32(error) => write!(f, "Invalid _SM_ entry point: {}", error),
+            Error::InvalidEntryPoint64(error) => {
+                write!(f, "Invalid _SM3_ entry point: {}", error)
+            }
+            Error::TruncatedStructure { offset, needed } => write!(
+                f,
+                "The data ends before the {} bytes needed at offset {:#X}",
+                needed, offset
+            ),
+            Error::InvalidTableLength(length) => write!(
+                f,
+                "The structure table length of {} bytes is shorter than the {} bytes of a structure",
+                length,
+                Header::SIZE + 2
+            ),
+            Error::ParseLimit(error) => write!(f, "{}", error),
+            Error::InvalidWinSMBiosData(error) => write!(f, "{}", error),
+            Error::UnsupportedPlatform => {
+                f.write_str("Loading SMBIOS from the device is not supported on this platform")
+            }
+            Error::PermissionDenied(error) => write!(
+                f,
+                "Permission denied reading the SMBIOS table, root or Administrator privileges are required: {}",
+                error
+            ),
+            Error::TableNotFound(error) => {
+                write!(f, "The SMBIOS table was not found: {}", error)
+            }
+            Error::OsError(error) => write!(f, "{}", error),
+            Error::Build(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidEntryPoint32(error) => Some(error),
+            Error::InvalidEntryPoint64(error) => Some(error),
+            Error::ParseLimit(error) => Some(error),
+            Error::InvalidWinSMBiosData(error) => Some(error),
+            Error::Build(error) => Some(error),
+            Error::PermissionDenied(error)
+            | Error::TableNotFound(error)
+            | Error::OsError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::OsError(error)
+    }
+}
+
+impl From<SMBiosEntryPoint32Error> for Error {
+    fn from(error: SMBiosEntryPoint32Error) -> Self {
+        Error::InvalidEntryPoint32(error)
+    }
+}
+
+impl From<SMBiosEntryPoint64Error> for Error {
+    fn from(error: SMBiosEntryPoint64Error) -> Self {
+        Error::InvalidEntryPoint64(error)
+    }
+}
+
+impl From<ParseLimitError> for Error {
+    fn from(error: ParseLimitError) -> Self {
+        Error::ParseLimit(error)
+    }
+}
+
+impl From<WinSMBiosDataError> for Error {
+    fn from(error: WinSMBiosDataError) -> Self {
+        Error::InvalidWinSMBiosData(error)
+    }
+}
+
+impl From<BuildError> for Error {
+    fn from(error: BuildError) -> Self {
+        Error::Build(error)
+    }
+}
+
+/// Converts to an [io::Error], for callers returning [io::Result]
+///
+/// The errors of the operating system are unwrapped, the other errors are
+/// wrapped with the [io::ErrorKind] closest to their cause.
+impl From<Error> for io::Error {
+    fn from(error: Error) -> Self {
+        let kind = match error {
+            Error::PermissionDenied(error)
+            | Error::TableNotFound(error)
+            | Error::OsError(error) => return error,
+            Error::EntryPointNotFound => io::ErrorKind::NotFound,
+            Error::TruncatedStructure { .. } => io::ErrorKind::UnexpectedEof,
+            Error::UnsupportedPlatform => io::ErrorKind::Unsupported,
+            Error::InvalidEntryPoint32(_)
+            | Error::InvalidEntryPoint64(_)
+            | Error::InvalidTableLength(_)
+            | Error::ParseLimit(_)
+            | Error::InvalidWinSMBiosData(_) => io::ErrorKind::InvalidData,
+            Error::Build(_) => io::ErrorKind::InvalidInput,
+        };
+        io::Error::new(kind, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    #[test]
+    fn test_error_conversions() {
+        let error = Error::from(io::Error::new(io::ErrorKind::PermissionDenied, "denied"));
+        assert_eq!(
+            error.io_error().map(io::Error::kind),
+            Some(io::ErrorKind::PermissionDenied)
+        );
+        assert_eq!(io::Error::from(error).to_string(), "denied");
+
+        let error = Error::from(SMBiosEntryPoint64Error::ChecksumVerificationFailed);
+        assert!(error.io_error().is_none());
+        assert!(error.source().is_some());
+        assert_eq!(
+            error.to_string(),
+            "Invalid _SM3_ entry point: Entry Point Structure checksum verification failed"
+        );
+        assert_eq!(io::Error::from(error).kind(), io::ErrorKind::InvalidData);
+
+        let error = Error::TruncatedStructure {
+            offset: 0x20,
+            needed: 6,
+        };
+        assert_eq!(
+            error.to_string(),
+            "The data ends before the 6 bytes needed at offset 0x20"
+        );
+        assert_eq!(io::Error::from(error).kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            io::Error::from(Error::EntryPointNotFound).kind(),
+            io::ErrorKind::NotFound
+        );
+
+        let error = Error::InvalidTableLength(4);
+        assert_eq!(
+            error.to_string(),
+            "The structure table length of 4 bytes is shorter than the 6 bytes of a structure"
+        );
+        assert_eq!(io::Error::from(error).kind(), io::ErrorKind::InvalidData);
+
+        let error = Error::from(ParseLimitError::TooManyStructures { limit: 1 });
+        assert!(matches!(error, Error::ParseLimit(_)));
+        assert!(error.source().is_some());
+        assert_eq!(io::Error::from(error).kind(), io::ErrorKind::InvalidData);
+
+        let error = Error::from(BuildError::MissingVersion);
+        assert!(matches!(error, Error::Build(BuildError::MissingVersion)));
+        assert!(error.source().is_some());
+        assert_eq!(io::Error::from(error).kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_classify_device_error() {
+        let error = Error::from(io::Error::new(io::ErrorKind::PermissionDenied, "denied"))
+            .classify_device_error();
+        assert!(matches!(error, Error::PermissionDenied(_)));
+        assert_eq!(error.io_error().unwrap().to_string(), "denied");
+        assert!(error.source().is_some());
+        assert_eq!(io::Error::from(error).to_string(), "denied");
+
+        let error =
+            Error::from(io::Error::new(io::ErrorKind::NotFound, "absent")).classify_device_error();
+        assert!(matches!(error, Error::TableNotFound(_)));
+        assert_eq!(error.to_string(), "The SMBIOS table was not found: absent");
+
+        let error =
+            Error::from(io::Error::new(io::ErrorKind::InvalidData, "bad")).classify_device_error();
+        assert!(matches!(error, Error::OsError(_)));
+
+        assert!(matches!(
+            Error::EntryPointNotFound.classify_device_error(),
+            Error::EntryPointNotFound
+        ));
+        assert!(Error::UnsupportedPlatform.io_error().is_none());
+    }
+}
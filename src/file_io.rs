@@ -3,11 +3,12 @@
 //! When testing this library it is useful to read stored
 //! raw data and then load it into the structures.
 use crate::core::SMBiosData;
+use crate::error::Error;
 use crate::windows::WinSMBiosData;
 use std::{fs::{read, read_dir, File}, path::Path};
-use std::io::{BufWriter, Error, Write};
+use std::io::{self, BufWriter, Write};
 
-/// Loads raw smbios data from a file and returns [SMBiosData] or [std::io::Error] on error.
+/// Loads raw smbios data from a file and returns [SMBiosData] or [Error] on error.
 ///
 /// Currently supports reading raw files containing only SMBIOS table data or
 /// Windows raw files containing the windows header and SMBIOS table data.
@@ -30,7 +31,7 @@ pub fn load_raw_files(folder: &Path) -> Vec<SMBiosData> {
     let entries = read_dir(folder)
         .expect("valid files")
         .map(|res| res.map(|e| e.path()))
-        .collect::<Result<Vec<_>, Error>>()
+        .collect::<Result<Vec<_>, io::Error>>()
         .expect("msg");
 
     for elem in entries {
@@ -45,7 +46,7 @@ pub fn load_raw_files(folder: &Path) -> Vec<SMBiosData> {
 }
 
 /// dumps raw data into a file
-pub fn dump_raw(data: Vec<u8>, out_path: &Path) -> Result<(), Error> {
+pub fn dump_raw(data: Vec<u8>, out_path: &Path) -> Result<(), io::Error> {
     let f = File::create(&out_path)?;
     let mut f = BufWriter::new(f);
     f.write_all(&data)?;
@@ -0,0 +1,520 @@
+//! Stable JSON output.
+//!
+//! A documented JSON shape of a table which, unlike the serde serialization
+//! of the structures, does not follow the internal layout of this library.
+
+mod names;
+
+use crate::core::{
+    handle_fields, string_fields, ReferenceField, SMBiosData, StructFields, UndefinedStruct,
+};
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::{format, string::String, vec::Vec};
+use core::convert::TryFrom;
+use names::*;
+use serde_json::{json, Map, Value};
+
+/// Version of the JSON shape emitted by [SMBiosData::to_json_value]
+///
+/// Incremented when the shape changes in a way that breaks consumers, such
+/// as a renamed or removed member. Members may be added without a change
+/// of version.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+impl SMBiosData {
+    /// The table as a JSON value of a stable, documented shape
+    ///
+    /// ```json
+    /// {
+    ///   "schema_version": 1,
+    ///   "smbios_version": { "major": 3, "minor": 4, "revision": 0 },
+    ///   "structures": [
+    ///     {
+    ///       "type": 1,
+    ///       "name": "System Information",
+    ///       "handle": 1,
+    ///       "length": 27,
+    ///       "fields": {
+    ///         "manufacturer": "Manufacturer",
+    ///         "uuid": "00360FE7-D4D5-11E5-9C43-BC0000F00000",
+    ///         "wake_up_type": { "raw": 6, "value": "PowerSwitch" }
+    ///       },
+    ///       "strings": ["Manufacturer"],
+    ///       "data": "0100..."
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// - `schema_version` is [JSON_SCHEMA_VERSION].
+    /// - `smbios_version` is null when the version of the table is not known.
+    /// - `name` is the structure name of the DMTF SMBIOS specification,
+    ///   "OEM-specific" for types 128 through 255, or null for types not
+    ///   defined by the specification.
+    /// - `strings` holds the string-set, each byte being an ISO-8859-1
+    ///   character, and `data` the formatted area following the header as
+    ///   a hexadecimal string. Together with the header they preserve the
+    ///   structure unchanged.
+    /// - `fields` names the fields of the structure after the DMTF SMBIOS
+    ///   specification, in lowercase words separated by underscores (for
+    ///   example "Wake-up Type" as `wake_up_type`). A field beyond the
+    ///   length of the structure (defined by a later version of the
+    ///   specification) is omitted.
+    ///
+    /// Field values are numbers, except:
+    /// - STRING fields are the referenced string, or null for no string.
+    /// - Enumerated fields are an object of the `raw` value and the `value`
+    ///   name, null when the raw value is not known to this library. The
+    ///   names are those of the library's enumerations when the shape was
+    ///   introduced and are kept as they are for a schema version.
+    /// - UUIDs are the canonical UUID string, null when the UUID is not
+    ///   present (all bits 0 or 1).
+    /// - Lists of handles are arrays of numbers, and lists of STRING fields
+    ///   arrays of strings or null.
+    ///
+    /// `fields` is given for BIOS Information (Type 0), System Information
+    /// (Type 1), Baseboard Information (Type 2), System Enclosure or Chassis
+    /// (Type 3), Processor Information (Type 4), Cache Information (Type 7),
+    /// Port Connector Information (Type 8), System Slots (Type 9), OEM
+    /// Strings (Type 11), System Configuration Options (Type 12), BIOS
+    /// Language Information (Type 13), Physical Memory Array (Type 16),
+    /// Memory Device (Type 17), Memory Array Mapped Address (Type 19),
+    /// Memory Device Mapped Address (Type 20) and End-of-Table (Type 127)
+    /// structures. It is null for other structures, which are described by
+    /// `data` and `strings`.
+    pub fn to_json_value(&self) -> Value {
+        json!({
+            "schema_version": JSON_SCHEMA_VERSION,
+            "smbios_version": self.version.map(|version| json!({
+                "major": version.major,
+                "minor": version.minor,
+                "revision": version.revision,
+            })),
+            "structures": self.iter().map(struct_value).collect::<Vec<Value>>(),
+        })
+    }
+}
+
+pub(crate) fn struct_value(parts: &UndefinedStruct) -> Value {
+    let struct_type = parts.header.struct_type();
+    let strings: Vec<String> = (&parts.strings).into_iter().collect();
+    let data: String = parts
+        .fields
+        .iter()
+        .skip(crate::core::Header::SIZE)
+        .map(|b| format!("{:02X}", b))
+        .collect();
+
+    json!({
+        "type": struct_type,
+        "name": struct_name(struct_type),
+        "handle": *parts.header.handle(),
+        "length": parts.header.length(),
+        "fields": struct_fields(parts),
+        "strings": strings,
+        "data": data,
+    })
+}
+
+/// Name of the structure type in the DMTF SMBIOS specification
+pub(crate) fn struct_name(struct_type: u8) -> Option<&'static str> {
+    Some(match struct_type {
+        0 => "BIOS Information",
+        1 => "System Information",
+        2 => "Baseboard (or Module) Information",
+        3 => "System Enclosure or Chassis",
+        4 => "Processor Information",
+        5 => "Memory Controller Information",
+        6 => "Memory Module Information",
+        7 => "Cache Information",
+        8 => "Port Connector Information",
+        9 => "System Slots",
+        10 => "On Board Devices Information",
+        11 => "OEM Strings",
+        12 => "System Configuration Options",
+        13 => "BIOS Language Information",
+        14 => "Group Associations",
+        15 => "System Event Log",
+        16 => "Physical Memory Array",
+        17 => "Memory Device",
+        18 => "32-Bit Memory Error Information",
+        19 => "Memory Array Mapped Address",
+        20 => "Memory Device Mapped Address",
+        21 => "Built-in Pointing Device",
+        22 => "Portable Battery",
+        23 => "System Reset",
+        24 => "Hardware Security",
+        25 => "System Power Controls",
+        26 => "Voltage Probe",
+        27 => "Cooling Device",
+        28 => "Temperature Probe",
+        29 => "Electrical Current Probe",
+        30 => "Out-of-Band Remote Access",
+        31 => "Boot Integrity Services (BIS) Entry Point",
+        32 => "System Boot Information",
+        33 => "64-Bit Memory Error Information",
+        34 => "Management Device",
+        35 => "Management Device Component",
+        36 => "Management Device Threshold Data",
+        37 => "Memory Channel",
+        38 => "IPMI Device Information",
+        39 => "System Power Supply",
+        40 => "Additional Information",
+        41 => "Onboard Devices Extended Information",
+        42 => "Management Controller Host Interface",
+        43 => "TPM Device",
+        44 => "Processor Additional Information",
+        45 => "Firmware Inventory Information",
+        46 => "String Property",
+        126 => "Inactive",
+        127 => "End-of-Table",
+        128..=255 => "OEM-specific",
+        _ => return None,
+    })
+}
+
+fn struct_fields(parts: &UndefinedStruct) -> Value {
+    let mut fields = Fields::new(parts);
+    match parts.header.struct_type() {
+        SMBiosInformation::STRUCT_TYPE => {
+            fields.word("bios_starting_address_segment", 0x06);
+            fields.byte("bios_rom_size", 0x09);
+            fields.qword("bios_characteristics", 0x0A);
+            fields.byte("bios_characteristics_extension_byte_1", 0x12);
+            fields.byte("bios_characteristics_extension_byte_2", 0x13);
+            fields.byte("system_bios_major_release", 0x14);
+            fields.byte("system_bios_minor_release", 0x15);
+            fields.byte("embedded_controller_firmware_major_release", 0x16);
+            fields.byte("embedded_controller_firmware_minor_release", 0x17);
+            fields.word("extended_bios_rom_size", 0x18);
+        }
+        SMBiosSystemInformation::STRUCT_TYPE => {
+            fields.uuid("uuid", 0x08);
+            fields.enumeration("wake_up_type", 0x18, WAKE_UP_TYPES);
+        }
+        SMBiosBaseboardInformation::STRUCT_TYPE => {
+            fields.byte("feature_flags", 0x09);
+            fields.enumeration("board_type", 0x0D, BOARD_TYPES);
+            fields.byte("number_of_contained_object_handles", 0x0E);
+        }
+        SMBiosSystemChassisInformation::STRUCT_TYPE => {
+            fields.enumeration("type", 0x05, CHASSIS_TYPES);
+            fields.enumeration("boot_up_state", 0x09, CHASSIS_STATES);
+            fields.enumeration("power_supply_state", 0x0A, CHASSIS_STATES);
+            fields.enumeration("thermal_state", 0x0B, CHASSIS_STATES);
+            fields.enumeration("security_status", 0x0C, CHASSIS_SECURITY_STATUSES);
+            fields.dword("oem_defined", 0x0D);
+            fields.byte("height", 0x11);
+            fields.byte("number_of_power_cords", 0x12);
+            fields.byte("contained_element_count", 0x13);
+            fields.byte("contained_element_record_length", 0x14);
+        }
+        SMBiosProcessorInformation::STRUCT_TYPE => {
+            fields.enumeration("processor_type", 0x05, PROCESSOR_TYPES);
+            fields.enumeration("processor_family", 0x06, PROCESSOR_FAMILIES);
+            fields.qword("processor_id", 0x08);
+            fields.byte("voltage", 0x11);
+            fields.word("external_clock", 0x12);
+            fields.word("max_speed", 0x14);
+            fields.word("current_speed", 0x16);
+            fields.byte("status", 0x18);
+            fields.enumeration("processor_upgrade", 0x19, PROCESSOR_UPGRADES);
+            fields.byte("core_count", 0x23);
+            fields.byte("core_enabled", 0x24);
+            fields.byte("thread_count", 0x25);
+            fields.word("processor_characteristics", 0x26);
+            fields.enumeration_word("processor_family_2", 0x28, processor_family_2);
+            fields.word("core_count_2", 0x2A);
+            fields.word("core_enabled_2", 0x2C);
+            fields.word("thread_count_2", 0x2E);
+            fields.word("thread_enabled", 0x30);
+        }
+        SMBiosCacheInformation::STRUCT_TYPE => {
+            fields.word("cache_configuration", 0x05);
+            fields.word("maximum_cache_size", 0x07);
+            fields.word("installed_size", 0x09);
+            fields.word("supported_sram_type", 0x0B);
+            fields.word("current_sram_type", 0x0D);
+            fields.byte("cache_speed", 0x0F);
+            fields.enumeration("error_correction_type", 0x10, ERROR_CORRECTION_TYPES);
+            fields.enumeration("system_cache_type", 0x11, SYSTEM_CACHE_TYPES);
+            fields.enumeration("associativity", 0x12, CACHE_ASSOCIATIVITIES);
+            fields.dword("maximum_cache_size_2", 0x13);
+            fields.dword("installed_cache_size_2", 0x17);
+        }
+        SMBiosPortConnectorInformation::STRUCT_TYPE => {
+            fields.enumeration("internal_connector_type", 0x05, CONNECTOR_TYPES);
+            fields.enumeration("external_connector_type", 0x07, CONNECTOR_TYPES);
+            fields.enumeration("port_type", 0x08, PORT_TYPES);
+        }
+        SMBiosSystemSlot::STRUCT_TYPE => {
+            fields.enumeration("slot_type", 0x05, SLOT_TYPES);
+            fields.enumeration("slot_data_bus_width", 0x06, SLOT_WIDTHS);
+            fields.enumeration("current_usage", 0x07, SLOT_CURRENT_USAGES);
+            fields.enumeration("slot_length", 0x08, SLOT_LENGTHS);
+            fields.word("slot_id", 0x09);
+            fields.byte("slot_characteristics_1", 0x0B);
+            fields.byte("slot_characteristics_2", 0x0C);
+            fields.word("segment_group_number", 0x0D);
+            fields.byte("bus_number", 0x0F);
+            fields.byte("device_function_number", 0x10);
+            fields.byte("data_bus_width", 0x11);
+            fields.byte("peer_grouping_count", 0x12);
+            if let Some(count) = parts.get_field_byte(0x12) {
+                // Peer groups are 5 bytes each
+                let offset = 0x13 + 5 * count as usize;
+                fields.byte("slot_information", offset);
+                fields.byte("slot_physical_width", offset + 1);
+                fields.word("slot_pitch", offset + 2);
+                fields.enumeration("slot_height", offset + 4, SLOT_HEIGHTS);
+            }
+        }
+        SMBiosOemStrings::STRUCT_TYPE | SMBiosSystemConfigurationOptions::STRUCT_TYPE => {
+            fields.byte("count", 0x04);
+        }
+        SMBiosBiosLanguageInformation::STRUCT_TYPE => {
+            fields.byte("installable_languages", 0x04);
+            fields.byte("flags", 0x05);
+        }
+        SMBiosPhysicalMemoryArray::STRUCT_TYPE => {
+            fields.enumeration("location", 0x04, MEMORY_ARRAY_LOCATIONS);
+            fields.enumeration("use", 0x05, MEMORY_ARRAY_USES);
+            fields.enumeration("memory_error_correction", 0x06, MEMORY_ERROR_CORRECTIONS);
+            fields.dword("maximum_capacity", 0x07);
+            fields.word("number_of_memory_devices", 0x0D);
+            fields.qword("extended_maximum_capacity", 0x0F);
+        }
+        SMBiosMemoryDevice::STRUCT_TYPE => {
+            fields.word("total_width", 0x08);
+            fields.word("data_width", 0x0A);
+            fields.word("size", 0x0C);
+            fields.enumeration("form_factor", 0x0E, MEMORY_FORM_FACTORS);
+            fields.byte("device_set", 0x0F);
+            fields.enumeration("memory_type", 0x12, MEMORY_TYPES);
+            fields.word("type_detail", 0x13);
+            fields.word("speed", 0x15);
+            fields.byte("attributes", 0x1B);
+            fields.dword("extended_size", 0x1C);
+            fields.word("configured_memory_speed", 0x20);
+            fields.word("minimum_voltage", 0x22);
+            fields.word("maximum_voltage", 0x24);
+            fields.word("configured_voltage", 0x26);
+            fields.enumeration("memory_technology", 0x28, MEMORY_TECHNOLOGIES);
+            fields.word("memory_operating_mode_capability", 0x29);
+            fields.word("module_manufacturer_id", 0x2C);
+            fields.word("module_product_id", 0x2E);
+            fields.word("memory_subsystem_controller_manufacturer_id", 0x30);
+            fields.word("memory_subsystem_controller_product_id", 0x32);
+            fields.qword("non_volatile_size", 0x34);
+            fields.qword("volatile_size", 0x3C);
+            fields.qword("cache_size", 0x44);
+            fields.qword("logical_size", 0x4C);
+            fields.dword("extended_speed", 0x54);
+            fields.dword("extended_configured_memory_speed", 0x58);
+        }
+        SMBiosMemoryArrayMappedAddress::STRUCT_TYPE => {
+            fields.dword("starting_address", 0x04);
+            fields.dword("ending_address", 0x08);
+            fields.byte("partition_width", 0x0E);
+            fields.qword("extended_starting_address", 0x0F);
+            fields.qword("extended_ending_address", 0x17);
+        }
+        SMBiosMemoryDeviceMappedAddress::STRUCT_TYPE => {
+            fields.dword("starting_address", 0x04);
+            fields.dword("ending_address", 0x08);
+            fields.byte("partition_row_position", 0x10);
+            fields.byte("interleave_position", 0x11);
+            fields.byte("interleaved_data_depth", 0x12);
+            fields.qword("extended_starting_address", 0x13);
+            fields.qword("extended_ending_address", 0x1B);
+        }
+        SMBiosEndOfTable::STRUCT_TYPE => (),
+        _ => return Value::Null,
+    }
+
+    for field in string_fields(parts) {
+        match field {
+            ReferenceField::Single(name, offset) => fields.string(name, offset),
+            ReferenceField::List(name, offsets) => {
+                let strings = offsets
+                    .into_iter()
+                    .map(|offset| fields.string_value(offset))
+                    .collect::<Vec<Value>>();
+                fields.insert(name, Some(strings));
+            }
+        }
+    }
+    for field in handle_fields(parts) {
+        match field {
+            ReferenceField::Single(name, offset) => fields.word(name, offset),
+            ReferenceField::List(name, offsets) => {
+                let handles = offsets
+                    .into_iter()
+                    .filter_map(|offset| parts.get_field_word(offset))
+                    .collect::<Vec<u16>>();
+                fields.insert(name, Some(handles));
+            }
+        }
+    }
+
+    Value::Object(fields.map)
+}
+
+/// Collects the fields of a structure present within its length
+struct Fields<'a> {
+    parts: &'a UndefinedStruct,
+    map: Map<String, Value>,
+}
+
+impl<'a> Fields<'a> {
+    fn new(parts: &'a UndefinedStruct) -> Self {
+        Self {
+            parts,
+            map: Map::new(),
+        }
+    }
+
+    fn insert<T: Into<Value>>(&mut self, name: &str, value: Option<T>) {
+        if let Some(value) = value {
+            self.map.insert(name.into(), value.into());
+        }
+    }
+
+    fn byte(&mut self, name: &str, offset: usize) {
+        self.insert(name, self.parts.get_field_byte(offset));
+    }
+
+    fn word(&mut self, name: &str, offset: usize) {
+        self.insert(name, self.parts.get_field_word(offset));
+    }
+
+    fn dword(&mut self, name: &str, offset: usize) {
+        self.insert(name, self.parts.get_field_dword(offset));
+    }
+
+    fn qword(&mut self, name: &str, offset: usize) {
+        self.insert(name, self.parts.get_field_qword(offset));
+    }
+
+    fn string(&mut self, name: &str, offset: usize) {
+        let value = self
+            .parts
+            .get_field_byte(offset)
+            .map(|_| self.string_value(offset));
+        self.insert(name, value);
+    }
+
+    /// The string referenced by the STRING field at `offset`, null for no string
+    fn string_value(&self, offset: usize) -> Value {
+        self.parts
+            .get_field_byte(offset)
+            .and_then(|index| self.parts.strings.get_string(index))
+            .map_or(Value::Null, Value::String)
+    }
+
+    fn uuid(&mut self, name: &str, offset: usize) {
+        let value = self
+            .parts
+            .get_field_data(offset, offset + 0x10)
+            .and_then(|raw| SystemUuidData::try_from(raw).ok())
+            .map(|uuid| match uuid {
+                SystemUuidData::Uuid(uuid) => Value::String(format!("{}", uuid)),
+                _ => Value::Null,
+            });
+        self.insert(name, value);
+    }
+
+    fn enumeration(&mut self, name: &str, offset: usize, names: &[(u16, &'static str)]) {
+        let value = self
+            .parts
+            .get_field_byte(offset)
+            .map(|raw| enumeration_value(raw.into(), value_name(names, raw.into())));
+        self.insert(name, value);
+    }
+
+    fn enumeration_word(
+        &mut self,
+        name: &str,
+        offset: usize,
+        names: fn(u16) -> Option<&'static str>,
+    ) {
+        let value = self
+            .parts
+            .get_field_word(offset)
+            .map(|raw| enumeration_value(raw, names(raw)));
+        self.insert(name, value);
+    }
+}
+
+/// The raw value and name of an enumerated field
+fn enumeration_value(raw: u16, name: Option<&'static str>) -> Value {
+    json!({
+        "raw": raw,
+        "value": name,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{SMBiosTableBuilder, StructBuilder, SystemInformationBuilder};
+    use crate::core::SMBiosVersion;
+
+    #[test]
+    fn test_to_json_value() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add(
+                SystemInformationBuilder::new()
+                    .manufacturer("Manufacturer")
+                    .uuid([
+                        0xE7, 0x0F, 0x36, 0x00, 0xD5, 0xD4, 0xE5, 0x11, 0x9C, 0x43, 0xBC, 0x00,
+                        0x00, 0xF0, 0x00, 0x00,
+                    ])
+                    .wakeup_type(0x06),
+            )
+            .unwrap();
+        // OEM structure without documented fields
+        builder
+            .add(StructBuilder::new(0x80).byte(0xAA).string("OEM"))
+            .unwrap();
+        let value = builder.build().unwrap().to_json_value();
+
+        assert_eq!(value["schema_version"], 1);
+        assert_eq!(
+            value["smbios_version"],
+            json!({ "major": 3, "minor": 4, "revision": 0 })
+        );
+
+        let system_information = &value["structures"][0];
+        assert_eq!(system_information["type"], 1);
+        assert_eq!(system_information["name"], "System Information");
+        assert_eq!(system_information["handle"], 0);
+        assert_eq!(system_information["strings"], json!(["Manufacturer"]));
+        let fields = &system_information["fields"];
+        assert_eq!(fields["manufacturer"], "Manufacturer");
+        assert_eq!(fields["product_name"], Value::Null);
+        assert_eq!(fields["uuid"], "00360FE7-D4D5-11E5-9C43-BC0000F00000");
+        assert_eq!(
+            fields["wake_up_type"],
+            json!({ "raw": 6, "value": "PowerSwitch" })
+        );
+        assert_eq!(fields["family"], Value::Null);
+
+        assert_eq!(
+            value["structures"][1],
+            json!({
+                "type": 0x80,
+                "name": "OEM-specific",
+                "handle": 1,
+                "length": 6,
+                "fields": null,
+                "strings": ["OEM"],
+                "data": "AA01",
+            })
+        );
+        assert_eq!(value["structures"][2]["name"], "End-of-Table");
+        assert_eq!(value["structures"][2]["fields"], json!({}));
+    }
+}
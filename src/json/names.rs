@@ -0,0 +1,912 @@
+//! Names of the values of enumerated fields.
+//!
+//! The names are part of the JSON shape emitted by
+//! [SMBiosData::to_json_value](crate::SMBiosData::to_json_value) and are kept
+//! here, apart from the library's enumerations, so that a renamed variant does
+//! not change the output. Each table is sorted by raw value.
+
+/// Wake-up Type (System Information, offset 18h)
+pub(super) const WAKE_UP_TYPES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "ApmTimer"),
+    (0x04, "ModemRing"),
+    (0x05, "LanRemote"),
+    (0x06, "PowerSwitch"),
+    (0x07, "PciPme"),
+    (0x08, "ACPowerRestored"),
+];
+
+/// Board Type (Baseboard Information, offset 0Dh)
+pub(super) const BOARD_TYPES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "ServerBlade"),
+    (0x04, "ConnectivitySwitch"),
+    (0x05, "SystemManagementModule"),
+    (0x06, "ProcessorModule"),
+    (0x07, "IOModule"),
+    (0x08, "MemoryModule"),
+    (0x09, "Daughterboard"),
+    (0x0A, "Motherboard"),
+    (0x0B, "ProcessorMemoryModule"),
+    (0x0C, "ProcessorIOModule"),
+    (0x0D, "InterconnectBoard"),
+];
+
+/// Type (System Enclosure or Chassis, offset 05h), including the chassis lock bit
+pub(super) const CHASSIS_TYPES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "Desktop"),
+    (0x04, "LowProfileDesktop"),
+    (0x05, "PizzaBox"),
+    (0x06, "MiniTower"),
+    (0x07, "Tower"),
+    (0x08, "Portable"),
+    (0x09, "Laptop"),
+    (0x0A, "Notebook"),
+    (0x0B, "HandHeld"),
+    (0x0C, "DockingStation"),
+    (0x0D, "AllInOne"),
+    (0x0E, "SubNotebook"),
+    (0x0F, "SpaceSaving"),
+    (0x10, "LunchBox"),
+    (0x11, "MainServerChassis"),
+    (0x12, "ExpansionChassis"),
+    (0x13, "SubChassis"),
+    (0x14, "BusExpansionChassis"),
+    (0x15, "PeripheralChassis"),
+    (0x16, "RaidChassis"),
+    (0x17, "RackMountChassis"),
+    (0x18, "SealedCasePC"),
+    (0x19, "MultiSystemChassis"),
+    (0x1A, "CompactPci"),
+    (0x1B, "AdvancedTca"),
+    (0x1C, "Blade"),
+    (0x1D, "BladeEnclosure"),
+    (0x1E, "Tablet"),
+    (0x1F, "Convertible"),
+    (0x20, "Detachable"),
+    (0x21, "IoTGateway"),
+    (0x22, "EmbeddedPC"),
+    (0x23, "MiniPC"),
+    (0x24, "StickPC"),
+    (0x81, "Other"),
+    (0x82, "Unknown"),
+    (0x83, "Desktop"),
+    (0x84, "LowProfileDesktop"),
+    (0x85, "PizzaBox"),
+    (0x86, "MiniTower"),
+    (0x87, "Tower"),
+    (0x88, "Portable"),
+    (0x89, "Laptop"),
+    (0x8A, "Notebook"),
+    (0x8B, "HandHeld"),
+    (0x8C, "DockingStation"),
+    (0x8D, "AllInOne"),
+    (0x8E, "SubNotebook"),
+    (0x8F, "SpaceSaving"),
+    (0x90, "LunchBox"),
+    (0x91, "MainServerChassis"),
+    (0x92, "ExpansionChassis"),
+    (0x93, "SubChassis"),
+    (0x94, "BusExpansionChassis"),
+    (0x95, "PeripheralChassis"),
+    (0x96, "RaidChassis"),
+    (0x97, "RackMountChassis"),
+    (0x98, "SealedCasePC"),
+    (0x99, "MultiSystemChassis"),
+    (0x9A, "CompactPci"),
+    (0x9B, "AdvancedTca"),
+    (0x9C, "Blade"),
+    (0x9D, "BladeEnclosure"),
+    (0x9E, "Tablet"),
+    (0x9F, "Convertible"),
+    (0xA0, "Detachable"),
+    (0xA1, "IoTGateway"),
+    (0xA2, "EmbeddedPC"),
+    (0xA3, "MiniPC"),
+    (0xA4, "StickPC"),
+];
+
+/// Boot-up, Power Supply and Thermal State (System Enclosure or Chassis)
+pub(super) const CHASSIS_STATES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "Safe"),
+    (0x04, "Warning"),
+    (0x05, "Critical"),
+    (0x06, "NonRecoverable"),
+];
+
+/// Security Status (System Enclosure or Chassis, offset 0Ch)
+pub(super) const CHASSIS_SECURITY_STATUSES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "StatusNone"),
+    (0x04, "ExternalInterfaceLockedOut"),
+    (0x05, "ExternalInterfaceEnabled"),
+];
+
+/// Processor Type (Processor Information, offset 05h)
+pub(super) const PROCESSOR_TYPES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "CentralProcessor"),
+    (0x04, "MathProcessor"),
+    (0x05, "DspProcessor"),
+    (0x06, "VideoProcessor"),
+];
+
+/// Processor Family (Processor Information, offset 06h)
+pub(super) const PROCESSOR_FAMILIES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "I8086"),
+    (0x04, "I80286"),
+    (0x05, "Intel386Processor"),
+    (0x06, "Intel486Processor"),
+    (0x07, "I8087"),
+    (0x08, "I80287"),
+    (0x09, "I80387"),
+    (0x0A, "I80487"),
+    (0x0B, "IntelPentiumProcessor"),
+    (0x0C, "PentiumProProcessor"),
+    (0x0D, "PentiumIIProcessor"),
+    (0x0E, "PentiumprocessorwithMMXtechnology"),
+    (0x0F, "IntelCeleronProcessor"),
+    (0x10, "PentiumIIXeonProcessor"),
+    (0x11, "PentiumIIIProcessor"),
+    (0x12, "M1Family"),
+    (0x13, "M2Family"),
+    (0x14, "IntelCeleronMProcessor"),
+    (0x15, "IntelPentium4HTProcessor"),
+    (0x18, "AMDDuronProcessorFamily"),
+    (0x19, "K5Family"),
+    (0x1A, "K6Family"),
+    (0x1B, "K62"),
+    (0x1C, "K63"),
+    (0x1D, "AMDAthlonProcessorFamily"),
+    (0x1E, "AMD29000Family"),
+    (0x1F, "K62Plus"),
+    (0x20, "PowerPCFamily"),
+    (0x21, "PowerPC601"),
+    (0x22, "PowerPC603"),
+    (0x23, "PowerPC603Plus"),
+    (0x24, "PowerPC604"),
+    (0x25, "PowerPC620"),
+    (0x26, "PowerPCx704"),
+    (0x27, "PowerPC750"),
+    (0x28, "IntelCoreDuoProcessor"),
+    (0x29, "IntelCoreDuomobileProcessor"),
+    (0x2A, "IntelCoreSolomobileProcessor"),
+    (0x2B, "IntelAtomProcessor"),
+    (0x2C, "IntelCoreMProcessor"),
+    (0x2D, "IntelCorem3Processor"),
+    (0x2E, "IntelCorem5Processor"),
+    (0x2F, "IntelCorem7Processor"),
+    (0x30, "AlphaFamily"),
+    (0x31, "Alpha21064"),
+    (0x32, "Alpha21066"),
+    (0x33, "Alpha21164"),
+    (0x34, "Alpha21164PC"),
+    (0x35, "Alpha21164a"),
+    (0x36, "Alpha21264"),
+    (0x37, "Alpha21364"),
+    (0x38, "AMDTurionIIUltraDualCoreMobileMProcessorFamily"),
+    (0x39, "AMDTurionIIDualCoreMobileMProcessorFamily"),
+    (0x3A, "AMDAthlonIIDualCoreMProcessorFamily"),
+    (0x3B, "AMDOpteron6100SeriesProcessor"),
+    (0x3C, "AMDOpteron4100SeriesProcessor"),
+    (0x3D, "AMDOpteron6200SeriesProcessor"),
+    (0x3E, "AMDOpteron4200SeriesProcessor"),
+    (0x3F, "AMDFXSeriesProcessor"),
+    (0x40, "MIPSFamily"),
+    (0x41, "MIPSR4000"),
+    (0x42, "MIPSR4200"),
+    (0x43, "MIPSR4400"),
+    (0x44, "MIPSR4600"),
+    (0x45, "MIPSR10000"),
+    (0x46, "AMDCSeriesProcessor"),
+    (0x47, "AMDESeriesProcessor"),
+    (0x48, "AMDASeriesProcessor"),
+    (0x49, "AMDGSeriesProcessor"),
+    (0x4A, "AMDZSeriesProcessor"),
+    (0x4B, "AMDRSeriesProcessor"),
+    (0x4C, "AMDOpteron4300SeriesProcessor"),
+    (0x4D, "AMDOpteron6300SeriesProcessor"),
+    (0x4E, "AMDOpteron3300SeriesProcessor"),
+    (0x4F, "AMDFireProSeriesProcessor"),
+    (0x50, "SPARCFamily"),
+    (0x51, "SuperSPARC"),
+    (0x52, "MicroSparcii"),
+    (0x53, "MicroSparciiep"),
+    (0x54, "UltraSPARC"),
+    (0x55, "UltraSPARCII"),
+    (0x56, "UltraSPARCIii"),
+    (0x57, "UltraSPARCIII"),
+    (0x58, "UltraSPARCIIIi"),
+    (0x60, "M68040Family"),
+    (0x61, "M68xxx"),
+    (0x62, "M68000"),
+    (0x63, "M68010"),
+    (0x64, "M68020"),
+    (0x65, "M68030"),
+    (0x66, "AMDAthlonX4QuadCoreProcessorFamily"),
+    (0x67, "AMDOpteronX1000SeriesProcessor"),
+    (0x68, "AMDOpteronX2000SeriesAPU"),
+    (0x69, "AMDOpteronASeriesProcessor"),
+    (0x6A, "AMDOpteronX3000SeriesAPU"),
+    (0x6B, "AMDZenProcessorFamily"),
+    (0x70, "HobbitFamily"),
+    (0x78, "CrusoeTM5000Family"),
+    (0x79, "CrusoeTM3000Family"),
+    (0x7A, "EfficeonTM8000Family"),
+    (0x80, "Weitek"),
+    (0x82, "Itaniumprocessor"),
+    (0x83, "AMDAthlon64ProcessorFamily"),
+    (0x84, "AMDOpteronProcessorFamily"),
+    (0x85, "AMDSempronProcessorFamily"),
+    (0x86, "AMDTurion64MobileTechnology"),
+    (0x87, "DualCoreAMDOpteronProcessorFamily"),
+    (0x88, "AMDAthlon64X2DualCoreProcessorFamily"),
+    (0x89, "AMDTurion64X2MobileTechnology"),
+    (0x8A, "QuadCoreAMDOpteronProcessorFamily"),
+    (0x8B, "ThirdGenerationAMDOpteronProcessorFamily"),
+    (0x8C, "AMDPhenomFXQuadCoreProcessorFamily"),
+    (0x8D, "AMDPhenomX4QuadCoreProcessorFamily"),
+    (0x8E, "AMDPhenomX2DualCoreProcessorFamily"),
+    (0x8F, "AMDAthlonX2DualCoreProcessorFamily"),
+    (0x90, "PARISCFamily"),
+    (0x91, "PARISC8500"),
+    (0x92, "PARISC8000"),
+    (0x93, "PARISC7300LC"),
+    (0x94, "PARISC7200"),
+    (0x95, "PARISC7100LC"),
+    (0x96, "PARISC7100"),
+    (0xA0, "V30Family"),
+    (0xA1, "QuadCoreIntelXeonProcessor3200Series"),
+    (0xA2, "DualCoreIntelXeonProcessor3000Series"),
+    (0xA3, "QuadCoreIntelXeonProcessor5300Series"),
+    (0xA4, "DualCoreIntelXeonProcessor5100Series"),
+    (0xA5, "DualCoreIntelXeonProcessor5000Series"),
+    (0xA6, "DualCoreIntelXeonProcessorLV"),
+    (0xA7, "DualCoreIntelXeonProcessorULV"),
+    (0xA8, "DualCoreIntelXeonProcessor7100Series"),
+    (0xA9, "QuadCoreIntelXeonProcessor5400Series"),
+    (0xAA, "QuadCoreIntelXeonProcessor"),
+    (0xAB, "DualCoreIntelXeonProcessor5200Series"),
+    (0xAC, "DualCoreIntelXeonProcessor7200Series"),
+    (0xAD, "QuadCoreIntelXeonProcessor7300Series"),
+    (0xAE, "QuadCoreIntelXeonProcessor7400Series"),
+    (0xAF, "MultiCoreIntelXeonProcessor7400Series"),
+    (0xB0, "PentiumIIIXeonProcessor"),
+    (0xB1, "PentiumIIIProcessorwithIntelSpeedStepTechnology"),
+    (0xB2, "Pentium4Processor"),
+    (0xB3, "IntelXeonProcessor"),
+    (0xB4, "AS400Family"),
+    (0xB5, "IntelXeonProcessorMP"),
+    (0xB6, "AMDAthlonXPProcessorFamily"),
+    (0xB7, "AMDAthlonMPProcessorFamily"),
+    (0xB8, "IntelItanium2Processor"),
+    (0xB9, "IntelPentiumMProcessor"),
+    (0xBA, "IntelCeleronDProcessor"),
+    (0xBB, "IntelPentiumDProcessor"),
+    (0xBC, "IntelPentiumProcessorExtremeEdition"),
+    (0xBD, "IntelCoreSoloProcessor"),
+    (0xBF, "IntelCore2DuoProcessor"),
+    (0xC0, "IntelCore2SoloProcessor"),
+    (0xC1, "IntelCore2ExtremeProcessor"),
+    (0xC2, "IntelCore2QuadProcessor"),
+    (0xC3, "IntelCore2ExtremeMobileProcessor"),
+    (0xC4, "IntelCore2DuoMobileProcessor"),
+    (0xC5, "IntelCore2SoloMobileProcessor"),
+    (0xC6, "IntelCorei7Processor"),
+    (0xC7, "DualCoreIntelCeleronProcessor"),
+    (0xC8, "IBM390Family"),
+    (0xC9, "G4"),
+    (0xCA, "G5"),
+    (0xCB, "ESA390G6"),
+    (0xCC, "ZArchitecturebase"),
+    (0xCD, "IntelCorei5processor"),
+    (0xCE, "IntelCorei3processor"),
+    (0xCF, "IntelCorei9processor"),
+    (0xD2, "VIAC7MProcessorFamily"),
+    (0xD3, "VIAC7DProcessorFamily"),
+    (0xD4, "VIAC7ProcessorFamily"),
+    (0xD5, "VIAEdenProcessorFamily"),
+    (0xD6, "MultiCoreIntelXeonProcessor"),
+    (0xD7, "DualCoreIntelXeonProcessor3xxxSeries"),
+    (0xD8, "QuadCoreIntelXeonProcessor3xxxSeries"),
+    (0xD9, "VIANanoProcessorFamily"),
+    (0xDA, "DualCoreIntelXeonProcessor5xxxSeries"),
+    (0xDB, "QuadCoreIntelXeonProcessor5xxxSeries"),
+    (0xDD, "DualCoreIntelXeonProcessor7xxxSeries"),
+    (0xDE, "QuadCoreIntelXeonProcessor7xxxSeries"),
+    (0xDF, "MultiCoreIntelXeonProcessor7xxxSeries"),
+    (0xE0, "MultiCoreIntelXeonProcessor3400Series"),
+    (0xE4, "AMDOpteron3000SeriesProcessor"),
+    (0xE5, "AMDSempronIIProcessor"),
+    (0xE6, "EmbeddedAMDOpteronQuadCoreProcessorFamily"),
+    (0xE7, "AMDPhenomTripleCoreProcessorFamily"),
+    (0xE8, "AMDTurionUltraDualCoreMobileProcessorFamily"),
+    (0xE9, "AMDTurionDualCoreMobileProcessorFamily"),
+    (0xEA, "AMDAthlonDualCoreProcessorFamily"),
+    (0xEB, "AMDSempronSIProcessorFamily"),
+    (0xEC, "AMDPhenomIIProcessorFamily"),
+    (0xED, "AMDAthlonIIProcessorFamily"),
+    (0xEE, "SixCoreAMDOpteronProcessorFamily"),
+    (0xEF, "AMDSempronMProcessorFamily"),
+    (0xFA, "I860"),
+    (0xFB, "I960"),
+    (0xFE, "SeeProcessorFamily2"),
+];
+
+/// Processor Family 2 (Processor Information, offset 28h) beyond
+/// the values of [PROCESSOR_FAMILIES]
+pub(super) const PROCESSOR_FAMILIES_2: &[(u16, &str)] = &[
+    (0x100, "ARMv7"),
+    (0x101, "ARMv8"),
+    (0x102, "ARMv9"),
+    (0x104, "SH3"),
+    (0x105, "SH4"),
+    (0x118, "ARM"),
+    (0x119, "StrongARM"),
+    (0x12C, "Cyrix6x86"),
+    (0x12D, "MediaGX"),
+    (0x12E, "MII"),
+    (0x140, "WinChip"),
+    (0x15E, "DSP"),
+    (0x1F4, "VideoProcessor"),
+    (0x200, "RISCVRV32"),
+    (0x201, "RISCVRV64"),
+    (0x202, "RISCVRV128"),
+    (0x258, "LoongArch"),
+    (0x259, "Loongson1ProcessorFamily"),
+    (0x25A, "Loongson2ProcessorFamily"),
+    (0x25B, "Loongson3ProcessorFamily"),
+    (0x25C, "Loongson2KProcessorFamily"),
+    (0x25D, "Loongson3AProcessorFamily"),
+    (0x25E, "Loongson3BProcessorFamily"),
+    (0x25F, "Loongson3CProcessorFamily"),
+    (0x260, "Loongson3DProcessorFamily"),
+    (0x261, "Loongson3EProcessorFamily"),
+    (0x262, "DualCoreLoongson2K2xxxSeries"),
+    (0x26C, "QuadCoreLoongson3A5xxxSeries"),
+    (0x26D, "MultiCoreLoongson3A5xxxSeries"),
+    (0x26E, "QuadCoreLoongson3B5xxxSeries"),
+    (0x26F, "MultiCoreLoongson3B5xxxSeries"),
+    (0x270, "MultiCoreLoongson3C5xxxSeries"),
+    (0x271, "MultiCoreLoongson3D5xxxSeries"),
+    (0x300, "IntelCore3"),
+    (0x301, "IntelCore5"),
+    (0x302, "IntelCore7"),
+    (0x303, "IntelCore9"),
+    (0x304, "IntelCoreUltra3"),
+    (0x305, "IntelCoreUltra5"),
+    (0x306, "IntelCoreUltra7"),
+    (0x307, "IntelCoreUltra9"),
+];
+
+/// Processor Upgrade (Processor Information, offset 19h)
+pub(super) const PROCESSOR_UPGRADES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "DaughterBoard"),
+    (0x04, "ZIFSocket"),
+    (0x05, "ReplaceablePiggyBack"),
+    (0x06, "NoUpgrade"),
+    (0x07, "LIFSocket"),
+    (0x08, "Slot1"),
+    (0x09, "Slot2"),
+    (0x0A, "PinSocket370"),
+    (0x0B, "SlotA"),
+    (0x0C, "SlotM"),
+    (0x0D, "Socket423"),
+    (0x0E, "SocketASocket462"),
+    (0x0F, "Socket478"),
+    (0x10, "Socket754"),
+    (0x11, "Socket940"),
+    (0x12, "Socket939"),
+    (0x13, "SocketmPGA604"),
+    (0x14, "SocketLGA771"),
+    (0x15, "SocketLGA775"),
+    (0x16, "SocketS1"),
+    (0x17, "SocketAM2"),
+    (0x18, "SocketF1207"),
+    (0x19, "SocketLGA1366"),
+    (0x1A, "SocketG34"),
+    (0x1B, "SocketAM3"),
+    (0x1C, "SocketC32"),
+    (0x1D, "SocketLGA1156"),
+    (0x1E, "SocketLGA1567"),
+    (0x1F, "SocketPGA988A"),
+    (0x20, "SocketBGA1288"),
+    (0x21, "SocketrPGA988B"),
+    (0x22, "SocketBGA1023"),
+    (0x23, "SocketBGA1224"),
+    (0x24, "SocketLGA1155"),
+    (0x25, "SocketLGA1356"),
+    (0x26, "SocketLGA2011"),
+    (0x27, "SocketFS1"),
+    (0x28, "SocketFS2"),
+    (0x29, "SocketFM1"),
+    (0x2A, "SocketFM2"),
+    (0x2B, "SocketLGA2011_3"),
+    (0x2C, "SocketLGA1356_3"),
+    (0x2D, "SocketLGA1150"),
+    (0x2E, "SocketBGA1168"),
+    (0x2F, "SocketBGA1234"),
+    (0x30, "SocketBGA1364"),
+    (0x31, "SocketAM4"),
+    (0x32, "SocketLGA1151"),
+    (0x33, "SocketBGA1356"),
+    (0x34, "SocketBGA1440"),
+    (0x35, "SocketBGA1515"),
+    (0x36, "SocketLGA3647_1"),
+    (0x37, "SocketSP3"),
+    (0x38, "SocketSP3r23"),
+    (0x39, "SocketLGA2066"),
+    (0x3A, "SocketBGA1392"),
+    (0x3B, "SocketBGA1510"),
+    (0x3C, "SocketBGA1528"),
+    (0x3D, "SocketLGA4189"),
+    (0x3E, "SocketLGA1200"),
+    (0x3F, "SocketLGA4677"),
+    (0x40, "SocketLGA1700"),
+    (0x41, "SocketBGA1744"),
+    (0x42, "SocketBGA1781"),
+    (0x43, "SocketBGA1211"),
+    (0x44, "SocketBGA2422"),
+    (0x45, "SocketLGA1211"),
+    (0x46, "SocketLGA2422"),
+    (0x47, "SocketLGA5773"),
+    (0x48, "SocketBGA5773"),
+    (0x49, "SocketAM5"),
+    (0x4A, "SocketSP5"),
+    (0x4B, "SocketSP6"),
+    (0x4C, "SocketBGA883"),
+    (0x4D, "SocketBGA1190"),
+    (0x4E, "SocketBGA4129"),
+    (0x4F, "SocketLGA4710"),
+    (0x50, "SocketLGA7529"),
+    (0x51, "SocketBGA1964"),
+    (0x52, "SocketBGA1792"),
+    (0x53, "SocketBGA2049"),
+    (0x54, "SocketBGA2551"),
+    (0x55, "SocketLGA1851"),
+    (0x56, "SocketBGA2114"),
+    (0x57, "SocketBGA2833"),
+];
+
+/// Error Correction Type (Cache Information, offset 10h)
+pub(super) const ERROR_CORRECTION_TYPES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "NoCorrection"),
+    (0x04, "Parity"),
+    (0x05, "SingleBitEcc"),
+    (0x06, "MultiBitEcc"),
+];
+
+/// System Cache Type (Cache Information, offset 11h)
+pub(super) const SYSTEM_CACHE_TYPES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "Instruction"),
+    (0x04, "Data"),
+    (0x05, "Unified"),
+];
+
+/// Associativity (Cache Information, offset 12h)
+pub(super) const CACHE_ASSOCIATIVITIES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "DirectMapped"),
+    (0x04, "SetAssociative2Way"),
+    (0x05, "SetAssociative4Way"),
+    (0x06, "FullyAssociative"),
+    (0x07, "SetAssociative8Way"),
+    (0x08, "SetAssociative16Way"),
+    (0x09, "SetAssociative12Way"),
+    (0x0A, "SetAssociative24Way"),
+    (0x0B, "SetAssociative32Way"),
+    (0x0C, "SetAssociative48Way"),
+    (0x0D, "SetAssociative64Way"),
+    (0x0E, "SetAssociative20Way"),
+];
+
+/// Internal and External Connector Type (Port Connector Information)
+pub(super) const CONNECTOR_TYPES: &[(u16, &str)] = &[
+    (0x00, "NoConnector"),
+    (0x01, "Centronics"),
+    (0x02, "MiniCentronics"),
+    (0x03, "Proprietary"),
+    (0x04, "DB25PinMale"),
+    (0x05, "DB25PinFemale"),
+    (0x06, "DB15PinMale"),
+    (0x07, "DB15PinFemale"),
+    (0x08, "DB9PinMale"),
+    (0x09, "DB9PinFemale"),
+    (0x0A, "RJ11"),
+    (0x0B, "RJ45"),
+    (0x0C, "MiniScsi50Pin"),
+    (0x0D, "MiniDin"),
+    (0x0E, "MicroDin"),
+    (0x0F, "Ps2"),
+    (0x10, "Infrared"),
+    (0x11, "HpHil"),
+    (0x12, "AccessBusUsb"),
+    (0x13, "SsaScsi"),
+    (0x14, "CircularDin8Male"),
+    (0x15, "CircularDin8Female"),
+    (0x16, "OnBoardIde"),
+    (0x17, "OnBoardFloppy"),
+    (0x18, "DualInline9Pin"),
+    (0x19, "DualInline25Pin"),
+    (0x1A, "DualInline50Pin"),
+    (0x1B, "DualInline68Pin"),
+    (0x1C, "OnBoardSoundInputCDRom"),
+    (0x1D, "MiniCentronicsType14"),
+    (0x1E, "MiniCentronicsType26"),
+    (0x1F, "MiniJackHeadphones"),
+    (0x20, "Bnc"),
+    (0x21, "Port1394"),
+    (0x22, "SasSataPlugReceptacle"),
+    (0x23, "UsbTypeCReceptacle"),
+    (0xA0, "PC98"),
+    (0xA1, "PC98Hireso"),
+    (0xA2, "PCH98"),
+    (0xA3, "PC98Note"),
+    (0xA4, "PC98Full"),
+    (0xFF, "Other"),
+];
+
+/// Port Type (Port Connector Information, offset 08h)
+pub(super) const PORT_TYPES: &[(u16, &str)] = &[
+    (0x00, "NoPort"),
+    (0x01, "ParallelPortXTATCompatible"),
+    (0x02, "ParallelPortPS2"),
+    (0x03, "ParallelPortEcp"),
+    (0x04, "ParallelPortEpp"),
+    (0x05, "ParallelPortEcpEpp"),
+    (0x06, "SerialPortXTATCompatible"),
+    (0x07, "SerialPort16450Compatible"),
+    (0x08, "SerialPort16550Compatible"),
+    (0x09, "SerialPort16550ACompatible"),
+    (0x0A, "ScsiPort"),
+    (0x0B, "MidiPort"),
+    (0x0C, "JoyStickPort"),
+    (0x0D, "KeyboardPort"),
+    (0x0E, "MousePort"),
+    (0x0F, "SsaScsi"),
+    (0x10, "Usb"),
+    (0x11, "Firewire"),
+    (0x12, "PcmciaTypeI"),
+    (0x13, "PcmciaTypeII"),
+    (0x14, "PcmciaTypeIii"),
+    (0x15, "Cardbus"),
+    (0x16, "AccessBusPort"),
+    (0x17, "ScsiII"),
+    (0x18, "ScsiWide"),
+    (0x19, "PC98"),
+    (0x1A, "PC98Hireso"),
+    (0x1B, "PCH98"),
+    (0x1C, "VideoPort"),
+    (0x1D, "AudioPort"),
+    (0x1E, "ModemPort"),
+    (0x1F, "NetworkPort"),
+    (0x20, "Sata"),
+    (0x21, "Sas"),
+    (0x22, "Mfdp"),
+    (0x23, "Thunderbolt"),
+    (0xA0, "Port8251Compatible"),
+    (0xA1, "Port8251FifoCompatible"),
+    (0xFF, "Other"),
+];
+
+/// Slot Type (System Slots, offset 05h)
+pub(super) const SLOT_TYPES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "Isa"),
+    (0x04, "Mca"),
+    (0x05, "Eisa"),
+    (0x06, "Pci"),
+    (0x07, "Pcmcia"),
+    (0x08, "VlVesa"),
+    (0x09, "Proprietary"),
+    (0x0A, "ProcessorCardSlot"),
+    (0x0B, "ProprietaryMemoryCardSlot"),
+    (0x0C, "IORiserCardSlot"),
+    (0x0D, "NuBus"),
+    (0x0E, "Pci66MhzCapable"),
+    (0x0F, "Agp(X1)"),
+    (0x10, "Agp(X2)"),
+    (0x11, "Agp(X4)"),
+    (0x12, "PciX"),
+    (0x13, "Agp(X8)"),
+    (0x14, "M2(M2Socket1DP)"),
+    (0x15, "M2(M2Socket1SD)"),
+    (0x16, "M2(M2Socket2)"),
+    (0x17, "M2(M2Socket3)"),
+    (0x18, "Mxm(MxmTypeI)"),
+    (0x19, "Mxm(MxmTypeII)"),
+    (0x1A, "Mxm(MxmTypeIIIStandard)"),
+    (0x1B, "Mxm(MxmTypeIIIHE)"),
+    (0x1C, "Mxm(MxmTypeIV)"),
+    (0x1D, "Mxm(Mxm3TypeA)"),
+    (0x1E, "Mxm(Mxm3TypeB)"),
+    (0x1F, "PciExpress(PCIExpressGen2, Sff8639)"),
+    (0x20, "PciExpress(PCIExpressGen3, Sff8639)"),
+    (0x21, "PciExpress(Undefined, PciExpressMini52WithKeepouts)"),
+    (
+        0x22,
+        "PciExpress(Undefined, PciExpressMini52WithoutKeepouts)",
+    ),
+    (0x23, "PciExpress(Undefined, PciExpressMini76)"),
+    (0x24, "PciExpress(PCIExpressGen4, Sff8639)"),
+    (0x25, "PciExpress(PCIExpressGen5, Sff8639)"),
+    (0x26, "OcpNic30SmallFormFactor"),
+    (0x27, "OcpNic30LargeFormFactor"),
+    (0x28, "OcpNicPriorTo30"),
+    (0x30, "CxlFlexbus1"),
+    (0xA0, "PC98C20"),
+    (0xA1, "PC98C24"),
+    (0xA2, "PC98E"),
+    (0xA3, "PC98LocalBus"),
+    (0xA4, "PC98Card"),
+    (0xA5, "PciExpress(PCIExpressGen1, UndefinedSlotWidth)"),
+    (0xA6, "PciExpress(PCIExpressGen1, X1)"),
+    (0xA7, "PciExpress(PCIExpressGen1, X2)"),
+    (0xA8, "PciExpress(PCIExpressGen1, X4)"),
+    (0xA9, "PciExpress(PCIExpressGen1, X8)"),
+    (0xAA, "PciExpress(PCIExpressGen1, X16)"),
+    (0xAB, "PciExpress(PCIExpressGen2, UndefinedSlotWidth)"),
+    (0xAC, "PciExpress(PCIExpressGen2, X1)"),
+    (0xAD, "PciExpress(PCIExpressGen2, X2)"),
+    (0xAE, "PciExpress(PCIExpressGen2, X4)"),
+    (0xAF, "PciExpress(PCIExpressGen2, X8)"),
+    (0xB0, "PciExpress(PCIExpressGen2, X16)"),
+    (0xB1, "PciExpress(PCIExpressGen3, UndefinedSlotWidth)"),
+    (0xB2, "PciExpress(PCIExpressGen3, X1)"),
+    (0xB3, "PciExpress(PCIExpressGen3, X2)"),
+    (0xB4, "PciExpress(PCIExpressGen3, X4)"),
+    (0xB5, "PciExpress(PCIExpressGen3, X8)"),
+    (0xB6, "PciExpress(PCIExpressGen3, X16)"),
+    (0xB8, "PciExpress(PCIExpressGen4, UndefinedSlotWidth)"),
+    (0xB9, "PciExpress(PCIExpressGen4, X1)"),
+    (0xBA, "PciExpress(PCIExpressGen4, X2)"),
+    (0xBB, "PciExpress(PCIExpressGen4, X4)"),
+    (0xBC, "PciExpress(PCIExpressGen4, X8)"),
+    (0xBD, "PciExpress(PCIExpressGen4, X16)"),
+    (0xBE, "PciExpress(PCIExpressGen5, UndefinedSlotWidth)"),
+    (0xBF, "PciExpress(PCIExpressGen5, X1)"),
+    (0xC0, "PciExpress(PCIExpressGen5, X2)"),
+    (0xC1, "PciExpress(PCIExpressGen5, X4)"),
+    (0xC2, "PciExpress(PCIExpressGen5, X8)"),
+    (0xC3, "PciExpress(PCIExpressGen5, X16)"),
+    (0xC4, "PciExpress(PCIExpressGen6, UndefinedSlotWidth)"),
+    (0xC5, "EnterpriseAndDataCenter1UE1"),
+    (0xC6, "EnterpriseAndDataCenter3InE3"),
+];
+
+/// Slot Data Bus Width (System Slots, offset 06h)
+pub(super) const SLOT_WIDTHS: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "Bit8"),
+    (0x04, "Bit16"),
+    (0x05, "Bit32"),
+    (0x06, "Bit64"),
+    (0x07, "Bit128"),
+    (0x08, "X1"),
+    (0x09, "X2"),
+    (0x0A, "X4"),
+    (0x0B, "X8"),
+    (0x0C, "X12"),
+    (0x0D, "X16"),
+    (0x0E, "X32"),
+];
+
+/// Current Usage (System Slots, offset 07h)
+pub(super) const SLOT_CURRENT_USAGES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "Available"),
+    (0x04, "InUse"),
+    (0x05, "Unavailable"),
+];
+
+/// Slot Length (System Slots, offset 08h)
+pub(super) const SLOT_LENGTHS: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "ShortLength"),
+    (0x04, "LongLength"),
+    (0x05, "DriveFormFactor25"),
+    (0x06, "DriveFormFactor35"),
+];
+
+/// Slot Height (System Slots)
+pub(super) const SLOT_HEIGHTS: &[(u16, &str)] = &[
+    (0x00, "NotApplicable"),
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "FullHeight"),
+    (0x04, "LowProfile"),
+];
+
+/// Location (Physical Memory Array, offset 04h)
+pub(super) const MEMORY_ARRAY_LOCATIONS: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "SystemBoardOrMotherboard"),
+    (0x04, "IsaAddOnCard"),
+    (0x05, "EisaAddOnCard"),
+    (0x06, "PciAddOnCard"),
+    (0x07, "McaAddOnCard"),
+    (0x08, "PcmciaAddOnCard"),
+    (0x09, "ProprietaryAddOnCard"),
+    (0x0A, "NuBus"),
+    (0xA0, "PC98C20AddOnCard"),
+    (0xA1, "PC98C24AddOnCard"),
+    (0xA2, "PC98EAddOnCard"),
+    (0xA3, "PC98LocalBusAddOnCard"),
+    (0xA4, "CxlFlexbus10AddOnCard"),
+];
+
+/// Use (Physical Memory Array, offset 05h)
+pub(super) const MEMORY_ARRAY_USES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "SystemMemory"),
+    (0x04, "VideoMemory"),
+    (0x05, "FlashMemory"),
+    (0x06, "NonVolatileRam"),
+    (0x07, "CacheMemory"),
+];
+
+/// Memory Error Correction (Physical Memory Array, offset 06h)
+pub(super) const MEMORY_ERROR_CORRECTIONS: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "NoCorrection"),
+    (0x04, "Parity"),
+    (0x05, "SingleBitEcc"),
+    (0x06, "MultiBitEcc"),
+    (0x07, "Crc"),
+];
+
+/// Form Factor (Memory Device, offset 0Eh)
+pub(super) const MEMORY_FORM_FACTORS: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "Simm"),
+    (0x04, "Sip"),
+    (0x05, "Chip"),
+    (0x06, "Dip"),
+    (0x07, "Zip"),
+    (0x08, "ProprietaryCard"),
+    (0x09, "Dimm"),
+    (0x0A, "Tsop"),
+    (0x0B, "RowOfChips"),
+    (0x0C, "Rimm"),
+    (0x0D, "Sodimm"),
+    (0x0E, "Srimm"),
+    (0x0F, "Fbdimm"),
+    (0x10, "Die"),
+    (0x11, "Camm"),
+];
+
+/// Memory Type (Memory Device, offset 12h)
+pub(super) const MEMORY_TYPES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "Dram"),
+    (0x04, "Edram"),
+    (0x05, "Vram"),
+    (0x06, "Sram"),
+    (0x07, "Ram"),
+    (0x08, "Rom"),
+    (0x09, "Flash"),
+    (0x0A, "Eeprom"),
+    (0x0B, "Feprom"),
+    (0x0C, "Eprom"),
+    (0x0D, "Cdram"),
+    (0x0E, "ThreeDram"),
+    (0x0F, "Sdram"),
+    (0x10, "Sgram"),
+    (0x11, "Rdram"),
+    (0x12, "Ddr"),
+    (0x13, "Ddr2"),
+    (0x14, "Ddr2Fbdimm"),
+    (0x18, "Ddr3"),
+    (0x19, "Fbd2"),
+    (0x1A, "Ddr4"),
+    (0x1B, "Lpddr"),
+    (0x1C, "Lpddr2"),
+    (0x1D, "Lpddr3"),
+    (0x1E, "Lpddr4"),
+    (0x1F, "LogicalNonVolatileDevice"),
+    (0x20, "Hbm"),
+    (0x21, "Hbm2"),
+    (0x22, "Ddr5"),
+    (0x23, "Lpddr5"),
+    (0x24, "Hbm3"),
+];
+
+/// Memory Technology (Memory Device, offset 28h)
+pub(super) const MEMORY_TECHNOLOGIES: &[(u16, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "Dram"),
+    (0x04, "NvdimmN"),
+    (0x05, "NvdimmF"),
+    (0x06, "NvdimmP"),
+    (0x07, "IntelOptaneDcPersistentMemory"),
+];
+
+/// The name of the `raw` value in the sorted `table`
+pub(super) fn value_name(table: &[(u16, &'static str)], raw: u16) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&raw, |(value, _)| *value)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+/// The name of the Processor Family 2 `raw` value
+pub(super) fn processor_family_2(raw: u16) -> Option<&'static str> {
+    value_name(PROCESSOR_FAMILIES, raw).or_else(|| value_name(PROCESSOR_FAMILIES_2, raw))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_sorted() {
+        for table in [
+            WAKE_UP_TYPES,
+            BOARD_TYPES,
+            CHASSIS_TYPES,
+            CHASSIS_STATES,
+            CHASSIS_SECURITY_STATUSES,
+            PROCESSOR_TYPES,
+            PROCESSOR_FAMILIES,
+            PROCESSOR_FAMILIES_2,
+            PROCESSOR_UPGRADES,
+            ERROR_CORRECTION_TYPES,
+            SYSTEM_CACHE_TYPES,
+            CACHE_ASSOCIATIVITIES,
+            CONNECTOR_TYPES,
+            PORT_TYPES,
+            SLOT_TYPES,
+            SLOT_WIDTHS,
+            SLOT_CURRENT_USAGES,
+            SLOT_LENGTHS,
+            SLOT_HEIGHTS,
+            MEMORY_ARRAY_LOCATIONS,
+            MEMORY_ARRAY_USES,
+            MEMORY_ERROR_CORRECTIONS,
+            MEMORY_FORM_FACTORS,
+            MEMORY_TYPES,
+            MEMORY_TECHNOLOGIES,
+        ]
+        .iter()
+        {
+            assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+        assert!(PROCESSOR_FAMILIES.last().unwrap().0 < PROCESSOR_FAMILIES_2[0].0);
+    }
+
+    #[test]
+    fn test_value_name() {
+        assert_eq!(value_name(WAKE_UP_TYPES, 0x06), Some("PowerSwitch"));
+        assert_eq!(value_name(WAKE_UP_TYPES, 0x09), None);
+        assert_eq!(processor_family_2(0x01), Some("Other"));
+        assert_eq!(processor_family_2(0x101), Some("ARMv8"));
+        assert_eq!(processor_family_2(0xFFFF), None);
+    }
+}
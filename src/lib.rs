@@ -3,36 +3,112 @@
 //! Implements the DMTF [System Management BIOS (SMBIOS) Reference Specification 3.4.0](https://www.dmtf.org/sites/default/files/standards/documents/DSP0134_3.4.0.pdf).
 //!
 //! This library focuses on the tasks involved with reading and interpreting
-//! BIOS data.
+//! BIOS data. Tables can also be synthesized with the [SMBiosTableBuilder]
+//! or edited with [SMBiosDataMut].
+//!
+//! Parsing does not panic on malformed input: a table of arbitrary bytes may
+//! be loaded with [SMBiosData::from_vec_and_version] (or [WinSMBiosData::new])
+//! and each of its structures read, with the fields a structure cannot hold
+//! retrieved as `None`. The fuzz targets of the repository check this for the
+//! parsers and the accessors of the structures they produce; it does not
+//! extend to structures whose public fields were altered by hand.
+//!
+//! Enumerated fields are read as a `*Data` structure, such as
+//! [ProcessorFamilyData], holding both the `raw` value of the field and the
+//! decoded `value`. The `None` variant of each enumeration stands for the
+//! values this library does not know, so values added by later versions of
+//! the specification remain readable through [RawValue::raw_value] and fall
+//! into a single arm of a match on `value`.
 
 #![warn(missing_docs)]
 #![deny(rust_2018_idioms)]
 #![cfg_attr(feature = "no_std", no_std)]
 
+#[cfg(feature = "no_std")]
+extern crate alloc;
+
+#[cfg(all(feature = "rayon", not(feature = "no_std")))]
+mod batch;
+mod builder;
+#[cfg(all(feature = "quick-xml", not(feature = "no_std")))]
+mod cim;
+mod consistency;
 mod core;
 #[cfg(not(feature = "no_std"))]
+mod error;
+#[cfg(not(feature = "no_std"))]
 mod file_io;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(not(feature = "no_std"))]
+mod load;
 #[cfg(not(feature = "no_std"))]
 mod macos;
+mod management_device_tree;
+mod memory_topology;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod processor_topology;
+#[cfg(feature = "serde_json")]
+mod query;
+#[cfg(feature = "serde_json")]
+mod redfish;
 mod structs;
+mod summary;
+#[cfg(feature = "uefi")]
+mod uefi;
 #[cfg(not(feature = "no_std"))]
 mod unix;
 #[cfg(not(feature = "no_std"))]
 mod windows;
 
+pub use consistency::*;
+pub use management_device_tree::*;
+pub use memory_topology::*;
+pub use processor_topology::*;
+#[cfg(feature = "serde_json")]
+pub use query::*;
+#[cfg(feature = "serde_json")]
+pub use redfish::*;
 pub use structs::*;
+pub use summary::*;
 
+#[cfg(all(feature = "rayon", not(feature = "no_std")))]
+pub use batch::*;
+pub use crate::builder::*;
 pub use crate::core::*;
 #[cfg(not(feature = "no_std"))]
+pub use error::*;
+#[cfg(all(feature = "quick-xml", not(feature = "no_std")))]
+pub use cim::*;
+#[cfg(not(feature = "no_std"))]
 pub use file_io::*;
+#[cfg(feature = "serde_json")]
+pub use json::*;
+#[cfg(not(feature = "no_std"))]
+pub use load::*;
+
+#[cfg(feature = "uefi")]
+pub use uefi::*;
 
 #[cfg(all(target_family = "windows", not(feature = "no_std")))]
 pub use windows::{load_windows_smbios_data, raw_smbios_from_device, table_load_from_device};
 
 #[cfg(not(feature = "no_std"))]
-pub use windows::WinSMBiosData;
+pub use windows::{WinSMBiosData, WinSMBiosDataError};
 
-#[cfg(all(any(target_os = "linux", target_os = "android", target_os = "freebsd"), not(feature = "no_std")))]
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+        target_os = "illumos"
+    ),
+    not(feature = "no_std")
+))]
 pub use unix::*;
 
 #[cfg(all(any(target_os = "macos", target_os = "ios"), not(feature = "no_std")))]
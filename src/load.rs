@@ -0,0 +1,52 @@
+//! Cross-platform SMBIOS loading.
+//!
+//! [load] reads the SMBIOS table of the running machine using the backend
+//! selected for the target platform at compile time, so callers do not need
+//! their own `cfg` blocks.
+
+use crate::core::SMBiosData;
+use crate::error::Error;
+
+/// Loads [SMBiosData] from the device of the running machine
+///
+/// Dispatches to the platform backend: the RSMB firmware table on Windows,
+/// sysfs (or /dev/mem) on Linux, IOKit on macOS, /dev/mem on the BSDs
+/// and /dev/smbios on Solaris and illumos.
+///
+/// Failures of the operating system are classified as
+/// [Error::PermissionDenied] or [Error::TableNotFound] where they have
+/// that cause, retaining the underlying [std::io::Error] (including the OS
+/// error code, see [std::io::Error::raw_os_error]).
+///
+/// Returns [Error::UnsupportedPlatform] on any other platform.
+pub fn load() -> Result<SMBiosData, Error> {
+    #[cfg(any(
+        target_family = "windows",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "macos",
+        target_os = "ios"
+    ))]
+    {
+        crate::table_load_from_device().map_err(Error::classify_device_error)
+    }
+
+    #[cfg(not(any(
+        target_family = "windows",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "macos",
+        target_os = "ios"
+    )))]
+    {
+        Err(Error::UnsupportedPlatform)
+    }
+}
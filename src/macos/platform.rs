@@ -9,7 +9,7 @@ use io_kit_sys::{
     IOServiceMatching, CFSTR,
 };
 use mach::*;
-use std::{convert::TryFrom, ffi::CString, io::Error, io::ErrorKind};
+use std::{ffi::CString, io::Error, io::ErrorKind};
 
 struct AppleSMBiosService {
     pub service_handle: io_service_t,
@@ -48,16 +48,17 @@ impl Drop for AppleSMBiosService {
     }
 }
 
-fn try_load_macos_entry_point() -> Result<SMBiosEntryPoint32, Error> {
+/// Copies the bytes of a data property of the AppleSMBIOS service
+fn try_load_macos_property(property_name: &str) -> Result<Vec<u8>, Error> {
     let service = AppleSMBiosService::try_init()?;
 
     unsafe {
-        let smbios_entry_point_name = CString::new("SMBIOS-EPS").expect("CString::new failed");
+        let property_name_cstr = CString::new(property_name).expect("CString::new failed");
 
         let option_bits: IOOptionBits = 0;
         let data_ref = IORegistryEntryCreateCFProperty(
             service.service_handle,
-            CFSTR(smbios_entry_point_name.as_ptr()),
+            CFSTR(property_name_cstr.as_ptr()),
             kCFAllocatorDefault,
             option_bits,
         ) as CFDataRef;
@@ -65,74 +66,42 @@ fn try_load_macos_entry_point() -> Result<SMBiosEntryPoint32, Error> {
         if data_ref.is_null() {
             return Err(Error::new(
                 ErrorKind::NotFound,
-                "SMBIOS-EPS entry point is unreachable",
+                format!("{} is unreachable", property_name),
             ));
         }
 
-        if !data_ref.is_null() {
-            CFRelease(data_ref.as_void_ptr());
-        }
-
         let data_ptr = CFDataGetBytePtr(data_ref);
         let data_length = CFDataGetLength(data_ref);
-        let mut entry_point = Vec::with_capacity(data_length as usize);
+        let mut data: Vec<u8> = Vec::with_capacity(data_length as usize);
 
-        std::ptr::copy(data_ptr, entry_point.as_mut_ptr(), data_length as usize);
-        entry_point.set_len(data_length as usize);
+        std::ptr::copy(data_ptr, data.as_mut_ptr(), data_length as usize);
+        data.set_len(data_length as usize);
 
-        SMBiosEntryPoint32::try_from(entry_point)
+        // The property is owned by this function (create rule) and
+        // must only be released once its bytes have been copied.
+        CFRelease(data_ref.as_void_ptr());
+
+        Ok(data)
     }
 }
 
 fn try_load_macos_table() -> Result<Vec<u8>, Error> {
-    let service = AppleSMBiosService::try_init()?;
-
-    unsafe {
-        let smbios_table_name = CString::new("SMBIOS").expect("CString::new failed");
-
-        let option_bits: IOOptionBits = 0;
-        let data_ref = IORegistryEntryCreateCFProperty(
-            service.service_handle,
-            CFSTR(smbios_table_name.as_ptr()),
-            kCFAllocatorDefault,
-            option_bits,
-        ) as CFDataRef;
-
-        if data_ref.is_null() {
-            return Err(Error::new(ErrorKind::NotFound, "SMBIOS is unreachable"));
-        }
-
-        if !data_ref.is_null() {
-            CFRelease(data_ref.as_void_ptr());
-        }
-
-        let data_ptr = CFDataGetBytePtr(data_ref);
-        let data_length = CFDataGetLength(data_ref);
-        let mut table: Vec<u8> = Vec::with_capacity(data_length as usize);
-
-        std::ptr::copy(data_ptr, table.as_mut_ptr(), data_length as usize);
-        table.set_len(data_length as usize);
-
-        Ok(table)
-    }
+    try_load_macos_property("SMBIOS")
 }
 
 /// Loads SMBIOS table data ([SMBiosData]) from the device
-pub fn table_load_from_device() -> Result<SMBiosData, Error> {
-    let entry_point = try_load_macos_entry_point()?;
-
-    let version = SMBiosVersion {
-        major: entry_point.major_version(),
-        minor: entry_point.minor_version(),
-        revision: 0,
-    };
-
+///
+/// Queries the `AppleSMBIOS` IOService for the `SMBIOS-EPS` (entry point)
+/// and `SMBIOS` (table) properties. The entry point is either a 64-bit
+/// (_SM3_) or a 32-bit (_SM_) entry point.
+pub fn table_load_from_device() -> Result<SMBiosData, crate::Error> {
+    let entry_point = try_load_macos_property("SMBIOS-EPS")?;
     let table = try_load_macos_table()?;
 
-    Ok(SMBiosData::from_vec_and_version(table, Some(version)))
+    SMBiosData::try_from_entry_point_and_vec(&entry_point, table)
 }
 
 /// Returns smbios raw data
-pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
+pub fn raw_smbios_from_device() -> Result<Vec<u8>, crate::Error> {
     Ok(try_load_macos_table()?)
 }
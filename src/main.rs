@@ -140,21 +140,11 @@ fn string_keyword(keyword: String, data: &SMBiosData) -> Result<String, BiosPars
                 chassis_info.asset_tag_number()
             })
             .ok_or(BiosParseError::ChassisAssetTagNotFound),
-        "processor-family" => match data.first::<SMBiosProcessorInformation>() {
-            Some(processor_info) => match processor_info.processor_family() {
-                Some(family) => match family.value {
-                    ProcessorFamily::SeeProcessorFamily2 => {
-                        match processor_info.processor_family_2() {
-                            Some(family) => Ok(format!("{}", family)),
-                            None => Err(BiosParseError::ProcessorFamilyNotFound),
-                        }
-                    }
-                    _ => Ok(format!("{}", family)),
-                },
-                None => Err(BiosParseError::ProcessorFamilyNotFound),
-            },
-            None => Err(BiosParseError::ProcessorFamilyNotFound),
-        },
+        "processor-family" => data
+            .first::<SMBiosProcessorInformation>()
+            .and_then(|processor_info| processor_info.effective_processor_family())
+            .map(|family| format!("{}", family))
+            .ok_or(BiosParseError::ProcessorFamilyNotFound),
         "processor-manufacturer" => data
             .find_map(|processor_info: SMBiosProcessorInformation| {
                 processor_info.processor_manufacturer()
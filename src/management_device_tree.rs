@@ -0,0 +1,316 @@
+use crate::core::{SMBiosData, UndefinedStruct};
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use serde::Serialize;
+
+/// # Management Device Tree
+///
+/// The sensors of a table as a tree, see
+/// [SMBiosData::management_device_tree]:
+///
+/// - [Management Devices (Type 34)](SMBiosManagementDevice)
+///   - [Management Device Components (Type 35)](SMBiosManagementDeviceComponent)
+///     of the device, each with its probe or cooling device and its
+///     [Management Device Threshold Data (Type 36)](SMBiosManagementDeviceThresholdData)
+#[derive(Debug, Serialize)]
+pub struct ManagementDeviceTree<'a> {
+    devices: Vec<ManagementDeviceNode<'a>>,
+    unassigned_components: Vec<ManagementComponentNode<'a>>,
+}
+
+impl<'a> ManagementDeviceTree<'a> {
+    /// Management Devices, in table order
+    pub fn devices(&self) -> &[ManagementDeviceNode<'a>] {
+        &self.devices
+    }
+
+    /// Management Device Components whose Management Device Handle
+    /// references no Management Device of the table, in table order
+    pub fn unassigned_components(&self) -> &[ManagementComponentNode<'a>] {
+        &self.unassigned_components
+    }
+
+    /// Iterates over the components of all devices, then over the
+    /// unassigned components
+    pub fn components(&self) -> impl Iterator<Item = &ManagementComponentNode<'a>> {
+        self.devices
+            .iter()
+            .flat_map(|device| device.components.iter())
+            .chain(self.unassigned_components.iter())
+    }
+}
+
+/// # Management Device of a [ManagementDeviceTree]
+#[derive(Debug, Serialize)]
+pub struct ManagementDeviceNode<'a> {
+    device: SMBiosManagementDevice<'a>,
+    components: Vec<ManagementComponentNode<'a>>,
+}
+
+impl<'a> ManagementDeviceNode<'a> {
+    /// The Management Device (Type 34)
+    pub fn device(&self) -> &SMBiosManagementDevice<'a> {
+        &self.device
+    }
+
+    /// Components of the device (Type 35), in table order
+    pub fn components(&self) -> &[ManagementComponentNode<'a>] {
+        &self.components
+    }
+}
+
+/// # Management Device Component of a [ManagementDeviceTree]
+#[derive(Debug, Serialize)]
+pub struct ManagementComponentNode<'a> {
+    component: SMBiosManagementDeviceComponent<'a>,
+    sensor: Option<DefinedStruct<'a>>,
+    threshold_data: Option<SMBiosManagementDeviceThresholdData<'a>>,
+}
+
+impl<'a> ManagementComponentNode<'a> {
+    fn new(component: SMBiosManagementDeviceComponent<'a>, data: &'a SMBiosData) -> Self {
+        let sensor = component
+            .component_handle()
+            .and_then(|handle| data.find_referenced(&handle))
+            .map(DefinedStruct::from)
+            .filter(|sensor| {
+                matches!(
+                    sensor,
+                    DefinedStruct::VoltageProbe(_)
+                        | DefinedStruct::CoolingDevice(_)
+                        | DefinedStruct::TemperatureProbe(_)
+                        | DefinedStruct::ElectricalCurrentProbe(_)
+                )
+            });
+        let threshold_data = component
+            .threshold_handle()
+            .and_then(|handle| data.resolve(&handle));
+
+        Self {
+            component,
+            sensor,
+            threshold_data,
+        }
+    }
+
+    /// The Management Device Component (Type 35)
+    pub fn component(&self) -> &SMBiosManagementDeviceComponent<'a> {
+        &self.component
+    }
+
+    /// The probe or cooling device referenced by
+    /// [SMBiosManagementDeviceComponent::component_handle]
+    ///
+    /// One of [DefinedStruct::VoltageProbe], [DefinedStruct::CoolingDevice],
+    /// [DefinedStruct::TemperatureProbe] or
+    /// [DefinedStruct::ElectricalCurrentProbe].
+    pub fn sensor(&self) -> Option<&DefinedStruct<'a>> {
+        self.sensor.as_ref()
+    }
+
+    /// The Management Device Threshold Data referenced by
+    /// [SMBiosManagementDeviceComponent::threshold_handle]
+    pub fn threshold_data(&self) -> Option<&SMBiosManagementDeviceThresholdData<'a>> {
+        self.threshold_data.as_ref()
+    }
+
+    /// The thresholds of [Self::threshold_data], in the units of
+    /// [Self::sensor]
+    ///
+    /// Thresholds are [SensorValue::Raw] when the sensor was not found.
+    pub fn thresholds(&self) -> Option<SensorThresholds> {
+        let threshold_data = self.threshold_data.as_ref()?;
+        let value = |raw: Option<u16>| raw.map(|raw| self.sensor_value(raw));
+
+        Some(SensorThresholds {
+            lower_non_critical: value(threshold_data.lower_threshold_non_critical()),
+            upper_non_critical: value(threshold_data.upper_threshold_non_critical()),
+            lower_critical: value(threshold_data.lower_threshold_critical()),
+            upper_critical: value(threshold_data.upper_threshold_critical()),
+            lower_non_recoverable: value(threshold_data.lower_threshold_non_recoverable()),
+            upper_non_recoverable: value(threshold_data.upper_threshold_non_recoverable()),
+        })
+    }
+
+    fn sensor_value(&self, raw: u16) -> SensorValue {
+        match self.sensor {
+            Some(DefinedStruct::VoltageProbe(_)) => SensorValue::Voltage(ProbeVoltage::from(raw)),
+            Some(DefinedStruct::CoolingDevice(_)) => SensorValue::Speed(RotationalSpeed::from(raw)),
+            Some(DefinedStruct::TemperatureProbe(_)) => {
+                SensorValue::Temperature(ProbeTemperature::from(raw))
+            }
+            Some(DefinedStruct::ElectricalCurrentProbe(_)) => {
+                SensorValue::Current(ProbeAmperage::from(raw))
+            }
+            _ => SensorValue::Raw(raw),
+        }
+    }
+}
+
+/// # Sensor Thresholds
+///
+/// The thresholds of a [ManagementComponentNode]. A field is None when the
+/// Threshold Data structure is too short to hold it.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct SensorThresholds {
+    /// Lower non-critical threshold
+    pub lower_non_critical: Option<SensorValue>,
+    /// Upper non-critical threshold
+    pub upper_non_critical: Option<SensorValue>,
+    /// Lower critical threshold
+    pub lower_critical: Option<SensorValue>,
+    /// Upper critical threshold
+    pub upper_critical: Option<SensorValue>,
+    /// Lower non-recoverable threshold
+    pub lower_non_recoverable: Option<SensorValue>,
+    /// Upper non-recoverable threshold
+    pub upper_non_recoverable: Option<SensorValue>,
+}
+
+/// # Sensor Value
+///
+/// A threshold in the units of the probe or cooling device it applies to
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum SensorValue {
+    /// Threshold of a Voltage Probe (Type 26)
+    Voltage(ProbeVoltage),
+    /// Threshold of a Cooling Device (Type 27)
+    Speed(RotationalSpeed),
+    /// Threshold of a Temperature Probe (Type 28)
+    Temperature(ProbeTemperature),
+    /// Threshold of an Electrical Current Probe (Type 29)
+    Current(ProbeAmperage),
+    /// Threshold of an unknown component, 0x8000 if unavailable
+    Raw(u16),
+}
+
+impl SMBiosData {
+    /// The management devices of the table as a [ManagementDeviceTree]
+    ///
+    /// Joins each Management Device Component to the Management Device it
+    /// references, and resolves its component handle to a probe or cooling
+    /// device and its threshold handle to a Threshold Data structure. A
+    /// handle of FFFFh, or one referencing a structure of another type,
+    /// resolves to None.
+    pub fn management_device_tree(&self) -> ManagementDeviceTree<'_> {
+        let handle_of = |parts: &UndefinedStruct| *parts.header.handle();
+
+        let mut unassigned_components: Vec<ManagementComponentNode<'_>> = self
+            .defined_struct_iter::<SMBiosManagementDeviceComponent<'_>>()
+            .map(|component| ManagementComponentNode::new(component, self))
+            .collect();
+
+        let devices = self
+            .defined_struct_iter::<SMBiosManagementDevice<'_>>()
+            .map(|device| {
+                let handle = handle_of(device.parts());
+                let (components, rest) = unassigned_components.drain(..).partition(|node| {
+                    node.component
+                        .management_device_handle()
+                        .map(|handle| *handle)
+                        == Some(handle)
+                });
+                unassigned_components = rest;
+
+                ManagementDeviceNode { device, components }
+            })
+            .collect();
+
+        ManagementDeviceTree {
+            devices,
+            unassigned_components,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{SMBiosTableBuilder, StructBuilder};
+    use crate::core::{Handle, SMBiosVersion};
+
+    fn component(device: u16, sensor: u16, threshold: u16) -> StructBuilder {
+        StructBuilder::new(35)
+            .string("Component")
+            .word(device)
+            .word(sensor)
+            .word(threshold)
+    }
+
+    fn threshold_data(thresholds: [u16; 6]) -> StructBuilder {
+        thresholds
+            .iter()
+            .fold(StructBuilder::new(36), |builder, threshold| {
+                builder.word(*threshold)
+            })
+    }
+
+    #[test]
+    fn test_management_device_tree() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add_with_handle(
+                Handle(0x2200),
+                StructBuilder::new(34)
+                    .string("LM78")
+                    .byte(0x04)
+                    .dword(0)
+                    .byte(0x05),
+            )
+            .unwrap();
+        builder
+            .add_with_handle(
+                Handle(0x1C00),
+                StructBuilder::new(28)
+                    .string("CPU")
+                    .byte(0x63)
+                    .data(&[0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80])
+                    .dword(0)
+                    .word(0x8000),
+            )
+            .unwrap();
+        builder
+            .add_with_handle(
+                Handle(0x2400),
+                threshold_data([0x8000, 850, 0x8000, 950, 0x8000, 1000]),
+            )
+            .unwrap();
+        builder.add(component(0x2200, 0x1C00, 0x2400)).unwrap();
+        // sensor handle referencing a threshold data structure
+        builder.add(component(0x2200, 0x2400, 0xFFFF)).unwrap();
+        builder.add(component(0x2201, 0x1C00, 0x2400)).unwrap();
+        let data = builder.build().unwrap();
+        let tree = data.management_device_tree();
+
+        assert_eq!(tree.devices().len(), 1);
+        assert_eq!(tree.unassigned_components().len(), 1);
+        assert_eq!(tree.components().count(), 3);
+
+        let device = &tree.devices()[0];
+        assert_eq!(device.device().description(), Some("LM78".to_string()));
+        assert_eq!(device.components().len(), 2);
+
+        let component = &device.components()[0];
+        assert!(matches!(
+            component.sensor(),
+            Some(DefinedStruct::TemperatureProbe(_))
+        ));
+        let thresholds = component.thresholds().unwrap();
+        assert_eq!(
+            thresholds.lower_non_critical,
+            Some(SensorValue::Temperature(ProbeTemperature::Unknown))
+        );
+        assert_eq!(
+            thresholds.upper_critical,
+            Some(SensorValue::Temperature(
+                ProbeTemperature::OneTenthDegreesC(950)
+            ))
+        );
+
+        let component = &device.components()[1];
+        assert!(component.sensor().is_none());
+        assert!(component.threshold_data().is_none());
+        assert!(component.thresholds().is_none());
+    }
+}
@@ -0,0 +1,275 @@
+use crate::core::{Handle, SMBiosData, UndefinedStruct};
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use serde::Serialize;
+
+/// # Memory Topology
+///
+/// The memory of a table as a tree, see [SMBiosData::memory_topology]:
+///
+/// - [Physical Memory Arrays (Type 16)](SMBiosPhysicalMemoryArray)
+///   - [Memory Array Mapped Addresses (Type 19)](SMBiosMemoryArrayMappedAddress)
+///     of the array
+///   - [Memory Devices (Type 17)](SMBiosMemoryDevice) of the array
+///     - [Memory Device Mapped Addresses (Type 20)](SMBiosMemoryDeviceMappedAddress)
+///       of the device
+#[derive(Debug, Serialize)]
+pub struct MemoryTopology<'a> {
+    arrays: Vec<MemoryArrayNode<'a>>,
+    unassigned_devices: Vec<MemoryDeviceNode<'a>>,
+}
+
+impl<'a> MemoryTopology<'a> {
+    /// Physical Memory Arrays, in table order
+    pub fn arrays(&self) -> &[MemoryArrayNode<'a>] {
+        &self.arrays
+    }
+
+    /// Memory Devices whose Physical Memory Array Handle references no
+    /// Physical Memory Array of the table, in table order
+    pub fn unassigned_devices(&self) -> &[MemoryDeviceNode<'a>] {
+        &self.unassigned_devices
+    }
+
+    /// Iterates over the Memory Devices of all arrays, then over the
+    /// unassigned Memory Devices
+    pub fn devices(&self) -> impl Iterator<Item = &MemoryDeviceNode<'a>> {
+        self.arrays
+            .iter()
+            .flat_map(|array| array.devices.iter())
+            .chain(self.unassigned_devices.iter())
+    }
+
+    /// The Memory Device with the given handle
+    pub fn find_device(&self, handle: Handle) -> Option<&MemoryDeviceNode<'a>> {
+        self.devices()
+            .find(|device| *device.device.parts().header.handle() == *handle)
+    }
+}
+
+/// # Physical Memory Array of a [MemoryTopology]
+#[derive(Debug, Serialize)]
+pub struct MemoryArrayNode<'a> {
+    array: SMBiosPhysicalMemoryArray<'a>,
+    mapped_addresses: Vec<SMBiosMemoryArrayMappedAddress<'a>>,
+    devices: Vec<MemoryDeviceNode<'a>>,
+}
+
+impl<'a> MemoryArrayNode<'a> {
+    /// The Physical Memory Array (Type 16)
+    pub fn array(&self) -> &SMBiosPhysicalMemoryArray<'a> {
+        &self.array
+    }
+
+    /// Address ranges mapped to the array (Type 19), in table order
+    pub fn mapped_addresses(&self) -> &[SMBiosMemoryArrayMappedAddress<'a>] {
+        &self.mapped_addresses
+    }
+
+    /// Memory Devices of the array (Type 17), in table order
+    pub fn devices(&self) -> &[MemoryDeviceNode<'a>] {
+        &self.devices
+    }
+}
+
+/// # Memory Device of a [MemoryTopology]
+#[derive(Debug, Serialize)]
+pub struct MemoryDeviceNode<'a> {
+    device: SMBiosMemoryDevice<'a>,
+    mapped_addresses: Vec<SMBiosMemoryDeviceMappedAddress<'a>>,
+}
+
+impl<'a> MemoryDeviceNode<'a> {
+    /// The Memory Device (Type 17)
+    pub fn device(&self) -> &SMBiosMemoryDevice<'a> {
+        &self.device
+    }
+
+    /// Address ranges mapped to the device (Type 20), in table order
+    pub fn mapped_addresses(&self) -> &[SMBiosMemoryDeviceMappedAddress<'a>] {
+        &self.mapped_addresses
+    }
+}
+
+impl SMBiosData {
+    /// The memory of the table as a [MemoryTopology]
+    ///
+    /// Joins the structures by handle: Memory Devices and Memory Array
+    /// Mapped Addresses to the Physical Memory Array they reference, and
+    /// Memory Device Mapped Addresses to the Memory Device they reference.
+    /// Mapped addresses referencing no structure of the table are left
+    /// out.
+    pub fn memory_topology(&self) -> MemoryTopology<'_> {
+        let handle_of = |parts: &UndefinedStruct| *parts.header.handle();
+
+        let mut device_mapped_addresses: Vec<SMBiosMemoryDeviceMappedAddress<'_>> = self
+            .defined_struct_iter::<SMBiosMemoryDeviceMappedAddress<'_>>()
+            .collect();
+        let mut array_mapped_addresses: Vec<SMBiosMemoryArrayMappedAddress<'_>> = self
+            .defined_struct_iter::<SMBiosMemoryArrayMappedAddress<'_>>()
+            .collect();
+
+        let mut unassigned_devices: Vec<MemoryDeviceNode<'_>> = self
+            .defined_struct_iter::<SMBiosMemoryDevice<'_>>()
+            .map(|device| {
+                let handle = handle_of(device.parts());
+                let (mapped_addresses, rest) =
+                    device_mapped_addresses
+                        .drain(..)
+                        .partition(|mapped_address| {
+                            mapped_address.memory_device_handle().map(|handle| *handle)
+                                == Some(handle)
+                        });
+                device_mapped_addresses = rest;
+                MemoryDeviceNode {
+                    device,
+                    mapped_addresses,
+                }
+            })
+            .collect();
+
+        let arrays = self
+            .defined_struct_iter::<SMBiosPhysicalMemoryArray<'_>>()
+            .map(|array| {
+                let handle = handle_of(array.parts());
+                let references = |array_handle: Option<Handle>| {
+                    array_handle.map(|array_handle| *array_handle) == Some(handle)
+                };
+
+                let (mapped_addresses, rest) =
+                    array_mapped_addresses
+                        .drain(..)
+                        .partition(|mapped_address| {
+                            references(mapped_address.physical_memory_array_handle())
+                        });
+                array_mapped_addresses = rest;
+
+                let (devices, rest) = unassigned_devices
+                    .drain(..)
+                    .partition(|node| references(node.device.physical_memory_array_handle()));
+                unassigned_devices = rest;
+
+                MemoryArrayNode {
+                    array,
+                    mapped_addresses,
+                    devices,
+                }
+            })
+            .collect();
+
+        MemoryTopology {
+            arrays,
+            unassigned_devices,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::{SMBiosTableBuilder, StructBuilder};
+    use crate::core::{Handle, SMBiosVersion};
+    use crate::SMBiosStruct;
+
+    fn memory_device(array_handle: u16) -> StructBuilder {
+        StructBuilder::new(17)
+            .word(array_handle)
+            .word(0xFFFE)
+            .word(72)
+            .word(64)
+            .word(0x4000)
+            .byte(0x09)
+            .byte(0)
+            .string("DIMM")
+            .string("")
+            .byte(0x1A)
+            .word(0)
+    }
+
+    fn device_mapped_address(device_handle: u16, start: u32, end: u32) -> StructBuilder {
+        StructBuilder::new(20)
+            .dword(start)
+            .dword(end)
+            .word(device_handle)
+            .word(0x1300)
+            .byte(0xFF)
+            .byte(0xFF)
+            .byte(0xFF)
+            .qword(0)
+            .qword(0)
+    }
+
+    #[test]
+    fn test_memory_topology() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add_with_handle(
+                Handle(0x1000),
+                StructBuilder::new(16)
+                    .byte(0x03)
+                    .byte(0x03)
+                    .byte(0x06)
+                    .dword(0x8000_0000)
+                    .word(0xFFFE)
+                    .word(2)
+                    .qword(0),
+            )
+            .unwrap();
+        builder
+            .add_with_handle(
+                Handle(0x1300),
+                StructBuilder::new(19)
+                    .dword(0xFFFF_FFFF)
+                    .dword(0xFFFF_FFFF)
+                    .word(0x1000)
+                    .byte(2)
+                    .qword(0x1_0000_0000)
+                    .qword(0x8_FFFF_FFFF),
+            )
+            .unwrap();
+        builder
+            .add_with_handle(Handle(0x1100), memory_device(0x1000))
+            .unwrap();
+        builder
+            .add_with_handle(Handle(0x1101), memory_device(0x1000))
+            .unwrap();
+        builder
+            .add_with_handle(Handle(0x1102), memory_device(0x2000))
+            .unwrap();
+        builder
+            .add(device_mapped_address(0x1100, 0x0040_0000, 0x013F_FFFF))
+            .unwrap();
+        builder
+            .add(device_mapped_address(0x1101, 0x0140_0000, 0x023F_FFFF))
+            .unwrap();
+        builder
+            .add(device_mapped_address(0x1199, 0, 0x3FF))
+            .unwrap();
+        let data = builder.build().unwrap();
+        let topology = data.memory_topology();
+
+        assert_eq!(topology.arrays().len(), 1);
+        let array = &topology.arrays()[0];
+        assert_eq!(array.array().number_of_memory_devices(), Some(2));
+        assert_eq!(array.mapped_addresses().len(), 1);
+        assert_eq!(
+            array.mapped_addresses()[0].address_range(),
+            Some(0x1_0000_0000..=0x8_FFFF_FFFF)
+        );
+
+        assert_eq!(array.devices().len(), 2);
+        let device = &array.devices()[1];
+        assert_eq!(*device.device().parts().header.handle(), 0x1101);
+        assert_eq!(device.mapped_addresses().len(), 1);
+        assert_eq!(
+            device.mapped_addresses()[0].address_range(),
+            Some(0x5_0000_0000..=0x8_FFFF_FFFF)
+        );
+
+        assert_eq!(topology.unassigned_devices().len(), 1);
+        assert_eq!(topology.devices().count(), 3);
+        let unassigned = topology.find_device(Handle(0x1102)).unwrap();
+        assert!(unassigned.mapped_addresses().is_empty());
+        assert!(topology.find_device(Handle(0x1199)).is_none());
+    }
+}
@@ -0,0 +1,412 @@
+//! Prometheus metrics.
+//!
+//! The firmware inventory of a table in the Prometheus text exposition
+//! format, for agents in the style of node_exporter.
+
+use crate::core::{trim_padding, Handle, MaybeValue, SMBiosData};
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::{format, string::String, vec, vec::Vec};
+use core::fmt::{Debug, Write};
+
+/// A metric family and its samples
+struct Family {
+    name: &'static str,
+    help: &'static str,
+    samples: Vec<(Vec<(&'static str, String)>, u64)>,
+}
+
+impl Family {
+    fn new(name: &'static str, help: &'static str) -> Self {
+        Family {
+            name,
+            help,
+            samples: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, labels: Vec<(&'static str, String)>, value: Option<u64>) {
+        if let Some(value) = value {
+            self.samples.push((labels, value));
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        if self.samples.is_empty() {
+            return;
+        }
+
+        // writing to a String does not fail
+        let _ = writeln!(out, "# HELP {} {}", self.name, self.help);
+        let _ = writeln!(out, "# TYPE {} gauge", self.name);
+        for (labels, value) in self.samples.iter() {
+            out.push_str(self.name);
+            if !labels.is_empty() {
+                out.push('{');
+                for (index, (name, value)) in labels.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    let _ = write!(out, "{}=\"{}\"", name, escape(value));
+                }
+                out.push('}');
+            }
+            let _ = writeln!(out, " {}", value);
+        }
+    }
+}
+
+impl SMBiosData {
+    /// The inventory of the table as metrics in the Prometheus text
+    /// exposition format (version 0.0.4)
+    ///
+    /// ```text
+    /// # HELP smbios_system_info System Information (Type 1) strings and UUID.
+    /// # TYPE smbios_system_info gauge
+    /// smbios_system_info{handle="0x0001",manufacturer="Manufacturer",product="Product",version="",serial="",sku="",family="",uuid="00360FE7-D4D5-11E5-9C43-BC0000F00000"} 1
+    /// # HELP smbios_memory_device_size_bytes Size of a Memory Device (Type 17), 0 when not installed.
+    /// # TYPE smbios_memory_device_size_bytes gauge
+    /// smbios_memory_device_size_bytes{handle="0x0011",locator="DIMM A1",bank="P0"} 17179869184
+    /// ```
+    ///
+    /// Metrics ending in `_info` have the value 1 and carry strings as labels,
+    /// an absent string being an empty label value. Samples of a structure
+    /// have a `handle` label, keeping series distinct when structures share
+    /// a designation. Samples of unknown values and families without
+    /// samples are omitted.
+    ///
+    /// | Metric | Labels |
+    /// |--------|--------|
+    /// | `smbios_version_info` | `version` |
+    /// | `smbios_bios_info` | `vendor`, `version`, `release_date` |
+    /// | `smbios_system_info` | `manufacturer`, `product`, `version`, `serial`, `sku`, `family`, `uuid` |
+    /// | `smbios_baseboard_info` | `manufacturer`, `product`, `version`, `serial` |
+    /// | `smbios_chassis_info` | `manufacturer`, `type`, `version`, `serial`, `asset_tag` |
+    /// | `smbios_processor_info` | `socket`, `manufacturer`, `version` |
+    /// | `smbios_processor_max_speed_hertz` | `socket` |
+    /// | `smbios_processor_current_speed_hertz` | `socket` |
+    /// | `smbios_processor_cores` | `socket` |
+    /// | `smbios_processor_threads` | `socket` |
+    /// | `smbios_memory_device_info` | `locator`, `bank`, `type`, `manufacturer`, `serial`, `part_number` |
+    /// | `smbios_memory_device_size_bytes` | `locator`, `bank` |
+    /// | `smbios_memory_device_configured_speed_transfers_per_second` | `locator`, `bank` |
+    pub fn to_prometheus_metrics(&self) -> String {
+        let mut version = Family::new("smbios_version_info", "SMBIOS version of the table.");
+        let mut bios = Family::new("smbios_bios_info", "BIOS Information (Type 0) strings.");
+        let mut system = Family::new(
+            "smbios_system_info",
+            "System Information (Type 1) strings and UUID.",
+        );
+        let mut baseboard = Family::new(
+            "smbios_baseboard_info",
+            "Baseboard Information (Type 2) strings.",
+        );
+        let mut chassis = Family::new(
+            "smbios_chassis_info",
+            "System Enclosure or Chassis (Type 3) type and strings.",
+        );
+        let mut processor = Family::new(
+            "smbios_processor_info",
+            "Processor Information (Type 4) strings.",
+        );
+        let mut max_speed = Family::new(
+            "smbios_processor_max_speed_hertz",
+            "Maximum speed of a processor supported by the system.",
+        );
+        let mut current_speed = Family::new(
+            "smbios_processor_current_speed_hertz",
+            "Speed of a processor at system boot.",
+        );
+        let mut cores = Family::new(
+            "smbios_processor_cores",
+            "Number of cores per processor socket.",
+        );
+        let mut threads = Family::new(
+            "smbios_processor_threads",
+            "Number of threads per processor socket.",
+        );
+        let mut memory = Family::new(
+            "smbios_memory_device_info",
+            "Memory Device (Type 17) type and strings.",
+        );
+        let mut memory_size = Family::new(
+            "smbios_memory_device_size_bytes",
+            "Size of a Memory Device (Type 17), 0 when not installed.",
+        );
+        let mut memory_speed = Family::new(
+            "smbios_memory_device_configured_speed_transfers_per_second",
+            "Configured speed of a Memory Device (Type 17).",
+        );
+
+        if let Some(smbios_version) = self.version {
+            version.add(
+                vec![(
+                    "version",
+                    format!("{}.{}", smbios_version.major, smbios_version.minor),
+                )],
+                Some(1),
+            );
+        }
+
+        for undefined_struct in self.iter() {
+            let handle = handle_label(undefined_struct.header.handle());
+            match DefinedStruct::from(undefined_struct) {
+                DefinedStruct::Information(bios_info) => bios.add(
+                    vec![
+                        handle,
+                        ("vendor", label(bios_info.vendor())),
+                        ("version", label(bios_info.version())),
+                        ("release_date", label(bios_info.release_date())),
+                    ],
+                    Some(1),
+                ),
+                DefinedStruct::SystemInformation(system_info) => system.add(
+                    vec![
+                        handle,
+                        ("manufacturer", label(system_info.manufacturer())),
+                        ("product", label(system_info.product_name())),
+                        ("version", label(system_info.version())),
+                        ("serial", label(system_info.serial_number())),
+                        ("sku", label(system_info.sku_number())),
+                        ("family", label(system_info.family())),
+                        (
+                            "uuid",
+                            match system_info.uuid() {
+                                Some(SystemUuidData::Uuid(uuid)) => format!("{}", uuid),
+                                _ => String::new(),
+                            },
+                        ),
+                    ],
+                    Some(1),
+                ),
+                DefinedStruct::BaseBoardInformation(baseboard_info) => baseboard.add(
+                    vec![
+                        handle,
+                        ("manufacturer", label(baseboard_info.manufacturer())),
+                        ("product", label(baseboard_info.product())),
+                        ("version", label(baseboard_info.version())),
+                        ("serial", label(baseboard_info.serial_number())),
+                    ],
+                    Some(1),
+                ),
+                DefinedStruct::SystemChassisInformation(chassis_info) => chassis.add(
+                    vec![
+                        handle,
+                        ("manufacturer", label(chassis_info.manufacturer())),
+                        (
+                            "type",
+                            chassis_info
+                                .chassis_type()
+                                .map(|chassis_type| {
+                                    enum_label(chassis_type.raw, &chassis_type.value)
+                                })
+                                .unwrap_or_default(),
+                        ),
+                        ("version", label(chassis_info.version())),
+                        ("serial", label(chassis_info.serial_number())),
+                        ("asset_tag", label(chassis_info.asset_tag_number())),
+                    ],
+                    Some(1),
+                ),
+                DefinedStruct::ProcessorInformation(processor_info) => {
+                    let socket = ("socket", label(processor_info.socket_designation()));
+                    processor.add(
+                        vec![
+                            handle.clone(),
+                            socket.clone(),
+                            (
+                                "manufacturer",
+                                label(processor_info.processor_manufacturer()),
+                            ),
+                            ("version", label(processor_info.processor_version())),
+                        ],
+                        Some(1),
+                    );
+                    max_speed.add(
+                        vec![handle.clone(), socket.clone()],
+                        processor_info
+                            .max_speed()
+                            .and_then(|speed| speed.megahertz().value())
+                            .map(|speed| speed.as_hertz()),
+                    );
+                    current_speed.add(
+                        vec![handle.clone(), socket.clone()],
+                        processor_info
+                            .current_speed()
+                            .and_then(|speed| speed.megahertz().value())
+                            .map(|speed| speed.as_hertz()),
+                    );
+                    cores.add(
+                        vec![handle.clone(), socket.clone()],
+                        processor_info.effective_core_count().map(u64::from),
+                    );
+                    threads.add(
+                        vec![handle, socket],
+                        processor_info.effective_thread_count().map(u64::from),
+                    );
+                }
+                DefinedStruct::MemoryDevice(memory_device) => {
+                    let locator = ("locator", label(memory_device.device_locator()));
+                    let bank = ("bank", label(memory_device.bank_locator()));
+                    memory.add(
+                        vec![
+                            handle.clone(),
+                            locator.clone(),
+                            bank.clone(),
+                            (
+                                "type",
+                                memory_device
+                                    .memory_type()
+                                    .map(|memory_type| {
+                                        enum_label(memory_type.raw, &memory_type.value)
+                                    })
+                                    .unwrap_or_default(),
+                            ),
+                            ("manufacturer", label(memory_device.manufacturer())),
+                            ("serial", label(memory_device.serial_number())),
+                            ("part_number", label(memory_device.part_number())),
+                        ],
+                        Some(1),
+                    );
+                    memory_size.add(
+                        vec![handle.clone(), locator.clone(), bank.clone()],
+                        match memory_device.effective_size() {
+                            MaybeValue::NotProvided => Some(0),
+                            size => size.as_bytes(),
+                        },
+                    );
+                    memory_speed.add(
+                        vec![handle, locator, bank],
+                        memory_device
+                            .effective_configured_memory_speed()
+                            .and_then(MaybeValue::value)
+                            .map(|speed| speed.as_transfers_per_second()),
+                    );
+                }
+                _ => (),
+            }
+        }
+
+        let mut out = String::new();
+        for family in [
+            version,
+            bios,
+            system,
+            baseboard,
+            chassis,
+            processor,
+            max_speed,
+            current_speed,
+            cores,
+            threads,
+            memory,
+            memory_size,
+            memory_speed,
+        ]
+        .iter()
+        {
+            family.write(&mut out);
+        }
+
+        out
+    }
+}
+
+fn handle_label(handle: Handle) -> (&'static str, String) {
+    ("handle", format!("0x{:04X}", handle.0))
+}
+
+/// A string label value, see [trim_padding]
+fn label(value: Option<String>) -> String {
+    trim_padding(value).unwrap_or_default()
+}
+
+/// The name of an enumerated value, or its raw value when not defined
+fn enum_label<T: Debug>(raw: u8, value: &T) -> String {
+    match format!("{:?}", value) {
+        name if name == "None" => format!("{}", raw),
+        name => name,
+    }
+}
+
+/// Escapes a label value (backslash, double quote and line feed)
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::{SMBiosTableBuilder, StructBuilder, SystemInformationBuilder};
+    use crate::core::SMBiosVersion;
+
+    #[test]
+    fn test_to_prometheus_metrics() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add(
+                SystemInformationBuilder::new()
+                    .manufacturer("Manufacturer ")
+                    .product_name("Product \"X\""),
+            )
+            .unwrap();
+        for (locator, size) in [("DIMM A1", 0u16), ("DIMM A2", 0x4000)].iter() {
+            builder
+                .add(
+                    StructBuilder::new(17)
+                        .word(0x1000)
+                        .word(0xFFFE)
+                        .word(72)
+                        .word(64)
+                        .word(*size)
+                        .byte(0x09)
+                        .byte(0)
+                        .string(locator)
+                        .string("P0")
+                        .byte(0x1A) // DDR4
+                        .word(0)
+                        .word(3200)
+                        .string("")
+                        .string("")
+                        .string("")
+                        .string("")
+                        .byte(0)
+                        .dword(0)
+                        .word(2933),
+                )
+                .unwrap();
+        }
+        let data = builder.build().unwrap();
+
+        let metrics = data.to_prometheus_metrics();
+        let expected = r#"# HELP smbios_version_info SMBIOS version of the table.
+# TYPE smbios_version_info gauge
+smbios_version_info{version="3.4"} 1
+# HELP smbios_system_info System Information (Type 1) strings and UUID.
+# TYPE smbios_system_info gauge
+smbios_system_info{handle="0x0000",manufacturer="Manufacturer",product="Product \"X\"",version="",serial="",sku="",family="",uuid=""} 1
+# HELP smbios_memory_device_info Memory Device (Type 17) type and strings.
+# TYPE smbios_memory_device_info gauge
+smbios_memory_device_info{handle="0x0001",locator="DIMM A1",bank="P0",type="Ddr4",manufacturer="",serial="",part_number=""} 1
+smbios_memory_device_info{handle="0x0002",locator="DIMM A2",bank="P0",type="Ddr4",manufacturer="",serial="",part_number=""} 1
+# HELP smbios_memory_device_size_bytes Size of a Memory Device (Type 17), 0 when not installed.
+# TYPE smbios_memory_device_size_bytes gauge
+smbios_memory_device_size_bytes{handle="0x0001",locator="DIMM A1",bank="P0"} 0
+smbios_memory_device_size_bytes{handle="0x0002",locator="DIMM A2",bank="P0"} 17179869184
+# HELP smbios_memory_device_configured_speed_transfers_per_second Configured speed of a Memory Device (Type 17).
+# TYPE smbios_memory_device_configured_speed_transfers_per_second gauge
+smbios_memory_device_configured_speed_transfers_per_second{handle="0x0001",locator="DIMM A1",bank="P0"} 2933000000
+smbios_memory_device_configured_speed_transfers_per_second{handle="0x0002",locator="DIMM A2",bank="P0"} 2933000000
+"#;
+        assert_eq!(metrics, expected);
+    }
+}
@@ -0,0 +1,263 @@
+use crate::core::{Handle, SMBiosData};
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use serde::Serialize;
+
+/// # Processor Topology
+///
+/// The processor sockets of a table with their caches, see
+/// [SMBiosData::processor_topology].
+#[derive(Debug, Serialize)]
+pub struct ProcessorTopology<'a> {
+    sockets: Vec<ProcessorSocket<'a>>,
+}
+
+impl<'a> ProcessorTopology<'a> {
+    /// Processor sockets, populated or not, in table order
+    pub fn sockets(&self) -> &[ProcessorSocket<'a>] {
+        &self.sockets
+    }
+
+    /// Iterates over the populated processor sockets
+    pub fn populated_sockets(&self) -> impl Iterator<Item = &ProcessorSocket<'a>> {
+        self.sockets.iter().filter(|socket| socket.is_populated())
+    }
+
+    /// Number of cores of the populated sockets, None when the count of a
+    /// populated socket is unknown
+    pub fn core_count(&self) -> Option<u32> {
+        self.populated_sockets()
+            .map(|socket| socket.core_count().map(u32::from))
+            .sum()
+    }
+
+    /// Number of threads of the populated sockets, None when the count of
+    /// a populated socket is unknown
+    pub fn thread_count(&self) -> Option<u32> {
+        self.populated_sockets()
+            .map(|socket| socket.thread_count().map(u32::from))
+            .sum()
+    }
+}
+
+/// # Processor socket of a [ProcessorTopology]
+#[derive(Debug, Serialize)]
+pub struct ProcessorSocket<'a> {
+    processor: SMBiosProcessorInformation<'a>,
+    l1_cache: Option<SMBiosCacheInformation<'a>>,
+    l2_cache: Option<SMBiosCacheInformation<'a>>,
+    l3_cache: Option<SMBiosCacheInformation<'a>>,
+}
+
+impl<'a> ProcessorSocket<'a> {
+    fn new(processor: SMBiosProcessorInformation<'a>, data: &'a SMBiosData) -> Self {
+        let cache = |handle: Option<Handle>| data.resolve(&handle?);
+
+        Self {
+            l1_cache: cache(processor.l1cache_handle()),
+            l2_cache: cache(processor.l2cache_handle()),
+            l3_cache: cache(processor.l3cache_handle()),
+            processor,
+        }
+    }
+
+    /// The Processor Information (Type 4)
+    pub fn processor(&self) -> &SMBiosProcessorInformation<'a> {
+        &self.processor
+    }
+
+    /// The Cache Information (Type 7) referenced by
+    /// [SMBiosProcessorInformation::l1cache_handle]
+    pub fn l1_cache(&self) -> Option<&SMBiosCacheInformation<'a>> {
+        self.l1_cache.as_ref()
+    }
+
+    /// The Cache Information (Type 7) referenced by
+    /// [SMBiosProcessorInformation::l2cache_handle]
+    pub fn l2_cache(&self) -> Option<&SMBiosCacheInformation<'a>> {
+        self.l2_cache.as_ref()
+    }
+
+    /// The Cache Information (Type 7) referenced by
+    /// [SMBiosProcessorInformation::l3cache_handle]
+    pub fn l3_cache(&self) -> Option<&SMBiosCacheInformation<'a>> {
+        self.l3_cache.as_ref()
+    }
+
+    /// Iterates over the caches found, from Level 1 to Level 3
+    pub fn caches(&self) -> impl Iterator<Item = &SMBiosCacheInformation<'a>> {
+        self.l1_cache
+            .iter()
+            .chain(self.l2_cache.iter())
+            .chain(self.l3_cache.iter())
+    }
+
+    /// Whether the socket holds a processor
+    pub fn is_populated(&self) -> bool {
+        self.processor
+            .status()
+            .map(|status| status.socket_populated())
+            .unwrap_or(false)
+    }
+
+    /// Number of cores of the socket
+    ///
+    /// Taken from 'core_count_2' when present (SMBIOS 3.0 and later),
+    /// otherwise from 'core_count'. None when the count is unknown.
+    pub fn core_count(&self) -> Option<u16> {
+        self.processor.effective_core_count()
+    }
+
+    /// Number of enabled cores of the socket
+    ///
+    /// Taken from 'cores_enabled_2' when present (SMBIOS 3.0 and later),
+    /// otherwise from 'cores_enabled'. None when the count is unknown.
+    pub fn cores_enabled(&self) -> Option<u16> {
+        self.processor.effective_cores_enabled()
+    }
+
+    /// Number of threads of the socket
+    ///
+    /// Taken from 'thread_count_2' when present (SMBIOS 3.0 and later),
+    /// otherwise from 'thread_count'. None when the count is unknown.
+    pub fn thread_count(&self) -> Option<u16> {
+        self.processor.effective_thread_count()
+    }
+}
+
+impl SMBiosData {
+    /// The processor sockets of the table as a [ProcessorTopology]
+    ///
+    /// Resolves the L1, L2 and L3 cache handles of each Processor
+    /// Information structure to Cache Information structures. A handle of
+    /// FFFFh, or one referencing no Cache Information of the table, leaves
+    /// the cache out.
+    pub fn processor_topology(&self) -> ProcessorTopology<'_> {
+        ProcessorTopology {
+            sockets: self
+                .defined_struct_iter::<SMBiosProcessorInformation<'_>>()
+                .map(|processor| ProcessorSocket::new(processor, self))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::{SMBiosTableBuilder, StructBuilder};
+    use crate::core::{Handle, SMBiosVersion};
+    use crate::SMBiosStruct;
+
+    fn processor(
+        status: u8,
+        caches: [u16; 3],
+        counts: [u8; 3],
+        counts_2: [u16; 3],
+    ) -> StructBuilder {
+        StructBuilder::new(4)
+            .string("CPU")
+            .byte(3)
+            .byte(0xB3)
+            .string("Intel")
+            .qword(0)
+            .string("Xeon")
+            .byte(0)
+            .word(0)
+            .word(4000)
+            .word(2100)
+            .byte(status)
+            .byte(1)
+            .word(caches[0])
+            .word(caches[1])
+            .word(caches[2])
+            .string("")
+            .string("")
+            .string("")
+            .byte(counts[0])
+            .byte(counts[1])
+            .byte(counts[2])
+            .word(0x00FC)
+            .word(0xB3)
+            .word(counts_2[0])
+            .word(counts_2[1])
+            .word(counts_2[2])
+    }
+
+    fn cache(configuration: u16) -> StructBuilder {
+        StructBuilder::new(7)
+            .string("Cache")
+            .word(configuration)
+            .word(0x0200)
+            .word(0x0200)
+            .word(0x0020)
+            .word(0x0020)
+            .byte(0)
+            .byte(0x05)
+            .byte(0x05)
+            .byte(0x08)
+    }
+
+    #[test]
+    fn test_processor_topology() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add_with_handle(Handle(0x0700), cache(0x0180))
+            .unwrap();
+        builder
+            .add_with_handle(Handle(0x0701), cache(0x0181))
+            .unwrap();
+        builder
+            .add_with_handle(Handle(0x0702), cache(0x0182))
+            .unwrap();
+        // 288 cores and 576 threads, counted by the 3.0 fields
+        builder
+            .add(processor(
+                0x41,
+                [0x0700, 0x0701, 0x0702],
+                [0xFF, 0xFF, 0xFF],
+                [288, 288, 576],
+            ))
+            .unwrap();
+        // an L2 handle referencing a processor, no L3 cache, 2.x counts
+        builder
+            .add_with_handle(
+                Handle(0x0400),
+                processor(0x41, [0x0700, 0x0400, 0xFFFF], [24, 20, 48], [0, 0, 0]),
+            )
+            .unwrap();
+        builder
+            .add(processor(0x00, [0xFFFF; 3], [0; 3], [0; 3]))
+            .unwrap();
+        let data = builder.build().unwrap();
+        let topology = data.processor_topology();
+
+        assert_eq!(topology.sockets().len(), 3);
+        assert_eq!(topology.populated_sockets().count(), 2);
+        assert_eq!(topology.core_count(), Some(312));
+        assert_eq!(topology.thread_count(), Some(624));
+
+        let socket = &topology.sockets()[0];
+        assert_eq!(socket.core_count(), Some(288));
+        assert_eq!(socket.cores_enabled(), Some(288));
+        assert_eq!(socket.thread_count(), Some(576));
+        let levels: Vec<u8> = socket
+            .caches()
+            .map(|cache| cache.cache_configuration().unwrap().cache_level())
+            .collect();
+        assert_eq!(levels, vec![1, 2, 3]);
+
+        let socket = &topology.sockets()[1];
+        assert_eq!(socket.core_count(), Some(24));
+        assert_eq!(socket.cores_enabled(), Some(20));
+        assert_eq!(socket.thread_count(), Some(48));
+        assert_eq!(*socket.l1_cache().unwrap().parts().header.handle(), 0x0700);
+        assert!(socket.l2_cache().is_none());
+        assert!(socket.l3_cache().is_none());
+
+        let socket = &topology.sockets()[2];
+        assert!(!socket.is_populated());
+        assert_eq!(socket.core_count(), None);
+        assert_eq!(socket.caches().count(), 0);
+    }
+}
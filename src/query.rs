@@ -0,0 +1,658 @@
+//! Field-level queries.
+//!
+//! Selects values of the stable JSON shape of the structures (see
+//! [SMBiosData::to_json_value]) by a short expression, for example the
+//! serial numbers of the installed memory devices:
+//!
+//! ```text
+//! memory_device[size>0].serial_number
+//! ```
+
+use crate::core::{SMBiosData, UndefinedStruct};
+use crate::json::{struct_name, struct_value};
+#[cfg(feature = "no_std")]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{cmp::Ordering, convert::TryFrom, fmt, str::FromStr};
+use serde_json::Value;
+
+/// # Query
+///
+/// Selects structures of a table and a value of each of them, see
+/// [SMBiosData::query]. A query is parsed from an expression or built:
+///
+/// ```
+/// # use smbioslib::*;
+/// let parsed: Query = "memory_device[size>0].serial_number".parse().unwrap();
+/// let built = Query::structure("memory_device")
+///     .filter("size", Comparison::Greater, 0)
+///     .select("serial_number");
+/// assert_eq!(parsed, built);
+/// ```
+///
+/// An expression is a structure selector, followed by any number of
+/// conditions in brackets and an optional path of the value to select:
+///
+/// - The selector is the name of a structure type in the DMTF SMBIOS
+///   specification in lowercase words separated by underscores (for
+///   example `memory_device`, `system_enclosure_or_chassis` or
+///   `oem_specific`), a structure type number, or `*` for all structures.
+/// - A condition is a path compared to a literal with `=`, `!=`, `<`,
+///   `<=`, `>` or `>=`, or a path alone which holds when the value is
+///   present and not null. A structure is selected when all conditions hold.
+/// - A literal is a number (decimal, or hexadecimal with a `0x` prefix), a
+///   string in single or double quotes, `true`, `false`, `null` or a word
+///   standing for a string.
+/// - A path names a field of the structure, then the members of nested
+///   values separated by dots, array elements being named by their index.
+///   A name which is not a field of the structure names a member of the
+///   structure (`type`, `name`, `handle`, `length`, `strings` or `data`).
+///
+/// Numbers compare to numbers and strings to strings. Enumerated fields
+/// compare by their `raw` value to numbers and by their `value` name to
+/// strings, so `memory_device[memory_type=Ddr4]` selects the DDR4 memory
+/// devices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    selector: Selector,
+    conditions: Vec<Condition>,
+    path: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    All,
+    Type(u8),
+    Name(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Condition {
+    path: Vec<String>,
+    comparison: Option<(Comparison, Value)>,
+}
+
+/// Comparison of a [Query] condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    /// `=`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `<=`
+    LessOrEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    fn holds(self, ordering: Option<Ordering>) -> bool {
+        match self {
+            Comparison::Equal => ordering == Some(Ordering::Equal),
+            Comparison::NotEqual => ordering != Some(Ordering::Equal),
+            Comparison::Less => ordering == Some(Ordering::Less),
+            Comparison::LessOrEqual => {
+                ordering == Some(Ordering::Less) || ordering == Some(Ordering::Equal)
+            }
+            Comparison::Greater => ordering == Some(Ordering::Greater),
+            Comparison::GreaterOrEqual => {
+                ordering == Some(Ordering::Greater) || ordering == Some(Ordering::Equal)
+            }
+        }
+    }
+}
+
+impl Query {
+    /// Selects all structures
+    pub fn all() -> Self {
+        Self::new(Selector::All)
+    }
+
+    /// Selects the structures of `struct_type`
+    pub fn struct_type(struct_type: u8) -> Self {
+        Self::new(Selector::Type(struct_type))
+    }
+
+    /// Selects the structures named `name`, such as `memory_device`
+    ///
+    /// Unlike a parsed expression, an unknown name is not an error and
+    /// selects no structure.
+    pub fn structure(name: &str) -> Self {
+        Self::new(Selector::Name(name.into()))
+    }
+
+    fn new(selector: Selector) -> Self {
+        Self {
+            selector,
+            conditions: Vec::new(),
+            path: Vec::new(),
+        }
+    }
+
+    /// Keeps the structures whose value at `path` compares to `value`
+    pub fn filter<V: Into<Value>>(mut self, path: &str, comparison: Comparison, value: V) -> Self {
+        self.conditions.push(Condition {
+            path: split_path(path),
+            comparison: Some((comparison, value.into())),
+        });
+        self
+    }
+
+    /// Keeps the structures having a value other than null at `path`
+    pub fn has(mut self, path: &str) -> Self {
+        self.conditions.push(Condition {
+            path: split_path(path),
+            comparison: None,
+        });
+        self
+    }
+
+    /// Selects the value at `path` of the structures, rather than the
+    /// structures themselves
+    pub fn select(mut self, path: &str) -> Self {
+        self.path = split_path(path);
+        self
+    }
+
+    /// The values selected in `data`, in table order
+    ///
+    /// Selected structures without a value at the path of the query are
+    /// skipped.
+    pub fn run(&self, data: &SMBiosData) -> Vec<Value> {
+        data.iter()
+            .filter(|parts| self.selects_type(parts))
+            .map(struct_value)
+            .filter(|structure| {
+                self.conditions
+                    .iter()
+                    .all(|condition| condition.holds(structure))
+            })
+            .filter_map(|structure| resolve(&structure, &self.path).cloned())
+            .collect()
+    }
+
+    fn selects_type(&self, parts: &UndefinedStruct) -> bool {
+        let struct_type = parts.header.struct_type();
+        match &self.selector {
+            Selector::All => true,
+            Selector::Type(selected) => *selected == struct_type,
+            Selector::Name(name) => is_named(struct_type, name),
+        }
+    }
+}
+
+impl Condition {
+    fn holds(&self, structure: &Value) -> bool {
+        let value = resolve(structure, &self.path).unwrap_or(&Value::Null);
+        match &self.comparison {
+            Some((comparison, literal)) => comparison.holds(compare(value, literal)),
+            None => !value.is_null(),
+        }
+    }
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        Parser {
+            expression,
+            position: 0,
+        }
+        .query()
+    }
+}
+
+impl SMBiosData {
+    /// The values selected by the query `expression` (see [Query]), in
+    /// table order
+    ///
+    /// ```
+    /// # use smbioslib::*;
+    /// # fn inventory(data: &SMBiosData) -> Result<(), QueryError> {
+    /// for serial_number in data.query("memory_device[size>0].serial_number")? {
+    ///     println!("{}", serial_number);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query(&self, expression: &str) -> Result<Vec<Value>, QueryError> {
+        Ok(expression.parse::<Query>()?.run(self))
+    }
+}
+
+/// Query expression errors
+pub enum QueryError {
+    /// The expression ends where more was expected
+    UnexpectedEnd,
+    /// The character is not expected at this position
+    UnexpectedCharacter {
+        /// Byte offset of the character in the expression
+        position: usize,
+        /// The unexpected character
+        character: char,
+    },
+    /// The string starting at this position has no closing quote
+    UnterminatedString {
+        /// Byte offset of the opening quote in the expression
+        position: usize,
+    },
+    /// The number does not fit a 64-bit integer
+    InvalidNumber(String),
+    /// No structure type has this name
+    UnknownStructure(String),
+}
+
+impl fmt::Debug for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("QueryError")
+            .field(&format_args!("{}", &self))
+            .finish()
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryError::UnexpectedEnd => f.write_str("Unexpected end of the query"),
+            QueryError::UnexpectedCharacter {
+                position,
+                character,
+            } => write!(
+                f,
+                "Unexpected character '{}' at position {} of the query",
+                character, position
+            ),
+            QueryError::UnterminatedString { position } => write!(
+                f,
+                "The string at position {} of the query has no closing quote",
+                position
+            ),
+            QueryError::InvalidNumber(number) => write!(f, "Invalid number {}", number),
+            QueryError::UnknownStructure(name) => write!(f, "Unknown structure {}", name),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for QueryError {}
+
+struct Parser<'a> {
+    expression: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn query(mut self) -> Result<Query, QueryError> {
+        self.skip_whitespace();
+        let selector = if self.eat('*') {
+            Selector::All
+        } else {
+            let word = self.word()?;
+            match parse_number(&word)? {
+                Some(number) => Selector::Type(
+                    u8::try_from(number).map_err(|_| QueryError::InvalidNumber(word))?,
+                ),
+                None if is_struct_name(&word) => Selector::Name(word),
+                None => return Err(QueryError::UnknownStructure(word)),
+            }
+        };
+        let mut query = Query::new(selector);
+
+        self.skip_whitespace();
+        while self.eat('[') {
+            query.conditions.push(self.condition()?);
+            self.skip_whitespace();
+            self.expect(']')?;
+            self.skip_whitespace();
+        }
+
+        if self.eat('.') {
+            query.path = self.path()?;
+            self.skip_whitespace();
+        }
+
+        match self.peek() {
+            None => Ok(query),
+            Some(character) => Err(self.unexpected(character)),
+        }
+    }
+
+    fn condition(&mut self) -> Result<Condition, QueryError> {
+        self.skip_whitespace();
+        let path = self.path()?;
+        self.skip_whitespace();
+        let comparison = if self.eat('=') {
+            Comparison::Equal
+        } else if self.eat('!') {
+            self.expect('=')?;
+            Comparison::NotEqual
+        } else if self.eat('<') {
+            if self.eat('=') {
+                Comparison::LessOrEqual
+            } else {
+                Comparison::Less
+            }
+        } else if self.eat('>') {
+            if self.eat('=') {
+                Comparison::GreaterOrEqual
+            } else {
+                Comparison::Greater
+            }
+        } else {
+            return Ok(Condition {
+                path,
+                comparison: None,
+            });
+        };
+
+        self.skip_whitespace();
+        let literal = self.literal()?;
+        Ok(Condition {
+            path,
+            comparison: Some((comparison, literal)),
+        })
+    }
+
+    fn literal(&mut self) -> Result<Value, QueryError> {
+        match self.peek() {
+            Some(quote) if quote == '"' || quote == '\'' => {
+                let start = self.position;
+                self.position += 1;
+                let rest = &self.expression[self.position..];
+                let length = rest
+                    .find(quote)
+                    .ok_or(QueryError::UnterminatedString { position: start })?;
+                self.position += length + 1;
+                Ok(Value::String(rest[..length].into()))
+            }
+            Some('-') => {
+                self.position += 1;
+                let word = self.word()?;
+                let number = parse_number(&word)?
+                    .and_then(|number| i64::try_from(number).ok())
+                    .ok_or_else(|| QueryError::InvalidNumber(format!("-{}", word)))?;
+                Ok(Value::from(-number))
+            }
+            _ => {
+                let word = self.word()?;
+                Ok(match (parse_number(&word)?, word.as_str()) {
+                    (Some(number), _) => Value::from(number),
+                    (None, "null") => Value::Null,
+                    (None, "true") => Value::Bool(true),
+                    (None, "false") => Value::Bool(false),
+                    (None, _) => Value::String(word),
+                })
+            }
+        }
+    }
+
+    fn path(&mut self) -> Result<Vec<String>, QueryError> {
+        let mut path = vec![self.word()?];
+        while self.eat('.') {
+            path.push(self.word()?);
+        }
+        Ok(path)
+    }
+
+    fn word(&mut self) -> Result<String, QueryError> {
+        let rest = &self.expression[self.position..];
+        let length = rest
+            .find(|character: char| !(character.is_ascii_alphanumeric() || character == '_'))
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(match self.peek() {
+                Some(character) => self.unexpected(character),
+                None => QueryError::UnexpectedEnd,
+            });
+        }
+        self.position += length;
+        Ok(rest[..length].into())
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.expression[self.position..].chars().next()
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), QueryError> {
+        match self.peek() {
+            Some(character) if character == expected => {
+                self.position += character.len_utf8();
+                Ok(())
+            }
+            Some(character) => Err(self.unexpected(character)),
+            None => Err(QueryError::UnexpectedEnd),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(character) = self.peek().filter(|character| character.is_whitespace()) {
+            self.position += character.len_utf8();
+        }
+    }
+
+    fn unexpected(&self, character: char) -> QueryError {
+        QueryError::UnexpectedCharacter {
+            position: self.position,
+            character,
+        }
+    }
+}
+
+/// The number of a word of decimal digits or of hexadecimal digits
+/// prefixed by `0x`, None for other words
+fn parse_number(word: &str) -> Result<Option<u64>, QueryError> {
+    let invalid = || QueryError::InvalidNumber(word.to_string());
+    if let Some(hex) = word.strip_prefix("0x").or_else(|| word.strip_prefix("0X")) {
+        return u64::from_str_radix(hex, 16)
+            .map(Some)
+            .map_err(|_| invalid());
+    }
+    if word.bytes().all(|byte| byte.is_ascii_digit()) {
+        return word.parse().map(Some).map_err(|_| invalid());
+    }
+    Ok(None)
+}
+
+fn split_path(path: &str) -> Vec<String> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn is_struct_name(name: &str) -> bool {
+    (0..=u8::MAX).any(|struct_type| is_named(struct_type, name))
+}
+
+/// Whether the structure type is named `name` (see [snake_case])
+fn is_named(struct_type: u8, name: &str) -> bool {
+    matches!(struct_name(struct_type), Some(struct_name) if snake_case(struct_name) == name)
+}
+
+/// The name in lowercase words separated by underscores, for example
+/// "End-of-Table" as `end_of_table`
+fn snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+    for word in name
+        .split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        if !snake_case.is_empty() {
+            snake_case.push('_');
+        }
+        snake_case.push_str(&word.to_ascii_lowercase());
+    }
+    snake_case
+}
+
+/// The value at `path` of the JSON shape of a structure, the structure
+/// itself for an empty path
+fn resolve<'v>(structure: &'v Value, path: &[String]) -> Option<&'v Value> {
+    let (first, rest) = match path.split_first() {
+        Some(split) => split,
+        None => return Some(structure),
+    };
+
+    let mut value = structure
+        .get("fields")
+        .and_then(|fields| fields.get(first.as_str()))
+        .or_else(|| structure.get(first.as_str()))?;
+    for segment in rest {
+        value = match value {
+            Value::Array(elements) => elements.get(segment.parse::<usize>().ok()?)?,
+            _ => value.get(segment.as_str())?,
+        };
+    }
+    Some(value)
+}
+
+fn compare(value: &Value, literal: &Value) -> Option<Ordering> {
+    match (value, literal) {
+        // enumerations, by name or by raw value
+        (Value::Object(object), Value::String(_)) => compare(object.get("value")?, literal),
+        (Value::Object(object), Value::Number(_)) => compare(object.get("raw")?, literal),
+        (Value::Number(value), Value::Number(literal)) => {
+            match (value.as_u64(), literal.as_u64()) {
+                (Some(value), Some(literal)) => Some(value.cmp(&literal)),
+                _ => match (value.as_i64(), literal.as_i64()) {
+                    (Some(value), Some(literal)) => Some(value.cmp(&literal)),
+                    _ => value.as_f64()?.partial_cmp(&literal.as_f64()?),
+                },
+            }
+        }
+        (Value::String(value), Value::String(literal)) => Some(value.cmp(literal)),
+        (Value::Bool(value), Value::Bool(literal)) => Some(value.cmp(literal)),
+        (Value::Null, Value::Null) => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{MemoryDeviceBuilder, SMBiosTableBuilder, SystemInformationBuilder};
+    use crate::core::{Handle, SMBiosVersion};
+    use serde_json::json;
+
+    #[test]
+    fn test_parse() {
+        let query: Query = " memory_device [ size > 0 ] [serial_number]\t.serial_number "
+            .parse()
+            .unwrap();
+        assert_eq!(
+            query,
+            Query::structure("memory_device")
+                .filter("size", Comparison::Greater, 0)
+                .has("serial_number")
+                .select("serial_number")
+        );
+
+        let query: Query = "17[memory_type!='Ddr4'][handle<=0x10][x>=-1]"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            query,
+            Query::struct_type(17)
+                .filter("memory_type", Comparison::NotEqual, "Ddr4")
+                .filter("handle", Comparison::LessOrEqual, 0x10)
+                .filter("x", Comparison::GreaterOrEqual, -1)
+        );
+        assert_eq!(
+            "*.handle".parse::<Query>().unwrap(),
+            Query::all().select("handle")
+        );
+        assert_eq!(
+            "end_of_table".parse::<Query>().unwrap(),
+            Query::structure("end_of_table")
+        );
+
+        assert!(matches!(
+            "memory_devices".parse::<Query>(),
+            Err(QueryError::UnknownStructure(_))
+        ));
+        assert!(matches!(
+            "memory_device[size>]".parse::<Query>(),
+            Err(QueryError::UnexpectedCharacter {
+                position: 19,
+                character: ']'
+            })
+        ));
+        assert!(matches!(
+            "memory_device[size".parse::<Query>(),
+            Err(QueryError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            "memory_device[part_number='A]".parse::<Query>(),
+            Err(QueryError::UnterminatedString { position: 26 })
+        ));
+        assert!(matches!(
+            "256".parse::<Query>(),
+            Err(QueryError::InvalidNumber(_))
+        ));
+    }
+
+    #[test]
+    fn test_query() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add(SystemInformationBuilder::new().manufacturer("Manufacturer"))
+            .unwrap();
+        for (size, serial_number) in [(40000u32, "A1"), (0, ""), (8192, "B2")].iter() {
+            builder
+                .add(
+                    MemoryDeviceBuilder::new(Handle(0xFFFE))
+                        .size_mb(*size)
+                        .memory_type(0x1A)
+                        .serial_number(serial_number),
+                )
+                .unwrap();
+        }
+        let data = builder.build().unwrap();
+
+        assert_eq!(
+            data.query("memory_device[size>0].serial_number").unwrap(),
+            vec![json!("A1"), json!("B2")]
+        );
+        assert_eq!(
+            data.query("memory_device[size=0x7FFF].extended_size")
+                .unwrap(),
+            vec![json!(40000)]
+        );
+        assert_eq!(
+            data.query("system_information.manufacturer").unwrap(),
+            vec![json!("Manufacturer")]
+        );
+        assert_eq!(
+            data.query("memory_device[memory_type=Ddr4]").unwrap().len(),
+            3
+        );
+        assert_eq!(
+            data.query("memory_device[memory_type=0x1A]").unwrap().len(),
+            3
+        );
+        assert_eq!(data.query("17").unwrap().len(), 3);
+        assert_eq!(data.query("*.type").unwrap().len(), 5);
+        assert!(data
+            .query("memory_device.no_such_field")
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,519 @@
+//! Redfish resource mapping.
+//!
+//! Converts structures into JSON fragments of the DMTF Redfish
+//! `ComputerSystem`, `Processor`, `Memory` and `Chassis` resources,
+//! following the SMBIOS to Redfish mapping guidance of DMTF (DSP2046).
+//!
+//! The fragments hold the properties derived from the table only. Resource
+//! annotations (`@odata.id`, `@odata.type`), `Id`, `Name` and links between
+//! resources depend on the service and are left to the caller. A property
+//! is omitted when the structure does not provide it or its value is
+//! unknown.
+
+use crate::core::{trim_padding, MaybeValue, SMBiosData};
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::{format, string::String, vec, vec::Vec};
+use serde_json::{json, Map, Value};
+
+/// Properties of a resource, skipping absent values
+struct Resource(Map<String, Value>);
+
+impl Resource {
+    fn new() -> Self {
+        Resource(Map::new())
+    }
+
+    fn set<T: Into<Value>>(&mut self, name: &str, value: Option<T>) {
+        if let Some(value) = value {
+            self.0.insert(name.into(), value.into());
+        }
+    }
+
+    /// Sets a string property, see [trim_padding]
+    fn string(&mut self, name: &str, value: Option<String>) {
+        self.set(name, trim_padding(value));
+    }
+
+    fn state(&mut self, state: Option<&str>) {
+        self.set("Status", state.map(|state| json!({ "State": state })));
+    }
+}
+
+impl From<Resource> for Value {
+    fn from(resource: Resource) -> Self {
+        Value::Object(resource.0)
+    }
+}
+
+/// The `ComputerSystem` resource of the table
+///
+/// Mapped from System Information (Type 1) with `BiosVersion` from BIOS
+/// Information (Type 0), and the `ProcessorSummary` and `MemorySummary`
+/// of the [redfish_processors] and [redfish_memory] of the table.
+/// `SystemType` is "Virtual" when the BIOS characteristics declare a
+/// virtual machine, otherwise "Physical".
+///
+/// Like the other `redfish_*` fragments, it holds the properties derived
+/// from the table only, following DMTF DSP2046. Resource annotations, `Id`,
+/// `Name` and links between resources are left to the caller.
+pub fn redfish_computer_system(data: &SMBiosData) -> Value {
+    let mut resource = Resource::new();
+    let bios_info = data.first::<SMBiosInformation<'_>>();
+    if let Some(system_info) = data.first::<SMBiosSystemInformation<'_>>() {
+        resource.string("Manufacturer", system_info.manufacturer());
+        resource.string("Model", system_info.product_name());
+        resource.string("SerialNumber", system_info.serial_number());
+        resource.string("SKU", system_info.sku_number());
+        resource.set(
+            "UUID",
+            match system_info.uuid() {
+                Some(SystemUuidData::Uuid(uuid)) => Some(format!("{}", uuid)),
+                _ => None,
+            },
+        );
+    }
+
+    let virtual_machine = bios_info
+        .as_ref()
+        .and_then(|bios_info| bios_info.characteristics_extension1())
+        .map(|extension| extension.smbios_table_describes_avirtual_machine())
+        .unwrap_or(false);
+    resource.set(
+        "SystemType",
+        Some(if virtual_machine {
+            "Virtual"
+        } else {
+            "Physical"
+        }),
+    );
+    resource.string(
+        "BiosVersion",
+        bios_info.and_then(|bios_info| bios_info.version()),
+    );
+
+    let processors: Vec<SMBiosProcessorInformation<'_>> = data
+        .defined_struct_iter::<SMBiosProcessorInformation<'_>>()
+        .filter(|processor| processor_populated(processor))
+        .collect();
+    let mut summary = Resource::new();
+    summary.set("Count", Some(processors.len()));
+    summary.string(
+        "Model",
+        processors
+            .iter()
+            .find_map(|processor| processor.processor_version()),
+    );
+    summary.set(
+        "CoreCount",
+        processors
+            .iter()
+            .map(|processor| processor.effective_core_count().map(u32::from))
+            .sum::<Option<u32>>(),
+    );
+    summary.set(
+        "LogicalProcessorCount",
+        processors
+            .iter()
+            .map(|processor| processor.effective_thread_count().map(u32::from))
+            .sum::<Option<u32>>(),
+    );
+    resource.set("ProcessorSummary", Some(summary));
+
+    let total_mib: u64 = data
+        .defined_struct_iter::<SMBiosMemoryDevice<'_>>()
+        .filter_map(|memory_device| capacity_mib(&memory_device))
+        .sum();
+    resource.set(
+        "MemorySummary",
+        Some(json!({ "TotalSystemMemoryGiB": total_mib as f64 / 1024.0 })),
+    );
+
+    resource.into()
+}
+
+/// The `Processor` resources of the table, one per Processor Information
+/// (Type 4) structure
+pub fn redfish_processors(data: &SMBiosData) -> Vec<Value> {
+    data.defined_struct_iter::<SMBiosProcessorInformation<'_>>()
+        .map(|processor_info| redfish_processor(&processor_info))
+        .collect()
+}
+
+/// The `Processor` resource of a Processor Information (Type 4) structure
+pub fn redfish_processor(processor_info: &SMBiosProcessorInformation<'_>) -> Value {
+    let mut resource = Resource::new();
+    resource.string("Socket", processor_info.socket_designation());
+    resource.set(
+        "ProcessorType",
+        processor_info
+            .processor_type()
+            .and_then(|processor_type| match processor_type.value {
+                ProcessorType::CentralProcessor => Some("CPU"),
+                ProcessorType::MathProcessor => Some("Accelerator"),
+                ProcessorType::DspProcessor => Some("DSP"),
+                ProcessorType::VideoProcessor => Some("GPU"),
+                ProcessorType::Other => Some("OEM"),
+                _ => None,
+            }),
+    );
+    resource.string("Manufacturer", processor_info.processor_manufacturer());
+    resource.string("Model", processor_info.processor_version());
+    resource.set("MaxSpeedMHz", mhz(processor_info.max_speed()));
+    resource.set("OperatingSpeedMHz", mhz(processor_info.current_speed()));
+    resource.set("TotalCores", processor_info.effective_core_count());
+    resource.set(
+        "TotalEnabledCores",
+        processor_info.effective_cores_enabled(),
+    );
+    resource.set("TotalThreads", processor_info.effective_thread_count());
+    resource.string("SerialNumber", processor_info.serial_number());
+    resource.string("PartNumber", processor_info.part_number());
+    if let Some(processor_id) = processor_info.processor_id() {
+        let mut id = Resource::new();
+        id.string("VendorId", processor_info.processor_manufacturer());
+        id.set(
+            "IdentificationRegisters",
+            Some(format!("0x{:016X}", u64::from_le_bytes(*processor_id))),
+        );
+        resource.set("ProcessorId", Some(id));
+    }
+
+    resource.state(processor_info.status().and_then(|status| {
+        if !status.socket_populated() {
+            return Some("Absent");
+        }
+
+        match status.cpu_status() {
+            CpuStatus::Enabled => Some("Enabled"),
+            CpuStatus::UserDisabled | CpuStatus::BiosDisabled => Some("Disabled"),
+            CpuStatus::Idle => Some("StandbyOffline"),
+            _ => None,
+        }
+    }));
+    resource.into()
+}
+
+/// The `Memory` resources of the table, one per Memory Device (Type 17)
+/// structure
+pub fn redfish_memory(data: &SMBiosData) -> Vec<Value> {
+    data.defined_struct_iter::<SMBiosMemoryDevice<'_>>()
+        .map(|memory_device| redfish_memory_module(&memory_device))
+        .collect()
+}
+
+/// The `Memory` resource of a Memory Device (Type 17) structure
+pub fn redfish_memory_module(memory_device: &SMBiosMemoryDevice<'_>) -> Value {
+    let mut resource = Resource::new();
+    resource.string("DeviceLocator", memory_device.device_locator());
+    resource.set("CapacityMiB", capacity_mib(memory_device));
+    resource.string("Manufacturer", memory_device.manufacturer());
+    resource.string("SerialNumber", memory_device.serial_number());
+    resource.string("PartNumber", memory_device.part_number());
+    resource.set(
+        "MemoryDeviceType",
+        memory_device
+            .memory_type()
+            .and_then(|memory_type| match memory_type.value {
+                MemoryDeviceType::Rom => Some("ROM"),
+                MemoryDeviceType::Sdram => Some("SDRAM"),
+                MemoryDeviceType::Ddr => Some("DDR"),
+                MemoryDeviceType::Ddr2 => Some("DDR2"),
+                MemoryDeviceType::Ddr2Fbdimm => Some("DDR2_SDRAM_FB_DIMM"),
+                MemoryDeviceType::Ddr3 => Some("DDR3"),
+                MemoryDeviceType::Ddr4 => Some("DDR4"),
+                MemoryDeviceType::Lpddr3 => Some("LPDDR3_SDRAM"),
+                MemoryDeviceType::Lpddr4 => Some("LPDDR4_SDRAM"),
+                MemoryDeviceType::Ddr5 => Some("DDR5"),
+                MemoryDeviceType::Lpddr5 => Some("LPDDR5_SDRAM"),
+                MemoryDeviceType::Hbm => Some("HBM"),
+                MemoryDeviceType::Hbm2 => Some("HBM2"),
+                MemoryDeviceType::Hbm3 => Some("HBM3"),
+                _ => None,
+            }),
+    );
+    resource.set(
+        "BaseModuleType",
+        memory_device
+            .form_factor()
+            .and_then(|form_factor| match form_factor.value {
+                MemoryFormFactor::Sodimm => Some("SO_DIMM"),
+                MemoryFormFactor::Die => Some("Die"),
+                MemoryFormFactor::Dimm => {
+                    memory_device
+                        .type_detail()
+                        .and_then(|type_detail| match () {
+                            _ if type_detail.lrdimm() => Some("LRDIMM"),
+                            _ if type_detail.registered() => Some("RDIMM"),
+                            _ if type_detail.unbuffered() => Some("UDIMM"),
+                            _ => None,
+                        })
+                }
+                _ => None,
+            }),
+    );
+    resource.set(
+        "MemoryType",
+        memory_device
+            .memory_technology()
+            .and_then(|technology| match technology.value {
+                MemoryDeviceTechnology::Dram => Some("DRAM"),
+                MemoryDeviceTechnology::NvdimmN => Some("NVDIMM_N"),
+                MemoryDeviceTechnology::NvdimmF => Some("NVDIMM_F"),
+                MemoryDeviceTechnology::NvdimmP => Some("NVDIMM_P"),
+                MemoryDeviceTechnology::IntelOptaneDcPersistentMemory => Some("IntelOptane"),
+                _ => None,
+            }),
+    );
+    resource.set(
+        "OperatingSpeedMhz",
+        memory_device
+            .effective_configured_memory_speed()
+            .and_then(MaybeValue::value)
+            .map(|speed| speed.as_megatransfers()),
+    );
+    resource.set(
+        "AllowedSpeedsMHz",
+        memory_device
+            .effective_speed()
+            .and_then(MaybeValue::value)
+            .map(|speed| vec![speed.as_megatransfers()]),
+    );
+    resource.set(
+        "DataWidthBits",
+        memory_device.data_width().filter(|width| *width != 0xFFFF),
+    );
+    resource.set(
+        "BusWidthBits",
+        memory_device.total_width().filter(|width| *width != 0xFFFF),
+    );
+    resource.set(
+        "RankCount",
+        memory_device
+            .attributes()
+            .map(|attributes| attributes & 0x0F)
+            .filter(|rank| *rank != 0),
+    );
+    resource.state(match memory_device.effective_size() {
+        MaybeValue::NotProvided => Some("Absent"),
+        MaybeValue::Value(_) => Some("Enabled"),
+        _ => None,
+    });
+    resource.into()
+}
+
+/// The `Chassis` resources of the table, one per System Enclosure or
+/// Chassis (Type 3) structure
+pub fn redfish_chassis(data: &SMBiosData) -> Vec<Value> {
+    data.defined_struct_iter::<SMBiosSystemChassisInformation<'_>>()
+        .map(|chassis_info| redfish_chassis_enclosure(&chassis_info))
+        .collect()
+}
+
+/// The `Chassis` resource of a System Enclosure or Chassis (Type 3)
+/// structure
+pub fn redfish_chassis_enclosure(chassis_info: &SMBiosSystemChassisInformation<'_>) -> Value {
+    let mut resource = Resource::new();
+    resource.set(
+        "ChassisType",
+        chassis_info.chassis_type().map(|chassis_type| {
+            use ChassisType::*;
+            match chassis_type.value {
+                Desktop | LowProfileDesktop | MiniTower | Tower | Portable | Laptop | Notebook
+                | HandHeld | AllInOne | SubNotebook | SpaceSaving | LunchBox
+                | MainServerChassis | SealedCasePC | Tablet | Convertible | Detachable
+                | IoTGateway | EmbeddedPC | MiniPC | StickPC => "StandAlone",
+                PizzaBox | RackMountChassis => "RackMount",
+                ExpansionChassis | BusExpansionChassis => "Expansion",
+                PeripheralChassis | MultiSystemChassis | BladeEnclosure | DockingStation => {
+                    "Enclosure"
+                }
+                RaidChassis => "StorageEnclosure",
+                Blade | AdvancedTca => "Blade",
+                SubChassis => "Module",
+                CompactPci => "Card",
+                _ => "Other",
+            }
+        }),
+    );
+    resource.string("Manufacturer", chassis_info.manufacturer());
+    resource.string("Version", chassis_info.version());
+    resource.string("SerialNumber", chassis_info.serial_number());
+    resource.string("SKU", chassis_info.sku_number());
+    resource.string("AssetTag", chassis_info.asset_tag_number());
+    resource.into()
+}
+
+fn processor_populated(processor_info: &SMBiosProcessorInformation<'_>) -> bool {
+    processor_info
+        .status()
+        .map(|status| status.socket_populated())
+        .unwrap_or(false)
+}
+
+fn mhz(speed: Option<ProcessorSpeed>) -> Option<u32> {
+    Some(speed?.megahertz().value()?.as_megahertz())
+}
+
+/// Size of an installed memory device in MiB
+fn capacity_mib(memory_device: &SMBiosMemoryDevice<'_>) -> Option<u64> {
+    memory_device.effective_size().as_mebibytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{SMBiosTableBuilder, StructBuilder, SystemInformationBuilder};
+    use crate::core::SMBiosVersion;
+
+    fn test_data() -> SMBiosData {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add(
+                SystemInformationBuilder::new()
+                    .manufacturer("Manufacturer ")
+                    .product_name("Product"),
+            )
+            .unwrap();
+        builder
+            .add(
+                StructBuilder::new(3)
+                    .string("Manufacturer")
+                    .byte(0x17) // Rack Mount Chassis
+                    .string("")
+                    .string("Serial")
+                    .string("")
+                    .data(&[3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0])
+                    .string("SKU"),
+            )
+            .unwrap();
+        for (socket, status) in [("CPU 0", 0x41u8), ("CPU 1", 0x00)].iter() {
+            builder
+                .add(
+                    StructBuilder::new(4)
+                        .string(socket)
+                        .byte(3) // Central Processor
+                        .byte(0xB3)
+                        .string("Intel")
+                        .qword(0xBFEBFBFF_00050657)
+                        .string("Xeon")
+                        .byte(0)
+                        .word(100)
+                        .word(4000)
+                        .word(2100)
+                        .byte(*status)
+                        .byte(1)
+                        .data(&[0xFF; 6])
+                        .string("")
+                        .string("")
+                        .string("")
+                        .byte(24)
+                        .byte(24)
+                        .byte(48)
+                        .word(0xEC)
+                        .word(0xB3)
+                        .word(24)
+                        .word(24)
+                        .word(48),
+                )
+                .unwrap();
+        }
+        builder
+            .add(
+                StructBuilder::new(17)
+                    .word(0x1000)
+                    .word(0xFFFE)
+                    .word(72)
+                    .word(64)
+                    .word(0x7FFF) // see extended size
+                    .byte(0x09) // DIMM
+                    .byte(0)
+                    .string("DIMM A1")
+                    .string("P0")
+                    .byte(0x1A) // DDR4
+                    .word(0x2080) // synchronous, registered
+                    .word(3200)
+                    .string("Vendor")
+                    .string("Serial")
+                    .string("")
+                    .string("Part   ")
+                    .byte(2)
+                    .dword(0x10000) // 64 GiB
+                    .word(2933)
+                    .data(&[0; 6])
+                    .byte(3), // DRAM
+            )
+            .unwrap();
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_redfish() {
+        let data = test_data();
+
+        let system = redfish_computer_system(&data);
+        assert_eq!(system["Manufacturer"], "Manufacturer");
+        assert_eq!(system["Model"], "Product");
+        assert_eq!(system["SystemType"], "Physical");
+        assert!(system.get("SerialNumber").is_none());
+        assert_eq!(
+            system["ProcessorSummary"],
+            json!({ "Count": 1, "Model": "Xeon", "CoreCount": 24, "LogicalProcessorCount": 48 })
+        );
+        assert_eq!(system["MemorySummary"]["TotalSystemMemoryGiB"], 64.0);
+
+        let processors = redfish_processors(&data);
+        assert_eq!(processors.len(), 2);
+        assert_eq!(
+            processors[0],
+            json!({
+                "Socket": "CPU 0",
+                "ProcessorType": "CPU",
+                "Manufacturer": "Intel",
+                "Model": "Xeon",
+                "MaxSpeedMHz": 4000,
+                "OperatingSpeedMHz": 2100,
+                "TotalCores": 24,
+                "TotalEnabledCores": 24,
+                "TotalThreads": 48,
+                "ProcessorId": {
+                    "VendorId": "Intel",
+                    "IdentificationRegisters": "0xBFEBFBFF00050657"
+                },
+                "Status": { "State": "Enabled" }
+            })
+        );
+        assert_eq!(processors[1]["Status"]["State"], "Absent");
+
+        let memory = redfish_memory(&data);
+        assert_eq!(
+            memory,
+            vec![json!({
+                "DeviceLocator": "DIMM A1",
+                "CapacityMiB": 65536,
+                "Manufacturer": "Vendor",
+                "SerialNumber": "Serial",
+                "PartNumber": "Part",
+                "MemoryDeviceType": "DDR4",
+                "BaseModuleType": "RDIMM",
+                "MemoryType": "DRAM",
+                "OperatingSpeedMhz": 2933,
+                "AllowedSpeedsMHz": [3200],
+                "DataWidthBits": 64,
+                "BusWidthBits": 72,
+                "RankCount": 2,
+                "Status": { "State": "Enabled" }
+            })]
+        );
+
+        assert_eq!(
+            redfish_chassis(&data),
+            vec![json!({
+                "ChassisType": "RackMount",
+                "Manufacturer": "Manufacturer",
+                "SerialNumber": "Serial",
+                "SKU": "SKU"
+            })]
+        );
+    }
+}
@@ -0,0 +1,509 @@
+//! [fmt::Display] of the structures, rendered from their serialization
+//!
+//! A structure is written as a line naming its handle, type and length
+//! followed by a line for each of its fields, `name: value`. Fields which
+//! the structure does not hold are left out. A `*Data` field is written as
+//! its value, or as its raw value when the value is unknown to this library,
+//! and a set of flags as the names of the flags which are set.
+
+use super::*;
+use crate::core::Header;
+#[cfg(feature = "no_std")]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use serde::ser::{
+    self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+
+macro_rules! display_structures {
+    ($($variant:ident($structure:ident),)*) => {
+        $(
+            impl fmt::Display for $structure<'_> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write_structure(&self.parts().header, self, f)
+                }
+            }
+        )*
+
+        impl fmt::Display for DefinedStruct<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(DefinedStruct::$variant(structure) => fmt::Display::fmt(structure, f),)*
+                }
+            }
+        }
+    };
+}
+
+display_structures! {
+    Information(SMBiosInformation),
+    SystemInformation(SMBiosSystemInformation),
+    BaseBoardInformation(SMBiosBaseboardInformation),
+    SystemChassisInformation(SMBiosSystemChassisInformation),
+    ProcessorInformation(SMBiosProcessorInformation),
+    MemoryControllerInformation(SMBiosMemoryControllerInformation),
+    MemoryModuleInformation(SMBiosMemoryModuleInformation),
+    CacheInformation(SMBiosCacheInformation),
+    PortConnectorInformation(SMBiosPortConnectorInformation),
+    SystemSlot(SMBiosSystemSlot),
+    OnBoardDeviceInformation(SMBiosOnBoardDeviceInformation),
+    OemStrings(SMBiosOemStrings),
+    SystemConfigurationOptions(SMBiosSystemConfigurationOptions),
+    LanguageInformation(SMBiosBiosLanguageInformation),
+    GroupAssociations(SMBiosGroupAssociations),
+    EventLog(SMBiosSystemEventLog),
+    PhysicalMemoryArray(SMBiosPhysicalMemoryArray),
+    MemoryDevice(SMBiosMemoryDevice),
+    MemoryErrorInformation32Bit(SMBiosMemoryErrorInformation32),
+    MemoryArrayMappedAddress(SMBiosMemoryArrayMappedAddress),
+    MemoryDeviceMappedAddress(SMBiosMemoryDeviceMappedAddress),
+    BuiltInPointingDevice(SMBiosBuiltInPointingDevice),
+    PortableBattery(SMBiosPortableBattery),
+    SystemReset(SMBiosSystemReset),
+    HardwareSecurity(SMBiosHardwareSecurity),
+    SystemPowerControls(SMBiosSystemPowerControls),
+    VoltageProbe(SMBiosVoltageProbe),
+    CoolingDevice(SMBiosCoolingDevice),
+    TemperatureProbe(SMBiosTemperatureProbe),
+    ElectricalCurrentProbe(SMBiosElectricalCurrentProbe),
+    OutOfBandRemoteAccess(SMBiosOutOfBandRemoteAccess),
+    BisEntryPoint(SMBiosBisEntryPoint),
+    SystemBootInformation(SMBiosSystemBootInformation),
+    MemoryErrorInformation64Bit(SMBiosMemoryErrorInformation64),
+    ManagementDevice(SMBiosManagementDevice),
+    ManagementDeviceComponent(SMBiosManagementDeviceComponent),
+    ManagementDeviceThresholdData(SMBiosManagementDeviceThresholdData),
+    MemoryChannel(SMBiosMemoryChannel),
+    IpmiDeviceInformation(SMBiosIpmiDeviceInformation),
+    SystemPowerSupply(SMBiosSystemPowerSupply),
+    AdditionalInformation(SMBiosAdditionalInformation),
+    OnboardDevicesExtendedInformation(SMBiosOnboardDevicesExtendedInformation),
+    ManagementControllerHostInterface(SMBiosManagementControllerHostInterface),
+    TpmDevice(SMBiosTpmDevice),
+    ProcessorAdditionalInformation(SMBiosProcessorAdditionalInformation),
+    Inactive(SMBiosInactive),
+    EndOfTable(SMBiosEndOfTable),
+    Undefined(SMBiosUnknown),
+}
+
+/// Writes the structure with the header `header` and the serialization
+/// `structure`
+fn write_structure<T: Serialize>(
+    header: &Header,
+    structure: &T,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    write!(
+        f,
+        "Handle {:#06X}, type {}, {} bytes",
+        *header.handle(),
+        header.struct_type(),
+        header.length()
+    )?;
+    let fields = structure
+        .serialize(TextSerializer { structure: true })
+        .map_err(|_| fmt::Error)?;
+    f.write_str(fields.as_deref().unwrap_or_default())
+}
+
+/// An error of [TextSerializer], which renders every value
+#[derive(Debug)]
+struct TextError;
+
+impl fmt::Display for TextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("The value cannot be written as text")
+    }
+}
+
+impl ser::StdError for TextError {}
+
+impl ser::Error for TextError {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        TextError
+    }
+}
+
+/// A serializer rendering a value as text, None for an absent value
+///
+/// `structure` is set for a structure, whose fields are written one per
+/// line rather than within braces.
+struct TextSerializer {
+    structure: bool,
+}
+
+/// Renders `value`, an absent value as `None`
+fn text<T: Serialize + ?Sized>(value: &T) -> Result<String, TextError> {
+    Ok(value
+        .serialize(TextSerializer { structure: false })?
+        .unwrap_or_else(|| "None".into()))
+}
+
+macro_rules! display {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+                Ok(Some(v.to_string()))
+            }
+        )*
+    };
+}
+
+impl Serializer for TextSerializer {
+    type Ok = Option<String>;
+    type Error = TextError;
+    type SerializeSeq = TextSeq;
+    type SerializeTuple = TextSeq;
+    type SerializeTupleStruct = TextSeq;
+    type SerializeTupleVariant = TextSeq;
+    type SerializeMap = TextMap;
+    type SerializeStruct = TextStruct;
+    type SerializeStructVariant = TextStruct;
+
+    display! {
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_str(&str);
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(format!("{:02X?}", v)))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Some("()".into()))
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(name.into()))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(variant.into()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(format!("{}({})", variant, text(value)?)))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(TextSeq::new("", "[", "]"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(TextSeq::new("", "(", ")"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(TextSeq::new(name, "(", ")"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(TextSeq::new(variant, "(", ")"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(TextMap {
+            entries: Vec::new(),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(TextStruct::new("", self.structure))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(TextStruct::new(variant, false))
+    }
+}
+
+/// Renders the elements of a sequence or tuple as `prefix(a, b)`
+struct TextSeq {
+    prefix: &'static str,
+    open: &'static str,
+    close: &'static str,
+    elements: Vec<String>,
+}
+
+impl TextSeq {
+    fn new(prefix: &'static str, open: &'static str, close: &'static str) -> Self {
+        TextSeq {
+            prefix,
+            open,
+            close,
+            elements: Vec::new(),
+        }
+    }
+
+    fn end(self) -> Result<Option<String>, TextError> {
+        Ok(Some(format!(
+            "{}{}{}{}",
+            self.prefix,
+            self.open,
+            self.elements.join(", "),
+            self.close
+        )))
+    }
+}
+
+macro_rules! text_elements {
+    ($($trait:ident::$method:ident;)*) => {
+        $(
+            impl $trait for TextSeq {
+                type Ok = Option<String>;
+                type Error = TextError;
+
+                fn $method<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+                    self.elements.push(text(value)?);
+                    Ok(())
+                }
+
+                fn end(self) -> Result<Self::Ok, Self::Error> {
+                    TextSeq::end(self)
+                }
+            }
+        )*
+    };
+}
+
+text_elements! {
+    SerializeSeq::serialize_element;
+    SerializeTuple::serialize_element;
+    SerializeTupleStruct::serialize_field;
+    SerializeTupleVariant::serialize_field;
+}
+
+/// Renders the entries of a map as `{key: value}`
+struct TextMap {
+    entries: Vec<String>,
+    key: Option<String>,
+}
+
+impl SerializeMap for TextMap {
+    type Ok = Option<String>;
+    type Error = TextError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = Some(text(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.key.take().unwrap_or_default();
+        self.entries.push(format!("{}: {}", key, text(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(format!("{{{}}}", self.entries.join(", "))))
+    }
+}
+
+/// Renders the fields of a struct as `prefix {name: value}`, or one per
+/// line for a structure
+struct TextStruct {
+    prefix: &'static str,
+    structure: bool,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl TextStruct {
+    fn new(prefix: &'static str, structure: bool) -> Self {
+        TextStruct {
+            prefix,
+            structure,
+            fields: Vec::new(),
+        }
+    }
+
+    fn end(self) -> Result<Option<String>, TextError> {
+        if self.structure {
+            return Ok(Some(
+                self.fields
+                    .iter()
+                    .map(|(name, value)| format!("\n\t{}: {}", name, value))
+                    .collect(),
+            ));
+        }
+
+        let join = |fields: &[(&str, String)]| {
+            fields
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, value))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        // a *Data field: its value, or its raw value when the value is
+        // unknown, followed by the fields decoded along with it
+        if let [("raw", raw), ("value", value), rest @ ..] = self.fields.as_slice() {
+            let value = if value == "None" { raw } else { value };
+            return Ok(Some(match rest {
+                [] => value.clone(),
+                _ => format!("{} ({})", value, join(rest)),
+            }));
+        }
+
+        // a set of flags: the flags which are set
+        if let [("raw", _), flags @ ..] = self.fields.as_slice() {
+            if flags
+                .iter()
+                .all(|(_, value)| value == "true" || value == "false")
+            {
+                let set: Vec<&str> = flags
+                    .iter()
+                    .filter(|(_, value)| value == "true")
+                    .map(|(name, _)| *name)
+                    .collect();
+                return Ok(Some(format!("{{{}}}", set.join(", "))));
+            }
+        }
+
+        let separator = if self.prefix.is_empty() { "" } else { " " };
+        Ok(Some(format!(
+            "{}{}{{{}}}",
+            self.prefix,
+            separator,
+            join(&self.fields)
+        )))
+    }
+}
+
+macro_rules! text_fields {
+    ($($trait:ident;)*) => {
+        $(
+            impl $trait for TextStruct {
+                type Ok = Option<String>;
+                type Error = TextError;
+
+                fn serialize_field<T: Serialize + ?Sized>(
+                    &mut self,
+                    key: &'static str,
+                    value: &T,
+                ) -> Result<(), Self::Error> {
+                    if !self.structure {
+                        self.fields.push((key, text(value)?));
+                        return Ok(());
+                    }
+
+                    // the header is written ahead of the fields, and the
+                    // fields the structure does not hold are left out
+                    if key != "header" {
+                        if let Some(value) = value.serialize(TextSerializer { structure: false })? {
+                            self.fields.push((key, value));
+                        }
+                    }
+                    Ok(())
+                }
+
+                fn end(self) -> Result<Self::Ok, Self::Error> {
+                    TextStruct::end(self)
+                }
+            }
+        )*
+    };
+}
+
+text_fields! {
+    SerializeStruct;
+    SerializeStructVariant;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::UndefinedStruct;
+
+    #[test]
+    fn test_display() {
+        let parts = UndefinedStruct::new(&vec![
+            0x03, 0x0D, 0x05, 0x00, 0x01, 0x03, 0x00, 0x00, 0x00, 0x03, 0x03, 0x7F, 0x02, b'A',
+            0x00, 0x00,
+        ]);
+        let chassis = SMBiosSystemChassisInformation::new(&parts);
+        // the thermal state 7Fh is unknown to this library
+        assert_eq!(
+            chassis.to_string(),
+            "Handle 0x0005, type 3, 13 bytes\n\
+             \tmanufacturer: A\n\
+             \tchassis_type: Desktop (lock_presence: NotPresent)\n\
+             \tbootup_state: Safe\n\
+             \tpower_supply_state: Safe\n\
+             \tthermal_state: 127\n\
+             \tsecurity_status: Unknown"
+        );
+        assert_eq!(DefinedStruct::from(&parts).to_string(), chassis.to_string());
+
+        let parts = UndefinedStruct::new(&vec![
+            0x02, 0x0F, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0A,
+            0x00, 0x00, 0x00,
+        ]);
+        let baseboard = SMBiosBaseboardInformation::new(&parts);
+        assert!(baseboard
+            .to_string()
+            .contains("\n\tfeature_flags: {hosting_board, is_replaceable}\n"));
+    }
+}
@@ -0,0 +1,110 @@
+/// Generates the field accessors of a structure, within its `impl` block
+///
+/// Each field is declared as `name, name_access: kind @ offset;` and read
+/// with the `get_field_*()` function of the kind: `byte`, `word`, `dword`,
+/// `qword`, `handle` or `string`. A field declared as
+/// `name, name_access: kind @ offset => Type;` is converted with
+/// `Type::from`. Doc comments are kept on the `name()` accessor, which
+/// returns an `Option`, while `name_access()` returns a
+/// [MaybeValue](crate::core::MaybeValue) telling apart a field the
+/// structure is too short to hold. `Handle` and `String` are resolved where
+/// the macro is invoked.
+///
+/// Offsets are checked at compile time, a field must follow the header and
+/// end within the 255 bytes a structure's length can span.
+///
+/// ```ignore
+/// impl<'a> SMBiosSystemInformation<'a> {
+///     field_accessors! {
+///         /// Manufacturer
+///         manufacturer, manufacturer_access: string @ 0x04;
+///
+///         /// Wake-up type
+///         wakeup_type, wakeup_type_access: byte @ 0x18 => SystemWakeUpTypeData;
+///     }
+/// }
+/// ```
+macro_rules! field_accessors {
+    ($($(#[$meta:meta])* $name:ident, $access:ident: $kind:ident @ $offset:literal $(=> $target:ty)?;)*) => {
+        $(
+            field_accessors!(@accessor $(#[$meta])* $name $kind $offset $($target)?);
+            field_accessors!(@access $name $access $kind $offset $($target)?);
+        )*
+    };
+    (@accessor $(#[$meta:meta])* $name:ident $kind:ident $offset:literal) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> Option<field_accessors!(@type $kind)> {
+            field_accessors!(@check $kind $offset);
+            field_accessors!(@get self.parts, $kind, $offset)
+        }
+    };
+    (@accessor $(#[$meta:meta])* $name:ident $kind:ident $offset:literal $target:ty) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> Option<$target> {
+            field_accessors!(@check $kind $offset);
+            field_accessors!(@get self.parts, $kind, $offset).map(<$target>::from)
+        }
+    };
+    (@access $name:ident $access:ident string $offset:literal) => {
+        #[doc = concat!(
+            "[Self::", stringify!($name), "], telling apart a field the structure is too ",
+            "short to hold and a string index past the end of the string-set, see ",
+            "[MaybeValue](crate::core::MaybeValue)"
+        )]
+        pub fn $access(&self) -> crate::core::MaybeValue<String> {
+            self.parts.access_field_string($offset)
+        }
+    };
+    (@access $name:ident $access:ident $kind:ident $offset:literal) => {
+        field_accessors!(@access $name $access $kind $offset field_accessors!(@type $kind));
+    };
+    (@access $name:ident $access:ident $kind:ident $offset:literal $target:ty) => {
+        #[doc = concat!(
+            "[Self::", stringify!($name), "], telling apart a field the structure is too ",
+            "short to hold, see [MaybeValue](crate::core::MaybeValue)"
+        )]
+        pub fn $access(&self) -> crate::core::MaybeValue<$target> {
+            crate::core::MaybeValue::of(
+                self.parts,
+                $offset,
+                field_accessors!(@width $kind),
+                self.$name(),
+            )
+        }
+    };
+    (@check $kind:ident $offset:literal) => {
+        #[allow(clippy::int_plus_one)]
+        const _: () = assert!(
+            $offset >= crate::core::Header::SIZE && $offset + field_accessors!(@width $kind) <= 0x100,
+            "the field is outside the formatted area of a structure"
+        );
+    };
+    (@type byte) => { u8 };
+    (@type word) => { u16 };
+    (@type dword) => { u32 };
+    (@type qword) => { u64 };
+    (@type handle) => { Handle };
+    (@type string) => { String };
+    (@width byte) => { 1 };
+    (@width word) => { 2 };
+    (@width dword) => { 4 };
+    (@width qword) => { 8 };
+    (@width handle) => { 2 };
+    (@width string) => { 1 };
+    (@get $parts:expr, byte, $offset:literal) => {
+        crate::core::StructFields::get_field_byte($parts, $offset)
+    };
+    (@get $parts:expr, word, $offset:literal) => {
+        crate::core::StructFields::get_field_word($parts, $offset)
+    };
+    (@get $parts:expr, dword, $offset:literal) => {
+        crate::core::StructFields::get_field_dword($parts, $offset)
+    };
+    (@get $parts:expr, qword, $offset:literal) => {
+        crate::core::StructFields::get_field_qword($parts, $offset)
+    };
+    (@get $parts:expr, handle, $offset:literal) => {
+        crate::core::StructFields::get_field_handle($parts, $offset)
+    };
+    (@get $parts:expr, string, $offset:literal) => { $parts.get_field_string($offset) };
+}
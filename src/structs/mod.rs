@@ -1,7 +1,13 @@
+#[macro_use]
+mod field_accessors;
+
 mod defined_struct;
+mod display;
+mod raw_value;
 mod structure;
 mod types;
 
 pub use defined_struct::*;
+pub use raw_value::*;
 pub use structure::*;
 pub use types::*;
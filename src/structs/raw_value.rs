@@ -0,0 +1,109 @@
+use super::types::*;
+
+/// # Raw Value of an Enumerated Field
+///
+/// Implemented by the `*Data` structures that enumerated fields are read as,
+/// such as [ProcessorFamilyData]. The raw value is the field as found in the
+/// table, including values this library does not know and decodes as the
+/// `None` variant of the enumeration.
+pub trait RawValue {
+    /// Type of the raw value
+    type Raw;
+
+    /// The raw value of the field
+    fn raw_value(&self) -> Self::Raw;
+}
+
+macro_rules! impl_raw_value {
+    ($($data:ty => $raw:ty),* $(,)?) => {
+        $(
+            impl RawValue for $data {
+                type Raw = $raw;
+
+                fn raw_value(&self) -> $raw {
+                    self.raw
+                }
+            }
+        )*
+    };
+}
+
+impl_raw_value! {
+    AccessMethodData => u8,
+    BoardTypeData => u8,
+    CacheAssociativityData => u8,
+    ChassisSecurityStatusData => u8,
+    ChassisStateData => u8,
+    ChassisTypeData => u8,
+    ErrorCorrectionTypeData => u8,
+    ErrorDetectingMethodData => u8,
+    HeaderFormatData => u8,
+    HostInterfaceTypeData => u8,
+    HostProtocolTypeData => u8,
+    InterleaveSupportData => u8,
+    IpmiInterfaceTypeData => u8,
+    LogTypeData => u8,
+    ManagementDeviceAddressTypeData => u8,
+    ManagementDeviceTypeData => u8,
+    MemoryArrayErrorCorrectionData => u8,
+    MemoryArrayLocationData => u8,
+    MemoryArrayUseData => u8,
+    MemoryChannelTypeData => u8,
+    MemoryDeviceTechnologyData => u8,
+    MemoryDeviceTypeData => u8,
+    MemoryErrorGranularityData => u8,
+    MemoryErrorOperationData => u8,
+    MemoryErrorTypeData => u8,
+    MemoryFormFactorData => u8,
+    PointingDeviceInterfaceData => u8,
+    PointingDeviceTypeData => u8,
+    PortInformationConnectorTypeData => u8,
+    PortInformationPortTypeData => u8,
+    PortableBatteryDeviceChemistryData => u8,
+    ProcessorArchitectureTypeData => u8,
+    ProcessorFamilyData => u8,
+    ProcessorFamilyData2 => u16,
+    ProcessorTypeData => u8,
+    ProcessorUpgradeData => u8,
+    RiscVXlenData => u8,
+    SlotCurrentUsageData => u8,
+    SlotHeightData => u8,
+    SlotLengthData => u8,
+    SlotWidthData => u8,
+    SystemCacheTypeData => u8,
+    SystemSlotTypeData => u8,
+    SystemWakeUpTypeData => u8,
+    VariableDataFormatTypeData => u8,
+}
+
+impl<'a> RawValue for SystemBootStatusData<'a> {
+    type Raw = &'a [u8];
+
+    fn raw_value(&self) -> &'a [u8] {
+        self.raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_value() {
+        let family = ProcessorFamilyData::from(0x01);
+        assert_eq!(family.raw_value(), 0x01);
+        assert_eq!(*family, ProcessorFamily::Other);
+
+        // A value this library does not know keeps its raw value
+        let chassis_type = ChassisTypeData::from(0x7F);
+        assert_eq!(chassis_type.raw_value(), 0x7F);
+        assert!(matches!(*chassis_type, ChassisType::None));
+
+        let family = ProcessorFamilyData2::from(0x0300);
+        assert_eq!(family.raw_value(), 0x0300);
+
+        let status = [0x80u8, 0x01];
+        let boot_status = SystemBootStatusData { raw: &status };
+        assert_eq!(boot_status.raw_value(), &status[..]);
+    }
+}
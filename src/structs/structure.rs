@@ -1,4 +1,7 @@
-use crate::core::UndefinedStruct;
+use crate::core::{minimum_length, SMBiosVersion, UndefinedStruct};
+#[cfg(feature = "no_std")]
+use alloc::format;
+use core::fmt;
 
 /// # SMBIOS Structure
 ///
@@ -14,4 +17,117 @@ pub trait SMBiosStruct<'a> {
 
     /// Contains the standard parts/sections of the implementing SMBIOS type.
     fn parts(&self) -> &'a UndefinedStruct;
+
+    /// Creates a new instance of the implementing SMBIOS type, verifying
+    /// that `parts` is a structure of the type
+    ///
+    /// Unlike [SMBiosStruct::new], which wraps any structure, fails when
+    /// the type of `parts` is not [SMBiosStruct::STRUCT_TYPE] or `parts` is
+    /// shorter than the earliest version of the specification defines the
+    /// type.
+    fn parse(parts: &'a UndefinedStruct) -> Result<Self, StructParseError>
+    where
+        Self: Sized,
+    {
+        let struct_type = parts.header.struct_type();
+        if struct_type != Self::STRUCT_TYPE {
+            return Err(StructParseError::TypeMismatch {
+                expected: Self::STRUCT_TYPE,
+                found: struct_type,
+            });
+        }
+
+        let length = parts.header.length();
+        if let Some(minimum) = minimum_length(parts, SMBiosVersion::new(2, 0, 0)) {
+            if (length as usize) < minimum {
+                return Err(StructParseError::TooShort { length, minimum });
+            }
+        }
+
+        Ok(Self::new(parts))
+    }
+}
+
+/// Errors of [SMBiosStruct::parse]
+pub enum StructParseError {
+    /// The structure is of another type
+    TypeMismatch {
+        /// The type parsed
+        expected: u8,
+        /// The type of the structure
+        found: u8,
+    },
+    /// The structure is shorter than any version of the type
+    TooShort {
+        /// Length of the formatted area, including the header
+        length: u8,
+        /// Length of the earliest version of the type
+        minimum: usize,
+    },
+}
+
+impl fmt::Debug for StructParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StructParseError")
+            .field(&format!("{}", &self))
+            .finish()
+    }
+}
+
+impl fmt::Display for StructParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StructParseError::TypeMismatch { expected, found } => write!(
+                f,
+                "Expected a structure of type {}, found type {}",
+                expected, found
+            ),
+            StructParseError::TooShort { length, minimum } => write!(
+                f,
+                "Structure length {} is shorter than the minimum of {}",
+                length, minimum
+            ),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for StructParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::{SMBiosOemStrings, SMBiosSystemInformation};
+
+    #[test]
+    fn test_parse() {
+        // System Information of SMBIOS 2.0
+        let parts = UndefinedStruct::new(&vec![
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, b'A', 0x00, 0x00,
+        ]);
+
+        let system_information = SMBiosSystemInformation::parse(&parts).unwrap();
+        assert_eq!(system_information.manufacturer(), Some("A".to_string()));
+        assert!(matches!(
+            SMBiosOemStrings::parse(&parts),
+            Err(StructParseError::TypeMismatch {
+                expected: 11,
+                found: 1
+            })
+        ));
+
+        let parts = UndefinedStruct::new(&vec![0x01, 0x06, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00]);
+        let error = SMBiosSystemInformation::parse(&parts).unwrap_err();
+        assert!(matches!(
+            error,
+            StructParseError::TooShort {
+                length: 6,
+                minimum: 8
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Structure length 6 is shorter than the minimum of 8"
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::core::{Handle, UndefinedStruct};
+use crate::core::{Handle, StructFields, UndefinedStruct};
 use crate::structs::SMBiosStruct;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
@@ -57,19 +57,16 @@ impl<'a> AdditionalInformationEntry<'a> {
     ///
     /// NOTE: This field is the same type and size as the field being referenced
     /// by this Additional Information Entry.
+    ///
+    /// None when the _Entry Length_ is too short to hold a value.
     pub fn value(&self) -> Option<&[u8]> {
         const VALUE_RELATIVE_OFFSET: usize = 5usize;
         let value_offset = self.entry_offset + VALUE_RELATIVE_OFFSET;
+        let value_size = (self.entry_length()? as usize).checked_sub(VALUE_RELATIVE_OFFSET)?;
 
-        match self.entry_length() {
-            Some(entry_length) => {
-                let value_size = entry_length as usize - VALUE_RELATIVE_OFFSET;
-                self.additional_information
-                    .parts()
-                    .get_field_data(value_offset, value_offset + value_size)
-            }
-            None => None,
-        }
+        self.additional_information
+            .parts()
+            .get_field_data(value_offset, value_offset + value_size)
     }
 }
 
@@ -228,9 +225,9 @@ impl<'a> SMBiosStruct<'a> for SMBiosAdditionalInformation<'a> {
 }
 
 impl<'a> SMBiosAdditionalInformation<'a> {
-    /// Number of [AdditionalInformationEntry] entries
-    pub fn number_of_entries(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x04)
+    field_accessors! {
+        /// Number of [AdditionalInformationEntry] entries
+        number_of_entries, number_of_entries_access: byte @ 0x04;
     }
 
     /// Iterates over the [AdditionalInformationEntry] entries
@@ -329,5 +326,21 @@ mod tests {
         );
 
         println!("additional_information: {:?}", additional_information);
+
+        // an entry length shorter than the entry fields leaves no value
+        let additional_information_bytes = vec![
+            0x28u8, 0x0A, 0x02, 0x01, // header (offsets 0-3)
+            0x01, // 1 additional entry (offset 4)
+            0x3, 0x04, 0x03, 0x22, 0x00, // entry (offsets 5-9)
+            0x00, 0x00, // end of structure (offsets 0x0A-0x0B)
+        ];
+        let parts = UndefinedStruct::new(&additional_information_bytes);
+        let additional_information = SMBiosAdditionalInformation::new(&parts);
+        let entry = additional_information
+            .entry_iterator()
+            .next()
+            .expect("must have an entry");
+        assert_eq!(entry.entry_length(), Some(3));
+        assert_eq!(entry.value(), None);
     }
 }
@@ -1,4 +1,4 @@
-use crate::core::{Handle, UndefinedStruct};
+use crate::core::{Handle, StructFields, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
@@ -28,58 +28,48 @@ impl<'a> SMBiosStruct<'a> for SMBiosBaseboardInformation<'a> {
 }
 
 impl<'a> SMBiosBaseboardInformation<'a> {
-    ///Baseboard manufacturer
-    pub fn manufacturer(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
+    field_accessors! {
+        ///Baseboard manufacturer
+        manufacturer, manufacturer_access: string @ 0x04;
 
-    /// Baseboard product
-    pub fn product(&self) -> Option<String> {
-        self.parts.get_field_string(0x05)
-    }
+        /// Baseboard product
+        product, product_access: string @ 0x05;
 
-    /// Baseboard version
-    pub fn version(&self) -> Option<String> {
-        self.parts.get_field_string(0x06)
-    }
+        /// Baseboard version
+        version, version_access: string @ 0x06;
 
-    /// Baseboard serial number
-    pub fn serial_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x07)
-    }
+        /// Baseboard serial number
+        serial_number, serial_number_access: string @ 0x07;
 
-    /// Baseboard asset tag
-    pub fn asset_tag(&self) -> Option<String> {
-        self.parts.get_field_string(0x08)
+        /// Baseboard asset tag
+        asset_tag, asset_tag_access: string @ 0x08;
     }
 
     /// Collection of flags that identify features of this baseboard.
     pub fn feature_flags(&self) -> Option<BaseboardFeatures> {
         self.parts
             .get_field_byte(0x09)
-            .map(|raw| BaseboardFeatures::from(raw))
+            .map(BaseboardFeatures::from)
     }
 
-    /// This baseboard's location within the chassis (chassis is referenced by ChassisHandle).
-    pub fn location_in_chassis(&self) -> Option<String> {
-        self.parts.get_field_string(0x0A)
-    }
+    field_accessors! {
+        /// This baseboard's location within the chassis (chassis is referenced by ChassisHandle).
+        location_in_chassis, location_in_chassis_access: string @ 0x0A;
 
-    /// Handle, or instance number, associated with the chassis in which this board resides.
-    pub fn chassis_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x0B)
+        /// Handle, or instance number, associated with the chassis in which this board resides.
+        chassis_handle, chassis_handle_access: handle @ 0x0B;
     }
 
     /// Type of baseboard.
     pub fn board_type(&self) -> Option<BoardTypeData> {
         self.parts
             .get_field_byte(0x0D)
-            .map(|raw| BoardTypeData::from(raw))
+            .map(BoardTypeData::from)
     }
 
-    /// The count of ObjectHandles.
-    pub fn number_of_contained_object_handles(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x0E)
+    field_accessors! {
+        /// The count of ObjectHandles.
+        number_of_contained_object_handles, number_of_contained_object_handles_access: byte @ 0x0E;
     }
 
     /// List of handles of other structures that are contained by this baseboard.
@@ -509,4 +499,46 @@ mod tests {
         // debug print test
         println!("baseboard_information: {:?}", baseboard_information);
     }
+
+    #[test]
+    fn test_baseboard_features_and_board_type() {
+        let features = BaseboardFeatures::from(0b0001_1010);
+        assert!(!features.hosting_board());
+        assert!(features.requires_daughterboard());
+        assert!(!features.is_removable());
+        assert!(features.is_replaceable());
+        assert!(features.is_hot_swappable());
+
+        assert_eq!(*BoardTypeData::from(0x03), BoardType::ServerBlade);
+        assert_eq!(*BoardTypeData::from(0x0D), BoardType::InterconnectBoard);
+
+        let board_type = BoardTypeData::from(0x0E);
+        assert_eq!(board_type.value, BoardType::None);
+        assert_eq!(board_type.raw, 0x0E);
+        assert_eq!(format!("{}", board_type), "14");
+    }
+
+    #[test]
+    fn test_contained_object_handles_truncated() {
+        // number_of_contained_object_handles(3) but the structure only holds one handle
+        let baseboard_information_bytes = vec![
+            0x02, 0x11, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x0F, 0x00, 0x03,
+            0x03, 0x05, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&baseboard_information_bytes);
+        let baseboard_information = SMBiosBaseboardInformation::new(&parts);
+
+        assert!(baseboard_information.feature_flags().unwrap().is_hot_swappable());
+        assert_eq!(
+            *baseboard_information.board_type().unwrap(),
+            BoardType::ServerBlade
+        );
+
+        let handles: Vec<Handle> = baseboard_information
+            .contained_object_handle_iterator()
+            .collect();
+        assert_eq!(handles.len(), 1);
+        assert_eq!(*handles[0], 0x0005);
+    }
 }
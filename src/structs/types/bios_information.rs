@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{MaybeValue, SMBiosStruct, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
 use core::ops::Deref;
@@ -23,171 +23,301 @@ impl<'a> SMBiosStruct<'a> for SMBiosInformation<'a> {
 }
 
 impl<'a> SMBiosInformation<'a> {
-    /// BIOS vendor's name
-    pub fn vendor(&self) -> Option<String> {
-        self.parts.get_field_string(0x4)
-    }
-
-    /// BIOS version
+    field_accessors! {
+        /// BIOS vendor's name
+        vendor, vendor_access: string @ 0x4;
+
+        /// BIOS version
+        ///
+        /// This value is a free-form string that may contain
+        /// Core and OEM version information.
+        version, version_access: string @ 0x5;
+
+        /// BIOS starting address segment
+        ///
+        /// Segment location of BIOS starting address
+        /// (for example, 0E800h).
+        ///
+        /// NOTE: The size of the runtime BIOS image can
+        /// be computed by subtracting the Starting
+        /// Address Segment from 10000h and
+        /// multiplying the result by 16.
+        starting_address_segment, starting_address_segment_access: word @ 0x6;
+
+        /// BIOS release date
+        ///
+        /// The date string, if supplied, is in either
+        /// mm/dd/yy or mm/dd/yyyy format. If the year
+        /// portion of the string is two digits, the year is
+        /// assumed to be 19yy.
+        ///
+        /// NOTE: The mm/dd/yyyy format is required for
+        /// SMBIOS version 2.3 and later.
+        release_date, release_date_access: string @ 0x8;
+
+        /// BIOS ROM size
+        ///
+        /// Size (n) where 64K * (n+1) is the size of the
+        /// physical device containing the BIOS, in
+        /// bytes.
+        ///
+        /// FFh - size is 16MB or greater, see Extended
+        /// BIOS ROM Size for actual size
+        ///
+        /// [SMBiosInformation::effective_rom_size] merges both
+        /// fields into a size in bytes.
+        rom_size, rom_size_access: byte @ 0x9;
+
+        /// BIOS characteristics
+        ///
+        /// Defines which functions the BIOS supports:
+        /// PCI, PCMCIA, Flash, etc
+        characteristics, characteristics_access: dword @ 0xA => BiosCharacteristics;
+
+        /// BIOS vendor reserved characteristics
+        bios_vendor_reserved_characteristics,
+        bios_vendor_reserved_characteristics_access: word @ 0xE;
+
+        /// System vendor reserved characteristics
+        system_vendor_reserved_characteristics,
+        system_vendor_reserved_characteristics_access: word @ 0x10;
+
+        /// Characteristics extension byte 0
+        characteristics_extension0, characteristics_extension0_access: byte @ 0x12
+            => BiosCharacteristicsExtension0;
+
+        /// Characteristics extension byte 1
+        characteristics_extension1, characteristics_extension1_access: byte @ 0x13
+            => BiosCharacteristicsExtension1;
+
+        /// System BIOS major release
+        ///
+        /// Identifies the major release of the System
+        /// BIOS; for example, the value is 0Ah for
+        /// revision 10.22 and 02h for revision 2.1.
+        ///
+        /// This field or the System BIOS Minor
+        /// Release field or both are updated each time
+        /// a System BIOS update for a given system is
+        /// released.
+        ///
+        /// If the system does not support the use of
+        /// this field, the value is 0FFh for both this field
+        /// and the System BIOS Minor Release field.
+        system_bios_major_release, system_bios_major_release_access: byte @ 0x14;
+
+        /// System BIOS minor release
+        ///
+        /// Identifies the minor release of the System
+        /// BIOS; for example, the value is 16h for
+        /// revision 10.22 and 01h for revision 2.1.
+        system_bios_minor_release, system_bios_minor_release_access: byte @ 0x15;
+
+        /// Embedded controller firmware major release
+        ///
+        /// Identifies the major release of the
+        /// embedded controller firmware; for example,
+        /// the value would be 0Ah for revision 10.22
+        /// and 02h for revision 2.1.
+        ///
+        /// This field or the Embedded Controller
+        /// Firmware Minor Release field or both are
+        /// updated each time an embedded controller
+        /// firmware update for a given system is
+        /// released.
+        ///
+        /// If the system does not have field
+        /// upgradeable embedded controller firmware,
+        /// the value is 0FFh.
+        e_c_firmware_major_release, e_c_firmware_major_release_access: byte @ 0x16;
+
+        /// Embedded controller firmware minor release
+        ///
+        /// Identifies the minor release of the
+        /// embedded controller firmware; for example,
+        /// the value is 16h for revision 10.22 and 01h
+        /// for revision 2.1.
+        /// If the system does not have field
+        /// upgradeable embedded controller firmware,
+        /// the value is 0FFh.
+        e_c_firmware_minor_release, e_c_firmware_minor_release_access: byte @ 0x17;
+
+        /// Extended BIOS ROM size
+        ///
+        /// Extended size of the physical device(s)
+        /// containing the BIOS, rounded up if needed.
+        ///
+        /// Bits 15:14 Unit
+        /// 00b - megabytes
+        /// 01b - gigabytes
+        /// 10b - reserved
+        /// 11b - reserved
+        /// Bits 13:0 Size
+        ///
+        /// Examples: a 16 MB device would be
+        /// represented as 0010h. A 48 GB device set
+        /// would be represented as
+        /// 0100_0000_0011_0000b or 4030h.
+        extended_rom_size, extended_rom_size_access: word @ 0x18 => ExtendedRomSize;
+    }
+
+    /// BIOS release date, parsed from [SMBiosInformation::release_date]
     ///
-    /// This value is a free-form string that may contain
-    /// Core and OEM version information.
-    pub fn version(&self) -> Option<String> {
-        self.parts.get_field_string(0x5)
+    /// Returns None when the release date is absent or is not a date of
+    /// the form mm/dd/yyyy or mm/dd/yy, see [BiosReleaseDate::parse].
+    pub fn parsed_release_date(&self) -> Option<BiosReleaseDate> {
+        BiosReleaseDate::parse(&self.release_date()?)
     }
 
-    /// BIOS starting address segment
+    /// BIOS ROM size, taken from 'extended_rom_size' when 'rom_size'
+    /// is FFh
     ///
-    /// Segment location of BIOS starting address
-    /// (for example, 0E800h).
+    /// The size is [MaybeValue::Unknown] when 'rom_size' is FFh and
+    /// 'extended_rom_size' is absent or zero, and
+    /// [MaybeValue::Reserved] when 'extended_rom_size' has a reserved unit.
     ///
-    /// NOTE: The size of the runtime BIOS image can
-    /// be computed by subtracting the Starting
-    /// Address Segment from 10000h and
-    /// multiplying the result by 16.
-    pub fn starting_address_segment(&self) -> Option<u16> {
-        self.parts.get_field_word(0x6)
+    /// Returns None if 'rom_size' is absent.
+    pub fn effective_rom_size(&self) -> Option<MaybeValue<RomSize>> {
+        Some(match self.rom_size()? {
+            0xFF => match self.extended_rom_size() {
+                Some(ExtendedRomSize::Megabytes(size)) if size != 0 => {
+                    MaybeValue::Value(RomSize(u64::from(size) << 20))
+                }
+                Some(ExtendedRomSize::Gigabytes(size)) if size != 0 => {
+                    MaybeValue::Value(RomSize(u64::from(size) << 30))
+                }
+                Some(ExtendedRomSize::Undefined(_)) => MaybeValue::Reserved,
+                _ => MaybeValue::Unknown,
+            },
+            size => MaybeValue::Value(RomSize((u64::from(size) + 1) << 16)),
+        })
     }
+}
 
-    /// BIOS release date
-    ///
-    /// The date string, if supplied, is in either
-    /// mm/dd/yy or mm/dd/yyyy format. If the year
-    /// portion of the string is two digits, the year is
-    /// assumed to be 19yy.
-    ///
-    /// NOTE: The mm/dd/yyyy format is required for
-    /// SMBIOS version 2.3 and later.
-    pub fn release_date(&self) -> Option<String> {
-        self.parts.get_field_string(0x8)
+/// # BIOS ROM Size
+///
+/// Size of the physical device(s) containing the BIOS, see
+/// [SMBiosInformation::effective_rom_size]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RomSize(u64);
+
+impl RomSize {
+    /// Size in bytes
+    pub fn as_bytes(&self) -> u64 {
+        self.0
     }
 
-    /// BIOS ROM size
-    ///
-    /// Size (n) where 64K * (n+1) is the size of the
-    /// physical device containing the BIOS, in
-    /// bytes.
-    ///
-    /// FFh - size is 16MB or greater, see Extended
-    /// BIOS ROM Size for actual size
-    pub fn rom_size(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x9)
+    /// Size in kibibytes (1024 bytes), rounded down
+    pub fn as_kibibytes(&self) -> u64 {
+        self.0 >> 10
     }
 
-    /// BIOS characteristics
-    ///
-    /// Defines which functions the BIOS supports:
-    /// PCI, PCMCIA, Flash, etc
-    pub fn characteristics(&self) -> Option<BiosCharacteristics> {
-        self.parts
-            .get_field_dword(0xA)
-            .map(|raw| BiosCharacteristics::from(raw))
+    /// Size in mebibytes (1024 kibibytes), rounded down
+    pub fn as_mebibytes(&self) -> u64 {
+        self.0 >> 20
     }
+}
 
-    /// BIOS vendor reserved characteristics
-    pub fn bios_vendor_reserved_characteristics(&self) -> Option<u16> {
-        self.parts.get_field_word(0xE)
+/// Writes the size in the largest of KB, MB and GB which it is a multiple of
+impl fmt::Display for RomSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            size if size != 0 && size % (1 << 30) == 0 => write!(f, "{} GB", size >> 30),
+            size if size != 0 && size % (1 << 20) == 0 => write!(f, "{} MB", size >> 20),
+            size => write!(f, "{} KB", size >> 10),
+        }
     }
+}
+
+/// # BIOS Release Date
+///
+/// A calendar date read from the release date string of the BIOS. It is
+/// ordered chronologically, and with the `time` feature it converts into a
+/// `time::Date`.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BiosReleaseDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl BiosReleaseDate {
+    /// Parses a date of the form mm/dd/yyyy, which SMBIOS 2.3 and later
+    /// require, or mm/dd/yy of earlier versions
+    ///
+    /// The month and day may have a single digit. A two digit year yy is
+    /// read as 19yy from 80 on and as 20yy below 80, as the BIOS of a PC
+    /// dates from 1980 or later. Returns None for any other form and for a
+    /// day which does not exist, such as 02/30/2020.
+    pub fn parse(date: &str) -> Option<Self> {
+        let mut parts = date.trim().split('/');
+        let month = digits(parts.next()?, 1, 2)? as u8;
+        let day = digits(parts.next()?, 1, 2)? as u8;
+        let year = parts.next()?;
+        if parts.next().is_some() {
+            return None;
+        }
 
-    /// System vendor reserved characteristics
-    pub fn system_vendor_reserved_characteristics(&self) -> Option<u16> {
-        self.parts.get_field_word(0x10)
+        let year = match year.len() {
+            2 => match digits(year, 2, 2)? {
+                year @ 80..=99 => 1900 + year,
+                year => 2000 + year,
+            },
+            _ => digits(year, 4, 4)?,
+        };
+
+        let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days = match month {
+            2 if leap_year => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            1..=12 => 31,
+            _ => return None,
+        };
+
+        if day == 0 || day > days {
+            return None;
+        }
+        Some(BiosReleaseDate { year, month, day })
     }
 
-    /// Characteristics extension byte 0
-    pub fn characteristics_extension0(&self) -> Option<BiosCharacteristicsExtension0> {
-        self.parts
-            .get_field_byte(0x12)
-            .map(|raw| BiosCharacteristicsExtension0::from(raw))
+    /// Year, such as 2019
+    pub fn year(&self) -> u16 {
+        self.year
     }
 
-    /// Characteristics extension byte 1
-    pub fn characteristics_extension1(&self) -> Option<BiosCharacteristicsExtension1> {
-        self.parts
-            .get_field_byte(0x13)
-            .map(|raw| BiosCharacteristicsExtension1::from(raw))
+    /// Month, 1 through 12
+    pub fn month(&self) -> u8 {
+        self.month
     }
 
-    /// System BIOS major release
-    ///
-    /// Identifies the major release of the System
-    /// BIOS; for example, the value is 0Ah for
-    /// revision 10.22 and 02h for revision 2.1.
-    ///
-    /// This field or the System BIOS Minor
-    /// Release field or both are updated each time
-    /// a System BIOS update for a given system is
-    /// released.
-    ///
-    /// If the system does not support the use of
-    /// this field, the value is 0FFh for both this field
-    /// and the System BIOS Minor Release field.
-    pub fn system_bios_major_release(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x14)
+    /// Day of the month, 1 through 31
+    pub fn day(&self) -> u8 {
+        self.day
     }
+}
 
-    /// System BIOS minor release
-    ///
-    /// Identifies the minor release of the System
-    /// BIOS; for example, the value is 16h for
-    /// revision 10.22 and 01h for revision 2.1.
-    pub fn system_bios_minor_release(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x15)
+/// The value of `part`, a number of `min` to `max` decimal digits
+fn digits(part: &str, min: usize, max: usize) -> Option<u16> {
+    if part.len() < min || part.len() > max || !part.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
     }
+    part.parse().ok()
+}
 
-    /// Embedded controller firmware major release
-    ///
-    /// Identifies the major release of the
-    /// embedded controller firmware; for example,
-    /// the value would be 0Ah for revision 10.22
-    /// and 02h for revision 2.1.
-    ///
-    /// This field or the Embedded Controller
-    /// Firmware Minor Release field or both are
-    /// updated each time an embedded controller
-    /// firmware update for a given system is
-    /// released.
-    ///
-    /// If the system does not have field
-    /// upgradeable embedded controller firmware,
-    /// the value is 0FFh.
-    pub fn e_c_firmware_major_release(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x16)
+/// Writes the date as yyyy-mm-dd
+impl fmt::Display for BiosReleaseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
     }
+}
 
-    /// Embedded controller firmware minor release
-    ///
-    /// Identifies the minor release of the
-    /// embedded controller firmware; for example,
-    /// the value is 16h for revision 10.22 and 01h
-    /// for revision 2.1.
-    /// If the system does not have field
-    /// upgradeable embedded controller firmware,
-    /// the value is 0FFh.
-    pub fn e_c_firmware_minor_release(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x17)
-    }
-
-    /// Extended BIOS ROM size
-    ///
-    /// Extended size of the physical device(s)
-    /// containing the BIOS, rounded up if needed.
-    ///
-    /// Bits 15:14 Unit
-    /// 00b - megabytes
-    /// 01b - gigabytes
-    /// 10b - reserved
-    /// 11b - reserved
-    /// Bits 13:0 Size
-    ///
-    /// Examples: a 16 MB device would be
-    /// represented as 0010h. A 48 GB device set
-    /// would be represented as
-    /// 0100_0000_0011_0000b or 4030h.
-    pub fn extended_rom_size(&self) -> Option<ExtendedRomSize> {
-        self.parts
-            .get_field_word(0x18)
-            .map(|raw| ExtendedRomSize::from(raw))
+#[cfg(feature = "time")]
+impl From<BiosReleaseDate> for time::Date {
+    fn from(date: BiosReleaseDate) -> Self {
+        let month = time::Month::January.nth_next(date.month - 1);
+        time::Date::from_calendar_date(date.year as i32, month, date.day)
+            .expect("the day exists in the month")
     }
 }
 
@@ -812,6 +942,18 @@ impl BiosCharacteristicsExtension1 {
     pub fn smbios_table_describes_avirtual_machine(&self) -> bool {
         self.raw & 0x10 == 0x10
     }
+
+    /// Manufacturing mode is supported. (Manufacturing mode is a special boot mode, not
+    /// normally available to end users, that modifies BIOS features and settings for use
+    /// while the computer is being manufactured and tested.)
+    pub fn manufacturing_mode_is_supported(&self) -> bool {
+        self.raw & 0x20 == 0x20
+    }
+
+    /// Manufacturing mode is enabled.
+    pub fn manufacturing_mode_is_enabled(&self) -> bool {
+        self.raw & 0x40 == 0x40
+    }
 }
 
 impl fmt::Debug for BiosCharacteristicsExtension1 {
@@ -838,6 +980,14 @@ impl fmt::Debug for BiosCharacteristicsExtension1 {
                 "smbios_table_describes_avirtual_machine",
                 &self.smbios_table_describes_avirtual_machine(),
             )
+            .field(
+                "manufacturing_mode_is_supported",
+                &self.manufacturing_mode_is_supported(),
+            )
+            .field(
+                "manufacturing_mode_is_enabled",
+                &self.manufacturing_mode_is_enabled(),
+            )
             .finish()
     }
 }
@@ -847,7 +997,7 @@ impl Serialize for BiosCharacteristicsExtension1 {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("BiosCharacteristicsExtension1", 6)?;
+        let mut state = serializer.serialize_struct("BiosCharacteristicsExtension1", 8)?;
         state.serialize_field("raw", &self.raw)?;
         state.serialize_field(
             "bios_boot_specification_is_supported",
@@ -869,6 +1019,14 @@ impl Serialize for BiosCharacteristicsExtension1 {
             "smbios_table_describes_avirtual_machine",
             &self.smbios_table_describes_avirtual_machine(),
         )?;
+        state.serialize_field(
+            "manufacturing_mode_is_supported",
+            &self.manufacturing_mode_is_supported(),
+        )?;
+        state.serialize_field(
+            "manufacturing_mode_is_enabled",
+            &self.manufacturing_mode_is_enabled(),
+        )?;
         state.end()
     }
 }
@@ -877,6 +1035,44 @@ impl Serialize for BiosCharacteristicsExtension1 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_release_date() {
+        for (date, expected) in [
+            ("08/06/2019", Some((2019, 8, 6))),
+            (" 8/6/2019 ", Some((2019, 8, 6))),
+            ("12/31/99", Some((1999, 12, 31))),
+            ("01/01/80", Some((1980, 1, 1))),
+            ("02/29/04", Some((2004, 2, 29))),
+            ("02/29/1900", None),
+            ("02/29/2000", Some((2000, 2, 29))),
+            ("04/31/2019", None),
+            ("13/01/2019", None),
+            ("00/01/2019", None),
+            ("08/06/219", None),
+            ("08/+6/2019", None),
+            ("2019-08-06", None),
+            ("08/06/2019/1", None),
+        ] {
+            let parsed = BiosReleaseDate::parse(date);
+            assert_eq!(
+                parsed.map(|date| (date.year(), date.month(), date.day())),
+                expected,
+                "{}",
+                date
+            );
+        }
+
+        let date = BiosReleaseDate::parse("12/31/99").unwrap();
+        assert_eq!(date.to_string(), "1999-12-31");
+        assert!(date < BiosReleaseDate::parse("01/01/2000").unwrap());
+
+        #[cfg(feature = "time")]
+        assert_eq!(
+            time::Date::from(date),
+            time::Date::from_calendar_date(1999, time::Month::December, 31).unwrap()
+        );
+    }
+
     #[test]
     fn unit_test() {
         // BIOS Information structure is sensitive to BIOS specification versions
@@ -918,6 +1114,10 @@ mod tests {
         assert_eq!(test_struct.version(), Some("S03KT33A".to_string()));
         assert_eq!(test_struct.starting_address_segment(), Some(61440));
         assert_eq!(test_struct.release_date(), Some("08/06/2019".to_string()));
+        assert_eq!(
+            test_struct.parsed_release_date(),
+            BiosReleaseDate::parse("8/6/2019")
+        );
         assert_eq!(test_struct.rom_size(), Some(255));
         assert_eq!(
             test_struct.characteristics(),
@@ -936,6 +1136,12 @@ mod tests {
             test_struct.characteristics_extension1(),
             Some(BiosCharacteristicsExtension1::from(13))
         );
+        let extension1 = test_struct.characteristics_extension1().unwrap();
+        assert!(extension1.bios_boot_specification_is_supported());
+        assert!(extension1.uefi_specification_is_supported());
+        assert!(!extension1.smbios_table_describes_avirtual_machine());
+        assert!(!extension1.manufacturing_mode_is_supported());
+        assert!(!extension1.manufacturing_mode_is_enabled());
         assert_eq!(test_struct.system_bios_major_release(), Some(0));
         assert_eq!(test_struct.system_bios_minor_release(), Some(33));
         assert_eq!(test_struct.e_c_firmware_major_release(), Some(17));
@@ -944,6 +1150,7 @@ mod tests {
         // 2.4 to 3.0 BIOS Information does not include _extended_rom_size()_ or
         // fields beyond.
         assert!(test_struct.extended_rom_size().is_none());
+        assert_eq!(test_struct.effective_rom_size(), Some(MaybeValue::Unknown));
 
         // 3.1 BIOS (includes _extended_rom_size_)
         let struct_type0 = vec![
@@ -963,7 +1170,36 @@ mod tests {
             ExtendedRomSize::Gigabytes(size) => assert_eq!(size, 48),
             _ => panic!("incorrect unit"),
         }
+        let rom_size = test_struct.effective_rom_size().unwrap().value().unwrap();
+        assert_eq!(rom_size.as_bytes(), 48 << 30);
+        assert_eq!(rom_size.to_string(), "48 GB");
+
+        // sizes below 16 MB are held by the ROM size byte, in 64K units
+        let mut struct_type0 = struct_type0.clone();
+        struct_type0[0x09] = 0x03;
+        let parts = UndefinedStruct::new(&struct_type0);
+        let rom_size = SMBiosInformation::new(&parts)
+            .effective_rom_size()
+            .unwrap()
+            .value()
+            .unwrap();
+        assert_eq!(rom_size.as_kibibytes(), 256);
+        assert_eq!(rom_size.to_string(), "256 KB");
+        struct_type0[0x09] = 0xFF;
+        struct_type0[0x18..0x1A].copy_from_slice(&0x8010u16.to_le_bytes());
+        let parts = UndefinedStruct::new(&struct_type0);
+        assert_eq!(
+            SMBiosInformation::new(&parts).effective_rom_size(),
+            Some(MaybeValue::Reserved)
+        );
 
         println!("{:?}", test_struct);
+
+        // 3.5 extension byte 2 bits
+        let extension1 = BiosCharacteristicsExtension1::from(0x78);
+        assert!(extension1.uefi_specification_is_supported());
+        assert!(extension1.smbios_table_describes_avirtual_machine());
+        assert!(extension1.manufacturing_mode_is_supported());
+        assert!(extension1.manufacturing_mode_is_enabled());
     }
 }
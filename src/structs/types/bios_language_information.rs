@@ -29,24 +29,18 @@ impl<'a> SMBiosStruct<'a> for SMBiosBiosLanguageInformation<'a> {
 }
 
 impl<'a> SMBiosBiosLanguageInformation<'a> {
-    /// Number of languages available
-    /// Each available language has a description
-    /// string. This field contains the number of strings
-    /// that follow the formatted area of the structure.
-    pub fn number_of_installable_languages(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x4)
-    }
-
-    /// Bit field indicating the format of the languages.
-    pub fn flags(&self) -> Option<BiosLanguageFlags> {
-        self.parts
-            .get_field_byte(0x5)
-            .map(|raw| BiosLanguageFlags::from(raw))
-    }
-
-    /// The currently installed language.
-    pub fn current_language(&self) -> Option<String> {
-        self.parts.get_field_string(0x15)
+    field_accessors! {
+        /// Number of languages available
+        /// Each available language has a description
+        /// string. This field contains the number of strings
+        /// that follow the formatted area of the structure.
+        number_of_installable_languages, number_of_installable_languages_access: byte @ 0x4;
+
+        /// Bit field indicating the format of the languages.
+        flags, flags_access: byte @ 0x5 => BiosLanguageFlags;
+
+        /// The currently installed language.
+        current_language, current_language_access: string @ 0x15;
     }
 
     /// Iterable collection of the installable languages.
@@ -50,9 +50,9 @@ impl<'a> SMBiosStruct<'a> for SMBiosBisEntryPoint<'a> {
 }
 
 impl<'a> SMBiosBisEntryPoint<'a> {
-    /// Used to make the 8-bit checksum of this structure equal zero.
-    pub fn checksum(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x04)
+    field_accessors! {
+        /// Used to make the 8-bit checksum of this structure equal zero.
+        checksum, checksum_access: byte @ 0x04;
     }
 
     // fn reserved_1(&self) -> Option<u8> {
@@ -63,27 +63,25 @@ impl<'a> SMBiosBisEntryPoint<'a> {
     //     self.parts.get_field_word(0x06)
     // }
 
-    /// BIS entry point pointer for use by 16-bit real-mode callers. This is a segmented pointer(in segment:offset form).
-    ///
-    /// Real Mode: In Real Mode all addresses are composed of Segment:Offset pairs, with
-    /// both Segment and Offset being 16 bits.These combine to form a 20-bit physical address
-    /// that cannot access above approximately the 1 Megabyte boundary.This mode is
-    /// referred to in this section as “16-bit real-mode” or “16-bit mode”. Callers in this mode
-    /// may only invoke BIS through the bisEntry16 entry point. The bisEntry16 function
-    /// returns with the processor in 16-bit real-mode.
-    pub fn bis_entry_16(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x08)
-    }
+    field_accessors! {
+        /// BIS entry point pointer for use by 16-bit real-mode callers. This is a segmented pointer(in segment:offset form).
+        ///
+        /// Real Mode: In Real Mode all addresses are composed of Segment:Offset pairs, with
+        /// both Segment and Offset being 16 bits.These combine to form a 20-bit physical address
+        /// that cannot access above approximately the 1 Megabyte boundary.This mode is
+        /// referred to in this section as “16-bit real-mode” or “16-bit mode”. Callers in this mode
+        /// may only invoke BIS through the bisEntry16 entry point. The bisEntry16 function
+        /// returns with the processor in 16-bit real-mode.
+        bis_entry_16, bis_entry_16_access: dword @ 0x08;
 
-    /// BIS entry point pointer for use by 32-bit flat physical address mode callers. This is a 32-bit physical address.
-    ///
-    /// Flat Mode: A 32-bit (protected) IA-32 processor mode where CS:0, DS:0, and SS:0 all
-    /// refer to physical location 0 and all have 4 GB of address space.This mode is referred to
-    /// in this section as “32-bit flat-mode” or “32-bit mode”. Callers in this mode may only
-    /// invoke BIS through the bisEntry32 entry point. The bisEntry32 function returns with the
-    /// processor in 32-bit flat-mode.
-    pub fn bis_entry_32(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x0C)
+        /// BIS entry point pointer for use by 32-bit flat physical address mode callers. This is a 32-bit physical address.
+        ///
+        /// Flat Mode: A 32-bit (protected) IA-32 processor mode where CS:0, DS:0, and SS:0 all
+        /// refer to physical location 0 and all have 4 GB of address space.This mode is referred to
+        /// in this section as “32-bit flat-mode” or “32-bit mode”. Callers in this mode may only
+        /// invoke BIS through the bisEntry32 entry point. The bisEntry32 function returns with the
+        /// processor in 32-bit flat-mode.
+        bis_entry_32, bis_entry_32_access: dword @ 0x0C;
     }
 
     // fn reserved_3(&self) -> Option<u64> {
@@ -30,24 +30,16 @@ impl<'a> SMBiosStruct<'a> for SMBiosBuiltInPointingDevice<'a> {
 }
 
 impl<'a> SMBiosBuiltInPointingDevice<'a> {
-    /// Type of pointing device.
-    pub fn device_type(&self) -> Option<PointingDeviceTypeData> {
-        self.parts
-            .get_field_byte(0x04)
-            .map(|raw| PointingDeviceTypeData::from(raw))
-    }
+    field_accessors! {
+        /// Type of pointing device.
+        device_type, device_type_access: byte @ 0x04 => PointingDeviceTypeData;
 
-    /// Interface type for the pointing device.
-    pub fn interface(&self) -> Option<PointingDeviceInterfaceData> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| PointingDeviceInterfaceData::from(raw))
-    }
+        /// Interface type for the pointing device.
+        interface, interface_access: byte @ 0x05 => PointingDeviceInterfaceData;
 
-    /// Number of buttons on the pointing device.
-    /// If the device has 3 buttons, the field value is 3.
-    pub fn number_of_buttons(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x06)
+        /// Number of buttons on the pointing device.
+        /// If the device has 3 buttons, the field value is 3.
+        number_of_buttons, number_of_buttons_access: byte @ 0x06;
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{SMBiosStruct, StructFields, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
 use core::ops::Deref;
@@ -32,77 +32,70 @@ impl<'a> SMBiosStruct<'a> for SMBiosCacheInformation<'a> {
 }
 
 impl<'a> SMBiosCacheInformation<'a> {
-    /// String number for reference designation
-    pub fn socket_designation(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
+    field_accessors! {
+        /// String number for reference designation
+        socket_designation, socket_designation_access: string @ 0x04;
 
-    /// Bit fields describing the cache configuration
-    pub fn cache_configuration(&self) -> Option<CacheConfiguaration> {
-        self.parts
-            .get_field_word(0x05)
-            .map(|raw| CacheConfiguaration::from(raw))
+        /// Bit fields describing the cache configuration
+        cache_configuration, cache_configuration_access: word @ 0x05 => CacheConfiguaration;
     }
 
     /// Maximum size that can be installed
-    pub fn maximum_cache_size(&self) -> Option<u16> {
-        self.parts.get_field_word(0x07)
+    ///
+    /// For caches of 2 GB or more this is [CacheSize::SeeCacheSize2] and the
+    /// size is given by _maximum_cache_size_2()_.
+    pub fn maximum_cache_size(&self) -> Option<CacheSize> {
+        self.parts
+            .get_field_word(0x07)
+            .map(CacheSize::from)
     }
 
     /// Same format as Max Cache Size field; set to 0 if no cache is installed
-    pub fn installed_size(&self) -> Option<u16> {
-        self.parts.get_field_word(0x09)
-    }
-
-    /// Supported SRAM type
-    pub fn supported_sram_type(&self) -> Option<SramTypes> {
+    pub fn installed_size(&self) -> Option<CacheSize> {
         self.parts
-            .get_field_word(0x0B)
-            .map(|raw| SramTypes::from(raw))
+            .get_field_word(0x09)
+            .map(CacheSize::from)
     }
 
-    /// Current SRAM type
-    pub fn current_sram_type(&self) -> Option<SramTypes> {
-        self.parts
-            .get_field_word(0x0D)
-            .map(|raw| SramTypes::from(raw))
-    }
+    field_accessors! {
+        /// Supported SRAM type
+        supported_sram_type, supported_sram_type_access: word @ 0x0B => SramTypes;
 
-    /// Cache module speed, in nanoseconds.
-    /// The value is 0 if the speed is unknown.
-    pub fn cache_speed(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x0F)
-    }
+        /// Current SRAM type
+        current_sram_type, current_sram_type_access: word @ 0x0D => SramTypes;
 
-    /// Error-correction scheme supported by this cache component
-    pub fn error_correction_type(&self) -> Option<ErrorCorrectionTypeData> {
-        self.parts
-            .get_field_byte(0x10)
-            .map(|raw| ErrorCorrectionTypeData::from(raw))
-    }
+        /// Cache module speed, in nanoseconds.
+        /// The value is 0 if the speed is unknown.
+        cache_speed, cache_speed_access: byte @ 0x0F;
 
-    /// Logical type of cache
-    pub fn system_cache_type(&self) -> Option<SystemCacheTypeData> {
-        self.parts
-            .get_field_byte(0x11)
-            .map(|raw| SystemCacheTypeData::from(raw))
-    }
+        /// Error-correction scheme supported by this cache component
+        error_correction_type, error_correction_type_access: byte @ 0x10 => ErrorCorrectionTypeData;
 
-    /// Associativity of the cache
-    pub fn associativity(&self) -> Option<CacheAssociativityData> {
-        self.parts
-            .get_field_byte(0x12)
-            .map(|raw| CacheAssociativityData::from(raw))
+        /// Logical type of cache
+        system_cache_type, system_cache_type_access: byte @ 0x11 => SystemCacheTypeData;
+
+        /// Associativity of the cache
+        associativity, associativity_access: byte @ 0x12 => CacheAssociativityData;
     }
 
     /// Maximum cache size
-    pub fn maximum_cache_size_2(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x13)
+    ///
+    /// If the size is less than 2047 MB this holds the same value as
+    /// _maximum_cache_size()_.
+    pub fn maximum_cache_size_2(&self) -> Option<CacheSize> {
+        self.parts
+            .get_field_dword(0x13)
+            .map(CacheSize::from)
     }
 
     /// Installed cache size
-    pub fn installed_cache_size_2(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x17)
+    ///
+    /// If the size is less than 2047 MB this holds the same value as
+    /// _installed_size()_.
+    pub fn installed_cache_size_2(&self) -> Option<CacheSize> {
+        self.parts
+            .get_field_dword(0x17)
+            .map(CacheSize::from)
     }
 }
 
@@ -621,6 +614,47 @@ impl Serialize for CacheConfiguaration {
         state.end()
     }
 }
+/// # Cache Size
+///
+/// Decodes both the 16 bit Maximum Cache Size/Installed Size fields and the
+/// 32 bit Maximum Cache Size 2/Installed Cache Size 2 fields.
+///
+/// In both encodings the most significant bit selects the granularity:
+/// 0 for 1K and 1 for 64K; the remaining bits hold the size in that granularity.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CacheSize {
+    /// Size of the cache in kilobytes
+    Kilobytes(u64),
+    /// The size is 2047 MB or more and is stored in the corresponding
+    /// Cache Size 2 field
+    SeeCacheSize2,
+}
+
+impl From<u16> for CacheSize {
+    fn from(raw: u16) -> Self {
+        match raw {
+            0xFFFF => CacheSize::SeeCacheSize2,
+            _ => {
+                let size = (raw & 0x7FFF) as u64;
+                match raw & 0x8000 {
+                    0x8000 => CacheSize::Kilobytes(size * 64),
+                    _ => CacheSize::Kilobytes(size),
+                }
+            }
+        }
+    }
+}
+
+impl From<u32> for CacheSize {
+    fn from(raw: u32) -> Self {
+        let size = (raw & 0x7FFF_FFFF) as u64;
+        match raw & 0x8000_0000 {
+            0x8000_0000 => CacheSize::Kilobytes(size * 64),
+            _ => CacheSize::Kilobytes(size),
+        }
+    }
+}
+
 /// # System Cache Location (relative to the CPU module)
 #[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum CacheLocation {
@@ -672,6 +706,33 @@ mod tests {
             CacheOperationalMode::WriteBack
         );
 
+        assert_eq!(
+            test_struct.maximum_cache_size(),
+            Some(CacheSize::Kilobytes(384))
+        );
+        assert_eq!(test_struct.installed_size(), Some(CacheSize::Kilobytes(384)));
+        assert_eq!(
+            test_struct.maximum_cache_size_2(),
+            Some(CacheSize::Kilobytes(384))
+        );
+        assert_eq!(
+            test_struct.installed_cache_size_2(),
+            Some(CacheSize::Kilobytes(384))
+        );
+
         println!("{:?}", test_struct);
     }
+
+    #[test]
+    fn test_cache_size() {
+        assert_eq!(CacheSize::from(0x0020u16), CacheSize::Kilobytes(32));
+        assert_eq!(CacheSize::from(0x8010u16), CacheSize::Kilobytes(1024));
+        assert_eq!(CacheSize::from(0xFFFFu16), CacheSize::SeeCacheSize2);
+        assert_eq!(CacheSize::from(0x0000_0020u32), CacheSize::Kilobytes(32));
+        // 4 GB in 64K granularity, beyond the 16 bit field
+        assert_eq!(
+            CacheSize::from(0x8001_0000u32),
+            CacheSize::Kilobytes(4 * 1024 * 1024)
+        );
+    }
 }
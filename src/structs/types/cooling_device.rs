@@ -1,4 +1,4 @@
-use crate::core::{Handle, UndefinedStruct};
+use crate::core::{Handle, MaybeValue, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
@@ -9,6 +9,8 @@ use alloc::string::String;
 ///
 /// This structure describes the attributes for a cooling device in the system. Each structure describes a single cooling device.
 ///
+/// NOTE This structure type was added in version 2.2 of this specification.
+///
 /// Compliant with:
 /// DMTF SMBIOS Reference Specification 3.4.0 (DSP0134)
 /// Document Date: 2020-07-17
@@ -29,63 +31,54 @@ impl<'a> SMBiosStruct<'a> for SMBiosCoolingDevice<'a> {
 }
 
 impl<'a> SMBiosCoolingDevice<'a> {
-    /// Handle, or instance number, of the temperature
-    /// probe monitoring this cooling device.
-    /// A value of 0xFFFF indicates that no probe is
-    /// provided.
-    pub fn temperature_probe_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x04)
-    }
+    field_accessors! {
+        /// Handle, or instance number, of the temperature
+        /// probe monitoring this cooling device.
+        /// A value of 0xFFFF indicates that no probe is
+        /// provided.
+        temperature_probe_handle, temperature_probe_handle_access: handle @ 0x04;
 
-    /// Cooling device type and status.
-    pub fn device_type_and_status(&self) -> Option<CoolingDeviceTypeAndStatus> {
-        self.parts
-            .get_field_byte(0x06)
-            .map(|raw| CoolingDeviceTypeAndStatus::from(raw))
-    }
+        /// Cooling device type and status.
+        device_type_and_status, device_type_and_status_access: byte @ 0x06
+            => CoolingDeviceTypeAndStatus;
 
-    /// Cooling unit group to which this cooling device is associated
-    /// Having multiple cooling devices in the same
-    /// cooling unit implies a redundant configuration. The
-    /// value is 00h if the cooling device is not a member
-    /// of a redundant cooling unit. Non-zero values imply
-    /// redundancy and that at least one other cooling
-    /// device will be enumerated with the same value
-    pub fn cooling_unit_group(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x07)
-    }
+        /// Cooling unit group to which this cooling device is associated
+        /// Having multiple cooling devices in the same
+        /// cooling unit implies a redundant configuration. The
+        /// value is 00h if the cooling device is not a member
+        /// of a redundant cooling unit. Non-zero values imply
+        /// redundancy and that at least one other cooling
+        /// device will be enumerated with the same value
+        cooling_unit_group, cooling_unit_group_access: byte @ 0x07;
 
-    /// OEM or BIOS vendor-specific information.
-    pub fn oem_defined(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x08)
-    }
+        /// OEM or BIOS vendor-specific information.
+        oem_defined, oem_defined_access: dword @ 0x08;
 
-    /// Nominal value for the cooling device’s rotational
-    /// speed, in revolutions-per-minute (rpm)
-    /// If the value is unknown or the cooling device is
-    /// non-rotating, the field is set to 0x8000. This field is
-    /// present in the structure only if the structure’s
-    /// length is larger than 0Ch
-    pub fn nominal_speed(&self) -> Option<RotationalSpeed> {
-        self.parts
-            .get_field_word(0x0C)
-            .map(|raw| RotationalSpeed::from(raw))
-    }
+        /// Nominal value for the cooling device’s rotational
+        /// speed, in revolutions-per-minute (rpm)
+        /// If the value is unknown or the cooling device is
+        /// non-rotating, the field is set to 0x8000. This field is
+        /// present in the structure only if the structure’s
+        /// length is larger than 0Ch
+        ///
+        /// Returns [None] when the field is not present.
+        nominal_speed, nominal_speed_access: word @ 0x0C => RotationalSpeed;
 
-    /// Additional descriptive information about the cooling device or its location
-    /// This field is present in the structure only if the
-    /// structure’s length is 0Fh or larger.
-    pub fn description(&self) -> Option<String> {
-        self.parts.get_field_string(0x0E)
+        /// Additional descriptive information about the cooling device or its location
+        /// This field is present in the structure only if the
+        /// structure’s length is 0Fh or larger.
+        ///
+        /// Added in version 2.7 of this specification; returns [None] for earlier structures.
+        description, description_access: string @ 0x0E;
     }
 }
 
 /// # Rotational Speed
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum RotationalSpeed {
     /// Revolutions per minute (RPM)
     Rpm(u16),
-    /// RPM is unknown
+    /// RPM is unknown or the cooling device is non-rotating
     Unknown,
 }
 
@@ -98,6 +91,15 @@ impl From<u16> for RotationalSpeed {
     }
 }
 
+impl From<RotationalSpeed> for MaybeValue<u16> {
+    fn from(speed: RotationalSpeed) -> Self {
+        match speed {
+            RotationalSpeed::Rpm(rpm) => MaybeValue::Value(rpm),
+            RotationalSpeed::Unknown => MaybeValue::Unknown,
+        }
+    }
+}
+
 impl fmt::Debug for SMBiosCoolingDevice<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(any::type_name::<SMBiosCoolingDevice<'_>>())
@@ -262,7 +264,7 @@ mod tests {
         let parts = UndefinedStruct::new(&struct_type27);
         let test_struct = SMBiosCoolingDevice::new(&parts);
 
-        //assert_eq!(test_struct.temperature_probe_handle(), Some(Handle(42)));
+        assert_eq!(test_struct.temperature_probe_handle(), Some(Handle(42)));
 
         let device_type_and_status = test_struct.device_type_and_status().unwrap();
         assert_eq!(
@@ -281,4 +283,59 @@ mod tests {
         }
         assert_eq!(test_struct.description(), Some("Cooling Dev 1".to_string()));
     }
+
+    #[test]
+    fn test_cooling_device_versions() {
+        // 2.2 structure without the nominal speed and description fields
+        let struct_type27 = vec![
+            0x1B, 0x0C, 0x2E, 0x00, 0xFF, 0xFF, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type27);
+        let test_struct = SMBiosCoolingDevice::new(&parts);
+
+        assert_eq!(test_struct.temperature_probe_handle(), Some(Handle(0xFFFF)));
+        let device_type_and_status = test_struct.device_type_and_status().unwrap();
+        assert_eq!(
+            device_type_and_status.device_status,
+            CoolingDeviceStatus::Unknown
+        );
+        assert_eq!(
+            device_type_and_status.device_type,
+            CoolingDeviceType::ActiveCooling
+        );
+        assert_eq!(test_struct.cooling_unit_group(), Some(0));
+        assert_eq!(test_struct.nominal_speed(), None);
+        assert_eq!(test_struct.description(), None);
+
+        // 2.7 structure with a known nominal speed and no description string
+        let struct_type27 = vec![
+            0x1B, 0x0F, 0x2F, 0x00, 0xFF, 0xFF, 0xA3, 0x02, 0x00, 0x00, 0x00, 0x00, 0xB8, 0x0B,
+            0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type27);
+        let test_struct = SMBiosCoolingDevice::new(&parts);
+
+        let device_type_and_status = test_struct.device_type_and_status().unwrap();
+        assert_eq!(
+            device_type_and_status.device_status,
+            CoolingDeviceStatus::Critical
+        );
+        assert_eq!(device_type_and_status.device_type, CoolingDeviceType::Fan);
+        assert_eq!(test_struct.cooling_unit_group(), Some(2));
+        assert_eq!(
+            test_struct.nominal_speed(),
+            Some(RotationalSpeed::Rpm(3000))
+        );
+        assert_eq!(test_struct.description(), None);
+
+        let device_type_and_status = CoolingDeviceTypeAndStatus::from(0x0A);
+        assert_eq!(
+            device_type_and_status.device_status,
+            CoolingDeviceStatus::None
+        );
+        assert_eq!(device_type_and_status.device_type, CoolingDeviceType::None);
+        assert_eq!(device_type_and_status.raw, 0x0A);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{MaybeValue, SMBiosStruct, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
 #[cfg(feature = "no_std")]
@@ -28,63 +28,34 @@ impl<'a> SMBiosStruct<'a> for SMBiosElectricalCurrentProbe<'a> {
 }
 
 impl<'a> SMBiosElectricalCurrentProbe<'a> {
-    ///  A string that contains additional descriptive information about the probe or its location
-    pub fn description(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
+    field_accessors! {
+        ///  A string that contains additional descriptive information about the probe or its location
+        description, description_access: string @ 0x04;
 
-    /// Probe’s physical location and status of the current monitored by this current probe
-    pub fn location_and_status(&self) -> Option<CurrentProbeLocationAndStatus> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| CurrentProbeLocationAndStatus::from(raw))
-    }
+        /// Probe’s physical location and status of the current monitored by this current probe
+        location_and_status, location_and_status_access: byte @ 0x05
+            => CurrentProbeLocationAndStatus;
 
-    /// Maximum current level readable by this probe, in milliamps
-    pub fn maximum_value(&self) -> Option<ProbeAmperage> {
-        self.parts
-            .get_field_word(0x06)
-            .map(|raw| ProbeAmperage::from(raw))
-    }
+        /// Maximum current level readable by this probe, in milliamps
+        maximum_value, maximum_value_access: word @ 0x06 => ProbeAmperage;
 
-    /// Minimum current level readable by this probe, in milliamps
-    pub fn minimum_value(&self) -> Option<ProbeAmperage> {
-        self.parts
-            .get_field_word(0x08)
-            .map(|raw| ProbeAmperage::from(raw))
-    }
+        /// Minimum current level readable by this probe, in milliamps
+        minimum_value, minimum_value_access: word @ 0x08 => ProbeAmperage;
 
-    /// Resolution for the probe’s reading, in tenths of milliamps
-    pub fn resolution(&self) -> Option<CurrentProbeResolution> {
-        self.parts
-            .get_field_word(0x0A)
-            .map(|raw| CurrentProbeResolution::from(raw))
-    }
+        /// Resolution for the probe’s reading, in tenths of milliamps
+        resolution, resolution_access: word @ 0x0A => CurrentProbeResolution;
 
-    /// Tolerance for reading from this probe, in plus/minus milliamps
-    pub fn tolerance(&self) -> Option<ProbeAmperage> {
-        self.parts
-            .get_field_word(0x0C)
-            .map(|raw| ProbeAmperage::from(raw))
-    }
+        /// Tolerance for reading from this probe, in plus/minus milliamps
+        tolerance, tolerance_access: word @ 0x0C => ProbeAmperage;
 
-    /// Accuracy for reading from this probe, in plus/minus 1/100th of a percent
-    pub fn accuracy(&self) -> Option<CurrentProbeAccuracy> {
-        self.parts
-            .get_field_word(0x0E)
-            .map(|raw| CurrentProbeAccuracy::from(raw))
-    }
+        /// Accuracy for reading from this probe, in plus/minus 1/100th of a percent
+        accuracy, accuracy_access: word @ 0x0E => CurrentProbeAccuracy;
 
-    /// OEM- or BIOS vendor-specific information.
-    pub fn oem_defined(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x10)
-    }
+        /// OEM- or BIOS vendor-specific information.
+        oem_defined, oem_defined_access: dword @ 0x10;
 
-    /// Nominal value for the probe’s reading in milliamps
-    pub fn nominal_value(&self) -> Option<ProbeAmperage> {
-        self.parts
-            .get_field_word(0x14)
-            .map(|raw| ProbeAmperage::from(raw))
+        /// Nominal value for the probe’s reading in milliamps
+        nominal_value, nominal_value_access: word @ 0x14 => ProbeAmperage;
     }
 }
 
@@ -244,10 +215,12 @@ impl From<u8> for CurrentProbeLocationAndStatus {
 }
 
 /// # Probe Amperage
-#[derive(Serialize, Debug)]
+///
+/// The field is a signed value, allowing currents flowing in the reverse direction.
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum ProbeAmperage {
     /// Amperage in milliamps
-    Milliamps(u16),
+    Milliamps(i16),
     /// Amperage is unknown
     Unknown,
 }
@@ -256,13 +229,22 @@ impl From<u16> for ProbeAmperage {
     fn from(raw: u16) -> Self {
         match raw {
             0x8000 => ProbeAmperage::Unknown,
-            _ => ProbeAmperage::Milliamps(raw),
+            _ => ProbeAmperage::Milliamps(raw as i16),
+        }
+    }
+}
+
+impl From<ProbeAmperage> for MaybeValue<i16> {
+    fn from(amperage: ProbeAmperage) -> Self {
+        match amperage {
+            ProbeAmperage::Milliamps(milliamps) => MaybeValue::Value(milliamps),
+            ProbeAmperage::Unknown => MaybeValue::Unknown,
         }
     }
 }
 
 /// # Current Probe Resolution
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum CurrentProbeResolution {
     /// Resolution for the probe's reading in tenths of milliamps
     TenthsOfMilliamps(u16),
@@ -279,8 +261,17 @@ impl From<u16> for CurrentProbeResolution {
     }
 }
 
+impl From<CurrentProbeResolution> for MaybeValue<u16> {
+    fn from(resolution: CurrentProbeResolution) -> Self {
+        match resolution {
+            CurrentProbeResolution::TenthsOfMilliamps(resolution) => MaybeValue::Value(resolution),
+            CurrentProbeResolution::Unknown => MaybeValue::Unknown,
+        }
+    }
+}
+
 /// # Current Probe Accuracy
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum CurrentProbeAccuracy {
     /// Accuracy for the probe's reading in 1/100th of a percent
     OneOneHundredthPercent(u16),
@@ -297,6 +288,15 @@ impl From<u16> for CurrentProbeAccuracy {
     }
 }
 
+impl From<CurrentProbeAccuracy> for MaybeValue<u16> {
+    fn from(accuracy: CurrentProbeAccuracy) -> Self {
+        match accuracy {
+            CurrentProbeAccuracy::OneOneHundredthPercent(accuracy) => MaybeValue::Value(accuracy),
+            CurrentProbeAccuracy::Unknown => MaybeValue::Unknown,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -348,4 +348,49 @@ mod tests {
             ProbeAmperage::Unknown => (),
         }
     }
+
+    #[test]
+    fn test_location_and_status_and_values() {
+        let struct_type29 = vec![
+            0x1D, 0x14, 0x34, 0x00, 0x00, 0x2A, 0x88, 0x13, 0x78, 0xEC, 0x00, 0x80, 0x64, 0x00,
+            0xC8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type29);
+        let test_struct = SMBiosElectricalCurrentProbe::new(&parts);
+
+        let location_and_status = test_struct.location_and_status().unwrap();
+        assert_eq!(location_and_status.status, CurrentProbeStatus::Other);
+        assert_eq!(
+            location_and_status.location,
+            CurrentProbeLocation::PowerUnit
+        );
+        assert_eq!(
+            test_struct.maximum_value(),
+            Some(ProbeAmperage::Milliamps(5000))
+        );
+        assert_eq!(
+            test_struct.minimum_value(),
+            Some(ProbeAmperage::Milliamps(-5000))
+        );
+        assert_eq!(
+            test_struct.resolution(),
+            Some(CurrentProbeResolution::Unknown)
+        );
+        assert_eq!(test_struct.tolerance(), Some(ProbeAmperage::Milliamps(100)));
+        assert_eq!(
+            test_struct.accuracy(),
+            Some(CurrentProbeAccuracy::OneOneHundredthPercent(200))
+        );
+        // nominal value is only present when the length is larger than 14h
+        assert_eq!(test_struct.nominal_value(), None);
+
+        let location_and_status = CurrentProbeLocationAndStatus::from(0xE3);
+        assert_eq!(location_and_status.status, CurrentProbeStatus::None);
+        assert_eq!(
+            location_and_status.location,
+            CurrentProbeLocation::Processor
+        );
+        assert_eq!(location_and_status.raw, 0xE3);
+    }
 }
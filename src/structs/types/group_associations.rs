@@ -1,5 +1,5 @@
-use crate::core::{Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::core::{Handle, SMBiosData, StructFields, UndefinedStruct};
+use crate::{DefinedStruct, SMBiosStruct};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
 #[cfg(feature = "no_std")]
@@ -31,9 +31,9 @@ impl<'a> SMBiosStruct<'a> for SMBiosGroupAssociations<'a> {
 }
 
 impl<'a> SMBiosGroupAssociations<'a> {
-    /// A string describing the group
-    pub fn group_name(&self) -> Option<String> {
-        self.parts.get_field_string(0x4)
+    field_accessors! {
+        /// A string describing the group
+        group_name, group_name_access: string @ 0x4;
     }
 
     /// Number of [GroupAssociationItem] entries
@@ -57,6 +57,39 @@ impl<'a> SMBiosGroupAssociations<'a> {
     pub fn item_iterator(&'a self) -> GroupAssociationItemIterator<'a> {
         GroupAssociationItemIterator::new(self)
     }
+
+    /// The structures of the group, in item order
+    ///
+    /// Items that [GroupAssociationItem::resolve] cannot find in `data` are
+    /// skipped.
+    pub fn members<'b>(&self, data: &'b SMBiosData) -> Vec<DefinedStruct<'b>> {
+        self.item_iterator()
+            .filter_map(|item| item.resolve(data))
+            .collect()
+    }
+
+    /// The structures of the group of type `T`, in item order
+    ///
+    /// For example, the processors of a group describing a NUMA node:
+    ///
+    /// ```
+    /// # use smbioslib::*;
+    /// # fn numa_node(data: &SMBiosData, group: &SMBiosGroupAssociations<'_>) {
+    /// for processor in group.members_of_type::<SMBiosProcessorInformation<'_>>(data) {
+    ///     println!("{:?}", processor.socket_designation());
+    /// }
+    /// # }
+    /// ```
+    pub fn members_of_type<'b, T>(&self, data: &'b SMBiosData) -> Vec<T>
+    where
+        T: SMBiosStruct<'b>,
+    {
+        self.item_iterator()
+            .filter(|item| item.struct_type() == Some(T::STRUCT_TYPE))
+            .filter_map(|item| item.resolve_parts(data))
+            .map(T::new)
+            .collect()
+    }
 }
 
 impl fmt::Debug for SMBiosGroupAssociations<'_> {
@@ -118,6 +151,21 @@ impl<'a> GroupAssociationItem<'a> {
             .parts()
             .get_field_handle(self.entry_offset + 1)
     }
+
+    /// The structure referenced by [Self::item_handle]
+    ///
+    /// [None] if `data` has no structure with this handle, or if the
+    /// structure found is not of type [Self::struct_type].
+    pub fn resolve<'b>(&self, data: &'b SMBiosData) -> Option<DefinedStruct<'b>> {
+        self.resolve_parts(data).map(DefinedStruct::from)
+    }
+
+    fn resolve_parts<'b>(&self, data: &'b SMBiosData) -> Option<&'b UndefinedStruct> {
+        let struct_type = self.struct_type()?;
+        let handle = self.item_handle()?;
+
+        data.resolve_parts(&handle, struct_type)
+    }
 }
 
 impl fmt::Debug for GroupAssociationItem<'_> {
@@ -256,4 +304,42 @@ mod tests {
         assert_eq!(first_item.struct_type(), Some(221));
         assert_eq!(*first_item.item_handle().unwrap(), 91);
     }
+
+    #[test]
+    fn test_members() {
+        use crate::{SMBiosMemoryDevice, SMBiosProcessorInformation};
+
+        let table = vec![
+            // group 0x0010: processor 0x0020, memory device 0x0030,
+            // processor 0x0031 (a memory device), memory device 0x0040 (missing)
+            0x0E, 0x11, 0x10, 0x00, 0x01, 0x04, 0x20, 0x00, 0x11, 0x30, 0x00, 0x04, 0x31, 0x00,
+            0x11, 0x40, 0x00, 0x4E, 0x6F, 0x64, 0x65, 0x20, 0x30, 0x00, 0x00,
+            // processor 0x0020
+            0x04, 0x1A, 0x20, 0x00, 0x00, 0x03, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x41, 0x01, 0xFF, 0xFF, 0x00, 0x00,
+            // memory device 0x0030
+            0x11, 0x15, 0x30, 0x00, 0x00, 0x10, 0xFE, 0xFF, 0x48, 0x00, 0x40, 0x00, 0x00, 0x40,
+            0x09, 0x00, 0x00, 0x00, 0x1A, 0x00, 0x00, 0x00, 0x00,
+            // memory device 0x0031
+            0x11, 0x15, 0x31, 0x00, 0x00, 0x10, 0xFE, 0xFF, 0x48, 0x00, 0x40, 0x00, 0x00, 0x40,
+            0x09, 0x00, 0x00, 0x00, 0x1A, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let data = SMBiosData::from_vec_and_version(table, None);
+        let group = data.first::<SMBiosGroupAssociations<'_>>().unwrap();
+        assert_eq!(group.group_name(), Some("Node 0".to_string()));
+
+        let members = group.members(&data);
+        assert_eq!(members.len(), 2);
+        assert!(matches!(members[0], DefinedStruct::ProcessorInformation(_)));
+        assert!(matches!(members[1], DefinedStruct::MemoryDevice(_)));
+
+        let processors = group.members_of_type::<SMBiosProcessorInformation<'_>>(&data);
+        assert_eq!(processors.len(), 1);
+        assert_eq!(*processors[0].parts().header.handle(), 0x0020);
+
+        let memory_devices = group.members_of_type::<SMBiosMemoryDevice<'_>>(&data);
+        assert_eq!(memory_devices.len(), 1);
+        assert_eq!(*memory_devices[0].parts().header.handle(), 0x0030);
+    }
 }
@@ -26,11 +26,10 @@ impl<'a> SMBiosStruct<'a> for SMBiosHardwareSecurity<'a> {
 }
 
 impl<'a> SMBiosHardwareSecurity<'a> {
-    /// Bit field that identifies the password and reset status for the system
-    pub fn hardware_security_settings(&self) -> Option<HardwareSecuritySettings> {
-        self.parts
-            .get_field_byte(0x4)
-            .map(|raw| HardwareSecuritySettings::from(raw))
+    field_accessors! {
+        /// Bit field that identifies the password and reset status for the system
+        hardware_security_settings, hardware_security_settings_access: byte @ 0x4
+            => HardwareSecuritySettings;
     }
 }
 
@@ -28,51 +28,35 @@ impl<'a> SMBiosStruct<'a> for SMBiosIpmiDeviceInformation<'a> {
 }
 
 impl<'a> SMBiosIpmiDeviceInformation<'a> {
-    /// Baseboard Management Controller (BMC) interface type.
-    pub fn interface_type(&self) -> Option<IpmiInterfaceTypeData> {
-        self.parts
-            .get_field_byte(0x04)
-            .map(|raw| IpmiInterfaceTypeData::from(raw))
-    }
-
-    /// IPMI specification revision, in BCD format, to which the BMC was designed
-    pub fn ipmi_specification_revision(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x05)
-    }
-
-    /// Slave address on the I2C bus of this BMC
-    pub fn i2c_target_address(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x06)
-    }
-
-    /// Bus ID of the NV storage device.
-    ///
-    /// If no storage device exists for this BMC, the field is set to 0FFh.
-    pub fn nvstorage_device_address(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x07)
-    }
-
-    /// Base address (either memory-mapped or I/O) of the BMC
-    ///
-    /// If the least-significant bit of the field is a 1, the address is in
-    /// I/O space; otherwise, the address is memory-mapped. Refer
-    /// to the [IPMI Interface Specification](https://www.intel.com/content/www/us/en/products/docs/servers/ipmi/ipmi-home.html) for usage details.
-    pub fn base_address(&self) -> Option<u64> {
-        self.parts.get_field_qword(0x08)
-    }
-
-    /// Base Address Modifier and Interrupt Info
-    pub fn base_address_modifier(&self) -> Option<BaseAddressModifier> {
-        self.parts
-            .get_field_byte(0x10)
-            .map(|raw| BaseAddressModifier::from(raw))
-    }
-
-    /// Interrupt number for IPMI System Interface
-    ///
-    /// 00h = unspecified/unsupported
-    pub fn interrupt_number(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x11)
+    field_accessors! {
+        /// Baseboard Management Controller (BMC) interface type.
+        interface_type, interface_type_access: byte @ 0x04 => IpmiInterfaceTypeData;
+
+        /// IPMI specification revision, in BCD format, to which the BMC was designed
+        ipmi_specification_revision, ipmi_specification_revision_access: byte @ 0x05;
+
+        /// Slave address on the I2C bus of this BMC
+        i2c_target_address, i2c_target_address_access: byte @ 0x06;
+
+        /// Bus ID of the NV storage device.
+        ///
+        /// If no storage device exists for this BMC, the field is set to 0FFh.
+        nvstorage_device_address, nvstorage_device_address_access: byte @ 0x07;
+
+        /// Base address (either memory-mapped or I/O) of the BMC
+        ///
+        /// If the least-significant bit of the field is a 1, the address is in
+        /// I/O space; otherwise, the address is memory-mapped. Refer
+        /// to the [IPMI Interface Specification](https://www.intel.com/content/www/us/en/products/docs/servers/ipmi/ipmi-home.html) for usage details.
+        base_address, base_address_access: qword @ 0x08;
+
+        /// Base Address Modifier and Interrupt Info
+        base_address_modifier, base_address_modifier_access: byte @ 0x10 => BaseAddressModifier;
+
+        /// Interrupt number for IPMI System Interface
+        ///
+        /// 00h = unspecified/unsupported
+        interrupt_number, interrupt_number_access: byte @ 0x11;
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{SMBiosStruct, StructFields, UndefinedStruct};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
 use core::ops::Deref;
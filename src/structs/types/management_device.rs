@@ -29,28 +29,18 @@ impl<'a> SMBiosStruct<'a> for SMBiosManagementDevice<'a> {
 }
 
 impl<'a> SMBiosManagementDevice<'a> {
-    /// Additional descriptive information about the device or its location
-    pub fn description(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
+    field_accessors! {
+        /// Additional descriptive information about the device or its location
+        description, description_access: string @ 0x04;
 
-    /// Device's type
-    pub fn device_type(&self) -> Option<ManagementDeviceTypeData> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| ManagementDeviceTypeData::from(raw))
-    }
+        /// Device's type
+        device_type, device_type_access: byte @ 0x05 => ManagementDeviceTypeData;
 
-    /// Device's address
-    pub fn address(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x06)
-    }
+        /// Device's address
+        address, address_access: dword @ 0x06;
 
-    /// Type of addressing used to access the device
-    pub fn address_type(&self) -> Option<ManagementDeviceAddressTypeData> {
-        self.parts
-            .get_field_byte(0x0A)
-            .map(|raw| ManagementDeviceAddressTypeData::from(raw))
+        /// Type of addressing used to access the device
+        address_type, address_type_access: byte @ 0x0A => ManagementDeviceAddressTypeData;
     }
 }
 
@@ -30,27 +30,21 @@ impl<'a> SMBiosStruct<'a> for SMBiosManagementDeviceComponent<'a> {
 }
 
 impl<'a> SMBiosManagementDeviceComponent<'a> {
-    /// Number of the string that contains additional descriptive information about the component
-    pub fn description(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
+    field_accessors! {
+        /// Number of the string that contains additional descriptive information about the component
+        description, description_access: string @ 0x04;
 
-    /// Handle, or instance number, of the Management Device that contains this component
-    pub fn management_device_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x05)
-    }
+        /// Handle, or instance number, of the Management Device that contains this component
+        management_device_handle, management_device_handle_access: handle @ 0x05;
 
-    /// Handle, or instance number, of the probe or cooling device that defines this component
-    pub fn component_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x07)
-    }
+        /// Handle, or instance number, of the probe or cooling device that defines this component
+        component_handle, component_handle_access: handle @ 0x07;
 
-    /// Handle, or instance number, associated with the device
-    /// thresholds;
-    /// A value of 0FFFFh indicates that no Threshold Data
-    /// structure is associated with this component.
-    pub fn threshold_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x09)
+        /// Handle, or instance number, associated with the device
+        /// thresholds;
+        /// A value of 0FFFFh indicates that no Threshold Data
+        /// structure is associated with this component.
+        threshold_handle, threshold_handle_access: handle @ 0x09;
     }
 }
 
@@ -32,34 +32,24 @@ impl<'a> SMBiosStruct<'a> for SMBiosManagementDeviceThresholdData<'a> {
 }
 
 impl<'a> SMBiosManagementDeviceThresholdData<'a> {
-    /// Lower non-critical threshold for this component
-    pub fn lower_threshold_non_critical(&self) -> Option<u16> {
-        self.parts.get_field_word(0x04)
-    }
+    field_accessors! {
+        /// Lower non-critical threshold for this component
+        lower_threshold_non_critical, lower_threshold_non_critical_access: word @ 0x04;
 
-    /// Upper non-critical threshold for this component
-    pub fn upper_threshold_non_critical(&self) -> Option<u16> {
-        self.parts.get_field_word(0x06)
-    }
+        /// Upper non-critical threshold for this component
+        upper_threshold_non_critical, upper_threshold_non_critical_access: word @ 0x06;
 
-    /// Lower critical threshold for this component
-    pub fn lower_threshold_critical(&self) -> Option<u16> {
-        self.parts.get_field_word(0x08)
-    }
+        /// Lower critical threshold for this component
+        lower_threshold_critical, lower_threshold_critical_access: word @ 0x08;
 
-    /// Upper critical threshold for this component
-    pub fn upper_threshold_critical(&self) -> Option<u16> {
-        self.parts.get_field_word(0x0A)
-    }
+        /// Upper critical threshold for this component
+        upper_threshold_critical, upper_threshold_critical_access: word @ 0x0A;
 
-    /// Lower non-recoverable threshold for this component
-    pub fn lower_threshold_non_recoverable(&self) -> Option<u16> {
-        self.parts.get_field_word(0x0C)
-    }
+        /// Lower non-recoverable threshold for this component
+        lower_threshold_non_recoverable, lower_threshold_non_recoverable_access: word @ 0x0C;
 
-    /// Upper non-recoverable threshold for this component
-    pub fn upper_threshold_non_recoverable(&self) -> Option<u16> {
-        self.parts.get_field_word(0x0E)
+        /// Upper non-recoverable threshold for this component
+        upper_threshold_non_recoverable, upper_threshold_non_recoverable_access: word @ 0x0E;
     }
 }
 
@@ -1,7 +1,7 @@
 use crate::core::{Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{MaybeValue, MemoryCapacity, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use core::{fmt, any};
+use core::{fmt, any, ops::RangeInclusive};
 
 /// # Memory Array Mapped Address (Type 19)
 ///
@@ -29,71 +29,94 @@ impl<'a> SMBiosStruct<'a> for SMBiosMemoryArrayMappedAddress<'a> {
 }
 
 impl<'a> SMBiosMemoryArrayMappedAddress<'a> {
-    /// Physical address, in kilobytes, of a range of
-    /// memory mapped to the specified Physical Memory
-    /// Array
-    /// When the field value is FFFF FFFFh, the actual
-    /// address is stored in the Extended Starting
-    /// Address field. When this field contains a valid
-    /// address, Ending Address must also contain a valid
-    /// address. When this field contains FFFF FFFFh,
-    /// Ending Address must also contain FFFF FFFFh.
-    pub fn starting_address(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x4)
+    field_accessors! {
+        /// Physical address, in kilobytes, of a range of
+        /// memory mapped to the specified Physical Memory
+        /// Array
+        /// When the field value is FFFF FFFFh, the actual
+        /// address is stored in the Extended Starting
+        /// Address field. When this field contains a valid
+        /// address, Ending Address must also contain a valid
+        /// address. When this field contains FFFF FFFFh,
+        /// Ending Address must also contain FFFF FFFFh.
+        starting_address, starting_address_access: dword @ 0x4;
+
+        /// Physical ending address of the last kilobyte of a
+        /// range of addresses mapped to the specified
+        /// Physical Memory Array
+        /// When the field value is FFFF FFFFh and the
+        /// Starting Address field also contains FFFF FFFFh,
+        /// the actual address is stored in the Extended
+        /// Ending Address field. When this field contains a
+        /// valid address, Starting Address must also contain
+        /// a valid address.
+        ending_address, ending_address_access: dword @ 0x8;
+
+        /// Handle, or instance number, associated with the
+        /// Physical Memory Array to which this address
+        /// range is mapped
+        /// Multiple address ranges can be mapped to a
+        /// single Physical Memory Array.
+        physical_memory_array_handle, physical_memory_array_handle_access: handle @ 0xC;
+
+        /// Number of Memory Devices that form a single row
+        /// of memory for the address partition defined by this
+        /// structure
+        partition_width, partition_width_access: byte @ 0xE;
+
+        /// Physical address, in bytes, of a range of memory
+        /// mapped to the specified Physical Memory Array
+        /// This field is valid when Starting Address contains
+        /// the value FFFF FFFFh. If Starting Address
+        /// contains a value other than FFFF FFFFh, this field
+        /// contains zeros. When this field contains a valid
+        /// address, Extended Ending Address must also
+        /// contain a valid address.
+        extended_starting_address, extended_starting_address_access: qword @ 0xF;
+
+        /// Physical ending address, in bytes, of the last of a
+        /// range of addresses mapped to the specified
+        /// Physical Memory Array
+        /// This field is valid when both Starting Address and
+        /// Ending Address contain the value FFFF FFFFh. If
+        /// Ending Address contains a value other than FFFF
+        /// FFFFh, this field contains zeros. When this field
+        /// contains a valid address, Extended Starting
+        /// Address must also contain a valid address.
+        extended_ending_address, extended_ending_address_access: qword @ 0x17;
     }
 
-    /// Physical ending address of the last kilobyte of a
-    /// range of addresses mapped to the specified
-    /// Physical Memory Array
-    /// When the field value is FFFF FFFFh and the
-    /// Starting Address field also contains FFFF FFFFh,
-    /// the actual address is stored in the Extended
-    /// Ending Address field. When this field contains a
-    /// valid address, Starting Address must also contain
-    /// a valid address.
-    pub fn ending_address(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x8)
+    /// Range of physical addresses, in bytes, mapped to the Physical Memory Array
+    ///
+    /// Reads the extended addresses when Starting Address and Ending
+    /// Address both contain FFFF FFFFh, otherwise converts the kilobyte
+    /// addresses to bytes.
+    pub fn address_range(&self) -> Option<RangeInclusive<u64>> {
+        match (self.starting_address()?, self.ending_address()?) {
+            (0xFFFF_FFFF, 0xFFFF_FFFF) => {
+                Some(self.extended_starting_address()?..=self.extended_ending_address()?)
+            }
+            (start, end) => Some(u64::from(start) << 10..=(u64::from(end) << 10 | 0x3FF)),
+        }
     }
 
-    /// Handle, or instance number, associated with the
-    /// Physical Memory Array to which this address
-    /// range is mapped
-    /// Multiple address ranges can be mapped to a
-    /// single Physical Memory Array.
-    pub fn physical_memory_array_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0xC)
-    }
-
-    /// Number of Memory Devices that form a single row
-    /// of memory for the address partition defined by this
-    /// structure
-    pub fn partition_width(&self) -> Option<u8> {
-        self.parts.get_field_byte(0xE)
-    }
-
-    /// Physical address, in bytes, of a range of memory
-    /// mapped to the specified Physical Memory Array
-    /// This field is valid when Starting Address contains
-    /// the value FFFF FFFFh. If Starting Address
-    /// contains a value other than FFFF FFFFh, this field
-    /// contains zeros. When this field contains a valid
-    /// address, Extended Ending Address must also
-    /// contain a valid address.
-    pub fn extended_starting_address(&self) -> Option<u64> {
-        self.parts.get_field_qword(0xF)
-    }
-
-    /// Physical ending address, in bytes, of the last of a
-    /// range of addresses mapped to the specified
-    /// Physical Memory Array
-    /// This field is valid when both Starting Address and
-    /// Ending Address contain the value FFFF FFFFh. If
-    /// Ending Address contains a value other than FFFF
-    /// FFFFh, this field contains zeros. When this field
-    /// contains a valid address, Extended Starting
-    /// Address must also contain a valid address.
-    pub fn extended_ending_address(&self) -> Option<u64> {
-        self.parts.get_field_qword(0x17)
+    /// Size, in bytes, of the range of physical addresses mapped to the
+    /// Physical Memory Array, see [Self::address_range]
+    ///
+    /// The size is [MaybeValue::Unknown] when the range ends before it
+    /// starts or spans the whole 64-bit address space, and
+    /// [MaybeValue::NotPresentInVersion] when the addresses are absent.
+    pub fn range_size(&self) -> MaybeValue<MemoryCapacity> {
+        let range = match self.address_range() {
+            Some(range) => range,
+            None => return MaybeValue::NotPresentInVersion,
+        };
+        let size = range
+            .end()
+            .checked_sub(*range.start())
+            .and_then(|size| size.checked_add(1))
+            .map(MemoryCapacity::new);
+        size.map_or(MaybeValue::Unknown, MaybeValue::Value)
     }
 }
 
@@ -161,5 +184,17 @@ mod tests {
         assert_eq!(test_struct.partition_width(), Some(4));
         assert_eq!(test_struct.extended_starting_address(), Some(0));
         assert_eq!(test_struct.extended_ending_address(), Some(0));
+        assert_eq!(
+            test_struct.range_size(),
+            MaybeValue::Value(MemoryCapacity::new(16 << 30))
+        );
+
+        // the extended addresses span the whole 64-bit address space
+        let mut struct_type19 = struct_type19.clone();
+        struct_type19[0x04..0x0C].copy_from_slice(&[0xFF; 8]);
+        struct_type19[0x17..0x1F].copy_from_slice(&[0xFF; 8]);
+        let parts = UndefinedStruct::new(&struct_type19);
+        let test_struct = SMBiosMemoryArrayMappedAddress::new(&parts);
+        assert_eq!(test_struct.range_size(), MaybeValue::Unknown);
     }
 }
@@ -1,4 +1,4 @@
-use crate::core::{Handle, UndefinedStruct};
+use crate::core::{Handle, StructFields, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
@@ -34,26 +34,20 @@ impl<'a> SMBiosStruct<'a> for SMBiosMemoryChannel<'a> {
 }
 
 impl<'a> SMBiosMemoryChannel<'a> {
-    /// Type of memory associated with the channel
-    pub fn channel_type(&self) -> Option<MemoryChannelTypeData> {
-        self.parts
-            .get_field_byte(0x04)
-            .map(|raw| MemoryChannelTypeData::from(raw))
-    }
-
-    /// Maximum load supported by the channel; the sum of all
-    /// device loads cannot exceed this value
-    pub fn maximum_channel_load(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x05)
-    }
-
-    /// Number of [super::SMBiosMemoryDevice]s (Type 11h) that are
-    /// associated with this channel
-    ///
-    /// This value also defines the number of Load/Handle pairs
-    /// that follow.
-    pub fn memory_device_count(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x06)
+    field_accessors! {
+        /// Type of memory associated with the channel
+        channel_type, channel_type_access: byte @ 0x04 => MemoryChannelTypeData;
+
+        /// Maximum load supported by the channel; the sum of all
+        /// device loads cannot exceed this value
+        maximum_channel_load, maximum_channel_load_access: byte @ 0x05;
+
+        /// Number of [super::SMBiosMemoryDevice]s (Type 11h) that are
+        /// associated with this channel
+        ///
+        /// This value also defines the number of Load/Handle pairs
+        /// that follow.
+        memory_device_count, memory_device_count_access: byte @ 0x06;
     }
 
     /// Load/Handle pairs defining the [super::SMBiosMemoryDevice]s
@@ -1,4 +1,4 @@
-use crate::core::{Handle, UndefinedStruct};
+use crate::core::{Handle, StructFields, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
@@ -31,70 +31,42 @@ impl<'a> SMBiosStruct<'a> for SMBiosMemoryControllerInformation<'a> {
 }
 
 impl<'a> SMBiosMemoryControllerInformation<'a> {
-    /// Error detecting method
-    pub fn error_detecting_method(&self) -> Option<ErrorDetectingMethodData> {
-        self.parts
-            .get_field_byte(0x04)
-            .map(|raw| ErrorDetectingMethodData::from(raw))
-    }
-
-    /// Error correcting capability
-    pub fn error_correcting_capability(&self) -> Option<ErrorCorrectingCapabilities> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| ErrorCorrectingCapabilities::from(raw))
-    }
-
-    /// Supported interleave
-    pub fn supported_interleave(&self) -> Option<InterleaveSupportData> {
-        self.parts
-            .get_field_byte(0x06)
-            .map(|raw| InterleaveSupportData::from(raw))
-    }
-
-    /// Current interleave
-    pub fn current_interleave(&self) -> Option<InterleaveSupportData> {
-        self.parts
-            .get_field_byte(0x07)
-            .map(|raw| InterleaveSupportData::from(raw))
-    }
-
-    /// Maximum Memory Module Size
-    ///
-    /// Size of the largest memory module supported (per slot),
-    /// specified as n, where 2**n is the maximum size in MB
-    ///
-    /// The maximum amount of memory supported by this controller
-    /// is that value times the number of slots, as specified in
-    /// offset 0Eh of this structure.
-    pub fn maximum_memory_module_size(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x08)
-    }
-
-    /// Supported Speeds
-    pub fn supported_speeds(&self) -> Option<MemorySpeeds> {
-        self.parts
-            .get_field_word(0x09)
-            .map(|raw| MemorySpeeds::from(raw))
-    }
-
-    /// Supported Memory Types
-    pub fn supported_memory_types(&self) -> Option<MemoryTypes> {
-        self.parts
-            .get_field_word(0x0B)
-            .map(|raw| MemoryTypes::from(raw))
-    }
-
-    /// Memory Module Voltage
-    pub fn memory_module_voltage(&self) -> Option<ModuleVoltage> {
-        self.parts
-            .get_field_byte(0x0D)
-            .map(|raw| ModuleVoltage::from(raw))
-    }
-
-    /// Number of Associated Memory Slots
-    pub fn number_of_associated_memory_slots(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x0E)
+    field_accessors! {
+        /// Error detecting method
+        error_detecting_method, error_detecting_method_access: byte @ 0x04
+            => ErrorDetectingMethodData;
+
+        /// Error correcting capability
+        error_correcting_capability, error_correcting_capability_access: byte @ 0x05
+            => ErrorCorrectingCapabilities;
+
+        /// Supported interleave
+        supported_interleave, supported_interleave_access: byte @ 0x06 => InterleaveSupportData;
+
+        /// Current interleave
+        current_interleave, current_interleave_access: byte @ 0x07 => InterleaveSupportData;
+
+        /// Maximum Memory Module Size
+        ///
+        /// Size of the largest memory module supported (per slot),
+        /// specified as n, where 2**n is the maximum size in MB
+        ///
+        /// The maximum amount of memory supported by this controller
+        /// is that value times the number of slots, as specified in
+        /// offset 0Eh of this structure.
+        maximum_memory_module_size, maximum_memory_module_size_access: byte @ 0x08;
+
+        /// Supported Speeds
+        supported_speeds, supported_speeds_access: word @ 0x09 => MemorySpeeds;
+
+        /// Supported Memory Types
+        supported_memory_types, supported_memory_types_access: word @ 0x0B => MemoryTypes;
+
+        /// Memory Module Voltage
+        memory_module_voltage, memory_module_voltage_access: byte @ 0x0D => ModuleVoltage;
+
+        /// Number of Associated Memory Slots
+        number_of_associated_memory_slots, number_of_associated_memory_slots_access: byte @ 0x0E;
     }
 
     /// Memory Module Configuration Handles
@@ -1,4 +1,6 @@
-use crate::core::{Handle, UndefinedStruct};
+use crate::core::{
+    Handle, MaybeValue, MegatransferSpeed, MemoryCapacity, StructFields, UndefinedStruct,
+};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
@@ -30,288 +32,273 @@ impl<'a> SMBiosStruct<'a> for SMBiosMemoryDevice<'a> {
 }
 
 impl<'a> SMBiosMemoryDevice<'a> {
-    /// Handle, or instance number, associated with the
-    /// [super::SMBiosPhysicalMemoryArray] to which this device belongs
-    pub fn physical_memory_array_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x04)
-    }
-
-    /// Handle, or instance number, associated with any
-    /// error that was previously detected for the device
-    /// If the system does not provide the error information
-    /// structure, the field contains FFFEh; otherwise, the
-    /// field contains either FFFFh (if no error was
-    /// detected) or the handle of the error-information
-    /// structure ([super::SMBiosMemoryErrorInformation32] or
-    /// [super::SMBiosMemoryErrorInformation64]).
-    pub fn memory_error_information_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x06)
-    }
-
-    /// Total width, in bits, of this memory device, including
-    /// any check or error-correction bits
-    /// If there are no error-correction bits, this value
-    /// should be equal to Data Width. If the width is
-    /// unknown, the field is set to FFFFh.
-    pub fn total_width(&self) -> Option<u16> {
-        self.parts.get_field_word(0x08)
-    }
-
-    /// Data width, in bits, of this memory device
-    /// A Data Width of 0 and a Total Width of 8 indicates
-    /// that the device is being used solely to provide 8
-    /// error-correction bits. If the width is unknown, the
-    /// field is set to FFFFh.
-    pub fn data_width(&self) -> Option<u16> {
-        self.parts.get_field_word(0x0A)
-    }
-
-    /// Size of the memory device
-    pub fn size(&self) -> Option<MemorySize> {
-        self.parts
-            .get_field_word(0x0C)
-            .map(|raw| MemorySize::from(raw))
-    }
-
-    /// Implementation form factor for this memory device
-    pub fn form_factor(&self) -> Option<MemoryFormFactorData> {
-        self.parts
-            .get_field_byte(0x0E)
-            .map(|raw| MemoryFormFactorData::from(raw))
-    }
-
-    /// Identifies when the Memory Device is one of a set
-    /// of Memory Devices that must be populated with all
-    /// devices of the same type and size, and the set to
-    /// which this device belongs
-    /// A value of 0 indicates that the device is not part of a
-    /// set; a value of FFh indicates that the attribute is
-    /// unknown.
-    /// NOTE: A Device Set number must be unique within the
-    /// context of the Memory Array containing this Memory
-    /// Device.
-    pub fn device_set(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x0F)
-    }
-
-    /// Identifies the physically-labeled socket or board position where
-    /// the memory device is located
-    /// EXAMPLE: “SIMM 3”
-    pub fn device_locator(&self) -> Option<String> {
-        self.parts.get_field_string(0x10)
-    }
-
-    /// Identifies the physically labeled bank where the memory device is located
-    /// EXAMPLE: “Bank 0” or “A”
-    pub fn bank_locator(&self) -> Option<String> {
-        self.parts.get_field_string(0x11)
-    }
-
-    /// Type of memory used in this device
-    pub fn memory_type(&self) -> Option<MemoryDeviceTypeData> {
-        self.parts
-            .get_field_byte(0x12)
-            .map(|raw| MemoryDeviceTypeData::from(raw))
-    }
-
-    /// Additional detail on the memory device type
-    pub fn type_detail(&self) -> Option<MemoryTypeDetails> {
-        self.parts
-            .get_field_word(0x13)
-            .map(|raw| MemoryTypeDetails::from(raw))
-    }
-
-    /// The maximum capable speed of the
-    /// device, in megatransfers per second (MT/s).
-    pub fn speed(&self) -> Option<MemorySpeed> {
-        self.parts
-            .get_field_word(0x15)
-            .map(|raw| MemorySpeed::from(raw))
-    }
-
-    /// The manufacturer of this memory device
-    pub fn manufacturer(&self) -> Option<String> {
-        self.parts.get_field_string(0x17)
-    }
-
-    /// The serial number of this memory device.
-    /// This value is set by the manufacturer and normally
-    /// is not changeable.
-    pub fn serial_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x18)
-    }
-
-    /// The asset tag of this memory device
-    pub fn asset_tag(&self) -> Option<String> {
-        self.parts.get_field_string(0x19)
-    }
-
-    /// The part number of this memory device.
-    /// This value is set by the manufacturer and normally
-    /// is not changeable.
-    pub fn part_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x1A)
-    }
-
-    /// Bits 7-4: reserved
-    /// Bits 3-0: rank
-    /// Value=0 for unknown rank information
-    pub fn attributes(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x1B)
-    }
-
-    /// Extended size of the memory device in MB (complements
-    /// the Size field at offset 0Ch)
-    pub fn extended_size(&self) -> Option<MemorySizeExtended> {
-        self.parts
-            .get_field_dword(0x1C)
-            .map(|raw| MemorySizeExtended::from(raw))
-    }
-
-    /// Identifies the configured speed of the memory
-    /// device, in megatransfers per second (MT/s). See
-    /// 7.18.4 for details.
-    /// 0000h = the speed is unknown
-    /// FFFFh = the speed is 65,535 MT/s or greater,
-    /// and the actual speed is stored in the Extended
-    /// Configured Memory Speed field
-    pub fn configured_memory_speed(&self) -> Option<MemorySpeed> {
-        self.parts
-            .get_field_word(0x20)
-            .map(|raw| MemorySpeed::from(raw))
-    }
-
-    /// Minimum operating voltage for this device, in
-    /// millivolts
-    /// If the value is 0, the voltage is unknown.
-    pub fn minimum_voltage(&self) -> Option<u16> {
-        self.parts.get_field_word(0x22)
-    }
-
-    /// Maximum operating voltage for this device, in
-    /// millivolts
-    /// If the value is 0, the voltage is unknown.
-    pub fn maximum_voltage(&self) -> Option<u16> {
-        self.parts.get_field_word(0x24)
-    }
-
-    /// Configured voltage for this device, in millivolts
-    /// If the value is 0, the voltage is unknown.
-    pub fn configured_voltage(&self) -> Option<u16> {
-        self.parts.get_field_word(0x26)
-    }
-
-    /// Memory technology type for this memory device.
-    pub fn memory_technology(&self) -> Option<MemoryDeviceTechnologyData> {
-        self.parts
-            .get_field_byte(0x28)
-            .map(|raw| MemoryDeviceTechnologyData::from(raw))
-    }
-
-    /// The operating modes supported by this memory device.
-    pub fn memory_operating_mode_capability(&self) -> Option<MemoryOperatingModeCapabilities> {
-        self.parts
-            .get_field_word(0x29)
-            .map(|raw| MemoryOperatingModeCapabilities::from(raw))
-    }
-
-    /// The firmware version of this memory device.
-    pub fn firmware_version(&self) -> Option<String> {
-        self.parts.get_field_string(0x2B)
-    }
-
-    /// The two-byte module manufacturer ID found in the SPD of this memory device; LSB first.
-    pub fn module_manufacturer_id(&self) -> Option<u16> {
-        self.parts.get_field_word(0x2C)
-    }
-
-    /// The two-byte module product ID found in the SPD of this memory device; LSB first.
-    pub fn module_product_id(&self) -> Option<u16> {
-        self.parts.get_field_word(0x2E)
-    }
-
-    /// The two-byte memory subsystem controller manufacturer ID found in the SPD of this memory device; LSB first.
-    pub fn memory_subsystem_controller_manufacturer_id(&self) -> Option<u16> {
-        self.parts.get_field_word(0x30)
-    }
-
-    /// The two-byte memory subsystem controller product ID found in the SPD of this memory device; LSB first.
-    pub fn memory_subsystem_controller_product_id(&self) -> Option<u16> {
-        self.parts.get_field_word(0x32)
-    }
-
-    /// Size of the Non-volatile portion of the memory
-    /// device in Bytes, if any.
+    field_accessors! {
+        /// Handle, or instance number, associated with the
+        /// [super::SMBiosPhysicalMemoryArray] to which this device belongs
+        physical_memory_array_handle, physical_memory_array_handle_access: handle @ 0x04;
+
+        /// Handle, or instance number, associated with any
+        /// error that was previously detected for the device
+        /// If the system does not provide the error information
+        /// structure, the field contains FFFEh; otherwise, the
+        /// field contains either FFFFh (if no error was
+        /// detected) or the handle of the error-information
+        /// structure ([super::SMBiosMemoryErrorInformation32] or
+        /// [super::SMBiosMemoryErrorInformation64]).
+        memory_error_information_handle, memory_error_information_handle_access: handle @ 0x06;
+
+        /// Total width, in bits, of this memory device, including
+        /// any check or error-correction bits
+        /// If there are no error-correction bits, this value
+        /// should be equal to Data Width. If the width is
+        /// unknown, the field is set to FFFFh.
+        total_width, total_width_access: word @ 0x08;
+
+        /// Data width, in bits, of this memory device
+        /// A Data Width of 0 and a Total Width of 8 indicates
+        /// that the device is being used solely to provide 8
+        /// error-correction bits. If the width is unknown, the
+        /// field is set to FFFFh.
+        data_width, data_width_access: word @ 0x0A;
+
+        /// Size of the memory device
+        size, size_access: word @ 0x0C => MemorySize;
+
+        /// Implementation form factor for this memory device
+        form_factor, form_factor_access: byte @ 0x0E => MemoryFormFactorData;
+
+        /// Identifies when the Memory Device is one of a set
+        /// of Memory Devices that must be populated with all
+        /// devices of the same type and size, and the set to
+        /// which this device belongs
+        /// A value of 0 indicates that the device is not part of a
+        /// set; a value of FFh indicates that the attribute is
+        /// unknown.
+        /// NOTE: A Device Set number must be unique within the
+        /// context of the Memory Array containing this Memory
+        /// Device.
+        device_set, device_set_access: byte @ 0x0F;
+
+        /// Identifies the physically-labeled socket or board position where
+        /// the memory device is located
+        /// EXAMPLE: “SIMM 3”
+        device_locator, device_locator_access: string @ 0x10;
+
+        /// Identifies the physically labeled bank where the memory device is located
+        /// EXAMPLE: “Bank 0” or “A”
+        bank_locator, bank_locator_access: string @ 0x11;
+
+        /// Type of memory used in this device
+        memory_type, memory_type_access: byte @ 0x12 => MemoryDeviceTypeData;
+
+        /// Additional detail on the memory device type
+        type_detail, type_detail_access: word @ 0x13 => MemoryTypeDetails;
+
+        /// The maximum capable speed of the
+        /// device, in megatransfers per second (MT/s).
+        speed, speed_access: word @ 0x15 => MemorySpeed;
+
+        /// The manufacturer of this memory device
+        manufacturer, manufacturer_access: string @ 0x17;
+
+        /// The serial number of this memory device.
+        /// This value is set by the manufacturer and normally
+        /// is not changeable.
+        serial_number, serial_number_access: string @ 0x18;
+
+        /// The asset tag of this memory device
+        asset_tag, asset_tag_access: string @ 0x19;
+
+        /// The part number of this memory device.
+        /// This value is set by the manufacturer and normally
+        /// is not changeable.
+        part_number, part_number_access: string @ 0x1A;
+
+        /// Bits 7-4: reserved
+        /// Bits 3-0: rank
+        /// Value=0 for unknown rank information
+        attributes, attributes_access: byte @ 0x1B;
+
+        /// Extended size of the memory device in MB (complements
+        /// the Size field at offset 0Ch)
+        extended_size, extended_size_access: dword @ 0x1C => MemorySizeExtended;
+
+        /// Identifies the configured speed of the memory
+        /// device, in megatransfers per second (MT/s). See
+        /// 7.18.4 for details.
+        /// 0000h = the speed is unknown
+        /// FFFFh = the speed is 65,535 MT/s or greater,
+        /// and the actual speed is stored in the Extended
+        /// Configured Memory Speed field
+        configured_memory_speed, configured_memory_speed_access: word @ 0x20 => MemorySpeed;
+
+        /// Minimum operating voltage for this device, in
+        /// millivolts
+        /// If the value is 0, the voltage is unknown.
+        minimum_voltage, minimum_voltage_access: word @ 0x22;
+
+        /// Maximum operating voltage for this device, in
+        /// millivolts
+        /// If the value is 0, the voltage is unknown.
+        maximum_voltage, maximum_voltage_access: word @ 0x24;
+
+        /// Configured voltage for this device, in millivolts
+        /// If the value is 0, the voltage is unknown.
+        configured_voltage, configured_voltage_access: word @ 0x26;
+
+        /// Memory technology type for this memory device.
+        memory_technology, memory_technology_access: byte @ 0x28 => MemoryDeviceTechnologyData;
+
+        /// The operating modes supported by this memory device.
+        memory_operating_mode_capability, memory_operating_mode_capability_access: word @ 0x29
+            => MemoryOperatingModeCapabilities;
+
+        /// The firmware version of this memory device.
+        firmware_version, firmware_version_access: string @ 0x2B;
+
+        /// The two-byte module manufacturer ID found in the SPD of this memory device; LSB first.
+        module_manufacturer_id, module_manufacturer_id_access: word @ 0x2C;
+
+        /// The two-byte module product ID found in the SPD of this memory device; LSB first.
+        module_product_id, module_product_id_access: word @ 0x2E;
+
+        /// The two-byte memory subsystem controller manufacturer ID found in the SPD of this memory device; LSB first.
+        memory_subsystem_controller_manufacturer_id,
+        memory_subsystem_controller_manufacturer_id_access: word @ 0x30;
+
+        /// The two-byte memory subsystem controller product ID found in the SPD of this memory device; LSB first.
+        memory_subsystem_controller_product_id,
+        memory_subsystem_controller_product_id_access: word @ 0x32;
+
+        /// Size of the Non-volatile portion of the memory
+        /// device in Bytes, if any.
+        ///
+        /// If the value is 0, there is no
+        /// non-volatile portion.
+        non_volatile_size, non_volatile_size_access: qword @ 0x34 => MemoryIndicatedSize;
+
+        /// Size of the Volatile portion of the memory device in
+        /// Bytes, if any.
+        ///
+        /// If the value is 0, there is no Volatile
+        /// portion.
+        volatile_size, volatile_size_access: qword @ 0x3C => MemoryIndicatedSize;
+
+        /// Size of the Cache portion of the memory device in
+        /// Bytes, if any.
+        ///
+        /// If the value is 0, there is no Cache
+        /// portion.
+        cache_size, cache_size_access: qword @ 0x44 => MemoryIndicatedSize;
+
+        /// Size of the Logical memory device in Bytes.
+        logical_size, logical_size_access: qword @ 0x4C => MemoryIndicatedSize;
+
+        /// Extended speed of the memory device
+        /// (complements the Speed field at offset 15h).
+        /// Identifies the maximum capable speed of the
+        /// device, in megatransfers per second (MT/s).
+        extended_speed, extended_speed_access: dword @ 0x54 => MemorySpeedExtended;
+
+        /// Extended configured memory speed of the memory
+        /// device (complements the 'configured_memory_speed'
+        /// field at offset 20h).
+        ///
+        /// Identifies the configured
+        /// speed of the memory device, in megatransfers per
+        /// second (MT/s)
+        ///
+        /// The 'extended_speed' and 'extended_configured_memory_speed' fields are intended to represent
+        /// memory devices that operate faster than 65,535 MT/s, which cannot be described using the
+        /// Speed or Configured Memory Speed fields. These fields are only meaningful if the value
+        /// in the Speed or Configured Memory Speed fields are FFFFh. For compatibility with older
+        /// SMBIOS parsers, memory devices slower than 65,535 MT/s should represent their speed
+        /// using the Speed and Configured Memory Speed fields, leaving the Extended Speed and
+        /// Extended Configured Memory Speed fields set to 0.
+        ///
+        /// Bit 31 is reserved for future use and must be set to 0
+        /// Bits 30:0 represent the speed or configured memory speed of the device in MT/s.
+        extended_configured_memory_speed, extended_configured_memory_speed_access: dword @ 0x58
+            => MemorySpeedExtended;
+
+        /// The two-byte PMIC0 manufacturer ID found in the SPD of this memory device; LSB first.
+        pmic0_manufacturer_id, pmic0_manufacturer_id_access: word @ 0x5C;
+
+        /// The PMIC0 revision number found in the SPD of this memory device.
+        pmic0_revision_number, pmic0_revision_number_access: word @ 0x5E;
+
+        /// The two-byte RCD manufacturer ID found in the SPD of this memory device; LSB first.
+        rcd_manufacturer_id, rcd_manufacturer_id_access: word @ 0x60;
+
+        /// The RCD revision number found in the SPD of this memory device.
+        rcd_revision_number, rcd_revision_number_access: word @ 0x62;
+    }
+
+    /// Maximum capable speed, taken from 'extended_speed' when 'speed' is
+    /// [MemorySpeed::SeeExtendedSpeed]
     ///
-    /// If the value is 0, there is no
-    /// non-volatile portion.
-    pub fn non_volatile_size(&self) -> Option<MemoryIndicatedSize> {
-        self.parts
-            .get_field_qword(0x34)
-            .map(|raw| MemoryIndicatedSize::from(raw))
+    /// Returns None if 'speed' is absent.
+    pub fn effective_speed(&self) -> Option<MaybeValue<MegatransferSpeed>> {
+        Some(effective_memory_speed(
+            self.speed()?,
+            self.parts.get_field_dword(0x54),
+        ))
     }
 
-    /// Size of the Volatile portion of the memory device in
-    /// Bytes, if any.
+    /// Configured speed, taken from 'extended_configured_memory_speed' when
+    /// 'configured_memory_speed' is [MemorySpeed::SeeExtendedSpeed]
     ///
-    /// If the value is 0, there is no Volatile
-    /// portion.
-    pub fn volatile_size(&self) -> Option<MemoryIndicatedSize> {
-        self.parts
-            .get_field_qword(0x3C)
-            .map(|raw| MemoryIndicatedSize::from(raw))
+    /// Returns None if 'configured_memory_speed' is absent.
+    pub fn effective_configured_memory_speed(&self) -> Option<MaybeValue<MegatransferSpeed>> {
+        Some(effective_memory_speed(
+            self.configured_memory_speed()?,
+            self.parts.get_field_dword(0x58),
+        ))
     }
 
-    /// Size of the Cache portion of the memory device in
-    /// Bytes, if any.
+    /// Size of the memory device, taken from 'extended_size' when 'size'
+    /// is [MemorySize::SeeExtendedSize]
     ///
-    /// If the value is 0, there is no Cache
-    /// portion.
-    pub fn cache_size(&self) -> Option<MemoryIndicatedSize> {
-        self.parts
-            .get_field_qword(0x44)
-            .map(|raw| MemoryIndicatedSize::from(raw))
-    }
-
-    /// Size of the Logical memory device in Bytes.
-    pub fn logical_size(&self) -> Option<MemoryIndicatedSize> {
-        self.parts
-            .get_field_qword(0x4C)
-            .map(|raw| MemoryIndicatedSize::from(raw))
-    }
-
-    /// Extended speed of the memory device
-    /// (complements the Speed field at offset 15h).
-    /// Identifies the maximum capable speed of the
-    /// device, in megatransfers per second (MT/s).
-    pub fn extended_speed(&self) -> Option<MemorySpeedExtended> {
-        self.parts
-            .get_field_dword(0x54)
-            .map(|raw| MemorySpeedExtended::from(raw))
+    /// The size is [MaybeValue::NotProvided] when no memory device is
+    /// installed in the socket, [MaybeValue::Unknown] when 'size' is
+    /// unknown or defers to an 'extended_size' which is absent or zero, and
+    /// [MaybeValue::NotPresentInVersion] when 'size' is absent.
+    pub fn effective_size(&self) -> MaybeValue<MemoryCapacity> {
+        let size = match self.size() {
+            Some(size) => size,
+            None => return MaybeValue::NotPresentInVersion,
+        };
+
+        match size {
+            MemorySize::NotInstalled => MaybeValue::NotProvided,
+            MemorySize::Unknown => MaybeValue::Unknown,
+            MemorySize::Kilobytes(size) => MaybeValue::Value(u64::from(size) << 10),
+            MemorySize::Megabytes(size) => MaybeValue::Value(u64::from(size) << 20),
+            MemorySize::SeeExtendedSize => match self.extended_size() {
+                Some(MemorySizeExtended::Megabytes(size)) => {
+                    MaybeValue::Value(u64::from(size) << 20)
+                }
+                _ => MaybeValue::Unknown,
+            },
+        }
+        .map(MemoryCapacity::new)
     }
+}
 
-    /// Extended configured memory speed of the memory
-    /// device (complements the 'configured_memory_speed'
-    /// field at offset 20h).
-    ///
-    /// Identifies the configured
-    /// speed of the memory device, in megatransfers per
-    /// second (MT/s)
-    ///
-    /// The 'extended_speed' and 'extended_configured_memory_speed' fields are intended to represent
-    /// memory devices that operate faster than 65,535 MT/s, which cannot be described using the
-    /// Speed or Configured Memory Speed fields. These fields are only meaningful if the value
-    /// in the Speed or Configured Memory Speed fields are FFFFh. For compatibility with older
-    /// SMBIOS parsers, memory devices slower than 65,535 MT/s should represent their speed
-    /// using the Speed and Configured Memory Speed fields, leaving the Extended Speed and
-    /// Extended Configured Memory Speed fields set to 0.
-    ///
-    /// Bit 31 is reserved for future use and must be set to 0
-    /// Bits 30:0 represent the speed or configured memory speed of the device in MT/s.
-    pub fn extended_configured_memory_speed(&self) -> Option<MemorySpeedExtended> {
-        self.parts
-            .get_field_dword(0x58)
-            .map(|raw| MemorySpeedExtended::from(raw))
+/// Merges a speed with the raw value of its extended speed, whose bit 31 is
+/// reserved
+fn effective_memory_speed(
+    speed: MemorySpeed,
+    extended_speed: Option<u32>,
+) -> MaybeValue<MegatransferSpeed> {
+    match speed {
+        MemorySpeed::MTs(speed) => MaybeValue::Value(MegatransferSpeed::new(u32::from(speed))),
+        MemorySpeed::Unknown => MaybeValue::Unknown,
+        MemorySpeed::SeeExtendedSpeed => match extended_speed {
+            Some(raw) if raw & 0x8000_0000 != 0 => MaybeValue::Reserved,
+            Some(raw) if raw != 0 => MaybeValue::Value(MegatransferSpeed::new(raw)),
+            _ => MaybeValue::Unknown,
+        },
     }
 }
 
@@ -372,6 +359,10 @@ impl fmt::Debug for SMBiosMemoryDevice<'_> {
                 "extended_configured_memory_speed",
                 &self.extended_configured_memory_speed(),
             )
+            .field("pmic0_manufacturer_id", &self.pmic0_manufacturer_id())
+            .field("pmic0_revision_number", &self.pmic0_revision_number())
+            .field("rcd_manufacturer_id", &self.rcd_manufacturer_id())
+            .field("rcd_revision_number", &self.rcd_revision_number())
             .finish()
     }
 }
@@ -381,7 +372,7 @@ impl Serialize for SMBiosMemoryDevice<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosMemoryDevice", 36)?;
+        let mut state = serializer.serialize_struct("SMBiosMemoryDevice", 40)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field(
             "physical_memory_array_handle",
@@ -436,6 +427,10 @@ impl Serialize for SMBiosMemoryDevice<'_> {
             "extended_configured_memory_speed",
             &self.extended_configured_memory_speed(),
         )?;
+        state.serialize_field("pmic0_manufacturer_id", &self.pmic0_manufacturer_id())?;
+        state.serialize_field("pmic0_revision_number", &self.pmic0_revision_number())?;
+        state.serialize_field("rcd_manufacturer_id", &self.rcd_manufacturer_id())?;
+        state.serialize_field("rcd_revision_number", &self.rcd_revision_number())?;
         state.end()
     }
 }
@@ -559,6 +554,8 @@ pub enum MemoryDeviceType {
     Ddr5,
     /// LPDDR5
     Lpddr5,
+    /// HBM3 (High Bandwidth Memory Generation 3)
+    Hbm3,
     /// A value unknown to this standard, check the raw value
     None,
 }
@@ -599,6 +596,7 @@ impl From<u8> for MemoryDeviceTypeData {
                 0x21 => MemoryDeviceType::Hbm2,
                 0x22 => MemoryDeviceType::Ddr5,
                 0x23 => MemoryDeviceType::Lpddr5,
+                0x24 => MemoryDeviceType::Hbm3,
                 _ => MemoryDeviceType::None,
             },
             raw,
@@ -683,6 +681,8 @@ pub enum MemoryFormFactor {
     Fbdimm,
     /// Die
     Die,
+    /// CAMM (Compression Attached Memory Module)
+    Camm,
     /// A value unknown to this standard, check the raw value
     None,
 }
@@ -707,6 +707,7 @@ impl From<u8> for MemoryFormFactorData {
                 0x0E => MemoryFormFactor::Srimm,
                 0x0F => MemoryFormFactor::Fbdimm,
                 0x10 => MemoryFormFactor::Die,
+                0x11 => MemoryFormFactor::Camm,
                 _ => MemoryFormFactor::None,
             },
             raw,
@@ -1032,7 +1033,7 @@ impl Serialize for MemoryOperatingModeCapabilities {
 }
 
 /// # Speed of Memory
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum MemorySpeed {
     /// Memory Speed is Unknown
     Unknown,
@@ -1160,6 +1161,18 @@ impl From<u64> for MemoryIndicatedSize {
     }
 }
 
+/// A size of 0, the memory device having no such portion, is
+/// [MaybeValue::NotProvided]
+impl From<MemoryIndicatedSize> for MaybeValue<u64> {
+    fn from(size: MemoryIndicatedSize) -> Self {
+        match size {
+            MemoryIndicatedSize::Bytes(0) => MaybeValue::NotProvided,
+            MemoryIndicatedSize::Bytes(bytes) => MaybeValue::Value(bytes),
+            MemoryIndicatedSize::Unknown => MaybeValue::Unknown,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1177,7 +1190,8 @@ mod tests {
         // 22h for version 2.7,
         // 28h for version 2.8,
         // 54h for version 3.2,
-        // 5Ch for version 3.3 and later
+        // 5Ch for version 3.3,
+        // 64h for version 3.7 and later
 
         // Memory Device structure version 2.8 (does not contain _memory_technology()_ field and beyond)
         let struct_type17 = vec![
@@ -1310,6 +1324,18 @@ mod tests {
             MemoryIndicatedSize::Unknown => (),
             MemoryIndicatedSize::Bytes(_) => panic!("expected unknown"),
         }
+        assert_eq!(
+            MaybeValue::from(test_struct.logical_size().unwrap()),
+            MaybeValue::Unknown
+        );
+        assert_eq!(
+            MaybeValue::from(MemoryIndicatedSize::from(0)),
+            MaybeValue::NotProvided
+        );
+        assert_eq!(
+            MaybeValue::from(MemoryIndicatedSize::from(8 << 30)),
+            MaybeValue::Value(8 << 30)
+        );
         assert_eq!(
             test_struct.extended_speed(),
             Some(MemorySpeedExtended::MTs(0x01020304))
@@ -1318,5 +1344,98 @@ mod tests {
             test_struct.extended_configured_memory_speed(),
             Some(MemorySpeedExtended::MTs(0x05060708))
         );
+        assert_eq!(
+            test_struct.effective_speed(),
+            Some(MaybeValue::Value(MegatransferSpeed::new(2666)))
+        );
+
+        // speeds of 65,535 MT/s or greater are held by the extended fields
+        let mut extended_speeds = struct_type17.clone();
+        extended_speeds[0x15..0x17].copy_from_slice(&[0xFF, 0xFF]);
+        extended_speeds[0x20..0x22].copy_from_slice(&[0xFF, 0xFF]);
+        extended_speeds[0x5B] |= 0x80; // reserved bit 31
+        let parts = UndefinedStruct::new(&extended_speeds);
+        let extended_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(
+            extended_struct.effective_speed(),
+            Some(MaybeValue::Value(MegatransferSpeed::new(0x01020304)))
+        );
+        assert_eq!(
+            extended_struct.effective_configured_memory_speed(),
+            Some(MaybeValue::Reserved)
+        );
+
+        let size = test_struct.effective_size();
+        assert_eq!(size.as_mebibytes(), Some(8192));
+        assert!(size.is_installed() && !size.is_unknown());
+
+        // sizes of 32 GB - 1 MB or greater are held by the extended field
+        let mut sizes = struct_type17.clone();
+        sizes[0x0C..0x0E].copy_from_slice(&[0xFF, 0x7F]);
+        let parts = UndefinedStruct::new(&sizes);
+        let size = SMBiosMemoryDevice::new(&parts).effective_size();
+        assert!(size.is_installed() && size.is_unknown());
+        sizes[0x1C..0x20].copy_from_slice(&0x8001_0000u32.to_le_bytes()); // reserved bit 31
+        let parts = UndefinedStruct::new(&sizes);
+        assert_eq!(
+            SMBiosMemoryDevice::new(&parts).effective_size(),
+            MaybeValue::Value(MemoryCapacity::new(64 << 30))
+        );
+        sizes[0x0C..0x0E].copy_from_slice(&[0x00, 0x81]); // 256 KB
+        let parts = UndefinedStruct::new(&sizes);
+        let size = SMBiosMemoryDevice::new(&parts).effective_size();
+        assert_eq!(size.as_bytes(), Some(256 << 10));
+        assert_eq!(size.as_mebibytes(), Some(0));
+        sizes[0x0C..0x0E].copy_from_slice(&[0x00, 0x00]);
+        let parts = UndefinedStruct::new(&sizes);
+        let size = SMBiosMemoryDevice::new(&parts).effective_size();
+        assert_eq!(size, MaybeValue::NotProvided);
+        assert!(!size.is_installed() && size.as_bytes().is_none());
+
+        // a structure too short to hold the size
+        let parts = UndefinedStruct::new(&sizes[..0x0C].to_vec());
+        assert_eq!(
+            SMBiosMemoryDevice::new(&parts).effective_size(),
+            MaybeValue::NotPresentInVersion
+        );
+
+        // 3.3 structure does not contain the PMIC0 and RCD fields
+        assert!(test_struct.pmic0_manufacturer_id().is_none());
+        assert!(test_struct.rcd_revision_number().is_none());
+
+        // 3.7 structure
+        let mut struct_type17 = struct_type17[..0x5C].to_vec();
+        struct_type17[0x01] = 0x64;
+        struct_type17.extend_from_slice(&[
+            0x8A, 0x4C, // pmic0_manufacturer_id
+            0x12, 0x00, // pmic0_revision_number
+            0x86, 0x32, // rcd_manufacturer_id
+            0xB1, 0x00, // rcd_revision_number
+            0x00, 0x00,
+        ]);
+
+        let parts = UndefinedStruct::new(&struct_type17);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+
+        assert_eq!(test_struct.pmic0_manufacturer_id(), Some(0x4C8A));
+        assert_eq!(test_struct.pmic0_revision_number(), Some(0x0012));
+        assert_eq!(test_struct.rcd_manufacturer_id(), Some(0x3286));
+        assert_eq!(test_struct.rcd_revision_number(), Some(0x00B1));
+    }
+
+    #[test]
+    fn test_memory_type_and_form_factor() {
+        assert_eq!(*MemoryDeviceTypeData::from(0x22), MemoryDeviceType::Ddr5);
+        assert_eq!(*MemoryDeviceTypeData::from(0x23), MemoryDeviceType::Lpddr5);
+        assert_eq!(*MemoryDeviceTypeData::from(0x24), MemoryDeviceType::Hbm3);
+        assert_eq!(*MemoryDeviceTypeData::from(0x25), MemoryDeviceType::None);
+        assert_eq!(*MemoryFormFactorData::from(0x10), MemoryFormFactor::Die);
+        assert_eq!(*MemoryFormFactorData::from(0x11), MemoryFormFactor::Camm);
+        assert_eq!(*MemoryFormFactorData::from(0x12), MemoryFormFactor::None);
+
+        let type_detail = MemoryTypeDetails::from(0x2080);
+        assert!(type_detail.synchronous());
+        assert!(type_detail.registered());
+        assert!(!type_detail.unbuffered());
     }
 }
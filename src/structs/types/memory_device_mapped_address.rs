@@ -1,7 +1,7 @@
 use crate::core::{Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{MaybeValue, MemoryCapacity, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use core::{fmt, any};
+use core::{fmt, any, ops::RangeInclusive};
 
 /// # Memory Device Mapped Address (Type 20)
 ///
@@ -27,109 +27,126 @@ impl<'a> SMBiosStruct<'a> for SMBiosMemoryDeviceMappedAddress<'a> {
 }
 
 impl<'a> SMBiosMemoryDeviceMappedAddress<'a> {
-    /// Physical address, in kilobytes, of a range of
-    /// memory mapped to the referenced [super::SMBiosMemoryDevice]
-    /// When the field value is FFFF FFFFh the actual
-    /// address is stored in the Extended Starting
-    /// Address field. When this field contains a valid
-    /// address, Ending Address must also contain a
-    /// valid address. When this field contains FFFF
-    /// FFFFh, Ending Address must also contain FFFF
-    /// FFFFh.
-    pub fn starting_address(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x4)
+    field_accessors! {
+        /// Physical address, in kilobytes, of a range of
+        /// memory mapped to the referenced [super::SMBiosMemoryDevice]
+        /// When the field value is FFFF FFFFh the actual
+        /// address is stored in the Extended Starting
+        /// Address field. When this field contains a valid
+        /// address, Ending Address must also contain a
+        /// valid address. When this field contains FFFF
+        /// FFFFh, Ending Address must also contain FFFF
+        /// FFFFh.
+        starting_address, starting_address_access: dword @ 0x4;
+
+        /// Physical ending address of the last kilobyte of a
+        /// range of addresses mapped to the referenced
+        /// [super::SMBiosMemoryDevice]
+        /// When the field value is FFFF FFFFh the actual
+        /// address is stored in the Extended Ending Address
+        /// field. When this field contains a valid address,
+        /// Starting Address must also contain a valid
+        /// address.
+        ending_address, ending_address_access: dword @ 0x8;
+
+        /// Handle, or instance number, associated with the
+        /// [super::SMBiosMemoryDevice] structure to which this address
+        /// range is mapped
+        /// Multiple address ranges can be mapped to a
+        /// single [super::SMBiosMemoryDevice]
+        memory_device_handle, memory_device_handle_access: handle @ 0xC;
+
+        /// Handle, or instance number, associated with the
+        /// Memory Array Mapped Address structure to which
+        /// this device address range is mapped
+        /// Multiple address ranges can be mapped to a
+        /// single [super::SMBiosMemoryArrayMappedAddress].
+        memory_array_mapped_address_handle, memory_array_mapped_address_handle_access: handle @ 0xE;
+
+        /// Position of the referenced [super::SMBiosMemoryDevice] in a row
+        /// of the address partition
+        /// For example, if two 8-bit devices form a 16-bit row,
+        /// this field’s value is either 1 or 2.
+        /// The value 0 is reserved. If the position is
+        /// unknown, the field contains FFh.
+        partition_row_position, partition_row_position_access: byte @ 0x10;
+
+        /// Position of the referenced [super::SMBiosMemoryDevice] in an
+        /// interleave
+        /// The value 0 indicates non-interleaved, 1 indicates
+        /// first interleave position, 2 the second interleave
+        /// position, and so on. If the position is unknown, the
+        /// field contains FFh.
+        /// EXAMPLES: In a 2:1 interleave, the value 1 indicates
+        /// the device in the ”even” position. In a 4:1 interleave, the
+        /// value 1 indicates the first of four possible positions.
+        interleave_position, interleave_position_access: byte @ 0x11;
+
+        /// Maximum number of consecutive rows from the
+        /// referenced [super::SMBiosMemoryDevice] that are accessed in a
+        /// single interleaved transfer
+        /// If the device is not part of an interleave, the field
+        /// contains 0; if the interleave configuration is
+        /// unknown, the value is FFh.
+        /// EXAMPLES: If a device transfers two rows each time it
+        /// is read, its Interleaved Data Depth is set to 2. If that
+        /// device is 2:1 interleaved and in Interleave Position 1, the
+        /// rows mapped to that device are 1, 2, 5, 6, 9, 10, etc.
+        interleaved_data_depth, interleaved_data_depth_access: byte @ 0x12;
+
+        /// Physical address, in bytes, of a range of memory
+        /// mapped to the referenced [super::SMBiosMemoryDevice]
+        /// This field is valid when Starting Address contains
+        /// the value FFFF FFFFh. If Starting Address
+        /// contains a value other than FFFF FFFFh, this field
+        /// contains zeros. When this field contains a valid
+        /// address, Extended Ending Address must also
+        /// contain a valid address.
+        extended_starting_address, extended_starting_address_access: qword @ 0x13;
+
+        /// Physical ending address, in bytes, of the last of a
+        /// range of addresses mapped to the referenced
+        /// [super::SMBiosMemoryDevice]
+        /// This field is valid when both Starting Address and
+        /// Ending Address contain the value FFFF FFFFh. If
+        /// Ending Address contains a value other than FFFF
+        /// FFFFh, this field contains zeros. When this field
+        /// contains a valid address, Extended Starting
+        /// Address must also contain a valid address
+        extended_ending_address, extended_ending_address_access: qword @ 0x1B;
     }
 
-    /// Physical ending address of the last kilobyte of a
-    /// range of addresses mapped to the referenced
-    /// [super::SMBiosMemoryDevice]
-    /// When the field value is FFFF FFFFh the actual
-    /// address is stored in the Extended Ending Address
-    /// field. When this field contains a valid address,
-    /// Starting Address must also contain a valid
-    /// address.
-    pub fn ending_address(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x8)
+    /// Range of physical addresses, in bytes, mapped to the Memory Device
+    ///
+    /// Reads the extended addresses when Starting Address and Ending
+    /// Address both contain FFFF FFFFh, otherwise converts the kilobyte
+    /// addresses to bytes.
+    pub fn address_range(&self) -> Option<RangeInclusive<u64>> {
+        match (self.starting_address()?, self.ending_address()?) {
+            (0xFFFF_FFFF, 0xFFFF_FFFF) => {
+                Some(self.extended_starting_address()?..=self.extended_ending_address()?)
+            }
+            (start, end) => Some(u64::from(start) << 10..=(u64::from(end) << 10 | 0x3FF)),
+        }
     }
 
-    /// Handle, or instance number, associated with the
-    /// [super::SMBiosMemoryDevice] structure to which this address
-    /// range is mapped
-    /// Multiple address ranges can be mapped to a
-    /// single [super::SMBiosMemoryDevice]
-    pub fn memory_device_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0xC)
-    }
-
-    /// Handle, or instance number, associated with the
-    /// Memory Array Mapped Address structure to which
-    /// this device address range is mapped
-    /// Multiple address ranges can be mapped to a
-    /// single [super::SMBiosMemoryArrayMappedAddress].
-    pub fn memory_array_mapped_address_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0xE)
-    }
-
-    /// Position of the referenced [super::SMBiosMemoryDevice] in a row
-    /// of the address partition
-    /// For example, if two 8-bit devices form a 16-bit row,
-    /// this field’s value is either 1 or 2.
-    /// The value 0 is reserved. If the position is
-    /// unknown, the field contains FFh.
-    pub fn partition_row_position(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x10)
-    }
-
-    /// Position of the referenced [super::SMBiosMemoryDevice] in an
-    /// interleave
-    /// The value 0 indicates non-interleaved, 1 indicates
-    /// first interleave position, 2 the second interleave
-    /// position, and so on. If the position is unknown, the
-    /// field contains FFh.
-    /// EXAMPLES: In a 2:1 interleave, the value 1 indicates
-    /// the device in the ”even” position. In a 4:1 interleave, the
-    /// value 1 indicates the first of four possible positions.
-    pub fn interleave_position(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x11)
-    }
-
-    /// Maximum number of consecutive rows from the
-    /// referenced [super::SMBiosMemoryDevice] that are accessed in a
-    /// single interleaved transfer
-    /// If the device is not part of an interleave, the field
-    /// contains 0; if the interleave configuration is
-    /// unknown, the value is FFh.
-    /// EXAMPLES: If a device transfers two rows each time it
-    /// is read, its Interleaved Data Depth is set to 2. If that
-    /// device is 2:1 interleaved and in Interleave Position 1, the
-    /// rows mapped to that device are 1, 2, 5, 6, 9, 10, etc.
-    pub fn interleaved_data_depth(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x12)
-    }
-
-    /// Physical address, in bytes, of a range of memory
-    /// mapped to the referenced [super::SMBiosMemoryDevice]
-    /// This field is valid when Starting Address contains
-    /// the value FFFF FFFFh. If Starting Address
-    /// contains a value other than FFFF FFFFh, this field
-    /// contains zeros. When this field contains a valid
-    /// address, Extended Ending Address must also
-    /// contain a valid address.
-    pub fn extended_starting_address(&self) -> Option<u64> {
-        self.parts.get_field_qword(0x13)
-    }
-
-    /// Physical ending address, in bytes, of the last of a
-    /// range of addresses mapped to the referenced
-    /// [super::SMBiosMemoryDevice]
-    /// This field is valid when both Starting Address and
-    /// Ending Address contain the value FFFF FFFFh. If
-    /// Ending Address contains a value other than FFFF
-    /// FFFFh, this field contains zeros. When this field
-    /// contains a valid address, Extended Starting
-    /// Address must also contain a valid address
-    pub fn extended_ending_address(&self) -> Option<u64> {
-        self.parts.get_field_qword(0x1B)
+    /// Size, in bytes, of the range of physical addresses mapped to the
+    /// Memory Device, see [Self::address_range]
+    ///
+    /// The size is [MaybeValue::Unknown] when the range ends before it
+    /// starts or spans the whole 64-bit address space, and
+    /// [MaybeValue::NotPresentInVersion] when the addresses are absent.
+    pub fn range_size(&self) -> MaybeValue<MemoryCapacity> {
+        let range = match self.address_range() {
+            Some(range) => range,
+            None => return MaybeValue::NotPresentInVersion,
+        };
+        let size = range
+            .end()
+            .checked_sub(*range.start())
+            .and_then(|size| size.checked_add(1))
+            .map(MemoryCapacity::new);
+        size.map_or(MaybeValue::Unknown, MaybeValue::Value)
     }
 }
 
@@ -209,5 +226,16 @@ mod tests {
         assert_eq!(test_struct.interleaved_data_depth(), Some(2));
         assert_eq!(test_struct.extended_starting_address(), Some(0));
         assert_eq!(test_struct.extended_ending_address(), Some(0));
+        assert_eq!(
+            test_struct.range_size(),
+            MaybeValue::Value(MemoryCapacity::new(8 << 30))
+        );
+
+        // a range ending before it starts
+        let mut struct_type20 = struct_type20.clone();
+        struct_type20[0x04..0x08].copy_from_slice(&0x0080_0000u32.to_le_bytes());
+        let parts = UndefinedStruct::new(&struct_type20);
+        let test_struct = SMBiosMemoryDeviceMappedAddress::new(&parts);
+        assert_eq!(test_struct.range_size(), MaybeValue::Unknown);
     }
 }
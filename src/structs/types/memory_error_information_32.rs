@@ -27,60 +27,42 @@ impl<'a> SMBiosStruct<'a> for SMBiosMemoryErrorInformation32<'a> {
 }
 
 impl<'a> SMBiosMemoryErrorInformation32<'a> {
-    /// Type of error that is associated with the current
-    /// status reported for the memory array or device
-    pub fn error_type(&self) -> Option<MemoryErrorTypeData> {
-        self.parts
-            .get_field_byte(0x04)
-            .map(|raw| MemoryErrorTypeData::from(raw))
-    }
-
-    /// Granularity (for example, device versus Partition)
-    /// to which the error can be resolved
-    pub fn error_granularity(&self) -> Option<MemoryErrorGranularityData> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| MemoryErrorGranularityData::from(raw))
-    }
-
-    /// Memory access operation that caused the error
-    pub fn error_operation(&self) -> Option<MemoryErrorOperationData> {
-        self.parts
-            .get_field_byte(0x06)
-            .map(|raw| MemoryErrorOperationData::from(raw))
-    }
-
-    /// Vendor-specific ECC syndrome or CRC data
-    /// associated with the erroneous access
-    /// If the value is unknown, this field contains 0000
-    /// 0000h.
-    pub fn vendor_syndrome(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x07)
-    }
-
-    /// 32-bit physical address of the error based on the
-    /// addressing of the bus to which the memory array
-    /// is connected
-    /// If the address is unknown, this field contains
-    /// 8000 0000h.
-    pub fn memory_array_error_address(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x0B)
-    }
-
-    /// 32-bit physical address of the error relative to the
-    /// start of the failing memory device, in bytes
-    /// If the address is unknown, this field contains
-    /// 8000 0000h.
-    pub fn device_error_address(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x0F)
-    }
-
-    /// Range, in bytes, within which the error can be
-    /// determined, when an error address is given
-    /// If the range is unknown, this field contains 8000
-    /// 0000h.
-    pub fn error_resolution(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x13)
+    field_accessors! {
+        /// Type of error that is associated with the current
+        /// status reported for the memory array or device
+        error_type, error_type_access: byte @ 0x04 => MemoryErrorTypeData;
+
+        /// Granularity (for example, device versus Partition)
+        /// to which the error can be resolved
+        error_granularity, error_granularity_access: byte @ 0x05 => MemoryErrorGranularityData;
+
+        /// Memory access operation that caused the error
+        error_operation, error_operation_access: byte @ 0x06 => MemoryErrorOperationData;
+
+        /// Vendor-specific ECC syndrome or CRC data
+        /// associated with the erroneous access
+        /// If the value is unknown, this field contains 0000
+        /// 0000h.
+        vendor_syndrome, vendor_syndrome_access: dword @ 0x07;
+
+        /// 32-bit physical address of the error based on the
+        /// addressing of the bus to which the memory array
+        /// is connected
+        /// If the address is unknown, this field contains
+        /// 8000 0000h.
+        memory_array_error_address, memory_array_error_address_access: dword @ 0x0B;
+
+        /// 32-bit physical address of the error relative to the
+        /// start of the failing memory device, in bytes
+        /// If the address is unknown, this field contains
+        /// 8000 0000h.
+        device_error_address, device_error_address_access: dword @ 0x0F;
+
+        /// Range, in bytes, within which the error can be
+        /// determined, when an error address is given
+        /// If the range is unknown, this field contains 8000
+        /// 0000h.
+        error_resolution, error_resolution_access: dword @ 0x13;
     }
 }
 
@@ -28,51 +28,37 @@ impl<'a> SMBiosStruct<'a> for SMBiosMemoryModuleInformation<'a> {
 }
 
 impl<'a> SMBiosMemoryModuleInformation<'a> {
-    /// Socket reference designation
-    ///
-    /// EXAMPLE: ‘J202’,0
-    pub fn socket_designation(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
-
-    /// Each nibble indicates a bank (RAS#) connection; 0xF
-    /// means no connection.
-    ///
-    /// EXAMPLE: If banks 1 & 3 (RAS# 1 & 3) were connected to a
-    /// SIMM socket the byte for that socket would be 13h. If only bank 2
-    /// (RAS 2) were connected, the byte for that socket would be 2Fh.
-    pub fn bank_connections(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x05)
-    }
-
-    /// Speed of the memory module, in ns (for example, 70d for
-    /// a 70ns module)
-    ///
-    /// If the speed is unknown, the field is set to 0.
-    pub fn current_speed(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x06)
-    }
-
-    /// Bit field for the current memory type
-    pub fn current_memory_type(&self) -> Option<MemoryTypes> {
-        self.parts
-            .get_field_word(0x07)
-            .map(|raw| MemoryTypes::from(raw))
-    }
-
-    /// Installed size
-    pub fn installed_size(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x09)
-    }
-
-    /// Enabled size
-    pub fn enabled_size(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x0A)
-    }
-
-    /// Bit fields error status
-    pub fn error_status(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x0B)
+    field_accessors! {
+        /// Socket reference designation
+        ///
+        /// EXAMPLE: ‘J202’,0
+        socket_designation, socket_designation_access: string @ 0x04;
+
+        /// Each nibble indicates a bank (RAS#) connection; 0xF
+        /// means no connection.
+        ///
+        /// EXAMPLE: If banks 1 & 3 (RAS# 1 & 3) were connected to a
+        /// SIMM socket the byte for that socket would be 13h. If only bank 2
+        /// (RAS 2) were connected, the byte for that socket would be 2Fh.
+        bank_connections, bank_connections_access: byte @ 0x05;
+
+        /// Speed of the memory module, in ns (for example, 70d for
+        /// a 70ns module)
+        ///
+        /// If the speed is unknown, the field is set to 0.
+        current_speed, current_speed_access: byte @ 0x06;
+
+        /// Bit field for the current memory type
+        current_memory_type, current_memory_type_access: word @ 0x07 => MemoryTypes;
+
+        /// Installed size
+        installed_size, installed_size_access: byte @ 0x09;
+
+        /// Enabled size
+        enabled_size, enabled_size_access: byte @ 0x0A;
+
+        /// Bit fields error status
+        error_status, error_status_access: byte @ 0x0B;
     }
 }
 
@@ -27,9 +27,9 @@ impl<'a> SMBiosStruct<'a> for SMBiosOemStrings<'a> {
 }
 
 impl<'a> SMBiosOemStrings<'a> {
-    /// Number of strings
-    pub fn count(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x04)
+    field_accessors! {
+        /// Number of strings
+        count, count_access: byte @ 0x04;
     }
 
     /// Iterable collection of OEM strings
@@ -1,4 +1,4 @@
-use crate::{Header, SMBiosStruct, UndefinedStruct};
+use crate::{Header, SMBiosStruct, StructFields, UndefinedStruct};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
 #[cfg(feature = "no_std")]
@@ -37,42 +37,24 @@ impl<'a> SMBiosStruct<'a> for SMBiosOnboardDevicesExtendedInformation<'a> {
 }
 
 impl<'a> SMBiosOnboardDevicesExtendedInformation<'a> {
-    /// The onboard device reference designation
-    pub fn reference_designation(&self) -> Option<String> {
-        self.parts.get_field_string(0x4)
-    }
+    field_accessors! {
+        /// The onboard device reference designation
+        reference_designation, reference_designation_access: string @ 0x4;
 
-    /// Device type bit field and enum
-    pub fn device_type(&self) -> Option<OnBoardDeviceType> {
-        self.parts
-            .get_field_byte(0x5)
-            .map(|raw| OnBoardDeviceType::from(raw))
-    }
+        /// Device type bit field and enum
+        device_type, device_type_access: byte @ 0x5 => OnBoardDeviceType;
 
-    /// Device type instance
-    pub fn device_type_instance(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x6)
-    }
+        /// Device type instance
+        device_type_instance, device_type_instance_access: byte @ 0x6;
 
-    /// Segment group number
-    pub fn segment_group_number(&self) -> Option<SegmentGroupNumber> {
-        self.parts
-            .get_field_word(0x7)
-            .map(|raw| SegmentGroupNumber::from(raw))
-    }
+        /// Segment group number
+        segment_group_number, segment_group_number_access: word @ 0x7 => SegmentGroupNumber;
 
-    /// Bus number
-    pub fn bus_number(&self) -> Option<BusNumber> {
-        self.parts
-            .get_field_byte(0x9)
-            .map(|raw| BusNumber::from(raw))
-    }
+        /// Bus number
+        bus_number, bus_number_access: byte @ 0x9 => BusNumber;
 
-    /// Device/Function number
-    pub fn device_function_number(&self) -> Option<DeviceFunctionNumber> {
-        self.parts
-            .get_field_byte(0xA)
-            .map(|raw| DeviceFunctionNumber::from(raw))
+        /// Device/Function number
+        device_function_number, device_function_number_access: byte @ 0xA => DeviceFunctionNumber;
     }
 }
 
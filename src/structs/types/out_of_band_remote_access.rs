@@ -33,16 +33,12 @@ impl<'a> SMBiosStruct<'a> for SMBiosOutOfBandRemoteAccess<'a> {
 }
 
 impl<'a> SMBiosOutOfBandRemoteAccess<'a> {
-    ///  The manufacturer of the out-of-band access facility
-    pub fn manufacturer_name(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
+    field_accessors! {
+        ///  The manufacturer of the out-of-band access facility
+        manufacturer_name, manufacturer_name_access: string @ 0x04;
 
-    /// Current remote-access connections (bit field)
-    pub fn connections(&self) -> Option<Connections> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| Connections::from(raw))
+        /// Current remote-access connections (bit field)
+        connections, connections_access: byte @ 0x05 => Connections;
     }
 }
 
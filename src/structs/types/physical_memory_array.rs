@@ -1,4 +1,4 @@
-use crate::core::{Handle, UndefinedStruct};
+use crate::core::{Handle, MaybeValue, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, ops::Deref, any};
@@ -26,72 +26,72 @@ impl<'a> SMBiosStruct<'a> for SMBiosPhysicalMemoryArray<'a> {
 }
 
 impl<'a> SMBiosPhysicalMemoryArray<'a> {
-    /// Physical location of the Memory Array, whether on
-    /// the system board or an add-in board
-    pub fn location(&self) -> Option<MemoryArrayLocationData> {
-        self.parts
-            .get_field_byte(0x04)
-            .map(|raw| MemoryArrayLocationData::from(raw))
-    }
-
-    /// Function for which the array is used
-    pub fn usage(&self) -> Option<MemoryArrayUseData> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| MemoryArrayUseData::from(raw))
-    }
-
-    /// Primary hardware error correction or detection
-    /// method supported by this memory array
-    pub fn memory_error_correction(&self) -> Option<MemoryArrayErrorCorrectionData> {
-        self.parts
-            .get_field_byte(0x06)
-            .map(|raw| MemoryArrayErrorCorrectionData::from(raw))
-    }
-
-    /// Maximum memory capacity, in kilobytes, for this array
-    ///
-    /// If the capacity is not represented in this field, then
-    /// the 'extended_maximum_capacity' field should be used.
-    ///
-    /// Values 2 TB (8000 0000h) or greater must be represented
-    /// in the Extended Maximum Capacity field.
-    pub fn maximum_capacity(&self) -> Option<MaximumMemoryCapacity> {
-        self.parts
-            .get_field_dword(0x07)
-            .map(|raw| MaximumMemoryCapacity::from(raw))
-    }
-
-    /// Handle, or instance number, associated with any
-    /// error that was previously detected for the array
-    ///
-    /// If the system does not provide the error
-    /// information structure, the field contains FFFEh;
-    /// otherwise, the field contains either FFFFh (if no
-    /// error was detected) or the handle of the errorinformation structure.
-    pub fn memory_error_information_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x0B)
+    field_accessors! {
+        /// Physical location of the Memory Array, whether on
+        /// the system board or an add-in board
+        location, location_access: byte @ 0x04 => MemoryArrayLocationData;
+
+        /// Function for which the array is used
+        usage, usage_access: byte @ 0x05 => MemoryArrayUseData;
+
+        /// Primary hardware error correction or detection
+        /// method supported by this memory array
+        memory_error_correction, memory_error_correction_access: byte @ 0x06
+            => MemoryArrayErrorCorrectionData;
+
+        /// Maximum memory capacity, in kilobytes, for this array
+        ///
+        /// If the capacity is not represented in this field, then
+        /// the 'extended_maximum_capacity' field should be used.
+        ///
+        /// Values 2 TB (8000 0000h) or greater must be represented
+        /// in the Extended Maximum Capacity field.
+        maximum_capacity, maximum_capacity_access: dword @ 0x07 => MaximumMemoryCapacity;
+
+        /// Handle, or instance number, associated with any
+        /// error that was previously detected for the array
+        ///
+        /// If the system does not provide the error
+        /// information structure, the field contains FFFEh;
+        /// otherwise, the field contains either FFFFh (if no
+        /// error was detected) or the handle of the errorinformation structure.
+        memory_error_information_handle, memory_error_information_handle_access: handle @ 0x0B;
+
+        /// Number of slots or sockets available for [super::SMBiosMemoryDevice]s in this array
+        ///
+        /// This value represents the number of [super::SMBiosMemoryDevice]
+        /// structures that compose this Memory
+        /// Array. Each [super::SMBiosMemoryDevice] has a reference to
+        /// the "owning" Memory Array.
+        number_of_memory_devices, number_of_memory_devices_access: word @ 0x0D;
+
+        /// Maximum memory capacity, in bytes, for this array
+        ///
+        /// This field is only valid when the Maximum
+        /// Capacity field contains 8000 0000h. When
+        /// Maximum Capacity contains a value that is not
+        /// 8000 0000h, Extended Maximum Capacity must
+        /// contain zeros.
+        extended_maximum_capacity, extended_maximum_capacity_access: qword @ 0x0F;
     }
 
-    /// Number of slots or sockets available for [super::SMBiosMemoryDevice]s in this array
+    /// Maximum memory capacity, in bytes, taken from
+    /// 'extended_maximum_capacity' when 'maximum_capacity' is
+    /// [MaximumMemoryCapacity::SeeExtendedMaximumCapacity]
     ///
-    /// This value represents the number of [super::SMBiosMemoryDevice]
-    /// structures that compose this Memory
-    /// Array. Each [super::SMBiosMemoryDevice] has a reference to
-    /// the "owning" Memory Array.
-    pub fn number_of_memory_devices(&self) -> Option<u16> {
-        self.parts.get_field_word(0x0D)
-    }
-
-    /// Maximum memory capacity, in bytes, for this array
-    ///
-    /// This field is only valid when the Maximum
-    /// Capacity field contains 8000 0000h. When
-    /// Maximum Capacity contains a value that is not
-    /// 8000 0000h, Extended Maximum Capacity must
-    /// contain zeros.
-    pub fn extended_maximum_capacity(&self) -> Option<u64> {
-        self.parts.get_field_qword(0x0F)
+    /// Returns None if 'maximum_capacity' is absent.
+    pub fn effective_maximum_capacity(&self) -> Option<MaybeValue<u64>> {
+        Some(match self.maximum_capacity()? {
+            MaximumMemoryCapacity::Kilobytes(capacity) => {
+                MaybeValue::Value(u64::from(capacity) << 10)
+            }
+            MaximumMemoryCapacity::SeeExtendedMaximumCapacity => {
+                match self.extended_maximum_capacity() {
+                    Some(capacity) if capacity != 0 => MaybeValue::Value(capacity),
+                    _ => MaybeValue::Unknown,
+                }
+            }
+        })
     }
 }
 
@@ -458,5 +458,26 @@ mod tests {
         );
         assert_eq!(test_struct.number_of_memory_devices(), Some(4));
         assert_eq!(test_struct.extended_maximum_capacity(), Some(0));
+        assert_eq!(
+            test_struct.effective_maximum_capacity(),
+            Some(MaybeValue::Value(0x6000_0000 << 10))
+        );
+
+        // capacities of 2 TB or greater are held by the extended field
+        let mut struct_type16 = struct_type16.clone();
+        struct_type16[0x07..0x0B].copy_from_slice(&[0x00, 0x00, 0x00, 0x80]);
+        let parts = UndefinedStruct::new(&struct_type16);
+        let test_struct = SMBiosPhysicalMemoryArray::new(&parts);
+        assert_eq!(
+            test_struct.effective_maximum_capacity(),
+            Some(MaybeValue::Unknown)
+        );
+        struct_type16[0x0F..0x17].copy_from_slice(&(4u64 << 40).to_le_bytes());
+        let parts = UndefinedStruct::new(&struct_type16);
+        let test_struct = SMBiosPhysicalMemoryArray::new(&parts);
+        assert_eq!(
+            test_struct.effective_maximum_capacity(),
+            Some(MaybeValue::Value(4 << 40))
+        );
     }
 }
@@ -30,41 +30,29 @@ impl<'a> SMBiosStruct<'a> for SMBiosPortConnectorInformation<'a> {
 }
 
 impl<'a> SMBiosPortConnectorInformation<'a> {
-    ///  Internal reference designator, that is,
-    /// internal to the system enclosure
-    ///
-    /// EXAMPLE: "J101"
-    pub fn internal_reference_designator(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
+    field_accessors! {
+        ///  Internal reference designator, that is,
+        /// internal to the system enclosure
+        ///
+        /// EXAMPLE: "J101"
+        internal_reference_designator, internal_reference_designator_access: string @ 0x04;
 
-    /// Internal connector type
-    pub fn internal_connector_type(&self) -> Option<PortInformationConnectorTypeData> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| PortInformationConnectorTypeData::from(raw))
-    }
+        /// Internal connector type
+        internal_connector_type, internal_connector_type_access: byte @ 0x05
+            => PortInformationConnectorTypeData;
 
-    /// External reference designation,
-    /// external to the system enclosure
-    ///
-    /// EXAMPLE: "COM A"
-    pub fn external_reference_designator(&self) -> Option<String> {
-        self.parts.get_field_string(0x06)
-    }
+        /// External reference designation,
+        /// external to the system enclosure
+        ///
+        /// EXAMPLE: "COM A"
+        external_reference_designator, external_reference_designator_access: string @ 0x06;
 
-    /// External connector type
-    pub fn external_connector_type(&self) -> Option<PortInformationConnectorTypeData> {
-        self.parts
-            .get_field_byte(0x07)
-            .map(|raw| PortInformationConnectorTypeData::from(raw))
-    }
+        /// External connector type
+        external_connector_type, external_connector_type_access: byte @ 0x07
+            => PortInformationConnectorTypeData;
 
-    /// Describes the function of the port
-    pub fn port_type(&self) -> Option<PortInformationPortTypeData> {
-        self.parts
-            .get_field_byte(0x08)
-            .map(|raw| PortInformationPortTypeData::from(raw))
+        /// Describes the function of the port
+        port_type, port_type_access: byte @ 0x08 => PortInformationPortTypeData;
     }
 }
 
@@ -182,7 +170,7 @@ pub enum PortInformationConnectorType {
     /// DB-9 pin male
     DB9PinMale,
     /// DB-9 pin female
-    DB8PinFemale,
+    DB9PinFemale,
     /// RJ-11
     RJ11,
     /// RJ-45
@@ -224,7 +212,7 @@ pub enum PortInformationConnectorType {
     /// Mini-Centronics Type-14
     MiniCentronicsType14,
     /// Mini-Centronics Type-26
-    MiniCentronicsTyp26,
+    MiniCentronicsType26,
     /// Mini-jack (headphones)
     MiniJackHeadphones,
     /// BNC
@@ -240,7 +228,7 @@ pub enum PortInformationConnectorType {
     /// PC-98Hireso
     PC98Hireso,
     /// PC-H98
-    PCH88,
+    PCH98,
     /// PC-98Note
     PC98Note,
     /// PC-98Full
@@ -264,7 +252,7 @@ impl From<u8> for PortInformationConnectorTypeData {
                 0x06 => PortInformationConnectorType::DB15PinMale,
                 0x07 => PortInformationConnectorType::DB15PinFemale,
                 0x08 => PortInformationConnectorType::DB9PinMale,
-                0x09 => PortInformationConnectorType::DB8PinFemale,
+                0x09 => PortInformationConnectorType::DB9PinFemale,
                 0x0A => PortInformationConnectorType::RJ11,
                 0x0B => PortInformationConnectorType::RJ45,
                 0x0C => PortInformationConnectorType::MiniScsi50Pin,
@@ -285,7 +273,7 @@ impl From<u8> for PortInformationConnectorTypeData {
                 0x1B => PortInformationConnectorType::DualInline68Pin,
                 0x1C => PortInformationConnectorType::OnBoardSoundInputCDRom,
                 0x1D => PortInformationConnectorType::MiniCentronicsType14,
-                0x1E => PortInformationConnectorType::MiniCentronicsTyp26,
+                0x1E => PortInformationConnectorType::MiniCentronicsType26,
                 0x1F => PortInformationConnectorType::MiniJackHeadphones,
                 0x20 => PortInformationConnectorType::Bnc,
                 0x21 => PortInformationConnectorType::Port1394,
@@ -293,7 +281,7 @@ impl From<u8> for PortInformationConnectorTypeData {
                 0x23 => PortInformationConnectorType::UsbTypeCReceptacle,
                 0xA0 => PortInformationConnectorType::PC98,
                 0xA1 => PortInformationConnectorType::PC98Hireso,
-                0xA2 => PortInformationConnectorType::PCH88,
+                0xA2 => PortInformationConnectorType::PCH98,
                 0xA3 => PortInformationConnectorType::PC98Note,
                 0xA4 => PortInformationConnectorType::PC98Full,
                 0xFF => PortInformationConnectorType::Other,
@@ -397,7 +385,7 @@ pub enum PortInformationPortType {
     /// PCMCIA Type I2
     PcmciaTypeI,
     /// PCMCIA Type II
-    PcmcialTypeII,
+    PcmciaTypeII,
     /// PCMCIA Type III
     PcmciaTypeIii,
     /// Cardbus
@@ -463,7 +451,7 @@ impl From<u8> for PortInformationPortTypeData {
                 0x10 => PortInformationPortType::Usb,
                 0x11 => PortInformationPortType::Firewire,
                 0x12 => PortInformationPortType::PcmciaTypeI,
-                0x13 => PortInformationPortType::PcmcialTypeII,
+                0x13 => PortInformationPortType::PcmciaTypeII,
                 0x14 => PortInformationPortType::PcmciaTypeIii,
                 0x15 => PortInformationPortType::Cardbus,
                 0x16 => PortInformationPortType::AccessBusPort,
@@ -525,4 +513,40 @@ mod tests {
             PortInformationPortType::MousePort
         );
     }
+
+    #[test]
+    fn test_connector_and_port_types() {
+        assert_eq!(
+            *PortInformationConnectorTypeData::from(0x09),
+            PortInformationConnectorType::DB9PinFemale
+        );
+        assert_eq!(
+            *PortInformationConnectorTypeData::from(0x22),
+            PortInformationConnectorType::SasSataPlugReceptacle
+        );
+        assert_eq!(
+            *PortInformationConnectorTypeData::from(0x23),
+            PortInformationConnectorType::UsbTypeCReceptacle
+        );
+        assert_eq!(
+            *PortInformationConnectorTypeData::from(0xA2),
+            PortInformationConnectorType::PCH98
+        );
+        assert_eq!(
+            *PortInformationPortTypeData::from(0x21),
+            PortInformationPortType::Sas
+        );
+        assert_eq!(
+            *PortInformationPortTypeData::from(0x23),
+            PortInformationPortType::Thunderbolt
+        );
+
+        // OEM and future values pass through as raw
+        let connector_type = PortInformationConnectorTypeData::from(0xB0);
+        assert_eq!(connector_type.value, PortInformationConnectorType::None);
+        assert_eq!(connector_type.raw, 0xB0);
+        let port_type = PortInformationPortTypeData::from(0x24);
+        assert_eq!(port_type.value, PortInformationPortType::None);
+        assert_eq!(port_type.raw, 0x24);
+    }
 }
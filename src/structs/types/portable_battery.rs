@@ -30,139 +30,106 @@ impl<'a> SMBiosStruct<'a> for SMBiosPortableBattery<'a> {
 }
 
 impl<'a> SMBiosPortableBattery<'a> {
-    /// Identifies the location of the battery
-    pub fn location(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
-
-    /// Names the company that manufactured the battery
-    pub fn manufacturer(&self) -> Option<String> {
-        self.parts.get_field_string(0x05)
-    }
-
-    /// The date on which the battery was manufactured.
-    ///
-    /// Version 2.2+ implementations that use a Smart
-    /// Battery set this field to 0 (no string) to indicate
-    /// that the SBDS Manufacture Date field contains
-    /// the information.
-    pub fn manufacture_date(&self) -> Option<String> {
-        self.parts.get_field_string(0x06)
-    }
-
-    /// The serial number for the battery
-    ///
-    /// Version 2.2+ implementations that use a Smart
-    /// Battery set this field to 0 (no string) to indicate
-    /// that the SBDS Serial Number field contains the
-    /// information.
-    pub fn serial_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x07)
-    }
-
-    /// Names the battery device
-    ///
-    /// EXAMPLE: "DR-36"
-    pub fn device_name(&self) -> Option<String> {
-        self.parts.get_field_string(0x08)
-    }
-
-    /// Identifies the battery chemistry
-    ///
-    /// Version 2.2+ implementations that use a Smart
-    /// Battery set this field to 02h (Unknown) to
-    /// indicate that the SBDS Device Chemistry field
-    /// contains the information.
-    pub fn device_chemistry(&self) -> Option<PortableBatteryDeviceChemistryData> {
-        self.parts
-            .get_field_byte(0x09)
-            .map(|raw| PortableBatteryDeviceChemistryData::from(raw))
-    }
-
-    /// Design capacity of the battery in mWatt-hours
-    ///
-    /// If the value is unknown, the field contains 0.
-    ///
-    /// For version 2.2+ implementations, this value is
-    /// multiplied by the 'design_capacity_multiplier' to
-    /// produce the actual value.
-    pub fn design_capacity(&self) -> Option<PortableBatteryDesignCapacity> {
-        self.parts
-            .get_field_word(0x0A)
-            .map(|raw| PortableBatteryDesignCapacity::from(raw))
-    }
-
-    /// Design voltage of the battery in mVolts
-    ///
-    /// If the value is unknown, the field contains 0.
-    pub fn design_voltage(&self) -> Option<PortableBatteryDesignVoltage> {
-        self.parts
-            .get_field_word(0x0C)
-            .map(|raw| PortableBatteryDesignVoltage::from(raw))
-    }
-
-    /// Contains the Smart Battery Data Specification version number
-    /// supported by this battery
-    ///
-    /// If the battery does not support the function, no
-    /// string is supplied.
-    pub fn sbds_version_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x0E)
-    }
-
-    /// Maximum error (as a percentage in the range 0
-    /// to 100) in the Watt-hour data reported by the
-    /// battery, indicating an upper bound on how much
-    /// additional energy the battery might have above
-    /// the energy it reports having
-    ///
-    /// If the value is unknown, the field contains FFh.
-    pub fn maximum_error_in_battery_data(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x0F)
-    }
-
-    /// 16-bit value that identifies the battery’s serial
-    /// number
-    ///
-    /// This value, when combined with the
-    /// Manufacturer, Device Name, and Manufacture
-    /// Date, uniquely identifies the battery. The Serial
-    /// Number field must be set to 0 (no string) for this
-    /// field to be valid.
-    pub fn sbds_serial_number(&self) -> Option<u16> {
-        self.parts.get_field_word(0x10)
-    }
-
-    /// Date the cell pack was manufactured, in packed
-    /// format
-    pub fn sbds_manufacture_date(&self) -> Option<u16> {
-        self.parts.get_field_word(0x12)
-    }
-
-    /// Number of the string that identifies the battery
-    /// chemistry (for example, “PbAc”)
-    /// The Device Chemistry field must be set to 02h
-    /// (Unknown) for this field to be valid.
-    pub fn sbds_device_chemistry(&self) -> Option<String> {
-        self.parts.get_field_string(0x14)
-    }
-
-    /// Multiplication factor of the Design Capacity
-    /// value, which assures that the mWatt hours value
-    /// does not overflow for SBDS implementations
-    ///
-    /// The multiplier default is 1, SBDS
-    /// implementations use the value 10 to correspond
-    /// to the data as returned from the SBDS Function
-    /// 18h.
-    pub fn design_capacity_multiplier(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x15)
-    }
-
-    /// Contains OEM- or BIOS vendor-specific
-    /// information
-    pub fn oem_specific(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x16)
+    field_accessors! {
+        /// Identifies the location of the battery
+        location, location_access: string @ 0x04;
+
+        /// Names the company that manufactured the battery
+        manufacturer, manufacturer_access: string @ 0x05;
+
+        /// The date on which the battery was manufactured.
+        ///
+        /// Version 2.2+ implementations that use a Smart
+        /// Battery set this field to 0 (no string) to indicate
+        /// that the SBDS Manufacture Date field contains
+        /// the information.
+        manufacture_date, manufacture_date_access: string @ 0x06;
+
+        /// The serial number for the battery
+        ///
+        /// Version 2.2+ implementations that use a Smart
+        /// Battery set this field to 0 (no string) to indicate
+        /// that the SBDS Serial Number field contains the
+        /// information.
+        serial_number, serial_number_access: string @ 0x07;
+
+        /// Names the battery device
+        ///
+        /// EXAMPLE: "DR-36"
+        device_name, device_name_access: string @ 0x08;
+
+        /// Identifies the battery chemistry
+        ///
+        /// Version 2.2+ implementations that use a Smart
+        /// Battery set this field to 02h (Unknown) to
+        /// indicate that the SBDS Device Chemistry field
+        /// contains the information.
+        device_chemistry, device_chemistry_access: byte @ 0x09
+            => PortableBatteryDeviceChemistryData;
+
+        /// Design capacity of the battery in mWatt-hours
+        ///
+        /// If the value is unknown, the field contains 0.
+        ///
+        /// For version 2.2+ implementations, this value is
+        /// multiplied by the 'design_capacity_multiplier' to
+        /// produce the actual value.
+        design_capacity, design_capacity_access: word @ 0x0A => PortableBatteryDesignCapacity;
+
+        /// Design voltage of the battery in mVolts
+        ///
+        /// If the value is unknown, the field contains 0.
+        design_voltage, design_voltage_access: word @ 0x0C => PortableBatteryDesignVoltage;
+
+        /// Contains the Smart Battery Data Specification version number
+        /// supported by this battery
+        ///
+        /// If the battery does not support the function, no
+        /// string is supplied.
+        sbds_version_number, sbds_version_number_access: string @ 0x0E;
+
+        /// Maximum error (as a percentage in the range 0
+        /// to 100) in the Watt-hour data reported by the
+        /// battery, indicating an upper bound on how much
+        /// additional energy the battery might have above
+        /// the energy it reports having
+        ///
+        /// If the value is unknown, the field contains FFh.
+        maximum_error_in_battery_data, maximum_error_in_battery_data_access: byte @ 0x0F;
+
+        /// 16-bit value that identifies the battery’s serial
+        /// number
+        ///
+        /// This value, when combined with the
+        /// Manufacturer, Device Name, and Manufacture
+        /// Date, uniquely identifies the battery. The Serial
+        /// Number field must be set to 0 (no string) for this
+        /// field to be valid.
+        sbds_serial_number, sbds_serial_number_access: word @ 0x10;
+
+        /// Date the cell pack was manufactured, in packed
+        /// format
+        sbds_manufacture_date, sbds_manufacture_date_access: word @ 0x12;
+
+        /// Number of the string that identifies the battery
+        /// chemistry (for example, “PbAc”)
+        /// The Device Chemistry field must be set to 02h
+        /// (Unknown) for this field to be valid.
+        sbds_device_chemistry, sbds_device_chemistry_access: string @ 0x14;
+
+        /// Multiplication factor of the Design Capacity
+        /// value, which assures that the mWatt hours value
+        /// does not overflow for SBDS implementations
+        ///
+        /// The multiplier default is 1, SBDS
+        /// implementations use the value 10 to correspond
+        /// to the data as returned from the SBDS Function
+        /// 18h.
+        design_capacity_multiplier, design_capacity_multiplier_access: byte @ 0x15;
+
+        /// Contains OEM- or BIOS vendor-specific
+        /// information
+        oem_specific, oem_specific_access: dword @ 0x16;
     }
 }
 
@@ -1,7 +1,9 @@
-use crate::core::{Handle, UndefinedStruct};
+use crate::core::{Handle, StructFields, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use core::{fmt, any};
+use core::{convert::TryInto, fmt, any};
+#[cfg(feature = "no_std")]
+use alloc::string::String;
 use core::ops::Deref;
 
 /// # Processor Additional Information (Type 44)
@@ -39,11 +41,11 @@ impl<'a> SMBiosProcessorAdditionalInformation<'a> {
     /// Offset of the ProcessorSpecificBlock field.
     const PROCESSOR_SPECIFIC_BLOCK_OFFSET: usize = 0x06usize;
 
-    /// Handle, or instance number, associated with the
-    /// [super::SMBiosProcessorInformation] structure (SMBIOS type 4) which the
-    /// Processor Additional Information structure describes.
-    pub fn referenced_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x04)
+    field_accessors! {
+        /// Handle, or instance number, associated with the
+        /// [super::SMBiosProcessorInformation] structure (SMBIOS type 4) which the
+        /// Processor Additional Information structure describes.
+        referenced_handle, referenced_handle_access: handle @ 0x04;
     }
 
     /// Processor-Specific Block
@@ -127,6 +129,24 @@ impl<'a> ProcessorSpecificBlock<'a> {
     pub fn processor_specific_data(&self) -> &'a [u8] {
         &self.raw[Self::PROCESSOR_SPECIFIC_DATA_OFFSET..]
     }
+
+    /// Decodes 'processor_specific_data' according to 'processor_type'
+    ///
+    /// Architectures without a decoder yield [ProcessorSpecificData::Other].
+    pub fn decode(&self) -> ProcessorSpecificData<'a> {
+        let data = self.processor_specific_data();
+        match self.processor_type().value {
+            ProcessorArchitectureType::Arm64Bit => {
+                ProcessorSpecificData::Arm64(Arm64ProcessorSpecificBlock { raw: data })
+            }
+            ProcessorArchitectureType::RiscV32Bit
+            | ProcessorArchitectureType::RiscV64Bit
+            | ProcessorArchitectureType::RiscV128Bit => {
+                ProcessorSpecificData::RiscV(RiscVProcessorSpecificBlock { raw: data })
+            }
+            _ => ProcessorSpecificData::Other(data),
+        }
+    }
 }
 
 impl fmt::Debug for ProcessorSpecificBlock<'_> {
@@ -152,6 +172,645 @@ impl Serialize for ProcessorSpecificBlock<'_> {
     }
 }
 
+/// # Decoded 'processor_specific_data' of a [ProcessorSpecificBlock]
+pub enum ProcessorSpecificData<'a> {
+    /// ARM64 (Aarch64) processor-specific block
+    Arm64(Arm64ProcessorSpecificBlock<'a>),
+    /// RISC-V (RV32, RV64 or RV128) processor-specific block
+    RiscV(RiscVProcessorSpecificBlock<'a>),
+    /// Processor-specific data of an architecture without a decoder
+    Other(&'a [u8]),
+}
+
+impl fmt::Debug for ProcessorSpecificData<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessorSpecificData::Arm64(block) => fmt.debug_tuple("Arm64").field(block).finish(),
+            ProcessorSpecificData::RiscV(block) => fmt.debug_tuple("RiscV").field(block).finish(),
+            ProcessorSpecificData::Other(data) => fmt.debug_tuple("Other").field(data).finish(),
+        }
+    }
+}
+
+impl Serialize for ProcessorSpecificData<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ProcessorSpecificData::Arm64(block) => {
+                serializer.serialize_newtype_variant("ProcessorSpecificData", 0, "Arm64", block)
+            }
+            ProcessorSpecificData::RiscV(block) => {
+                serializer.serialize_newtype_variant("ProcessorSpecificData", 1, "RiscV", block)
+            }
+            ProcessorSpecificData::Other(data) => {
+                serializer.serialize_newtype_variant("ProcessorSpecificData", 2, "Other", data)
+            }
+        }
+    }
+}
+
+/// # ARM64 Processor-Specific Block
+///
+/// The 'processor_specific_data' of a [ProcessorSpecificBlock] whose
+/// 'processor_type' is [ProcessorArchitectureType::Arm64Bit].
+///
+/// | Offset | Name            | Length |
+/// |--------|-----------------|--------|
+/// | 00h    | Revision        | WORD   |
+/// | 02h    | Length          | BYTE   |
+/// | 03h    | MIDR_EL1        | QWORD  |
+/// | 0Bh    | SMCCC Version   | DWORD  |
+/// | 0Fh    | Feature Support | DWORD  |
+pub struct Arm64ProcessorSpecificBlock<'a> {
+    /// Raw byte slice for this processor specific data
+    pub raw: &'a [u8],
+}
+
+impl<'a> Arm64ProcessorSpecificBlock<'a> {
+    /// Revision of the block
+    ///
+    /// The major revision is in the high byte and the minor revision in the low byte.
+    pub fn revision(&self) -> Option<u16> {
+        self.raw
+            .get(0x00..0x02)
+            .map(|bytes| u16::from_le_bytes(bytes.try_into().expect("u16 is 2 bytes")))
+    }
+
+    /// Length of the block, in bytes
+    pub fn length(&self) -> Option<u8> {
+        self.raw.get(0x02).copied()
+    }
+
+    /// Main ID Register (MIDR_EL1)
+    pub fn midr(&self) -> Option<Arm64Midr> {
+        self.raw.get(0x03..0x0B).map(|bytes| {
+            Arm64Midr::from(u64::from_le_bytes(
+                bytes.try_into().expect("u64 is 8 bytes"),
+            ))
+        })
+    }
+
+    /// SMCCC Version
+    ///
+    /// Value returned by SMCCC_VERSION; 0 when the SMC Calling
+    /// Convention is not supported.
+    pub fn smccc_version(&self) -> Option<u32> {
+        self.raw
+            .get(0x0B..0x0F)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().expect("u32 is 4 bytes")))
+    }
+
+    /// SMC Calling Convention (SMCCC) is supported.
+    pub fn smccc_is_supported(&self) -> Option<bool> {
+        self.smccc_version().map(|version| version != 0)
+    }
+
+    /// Feature Support
+    pub fn feature_support(&self) -> Option<Arm64FeatureSupport> {
+        self.raw.get(0x0F..0x13).map(|bytes| {
+            Arm64FeatureSupport::from(u32::from_le_bytes(
+                bytes.try_into().expect("u32 is 4 bytes"),
+            ))
+        })
+    }
+}
+
+impl fmt::Debug for Arm64ProcessorSpecificBlock<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(any::type_name::<Arm64ProcessorSpecificBlock<'_>>())
+            .field("revision", &self.revision())
+            .field("length", &self.length())
+            .field("midr", &self.midr())
+            .field("smccc_version", &self.smccc_version())
+            .field("feature_support", &self.feature_support())
+            .finish()
+    }
+}
+
+impl Serialize for Arm64ProcessorSpecificBlock<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Arm64ProcessorSpecificBlock", 5)?;
+        state.serialize_field("revision", &self.revision())?;
+        state.serialize_field("length", &self.length())?;
+        state.serialize_field("midr", &self.midr())?;
+        state.serialize_field("smccc_version", &self.smccc_version())?;
+        state.serialize_field("feature_support", &self.feature_support())?;
+        state.end()
+    }
+}
+
+/// # ARM64 Main ID Register (MIDR_EL1)
+#[derive(PartialEq, Eq)]
+pub struct Arm64Midr {
+    /// Raw value
+    pub raw: u64,
+}
+
+impl Deref for Arm64Midr {
+    type Target = u64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u64> for Arm64Midr {
+    fn from(raw: u64) -> Self {
+        Arm64Midr { raw }
+    }
+}
+
+impl Arm64Midr {
+    /// Implementer code (bits 31:24), for example 41h for Arm Limited
+    pub fn implementer(&self) -> u8 {
+        (self.raw >> 24) as u8
+    }
+
+    /// Variant number (bits 23:20)
+    pub fn variant(&self) -> u8 {
+        ((self.raw >> 20) & 0x0F) as u8
+    }
+
+    /// Architecture (bits 19:16), 0Fh when defined by the ID registers
+    pub fn architecture(&self) -> u8 {
+        ((self.raw >> 16) & 0x0F) as u8
+    }
+
+    /// Primary part number (bits 15:4)
+    pub fn part_number(&self) -> u16 {
+        ((self.raw >> 4) & 0x0FFF) as u16
+    }
+
+    /// Revision number (bits 3:0)
+    pub fn revision(&self) -> u8 {
+        (self.raw & 0x0F) as u8
+    }
+}
+
+impl fmt::Debug for Arm64Midr {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(any::type_name::<Arm64Midr>())
+            .field("raw", &self.raw)
+            .field("implementer", &self.implementer())
+            .field("variant", &self.variant())
+            .field("architecture", &self.architecture())
+            .field("part_number", &self.part_number())
+            .field("revision", &self.revision())
+            .finish()
+    }
+}
+
+impl Serialize for Arm64Midr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Arm64Midr", 6)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("implementer", &self.implementer())?;
+        state.serialize_field("variant", &self.variant())?;
+        state.serialize_field("architecture", &self.architecture())?;
+        state.serialize_field("part_number", &self.part_number())?;
+        state.serialize_field("revision", &self.revision())?;
+        state.end()
+    }
+}
+
+/// # ARM64 Feature Support
+#[derive(PartialEq, Eq)]
+pub struct Arm64FeatureSupport {
+    /// Raw value
+    pub raw: u32,
+}
+
+impl Deref for Arm64FeatureSupport {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u32> for Arm64FeatureSupport {
+    fn from(raw: u32) -> Self {
+        Arm64FeatureSupport { raw }
+    }
+}
+
+impl Arm64FeatureSupport {
+    /// Scalable Vector Extension (SVE) is supported.
+    pub fn sve_is_supported(&self) -> bool {
+        self.raw & 0x01 == 0x01
+    }
+
+    /// Scalable Vector Extension version 2 (SVE2) is supported.
+    pub fn sve2_is_supported(&self) -> bool {
+        self.raw & 0x02 == 0x02
+    }
+}
+
+impl fmt::Debug for Arm64FeatureSupport {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(any::type_name::<Arm64FeatureSupport>())
+            .field("raw", &self.raw)
+            .field("sve_is_supported", &self.sve_is_supported())
+            .field("sve2_is_supported", &self.sve2_is_supported())
+            .finish()
+    }
+}
+
+impl Serialize for Arm64FeatureSupport {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Arm64FeatureSupport", 3)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("sve_is_supported", &self.sve_is_supported())?;
+        state.serialize_field("sve2_is_supported", &self.sve2_is_supported())?;
+        state.end()
+    }
+}
+
+/// # RISC-V Processor-Specific Block
+///
+/// The 'processor_specific_data' of a [ProcessorSpecificBlock] whose
+/// 'processor_type' is one of the RISC-V [ProcessorArchitectureType] values.
+///
+/// Machine-level identifiers and trap delegation registers are 128 bits wide
+/// so that RV32, RV64 and RV128 harts share the same layout.
+pub struct RiscVProcessorSpecificBlock<'a> {
+    /// Raw byte slice for this processor specific data
+    pub raw: &'a [u8],
+}
+
+impl<'a> RiscVProcessorSpecificBlock<'a> {
+    fn get_u128(&self, offset: usize) -> Option<u128> {
+        self.raw
+            .get(offset..offset + 16)
+            .map(|bytes| u128::from_le_bytes(bytes.try_into().expect("u128 is 16 bytes")))
+    }
+
+    /// Revision of the block
+    ///
+    /// The major revision is in the high byte and the minor revision in the low byte.
+    pub fn revision(&self) -> Option<u16> {
+        self.raw
+            .get(0x00..0x02)
+            .map(|bytes| u16::from_le_bytes(bytes.try_into().expect("u16 is 2 bytes")))
+    }
+
+    /// Length of the block, in bytes
+    pub fn structure_length(&self) -> Option<u8> {
+        self.raw.get(0x02).copied()
+    }
+
+    /// ID of the hart (mhartid)
+    pub fn hart_id(&self) -> Option<u128> {
+        self.get_u128(0x03)
+    }
+
+    /// The hart is the boot hart
+    pub fn boot_hart(&self) -> Option<bool> {
+        self.raw.get(0x13).map(|boot_hart| *boot_hart == 1)
+    }
+
+    /// Vendor ID of the hart (mvendorid)
+    pub fn machine_vendor_id(&self) -> Option<u128> {
+        self.get_u128(0x14)
+    }
+
+    /// Base microarchitecture of the hart (marchid)
+    pub fn machine_architecture_id(&self) -> Option<u128> {
+        self.get_u128(0x24)
+    }
+
+    /// Unique encoding of the version of the processor implementation (mimpid)
+    pub fn machine_implementation_id(&self) -> Option<u128> {
+        self.get_u128(0x34)
+    }
+
+    /// The ISA extensions supported by the hart (the extensions field of misa)
+    pub fn instruction_set_supported(&self) -> Option<RiscVIsaExtensions> {
+        self.raw.get(0x44..0x48).map(|bytes| {
+            RiscVIsaExtensions::from(u32::from_le_bytes(
+                bytes.try_into().expect("u32 is 4 bytes"),
+            ))
+        })
+    }
+
+    /// The privilege levels supported by the hart
+    pub fn privilege_level_supported(&self) -> Option<RiscVPrivilegeLevels> {
+        self.raw.get(0x48).map(|levels| RiscVPrivilegeLevels::from(*levels))
+    }
+
+    /// Machine exception delegation register (medeleg)
+    pub fn machine_exception_trap_delegation(&self) -> Option<u128> {
+        self.get_u128(0x49)
+    }
+
+    /// Machine interrupt delegation register (mideleg)
+    pub fn machine_interrupt_trap_delegation(&self) -> Option<u128> {
+        self.get_u128(0x59)
+    }
+
+    /// The register width (XLEN) of the hart
+    pub fn register_width(&self) -> Option<RiscVXlenData> {
+        self.raw.get(0x69).map(|xlen| RiscVXlenData::from(*xlen))
+    }
+
+    /// The machine mode XLEN
+    pub fn machine_mode_xlen(&self) -> Option<RiscVXlenData> {
+        self.raw.get(0x6A).map(|xlen| RiscVXlenData::from(*xlen))
+    }
+
+    /// The supervisor mode XLEN
+    pub fn supervisor_mode_xlen(&self) -> Option<RiscVXlenData> {
+        self.raw.get(0x6C).map(|xlen| RiscVXlenData::from(*xlen))
+    }
+
+    /// The user mode XLEN
+    pub fn user_mode_xlen(&self) -> Option<RiscVXlenData> {
+        self.raw.get(0x6D).map(|xlen| RiscVXlenData::from(*xlen))
+    }
+}
+
+impl fmt::Debug for RiscVProcessorSpecificBlock<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(any::type_name::<RiscVProcessorSpecificBlock<'_>>())
+            .field("revision", &self.revision())
+            .field("structure_length", &self.structure_length())
+            .field("hart_id", &self.hart_id())
+            .field("boot_hart", &self.boot_hart())
+            .field("machine_vendor_id", &self.machine_vendor_id())
+            .field("machine_architecture_id", &self.machine_architecture_id())
+            .field("machine_implementation_id", &self.machine_implementation_id())
+            .field("instruction_set_supported", &self.instruction_set_supported())
+            .field("privilege_level_supported", &self.privilege_level_supported())
+            .field(
+                "machine_exception_trap_delegation",
+                &self.machine_exception_trap_delegation(),
+            )
+            .field(
+                "machine_interrupt_trap_delegation",
+                &self.machine_interrupt_trap_delegation(),
+            )
+            .field("register_width", &self.register_width())
+            .field("machine_mode_xlen", &self.machine_mode_xlen())
+            .field("supervisor_mode_xlen", &self.supervisor_mode_xlen())
+            .field("user_mode_xlen", &self.user_mode_xlen())
+            .finish()
+    }
+}
+
+impl Serialize for RiscVProcessorSpecificBlock<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("RiscVProcessorSpecificBlock", 15)?;
+        state.serialize_field("revision", &self.revision())?;
+        state.serialize_field("structure_length", &self.structure_length())?;
+        state.serialize_field("hart_id", &self.hart_id())?;
+        state.serialize_field("boot_hart", &self.boot_hart())?;
+        state.serialize_field("machine_vendor_id", &self.machine_vendor_id())?;
+        state.serialize_field("machine_architecture_id", &self.machine_architecture_id())?;
+        state.serialize_field(
+            "machine_implementation_id",
+            &self.machine_implementation_id(),
+        )?;
+        state.serialize_field(
+            "instruction_set_supported",
+            &self.instruction_set_supported(),
+        )?;
+        state.serialize_field(
+            "privilege_level_supported",
+            &self.privilege_level_supported(),
+        )?;
+        state.serialize_field(
+            "machine_exception_trap_delegation",
+            &self.machine_exception_trap_delegation(),
+        )?;
+        state.serialize_field(
+            "machine_interrupt_trap_delegation",
+            &self.machine_interrupt_trap_delegation(),
+        )?;
+        state.serialize_field("register_width", &self.register_width())?;
+        state.serialize_field("machine_mode_xlen", &self.machine_mode_xlen())?;
+        state.serialize_field("supervisor_mode_xlen", &self.supervisor_mode_xlen())?;
+        state.serialize_field("user_mode_xlen", &self.user_mode_xlen())?;
+        state.end()
+    }
+}
+
+/// # RISC-V ISA Extensions
+///
+/// Bit n represents the extension named by the letter 'A' + n, as in the
+/// extensions field of the misa register.
+#[derive(PartialEq, Eq)]
+pub struct RiscVIsaExtensions {
+    /// Raw value
+    pub raw: u32,
+}
+
+impl Deref for RiscVIsaExtensions {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u32> for RiscVIsaExtensions {
+    fn from(raw: u32) -> Self {
+        RiscVIsaExtensions { raw }
+    }
+}
+
+impl RiscVIsaExtensions {
+    /// The extension named by `letter` ('A' to 'Z') is supported.
+    pub fn is_supported(&self, letter: char) -> bool {
+        match letter.to_ascii_uppercase() {
+            letter @ 'A'..='Z' => self.raw & (1 << (letter as u32 - 'A' as u32)) != 0,
+            _ => false,
+        }
+    }
+
+    /// The letters of the supported extensions (for example "ACDFIMSU")
+    pub fn letters(&self) -> String {
+        ('A'..='Z')
+            .filter(|letter| self.is_supported(*letter))
+            .collect()
+    }
+}
+
+impl fmt::Debug for RiscVIsaExtensions {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(any::type_name::<RiscVIsaExtensions>())
+            .field("raw", &self.raw)
+            .field("letters", &self.letters())
+            .finish()
+    }
+}
+
+impl Serialize for RiscVIsaExtensions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("RiscVIsaExtensions", 2)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("letters", &self.letters())?;
+        state.end()
+    }
+}
+
+/// # RISC-V Privilege Levels
+#[derive(PartialEq, Eq)]
+pub struct RiscVPrivilegeLevels {
+    /// Raw value
+    pub raw: u8,
+}
+
+impl Deref for RiscVPrivilegeLevels {
+    type Target = u8;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u8> for RiscVPrivilegeLevels {
+    fn from(raw: u8) -> Self {
+        RiscVPrivilegeLevels { raw }
+    }
+}
+
+impl RiscVPrivilegeLevels {
+    /// Machine mode is supported.
+    pub fn machine_mode_is_supported(&self) -> bool {
+        self.raw & 0x01 == 0x01
+    }
+
+    /// Supervisor mode is supported.
+    pub fn supervisor_mode_is_supported(&self) -> bool {
+        self.raw & 0x04 == 0x04
+    }
+
+    /// User mode is supported.
+    pub fn user_mode_is_supported(&self) -> bool {
+        self.raw & 0x08 == 0x08
+    }
+}
+
+impl fmt::Debug for RiscVPrivilegeLevels {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(any::type_name::<RiscVPrivilegeLevels>())
+            .field("raw", &self.raw)
+            .field("machine_mode_is_supported", &self.machine_mode_is_supported())
+            .field(
+                "supervisor_mode_is_supported",
+                &self.supervisor_mode_is_supported(),
+            )
+            .field("user_mode_is_supported", &self.user_mode_is_supported())
+            .finish()
+    }
+}
+
+impl Serialize for RiscVPrivilegeLevels {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("RiscVPrivilegeLevels", 4)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("machine_mode_is_supported", &self.machine_mode_is_supported())?;
+        state.serialize_field(
+            "supervisor_mode_is_supported",
+            &self.supervisor_mode_is_supported(),
+        )?;
+        state.serialize_field("user_mode_is_supported", &self.user_mode_is_supported())?;
+        state.end()
+    }
+}
+
+/// # RISC-V Register Width (XLEN) Data
+pub struct RiscVXlenData {
+    /// Raw value
+    ///
+    /// _raw_ is most useful when _value_ is None.
+    /// This is most likely to occur when the standard was updated but
+    /// this library code has not been updated to match the current
+    /// standard.
+    pub raw: u8,
+    /// The contained [RiscVXlen] value
+    pub value: RiscVXlen,
+}
+
+impl fmt::Debug for RiscVXlenData {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(any::type_name::<RiscVXlenData>())
+            .field("raw", &self.raw)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl Serialize for RiscVXlenData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("RiscVXlenData", 2)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+
+impl Deref for RiscVXlenData {
+    type Target = RiscVXlen;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// # RISC-V Register Width (XLEN)
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum RiscVXlen {
+    /// The mode is not supported
+    Unsupported,
+    /// 32-bit
+    Xlen32,
+    /// 64-bit
+    Xlen64,
+    /// 128-bit
+    Xlen128,
+    /// A value unknown to this standard, check the raw value
+    None,
+}
+
+impl From<u8> for RiscVXlenData {
+    fn from(raw: u8) -> Self {
+        RiscVXlenData {
+            value: match raw {
+                0x00 => RiscVXlen::Unsupported,
+                0x01 => RiscVXlen::Xlen32,
+                0x02 => RiscVXlen::Xlen64,
+                0x03 => RiscVXlen::Xlen128,
+                _ => RiscVXlen::None,
+            },
+            raw,
+        }
+    }
+}
+
 /// # Processor Architecture Types Data
 pub struct ProcessorArchitectureTypeData {
     /// Raw value
@@ -285,4 +944,93 @@ mod tests {
             &[0x03, 0x02, 0x01]
         );
     }
+
+    #[test]
+    fn test_arm64_processor_specific_block() {
+        let mut struct_type44 = vec![44u8, 0x00, 0x2F, 0x00, 0x08, 0x00, 0x13, 0x05];
+        struct_type44.extend_from_slice(&[0x00, 0x01, 0x13]);
+        struct_type44.extend_from_slice(&0x413F_D0C1u64.to_le_bytes());
+        struct_type44.extend_from_slice(&0x0001_0001u32.to_le_bytes());
+        struct_type44.extend_from_slice(&0x0000_0001u32.to_le_bytes());
+        struct_type44[1] = struct_type44.len() as u8;
+        struct_type44.extend_from_slice(&[0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type44);
+        let test_struct = SMBiosProcessorAdditionalInformation::new(&parts);
+        let block = test_struct.processor_specific_block().unwrap();
+
+        match block.decode() {
+            ProcessorSpecificData::Arm64(arm64) => {
+                assert_eq!(arm64.revision(), Some(0x0100));
+                assert_eq!(arm64.length(), Some(0x13));
+                let midr = arm64.midr().unwrap();
+                assert_eq!(midr.implementer(), 0x41);
+                assert_eq!(midr.variant(), 0x3);
+                assert_eq!(midr.architecture(), 0xF);
+                assert_eq!(midr.part_number(), 0xD0C);
+                assert_eq!(midr.revision(), 0x1);
+                assert_eq!(arm64.smccc_version(), Some(0x0001_0001));
+                assert_eq!(arm64.smccc_is_supported(), Some(true));
+                let features = arm64.feature_support().unwrap();
+                assert!(features.sve_is_supported());
+                assert!(!features.sve2_is_supported());
+            }
+            other => panic!("Expected Arm64, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_riscv_processor_specific_block() {
+        let mut data = vec![0u8; 0x6E];
+        data[0x00..0x02].copy_from_slice(&0x0100u16.to_le_bytes());
+        data[0x02] = 0x6E;
+        data[0x03] = 0x02; // hart id
+        data[0x13] = 0x01; // boot hart
+        data[0x14..0x18].copy_from_slice(&0x0489u32.to_le_bytes()); // mvendorid
+        data[0x24] = 0x07; // marchid
+        data[0x34] = 0x09; // mimpid
+        data[0x44..0x48].copy_from_slice(&0x0014_112Du32.to_le_bytes()); // ACDFIMSU
+        data[0x48] = 0x0D; // M, S, U
+        data[0x59] = 0x22; // mideleg
+        data[0x69] = 0x02;
+        data[0x6A] = 0x02;
+        data[0x6C] = 0x02;
+        data[0x6D] = 0x01;
+
+        let mut struct_type44 = vec![44u8, 0x00, 0x30, 0x00, 0x08, 0x00, 0x6E, 0x07];
+        struct_type44.extend_from_slice(&data);
+        struct_type44[1] = struct_type44.len() as u8;
+        struct_type44.extend_from_slice(&[0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type44);
+        let test_struct = SMBiosProcessorAdditionalInformation::new(&parts);
+        let block = test_struct.processor_specific_block().unwrap();
+
+        match block.decode() {
+            ProcessorSpecificData::RiscV(riscv) => {
+                assert_eq!(riscv.revision(), Some(0x0100));
+                assert_eq!(riscv.structure_length(), Some(0x6E));
+                assert_eq!(riscv.hart_id(), Some(2));
+                assert_eq!(riscv.boot_hart(), Some(true));
+                assert_eq!(riscv.machine_vendor_id(), Some(0x0489));
+                assert_eq!(riscv.machine_architecture_id(), Some(7));
+                assert_eq!(riscv.machine_implementation_id(), Some(9));
+                let extensions = riscv.instruction_set_supported().unwrap();
+                assert_eq!(extensions.letters(), "ACDFIMSU");
+                assert!(extensions.is_supported('m'));
+                assert!(!extensions.is_supported('V'));
+                let levels = riscv.privilege_level_supported().unwrap();
+                assert!(levels.machine_mode_is_supported());
+                assert!(levels.supervisor_mode_is_supported());
+                assert!(levels.user_mode_is_supported());
+                assert_eq!(riscv.machine_exception_trap_delegation(), Some(0));
+                assert_eq!(riscv.machine_interrupt_trap_delegation(), Some(0x22));
+                assert_eq!(*riscv.register_width().unwrap(), RiscVXlen::Xlen64);
+                assert_eq!(*riscv.machine_mode_xlen().unwrap(), RiscVXlen::Xlen64);
+                assert_eq!(*riscv.supervisor_mode_xlen().unwrap(), RiscVXlen::Xlen64);
+                assert_eq!(*riscv.user_mode_xlen().unwrap(), RiscVXlen::Xlen32);
+            }
+            other => panic!("Expected RiscV, found {:?}", other),
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::core::{Handle, UndefinedStruct};
+use crate::core::{Handle, MaybeValue, MegahertzSpeed, StructFields, UndefinedStruct};
 use crate::SMBiosStruct;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::convert::TryInto;
@@ -39,30 +39,20 @@ impl<'a> SMBiosStruct<'a> for SMBiosProcessorInformation<'a> {
 }
 
 impl<'a> SMBiosProcessorInformation<'a> {
-    /// Socket reference designation
-    ///
-    /// EXAMPLE: "J202"
-    pub fn socket_designation(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
+    field_accessors! {
+        /// Socket reference designation
+        ///
+        /// EXAMPLE: "J202"
+        socket_designation, socket_designation_access: string @ 0x04;
 
-    /// Processor type
-    pub fn processor_type(&self) -> Option<ProcessorTypeData> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| ProcessorTypeData::from(raw))
-    }
+        /// Processor type
+        processor_type, processor_type_access: byte @ 0x05 => ProcessorTypeData;
 
-    /// Processor family
-    pub fn processor_family(&self) -> Option<ProcessorFamilyData> {
-        self.parts
-            .get_field_byte(0x06)
-            .map(|raw| ProcessorFamilyData::from(raw))
-    }
+        /// Processor family
+        processor_family, processor_family_access: byte @ 0x06 => ProcessorFamilyData;
 
-    /// Processor manufacturer
-    pub fn processor_manufacturer(&self) -> Option<String> {
-        self.parts.get_field_string(0x07)
+        /// Processor manufacturer
+        processor_manufacturer, processor_manufacturer_access: string @ 0x07;
     }
 
     /// Raw processor identification data
@@ -77,215 +67,200 @@ impl<'a> SMBiosProcessorInformation<'a> {
             .map(|raw| raw.try_into().expect("incorrect length"))
     }
 
-    /// Processor version
-    pub fn processor_version(&self) -> Option<String> {
-        self.parts.get_field_string(0x10)
-    }
-
-    /// Voltage
-    pub fn voltage(&self) -> Option<ProcessorVoltage> {
-        self.parts
-            .get_field_byte(0x11)
-            .map(|raw| ProcessorVoltage::from(raw))
-    }
-
-    /// External clock frequency, in MHz
-    ///
-    /// If the value is unknown, the field is set to 0.
-    pub fn external_clock(&self) -> Option<ProcessorExternalClock> {
-        self.parts
-            .get_field_word(0x12)
-            .map(|raw| ProcessorExternalClock::from(raw))
-    }
-
-    /// Maximum processor speed (in MHz) supported
-    /// by the system for this processor socket
-    ///
-    /// 0E9h is for a 233 MHz processor.
-    ///
-    /// NOTE: This field identifies a capability for the system,
-    /// not the processor itself.
-    pub fn max_speed(&self) -> Option<ProcessorSpeed> {
-        self.parts
-            .get_field_word(0x14)
-            .map(|raw| ProcessorSpeed::from(raw))
-    }
-
-    /// Current speed
-    ///
-    /// Same format as Max Speed
-    ///
-    /// NOTE: This field identifies the processor's speed at
-    /// system boot; the processor may support
-    /// more than one speed.
-    pub fn current_speed(&self) -> Option<ProcessorSpeed> {
-        self.parts
-            .get_field_word(0x16)
-            .map(|raw| ProcessorSpeed::from(raw))
-    }
-
-    /// Status bit field
-    pub fn status(&self) -> Option<ProcessorStatus> {
-        self.parts
-            .get_field_byte(0x18)
-            .map(|raw| ProcessorStatus::from(raw))
-    }
-
-    /// Processor upgrade
-    pub fn processor_upgrade(&self) -> Option<ProcessorUpgradeData> {
-        self.parts
-            .get_field_byte(0x19)
-            .map(|raw| ProcessorUpgradeData::from(raw))
-    }
-
-    /// Handle of a [super::SMBiosCacheInformation] structure that
-    /// defines the attributes of the primary (Level 1)
-    /// cache for this processor
-    ///
-    /// For version 2.1 and version 2.2
-    /// implementations, the value is 0FFFFh if the
-    /// processor has no L1 cache. For version 2.3 and
-    /// later implementations, the value is 0FFFFh if
-    /// the Cache Information structure is not provided.
-    pub fn l1cache_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x1A)
-    }
-
-    /// Handle of a [super::SMBiosCacheInformation] structure that
-    /// defines the attributes of the primary (Level 2)
-    /// cache for this processor
-    ///
-    /// For version 2.1 and version 2.2
-    /// implementations, the value is 0FFFFh if the
-    /// processor has no L2 cache. For version 2.3 and
-    /// later implementations, the value is 0FFFFh if
-    /// the Cache Information structure is not provided.
-    pub fn l2cache_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x1C)
-    }
-
-    /// Handle of a [super::SMBiosCacheInformation] structure that
-    /// defines the attributes of the primary (Level 3)
-    /// cache for this processor
-    ///
-    /// For version 2.1 and version 2.2
-    /// implementations, the value is 0FFFFh if the
-    /// processor has no L3 cache. For version 2.3 and
-    /// later implementations, the value is 0FFFFh if
-    /// the Cache Information structure is not provided.
-    pub fn l3cache_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x1E)
-    }
-
-    /// The serial number of this processor
-    ///
-    /// This value is set by the manufacturer and
-    /// normally not changeable.
-    pub fn serial_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x20)
-    }
-
-    /// The asset tag of this processor
-    pub fn asset_tag(&self) -> Option<String> {
-        self.parts.get_field_string(0x21)
-    }
-
-    /// The part number of this processor
-    ///
-    /// This value is set by the manufacturer and
-    /// normally not changeable.
-    pub fn part_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x22)
-    }
-
-    /// Number of cores per processor socket
-    ///
-    /// For core counts of 256 or greater, the
-    /// 'core_count_2' field is set to the number of cores.
-    pub fn core_count(&self) -> Option<CoreCount> {
-        self.parts
-            .get_field_byte(0x23)
-            .map(|raw| CoreCount::from(raw))
-    }
-
-    /// Number of enabled cores per processor socket
+    field_accessors! {
+        /// Processor version
+        processor_version, processor_version_access: string @ 0x10;
+
+        /// Voltage
+        voltage, voltage_access: byte @ 0x11 => ProcessorVoltage;
+
+        /// External clock frequency, in MHz
+        ///
+        /// If the value is unknown, the field is set to 0.
+        external_clock, external_clock_access: word @ 0x12 => ProcessorExternalClock;
+
+        /// Maximum processor speed (in MHz) supported
+        /// by the system for this processor socket
+        ///
+        /// 0E9h is for a 233 MHz processor.
+        ///
+        /// NOTE: This field identifies a capability for the system,
+        /// not the processor itself.
+        max_speed, max_speed_access: word @ 0x14 => ProcessorSpeed;
+
+        /// Current speed
+        ///
+        /// Same format as Max Speed
+        ///
+        /// NOTE: This field identifies the processor's speed at
+        /// system boot; the processor may support
+        /// more than one speed.
+        current_speed, current_speed_access: word @ 0x16 => ProcessorSpeed;
+
+        /// Status bit field
+        status, status_access: byte @ 0x18 => ProcessorStatus;
+
+        /// Processor upgrade
+        processor_upgrade, processor_upgrade_access: byte @ 0x19 => ProcessorUpgradeData;
+
+        /// Handle of a [super::SMBiosCacheInformation] structure that
+        /// defines the attributes of the primary (Level 1)
+        /// cache for this processor
+        ///
+        /// For version 2.1 and version 2.2
+        /// implementations, the value is 0FFFFh if the
+        /// processor has no L1 cache. For version 2.3 and
+        /// later implementations, the value is 0FFFFh if
+        /// the Cache Information structure is not provided.
+        l1cache_handle, l1cache_handle_access: handle @ 0x1A;
+
+        /// Handle of a [super::SMBiosCacheInformation] structure that
+        /// defines the attributes of the primary (Level 2)
+        /// cache for this processor
+        ///
+        /// For version 2.1 and version 2.2
+        /// implementations, the value is 0FFFFh if the
+        /// processor has no L2 cache. For version 2.3 and
+        /// later implementations, the value is 0FFFFh if
+        /// the Cache Information structure is not provided.
+        l2cache_handle, l2cache_handle_access: handle @ 0x1C;
+
+        /// Handle of a [super::SMBiosCacheInformation] structure that
+        /// defines the attributes of the primary (Level 3)
+        /// cache for this processor
+        ///
+        /// For version 2.1 and version 2.2
+        /// implementations, the value is 0FFFFh if the
+        /// processor has no L3 cache. For version 2.3 and
+        /// later implementations, the value is 0FFFFh if
+        /// the Cache Information structure is not provided.
+        l3cache_handle, l3cache_handle_access: handle @ 0x1E;
+
+        /// The serial number of this processor
+        ///
+        /// This value is set by the manufacturer and
+        /// normally not changeable.
+        serial_number, serial_number_access: string @ 0x20;
+
+        /// The asset tag of this processor
+        asset_tag, asset_tag_access: string @ 0x21;
+
+        /// The part number of this processor
+        ///
+        /// This value is set by the manufacturer and
+        /// normally not changeable.
+        part_number, part_number_access: string @ 0x22;
+
+        /// Number of cores per processor socket
+        ///
+        /// For core counts of 256 or greater, the
+        /// 'core_count_2' field is set to the number of cores.
+        core_count, core_count_access: byte @ 0x23 => CoreCount;
+
+        /// Number of enabled cores per processor socket
+        ///
+        /// For core counts of 256 or greater, the
+        /// 'cores_enabled_2' field is set to the number of enabled
+        /// cores.
+        cores_enabled, cores_enabled_access: byte @ 0x24 => CoresEnabled;
+
+        /// Number of threads per processor socket
+        ///
+        /// For thread counts of 256 or greater,
+        /// 'thread_count_2' field is set to the number of
+        /// threads.
+        thread_count, thread_count_access: byte @ 0x25 => ThreadCount;
+
+        /// Defines which functions the processor supports
+        processor_characteristics, processor_characteristics_access: word @ 0x26
+            => ProcessorCharacteristics;
+
+        /// Processor family 2
+        processor_family_2, processor_family_2_access: word @ 0x28 => ProcessorFamilyData2;
+    }
+
+    /// Processor family, taken from 'processor_family_2' when
+    /// 'processor_family' is [ProcessorFamily::SeeProcessorFamily2]
     ///
-    /// For core counts of 256 or greater, the
-    /// 'cores_enabled_2' field is set to the number of enabled
-    /// cores.
-    pub fn cores_enabled(&self) -> Option<CoresEnabled> {
-        self.parts
-            .get_field_byte(0x24)
-            .map(|raw| CoresEnabled::from(raw))
-    }
-
-    /// Number of threads per processor socket
-    ///
-    /// For thread counts of 256 or greater,
-    /// 'thread_count_2' field is set to the number of
-    /// threads.
-    pub fn thread_count(&self) -> Option<ThreadCount> {
-        self.parts
-            .get_field_byte(0x25)
-            .map(|raw| ThreadCount::from(raw))
-    }
-
-    /// Defines which functions the processor supports
-    pub fn processor_characteristics(&self) -> Option<ProcessorCharacteristics> {
-        self.parts
-            .get_field_word(0x26)
-            .map(|raw| ProcessorCharacteristics::from(raw))
-    }
-
-    /// Processor family 2
-    pub fn processor_family_2(&self) -> Option<ProcessorFamilyData2> {
-        self.parts
-            .get_field_word(0x28)
-            .map(|raw| ProcessorFamilyData2::from(raw))
+    /// Returns None if 'processor_family' is absent, or if it refers to an
+    /// absent 'processor_family_2'.
+    pub fn effective_processor_family(&self) -> Option<ProcessorFamilyData2> {
+        let family = self.processor_family()?;
+        match family.value {
+            ProcessorFamily::SeeProcessorFamily2 => self.processor_family_2(),
+            _ => Some(ProcessorFamilyData2::from(family.raw as u16)),
+        }
     }
 
-    /// Number of Cores per processor socket.
+    field_accessors! {
+        /// Number of Cores per processor socket.
+        ///
+        /// Supports core counts >255. If this field is
+        /// present, it holds the core count for the
+        /// processor socket. 'core_count' will also hold the
+        /// core count, except for core counts that are 256
+        /// or greater. In that case, 'core_count' shall be set
+        /// to 'CoreCount::SeeCoreCount2' and 'core_count_2' will hold the count.
+        core_count_2, core_count_2_access: word @ 0x2A => CoreCount2;
+
+        /// Number of enabled cores per processor socket.
+        ///
+        /// Supports core enabled counts >255. If this field
+        /// is present, it holds the core enabled count for
+        /// the processor socket. 'cores_enabled' will also
+        /// hold the core enabled count, except for core
+        /// counts that are 256 or greater. In that case,
+        /// 'cores_enabled' shall be set to 'CoresEnabled::SeeCoresEnabled2'
+        /// and 'cores_enabled_2' will hold the count.
+        cores_enabled_2, cores_enabled_2_access: word @ 0x2C => CoresEnabled2;
+
+        /// Number of threads per processor socket.
+        ///
+        /// Supports thread counts >255. If this field is
+        /// present, it holds the thread count for the
+        /// processor socket. 'thread_count' will also hold
+        /// the thread count, except for thread counts that
+        /// are 256 or greater. In that case, 'thread_count'
+        /// shall be set to 'ThreadCount::SeeThreadCount2'
+        /// and 'thread_count_2' will hold the count.
+        thread_count_2, thread_count_2_access: word @ 0x2E => ThreadCount2;
+    }
+
+    /// Number of cores per processor socket, taken from 'core_count_2'
+    /// when present (SMBIOS 3.0 and later), otherwise from 'core_count'
     ///
-    /// Supports core counts >255. If this field is
-    /// present, it holds the core count for the
-    /// processor socket. 'core_count' will also hold the
-    /// core count, except for core counts that are 256
-    /// or greater. In that case, 'core_count' shall be set
-    /// to 'CoreCount::SeeCoreCount2' and 'core_count_2' will hold the count.
-    pub fn core_count_2(&self) -> Option<CoreCount2> {
-        self.parts
-            .get_field_word(0x2A)
-            .map(|raw| CoreCount2::from(raw))
+    /// Returns None if the count is unknown.
+    pub fn effective_core_count(&self) -> Option<u16> {
+        match (self.core_count_2(), self.core_count()) {
+            (Some(CoreCount2::Count(count)), _) => Some(count),
+            (_, Some(CoreCount::Count(count))) => Some(u16::from(count)),
+            _ => None,
+        }
     }
 
-    /// Number of enabled cores per processor socket.
+    /// Number of enabled cores per processor socket, taken from
+    /// 'cores_enabled_2' when present (SMBIOS 3.0 and later), otherwise from
+    /// 'cores_enabled'
     ///
-    /// Supports core enabled counts >255. If this field
-    /// is present, it holds the core enabled count for
-    /// the processor socket. 'cores_enabled' will also
-    /// hold the core enabled count, except for core
-    /// counts that are 256 or greater. In that case,
-    /// 'cores_enabled' shall be set to 'CoresEnabled::SeeCoresEnabled2'
-    /// and 'cores_enabled_2' will hold the count.
-    pub fn cores_enabled_2(&self) -> Option<CoresEnabled2> {
-        self.parts
-            .get_field_word(0x2C)
-            .map(|raw| CoresEnabled2::from(raw))
+    /// Returns None if the count is unknown.
+    pub fn effective_cores_enabled(&self) -> Option<u16> {
+        match (self.cores_enabled_2(), self.cores_enabled()) {
+            (Some(CoresEnabled2::Count(count)), _) => Some(count),
+            (_, Some(CoresEnabled::Count(count))) => Some(u16::from(count)),
+            _ => None,
+        }
     }
 
-    /// Number of threads per processor socket.
+    /// Number of threads per processor socket, taken from 'thread_count_2'
+    /// when present (SMBIOS 3.0 and later), otherwise from 'thread_count'
     ///
-    /// Supports thread counts >255. If this field is
-    /// present, it holds the thread count for the
-    /// processor socket. 'thread_count' will also hold
-    /// the thread count, except for thread counts that
-    /// are 256 or greater. In that case, 'thread_count'
-    /// shall be set to 'ThreadCount::SeeThreadCount2'
-    /// and 'thread_count_2' will hold the count.
-    pub fn thread_count_2(&self) -> Option<ThreadCount2> {
-        self.parts
-            .get_field_word(0x2E)
-            .map(|raw| ThreadCount2::from(raw))
+    /// Returns None if the count is unknown.
+    pub fn effective_thread_count(&self) -> Option<u16> {
+        match (self.thread_count_2(), self.thread_count()) {
+            (Some(ThreadCount2::Count(count)), _) => Some(count),
+            (_, Some(ThreadCount::Count(count))) => Some(u16::from(count)),
+            _ => None,
+        }
     }
 }
 
@@ -981,6 +956,8 @@ pub enum ProcessorFamily {
     ARMv7,
     /// ARMv8
     ARMv8,
+    /// ARMv9
+    ARMv9,
     /// SH-3
     SH3,
     /// SH-4
@@ -1007,6 +984,56 @@ pub enum ProcessorFamily {
     RISCVRV64,
     /// RISC-V RV128
     RISCVRV128,
+    /// LoongArch
+    LoongArch,
+    /// Loongson™ 1 Processor Family
+    Loongson1ProcessorFamily,
+    /// Loongson™ 2 Processor Family
+    Loongson2ProcessorFamily,
+    /// Loongson™ 3 Processor Family
+    Loongson3ProcessorFamily,
+    /// Loongson™ 2K Processor Family
+    Loongson2KProcessorFamily,
+    /// Loongson™ 3A Processor Family
+    Loongson3AProcessorFamily,
+    /// Loongson™ 3B Processor Family
+    Loongson3BProcessorFamily,
+    /// Loongson™ 3C Processor Family
+    Loongson3CProcessorFamily,
+    /// Loongson™ 3D Processor Family
+    Loongson3DProcessorFamily,
+    /// Loongson™ 3E Processor Family
+    Loongson3EProcessorFamily,
+    /// Dual-Core Loongson™ 2K Processor 2xxx Series
+    DualCoreLoongson2K2xxxSeries,
+    /// Quad-Core Loongson™ 3A Processor 5xxx Series
+    QuadCoreLoongson3A5xxxSeries,
+    /// Multi-Core Loongson™ 3A Processor 5xxx Series
+    MultiCoreLoongson3A5xxxSeries,
+    /// Quad-Core Loongson™ 3B Processor 5xxx Series
+    QuadCoreLoongson3B5xxxSeries,
+    /// Multi-Core Loongson™ 3B Processor 5xxx Series
+    MultiCoreLoongson3B5xxxSeries,
+    /// Multi-Core Loongson™ 3C Processor 5xxx Series
+    MultiCoreLoongson3C5xxxSeries,
+    /// Multi-Core Loongson™ 3D Processor 5xxx Series
+    MultiCoreLoongson3D5xxxSeries,
+    /// Intel® Core™ 3
+    IntelCore3,
+    /// Intel® Core™ 5
+    IntelCore5,
+    /// Intel® Core™ 7
+    IntelCore7,
+    /// Intel® Core™ 9
+    IntelCore9,
+    /// Intel® Core™ Ultra 3
+    IntelCoreUltra3,
+    /// Intel® Core™ Ultra 5
+    IntelCoreUltra5,
+    /// Intel® Core™ Ultra 7
+    IntelCoreUltra7,
+    /// Intel® Core™ Ultra 9
+    IntelCoreUltra9,
     /// A value unknown to this standard, check the raw value
     None,
 }
@@ -1216,6 +1243,7 @@ impl From<u16> for ProcessorFamily {
             0xFE => ProcessorFamily::SeeProcessorFamily2,
             0x100 => ProcessorFamily::ARMv7,
             0x101 => ProcessorFamily::ARMv8,
+            0x102 => ProcessorFamily::ARMv9,
             0x104 => ProcessorFamily::SH3,
             0x105 => ProcessorFamily::SH4,
             0x118 => ProcessorFamily::ARM,
@@ -1229,6 +1257,31 @@ impl From<u16> for ProcessorFamily {
             0x200 => ProcessorFamily::RISCVRV32,
             0x201 => ProcessorFamily::RISCVRV64,
             0x202 => ProcessorFamily::RISCVRV128,
+            0x258 => ProcessorFamily::LoongArch,
+            0x259 => ProcessorFamily::Loongson1ProcessorFamily,
+            0x25A => ProcessorFamily::Loongson2ProcessorFamily,
+            0x25B => ProcessorFamily::Loongson3ProcessorFamily,
+            0x25C => ProcessorFamily::Loongson2KProcessorFamily,
+            0x25D => ProcessorFamily::Loongson3AProcessorFamily,
+            0x25E => ProcessorFamily::Loongson3BProcessorFamily,
+            0x25F => ProcessorFamily::Loongson3CProcessorFamily,
+            0x260 => ProcessorFamily::Loongson3DProcessorFamily,
+            0x261 => ProcessorFamily::Loongson3EProcessorFamily,
+            0x262 => ProcessorFamily::DualCoreLoongson2K2xxxSeries,
+            0x26C => ProcessorFamily::QuadCoreLoongson3A5xxxSeries,
+            0x26D => ProcessorFamily::MultiCoreLoongson3A5xxxSeries,
+            0x26E => ProcessorFamily::QuadCoreLoongson3B5xxxSeries,
+            0x26F => ProcessorFamily::MultiCoreLoongson3B5xxxSeries,
+            0x270 => ProcessorFamily::MultiCoreLoongson3C5xxxSeries,
+            0x271 => ProcessorFamily::MultiCoreLoongson3D5xxxSeries,
+            0x300 => ProcessorFamily::IntelCore3,
+            0x301 => ProcessorFamily::IntelCore5,
+            0x302 => ProcessorFamily::IntelCore7,
+            0x303 => ProcessorFamily::IntelCore9,
+            0x304 => ProcessorFamily::IntelCoreUltra3,
+            0x305 => ProcessorFamily::IntelCoreUltra5,
+            0x306 => ProcessorFamily::IntelCoreUltra7,
+            0x307 => ProcessorFamily::IntelCoreUltra9,
             _ => ProcessorFamily::None,
         }
     }
@@ -1268,6 +1321,16 @@ impl Serialize for ProcessorUpgradeData {
     }
 }
 
+impl fmt::Display for ProcessorUpgradeData {
+    /// Displays ProcessorUpgrade either by name or as a hex value if the name for the value is unknown.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            ProcessorUpgrade::None => write!(f, "{:#X}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
 impl Deref for ProcessorUpgradeData {
     type Target = ProcessorUpgrade;
 
@@ -1403,6 +1466,56 @@ pub enum ProcessorUpgrade {
     SocketLGA4189,
     /// Socket LGA1200
     SocketLGA1200,
+    /// Socket LGA4677
+    SocketLGA4677,
+    /// Socket LGA1700
+    SocketLGA1700,
+    /// Socket BGA1744
+    SocketBGA1744,
+    /// Socket BGA1781
+    SocketBGA1781,
+    /// Socket BGA1211
+    SocketBGA1211,
+    /// Socket BGA2422
+    SocketBGA2422,
+    /// Socket LGA1211
+    SocketLGA1211,
+    /// Socket LGA2422
+    SocketLGA2422,
+    /// Socket LGA5773
+    SocketLGA5773,
+    /// Socket BGA5773
+    SocketBGA5773,
+    /// Socket AM5
+    SocketAM5,
+    /// Socket SP5
+    SocketSP5,
+    /// Socket SP6
+    SocketSP6,
+    /// Socket BGA883
+    SocketBGA883,
+    /// Socket BGA1190
+    SocketBGA1190,
+    /// Socket BGA4129
+    SocketBGA4129,
+    /// Socket LGA4710
+    SocketLGA4710,
+    /// Socket LGA7529
+    SocketLGA7529,
+    /// Socket BGA1964
+    SocketBGA1964,
+    /// Socket BGA1792
+    SocketBGA1792,
+    /// Socket BGA2049
+    SocketBGA2049,
+    /// Socket BGA2551
+    SocketBGA2551,
+    /// Socket LGA1851
+    SocketLGA1851,
+    /// Socket BGA2114
+    SocketBGA2114,
+    /// Socket BGA2833
+    SocketBGA2833,
     /// A value unknown to this standard, check the raw value
     None,
 }
@@ -1473,6 +1586,31 @@ impl From<u8> for ProcessorUpgradeData {
                 0x3C => ProcessorUpgrade::SocketBGA1528,
                 0x3D => ProcessorUpgrade::SocketLGA4189,
                 0x3E => ProcessorUpgrade::SocketLGA1200,
+                0x3F => ProcessorUpgrade::SocketLGA4677,
+                0x40 => ProcessorUpgrade::SocketLGA1700,
+                0x41 => ProcessorUpgrade::SocketBGA1744,
+                0x42 => ProcessorUpgrade::SocketBGA1781,
+                0x43 => ProcessorUpgrade::SocketBGA1211,
+                0x44 => ProcessorUpgrade::SocketBGA2422,
+                0x45 => ProcessorUpgrade::SocketLGA1211,
+                0x46 => ProcessorUpgrade::SocketLGA2422,
+                0x47 => ProcessorUpgrade::SocketLGA5773,
+                0x48 => ProcessorUpgrade::SocketBGA5773,
+                0x49 => ProcessorUpgrade::SocketAM5,
+                0x4A => ProcessorUpgrade::SocketSP5,
+                0x4B => ProcessorUpgrade::SocketSP6,
+                0x4C => ProcessorUpgrade::SocketBGA883,
+                0x4D => ProcessorUpgrade::SocketBGA1190,
+                0x4E => ProcessorUpgrade::SocketBGA4129,
+                0x4F => ProcessorUpgrade::SocketLGA4710,
+                0x50 => ProcessorUpgrade::SocketLGA7529,
+                0x51 => ProcessorUpgrade::SocketBGA1964,
+                0x52 => ProcessorUpgrade::SocketBGA1792,
+                0x53 => ProcessorUpgrade::SocketBGA2049,
+                0x54 => ProcessorUpgrade::SocketBGA2551,
+                0x55 => ProcessorUpgrade::SocketLGA1851,
+                0x56 => ProcessorUpgrade::SocketBGA2114,
+                0x57 => ProcessorUpgrade::SocketBGA2833,
                 _ => ProcessorUpgrade::None,
             },
             raw,
@@ -1707,6 +1845,28 @@ impl From<u16> for ProcessorExternalClock {
     }
 }
 
+impl ProcessorExternalClock {
+    /// The clock frequency as a [MegahertzSpeed], [MaybeValue::Unknown]
+    /// when unknown
+    pub fn megahertz(&self) -> MaybeValue<MegahertzSpeed> {
+        match self {
+            ProcessorExternalClock::MHz(mhz) => {
+                MaybeValue::Value(MegahertzSpeed::new(u32::from(*mhz)))
+            }
+            ProcessorExternalClock::Unknown => MaybeValue::Unknown,
+        }
+    }
+}
+
+impl From<ProcessorExternalClock> for MaybeValue<u16> {
+    fn from(clock: ProcessorExternalClock) -> Self {
+        match clock {
+            ProcessorExternalClock::MHz(mhz) => MaybeValue::Value(mhz),
+            ProcessorExternalClock::Unknown => MaybeValue::Unknown,
+        }
+    }
+}
+
 impl fmt::Debug for ProcessorExternalClock {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         use ProcessorExternalClock::*;
@@ -1735,6 +1895,25 @@ impl From<u16> for ProcessorSpeed {
     }
 }
 
+impl ProcessorSpeed {
+    /// The speed as a [MegahertzSpeed], [MaybeValue::Unknown] when unknown
+    pub fn megahertz(&self) -> MaybeValue<MegahertzSpeed> {
+        match self {
+            ProcessorSpeed::MHz(mhz) => MaybeValue::Value(MegahertzSpeed::new(u32::from(*mhz))),
+            ProcessorSpeed::Unknown => MaybeValue::Unknown,
+        }
+    }
+}
+
+impl From<ProcessorSpeed> for MaybeValue<u16> {
+    fn from(speed: ProcessorSpeed) -> Self {
+        match speed {
+            ProcessorSpeed::MHz(mhz) => MaybeValue::Value(mhz),
+            ProcessorSpeed::Unknown => MaybeValue::Unknown,
+        }
+    }
+}
+
 impl fmt::Debug for ProcessorSpeed {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         use ProcessorSpeed::*;
@@ -2021,6 +2200,19 @@ mod tests {
             ProcessorSpeed::MHz(mhz) => assert_eq!(mhz, 3600),
             ProcessorSpeed::Unknown => panic!("expected MHz"),
         }
+        assert_eq!(
+            test_struct.current_speed().unwrap().megahertz(),
+            MaybeValue::Value(MegahertzSpeed::new(3600))
+        );
+        assert_eq!(ProcessorSpeed::from(0).megahertz(), MaybeValue::Unknown);
+        assert_eq!(
+            ProcessorExternalClock::from(100).megahertz(),
+            MaybeValue::Value(MegahertzSpeed::new(100))
+        );
+        assert_eq!(
+            ProcessorExternalClock::from(0).megahertz(),
+            MaybeValue::Unknown
+        );
         let processor_status = test_struct.status().unwrap();
         assert!(processor_status.socket_populated());
         assert_eq!(processor_status.cpu_status(), CpuStatus::Enabled);
@@ -2057,6 +2249,10 @@ mod tests {
             *test_struct.processor_family_2().unwrap(),
             ProcessorFamily::IntelXeonProcessor
         );
+        assert_eq!(
+            *test_struct.effective_processor_family().unwrap(),
+            ProcessorFamily::IntelXeonProcessor
+        );
 
         match test_struct.core_count_2().unwrap() {
             CoreCount2::Count(number) => assert_eq!(number, 6),
@@ -2074,4 +2270,112 @@ mod tests {
             ThreadCount2::Reserved => panic!("expected number"),
         }
     }
+
+    #[test]
+    fn test_processor_family_and_upgrade_values() {
+        assert_eq!(*ProcessorFamilyData2::from(0x102), ProcessorFamily::ARMv9);
+        assert_eq!(*ProcessorFamilyData2::from(0x258), ProcessorFamily::LoongArch);
+        assert_eq!(
+            *ProcessorFamilyData2::from(0x271),
+            ProcessorFamily::MultiCoreLoongson3D5xxxSeries
+        );
+        assert_eq!(
+            *ProcessorFamilyData2::from(0x307),
+            ProcessorFamily::IntelCoreUltra9
+        );
+        assert_eq!(*ProcessorUpgradeData::from(0x49), ProcessorUpgrade::SocketAM5);
+        assert_eq!(
+            *ProcessorUpgradeData::from(0x57),
+            ProcessorUpgrade::SocketBGA2833
+        );
+
+        // Values unknown to this library remain readable through the raw value
+        let family = ProcessorFamilyData2::from(0x3FF);
+        assert_eq!(family.value, ProcessorFamily::None);
+        assert_eq!(format!("{}", family), "0x3FF");
+        let upgrade = ProcessorUpgradeData::from(0xF0);
+        assert_eq!(upgrade.value, ProcessorUpgrade::None);
+        assert_eq!(format!("{}", upgrade), "0xF0");
+        assert_eq!(format!("{}", ProcessorUpgradeData::from(0x49)), "SocketAM5");
+    }
+
+    #[test]
+    fn test_effective_processor_family() {
+        // processor_family is 0xFE, processor_family_2 is 0x0101 (ARMv8)
+        let mut struct_type4 = vec![0u8; 0x2A];
+        struct_type4[0x00] = 0x04;
+        struct_type4[0x01] = 0x2A;
+        struct_type4[0x06] = 0xFE;
+        struct_type4[0x28] = 0x01;
+        struct_type4[0x29] = 0x01;
+        struct_type4.extend_from_slice(&[0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type4);
+        let test_struct = SMBiosProcessorInformation::new(&parts);
+
+        assert_eq!(
+            *test_struct.processor_family().unwrap(),
+            ProcessorFamily::SeeProcessorFamily2
+        );
+        let family = test_struct.effective_processor_family().unwrap();
+        assert_eq!(family.raw, 0x0101);
+        assert_eq!(*family, ProcessorFamily::ARMv8);
+
+        // processor_family_2 is absent in a 2.5 structure
+        let mut struct_type4 = struct_type4[..0x28].to_vec();
+        struct_type4[0x01] = 0x28;
+        struct_type4.extend_from_slice(&[0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type4);
+        let test_struct = SMBiosProcessorInformation::new(&parts);
+        assert!(test_struct.effective_processor_family().is_none());
+    }
+
+    #[test]
+    fn test_effective_counts() {
+        // core_count is 0xFF with 300 cores in core_count_2, threads are
+        // counted in thread_count only and cores_enabled is unknown
+        let mut struct_type4 = vec![0u8; 0x30];
+        struct_type4[0x00] = 0x04;
+        struct_type4[0x01] = 0x30;
+        struct_type4[0x23] = 0xFF;
+        struct_type4[0x25] = 0x30;
+        struct_type4[0x2A..0x2C].copy_from_slice(&300u16.to_le_bytes());
+        struct_type4.extend_from_slice(&[0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type4);
+        let test_struct = SMBiosProcessorInformation::new(&parts);
+        assert_eq!(test_struct.effective_core_count(), Some(300));
+        assert_eq!(test_struct.effective_cores_enabled(), None);
+        assert_eq!(test_struct.effective_thread_count(), Some(0x30));
+
+        // a 2.5 structure has no count fields
+        let parts = UndefinedStruct::new(&vec![0x04, 0x04, 0x00, 0x00, 0x00, 0x00]);
+        let test_struct = SMBiosProcessorInformation::new(&parts);
+        assert_eq!(test_struct.effective_core_count(), None);
+    }
+
+    #[test]
+    fn test_field_access() {
+        // SMBIOS 2.5 structure, ending after 'thread_count', with no
+        // serial number string
+        let mut struct_type4 = vec![0u8; 0x28];
+        struct_type4[0x00] = 0x04;
+        struct_type4[0x01] = 0x28;
+        struct_type4[0x23] = 0x08;
+        struct_type4.extend_from_slice(&[0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type4);
+        let test_struct = SMBiosProcessorInformation::new(&parts);
+        assert!(matches!(
+            test_struct.core_count_access(),
+            MaybeValue::Value(CoreCount::Count(8))
+        ));
+        assert_eq!(test_struct.serial_number_access(), MaybeValue::NotProvided);
+        assert!(matches!(
+            test_struct.core_count_2_access(),
+            MaybeValue::NotPresentInVersion
+        ));
+        assert!(!test_struct.processor_family_2_access().is_present());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::core::UndefinedStruct;
+use crate::core::{StructFields, UndefinedStruct};
 use crate::{BoardTypeData, SMBiosStruct, SMBiosType};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
@@ -36,130 +36,90 @@ impl<'a> SMBiosStruct<'a> for SMBiosSystemChassisInformation<'a> {
 impl<'a> SMBiosSystemChassisInformation<'a> {
     const CONTAINED_ELEMENTS_OFFSET: usize = 0x15usize;
 
-    /// Manufacturer
-    pub fn manufacturer(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
-
-    /// Chassis type
-    ///
-    /// Bit 7 Chassis lock is present if 1.
-    /// Otherwise, either a lock is not present or it is
-    /// unknown if the enclosure has a lock.
-    /// Bits 6:0 Enumeration value.
-    pub fn chassis_type(&self) -> Option<ChassisTypeData> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| ChassisTypeData::from(raw))
-    }
-
-    /// Version
-    pub fn version(&self) -> Option<String> {
-        self.parts.get_field_string(0x06)
-    }
-
-    /// Serial number
-    pub fn serial_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x07)
-    }
-
-    /// Asset tag number
-    pub fn asset_tag_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x08)
-    }
-
-    /// Boot-up State
-    ///
-    /// State of the enclosure when it was last booted.
-    pub fn bootup_state(&self) -> Option<ChassisStateData> {
-        self.parts
-            .get_field_byte(0x09)
-            .map(|raw| ChassisStateData::from(raw))
-    }
-
-    /// Power supply state
-    ///
-    /// State of the enclosure’s power supply (or
-    /// supplies) when last booted
-    pub fn power_supply_state(&self) -> Option<ChassisStateData> {
-        self.parts
-            .get_field_byte(0x0A)
-            .map(|raw| ChassisStateData::from(raw))
-    }
-
-    /// Thermal state
-    ///
-    /// Thermal state of the enclosure when last
-    /// booted.
-    pub fn thermal_state(&self) -> Option<ChassisStateData> {
-        self.parts
-            .get_field_byte(0x0B)
-            .map(|raw| ChassisStateData::from(raw))
-    }
-
-    /// Security status
-    ///
-    /// Physical security status of the enclosure when
-    /// last booted.
-    pub fn security_status(&self) -> Option<ChassisSecurityStatusData> {
-        self.parts
-            .get_field_byte(0x0C)
-            .map(|raw| ChassisSecurityStatusData::from(raw))
-    }
-
-    /// OEM-defined
-    ///
-    /// OEM- or BIOS vendor-specific information
-    pub fn oem_defined(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x0D)
-    }
-
-    /// Height
-    ///
-    /// Height of the enclosure, in 'U's
-    ///
-    /// A U is a standard unit of measure for the
-    /// height of a rack or rack-mountable component
-    /// and is equal to 1.75 inches or 4.445 cm.
-    pub fn height(&self) -> Option<ChassisHeight> {
-        self.parts
-            .get_field_byte(0x11)
-            .map(|raw| ChassisHeight::from(raw))
-    }
-
-    /// Number of power cords
-    ///
-    /// Number of power cords associated with the
-    /// enclosure or chassis
-    pub fn number_of_power_cords(&self) -> Option<PowerCords> {
-        self.parts
-            .get_field_byte(0x12)
-            .map(|raw| PowerCords::from(raw))
-    }
-
-    /// Contained element count (n)
-    ///
-    /// Number of Contained Element records that
-    /// follow, in the range 0 to 255
-    /// Each Contained Element group comprises m
-    /// bytes, as specified by the Contained Element
-    /// Record Length field that follows. If no
-    /// Contained Elements are included, this field is
-    /// set to 0.
-    pub fn contained_element_count(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x13)
-    }
-
-    /// Contained element record length (m)
-    ///
-    /// Byte length of each Contained Element record
-    /// that follows, in the range 0 to 255
-    /// If no Contained Elements are included, this
-    /// field is set to 0. For version 2.3.2 and later of
-    /// this specification, this field is set to at least 03h
-    /// when Contained Elements are specified.
-    pub fn contained_element_record_length(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x14)
+    field_accessors! {
+        /// Manufacturer
+        manufacturer, manufacturer_access: string @ 0x04;
+
+        /// Chassis type
+        ///
+        /// Bit 7 Chassis lock is present if 1.
+        /// Otherwise, either a lock is not present or it is
+        /// unknown if the enclosure has a lock.
+        /// Bits 6:0 Enumeration value.
+        chassis_type, chassis_type_access: byte @ 0x05 => ChassisTypeData;
+
+        /// Version
+        version, version_access: string @ 0x06;
+
+        /// Serial number
+        serial_number, serial_number_access: string @ 0x07;
+
+        /// Asset tag number
+        asset_tag_number, asset_tag_number_access: string @ 0x08;
+
+        /// Boot-up State
+        ///
+        /// State of the enclosure when it was last booted.
+        bootup_state, bootup_state_access: byte @ 0x09 => ChassisStateData;
+
+        /// Power supply state
+        ///
+        /// State of the enclosure’s power supply (or
+        /// supplies) when last booted
+        power_supply_state, power_supply_state_access: byte @ 0x0A => ChassisStateData;
+
+        /// Thermal state
+        ///
+        /// Thermal state of the enclosure when last
+        /// booted.
+        thermal_state, thermal_state_access: byte @ 0x0B => ChassisStateData;
+
+        /// Security status
+        ///
+        /// Physical security status of the enclosure when
+        /// last booted.
+        security_status, security_status_access: byte @ 0x0C => ChassisSecurityStatusData;
+
+        /// OEM-defined
+        ///
+        /// OEM- or BIOS vendor-specific information
+        oem_defined, oem_defined_access: dword @ 0x0D;
+
+        /// Height
+        ///
+        /// Height of the enclosure, in 'U's
+        ///
+        /// A U is a standard unit of measure for the
+        /// height of a rack or rack-mountable component
+        /// and is equal to 1.75 inches or 4.445 cm.
+        height, height_access: byte @ 0x11 => ChassisHeight;
+
+        /// Number of power cords
+        ///
+        /// Number of power cords associated with the
+        /// enclosure or chassis
+        number_of_power_cords, number_of_power_cords_access: byte @ 0x12 => PowerCords;
+
+        /// Contained element count (n)
+        ///
+        /// Number of Contained Element records that
+        /// follow, in the range 0 to 255
+        /// Each Contained Element group comprises m
+        /// bytes, as specified by the Contained Element
+        /// Record Length field that follows. If no
+        /// Contained Elements are included, this field is
+        /// set to 0.
+        contained_element_count, contained_element_count_access: byte @ 0x13;
+
+        /// Contained element record length (m)
+        ///
+        /// Byte length of each Contained Element record
+        /// that follows, in the range 0 to 255
+        /// If no Contained Elements are included, this
+        /// field is set to 0. For version 2.3.2 and later of
+        /// this specification, this field is set to at least 03h
+        /// when Contained Elements are specified.
+        contained_element_record_length, contained_element_record_length_access: byte @ 0x14;
     }
 
     fn contained_elements_size(&self) -> Option<usize> {
@@ -283,7 +243,7 @@ impl From<u8> for PowerCords {
 }
 
 /// # Chassis Lock Presence
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum ChassisLockPresence {
     /// The enclosure has a lock.
     Present,
@@ -321,7 +281,7 @@ impl Serialize for ChassisTypeData {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("ChassisTypeData", 2)?;
+        let mut state = serializer.serialize_struct("ChassisTypeData", 3)?;
         state.serialize_field("raw", &self.raw)?;
         state.serialize_field("value", &self.value)?;
         state.serialize_field("lock_presence", &self.lock_presence)?;
@@ -330,9 +290,11 @@ impl Serialize for ChassisTypeData {
 }
 
 impl fmt::Display for ChassisTypeData {
+    /// Displays ChassisType either by name or as the enumeration value (without the lock bit)
+    /// if the name for the value is unknown.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
-            ChassisType::None => write!(f, "{}", &self.raw),
+            ChassisType::None => write!(f, "{}", self.raw & 0x7F),
             _ => write!(f, "{:?}", &self.value),
         }
     }
@@ -910,6 +872,10 @@ mod tests {
 
         assert_eq!(test_struct.manufacturer(), Some("LENOVO".to_string()));
         assert_eq!(*test_struct.chassis_type().unwrap(), ChassisType::Desktop);
+        assert_eq!(
+            test_struct.chassis_type().unwrap().lock_presence,
+            ChassisLockPresence::NotPresent
+        );
         assert_eq!(test_struct.version(), Some("None".to_string()));
         assert_eq!(test_struct.serial_number(), Some("MJ06URDZ".to_string()));
         assert_eq!(test_struct.asset_tag_number(), Some("4089985".to_string()));
@@ -952,4 +918,16 @@ mod tests {
         }
         assert_eq!(test_struct.sku_number(), Some("Default string".to_string()));
     }
+
+    #[test]
+    fn test_chassis_type_lock_bit() {
+        let chassis_type = ChassisTypeData::from(0x97);
+        assert_eq!(chassis_type.value, ChassisType::RackMountChassis);
+        assert_eq!(chassis_type.lock_presence, ChassisLockPresence::Present);
+
+        let chassis_type = ChassisTypeData::from(0xFF);
+        assert_eq!(chassis_type.value, ChassisType::None);
+        assert_eq!(chassis_type.lock_presence, ChassisLockPresence::Present);
+        assert_eq!(format!("{}", chassis_type), "127");
+    }
 }
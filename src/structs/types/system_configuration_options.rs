@@ -26,9 +26,9 @@ impl<'a> SMBiosStruct<'a> for SMBiosSystemConfigurationOptions<'a> {
 }
 
 impl<'a> SMBiosSystemConfigurationOptions<'a> {
-    /// Number of strings
-    pub fn count(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x04)
+    field_accessors! {
+        /// Number of strings
+        count, count_access: byte @ 0x04;
     }
 
     /// Iterable collection of OEM strings
@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{SMBiosStruct, StructFields, UndefinedStruct};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
 use core::ops::Deref;
@@ -34,96 +34,73 @@ impl<'a> SMBiosStruct<'a> for SMBiosSystemEventLog<'a> {
 impl<'a> SMBiosSystemEventLog<'a> {
     const LOG_TYPE_DESCRIPTORS_OFFSET: usize = 0x17usize;
 
-    /// Length, in bytes, of the overall event log area,
-    /// from the first byte of header to the last byte of data
-    pub fn log_area_length(&self) -> Option<u16> {
-        self.parts.get_field_word(0x04)
-    }
-
-    /// Defines the starting offset (or index) within the
-    /// nonvolatile storage of the event-log’s header,
-    /// from the Access Method Address
-    /// For single-byte indexed I/O accesses, the
-    /// most-significant byte of the start offset is set
-    /// to 00h.
-    pub fn log_header_start_offset(&self) -> Option<u16> {
-        self.parts.get_field_word(0x06)
-    }
-
-    /// Defines the starting offset (or index) within the
-    /// nonvolatile storage of the event-log’s first
-    /// data byte, from the Access Method Address
-    /// For single-byte indexed I/O accesses, the
-    /// most-significant byte of the start offset is set
-    /// to 00h.
-    ///
-    /// NOTE: The data directly follows any header
-    /// information. Therefore, the header length
-    /// can be determined by subtracting the
-    /// Header Start Offset from the Data Start
-    /// Offset.
-    pub fn log_data_start_offset(&self) -> Option<u16> {
-        self.parts.get_field_word(0x08)
-    }
-
-    /// Defines the Location and Method used by higher-level software to access the log area
-    pub fn access_method(&self) -> Option<AccessMethodData> {
-        self.parts
-            .get_field_byte(0x0A)
-            .map(|raw| AccessMethodData::from(raw))
-    }
-
-    /// Current status of the system event-log
-    pub fn log_status(&self) -> Option<LogStatus> {
-        self.parts
-            .get_field_byte(0x0B)
-            .map(|raw| LogStatus::from(raw))
-    }
-
-    /// Unique token that is reassigned every time
-    /// the event log changes
-    ///
-    /// Can be used to determine if additional events
-    /// have occurred since the last time the log was
-    /// read.
-    pub fn log_change_token(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x0C)
-    }
-
-    /// Address associated with the access method
-    ///
-    /// The data present depends on the Access
-    /// Method field value
-    pub fn access_method_address(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x10)
-    }
-
-    /// Format of the log header area
-    pub fn log_header_format(&self) -> Option<HeaderFormatData> {
-        self.parts
-            .get_field_byte(0x14)
-            .map(|raw| HeaderFormatData::from(raw))
-    }
-
-    /// Number of supported event log type
-    /// descriptors that follow
-    ///
-    /// If the value is 0, the list that starts at offset
-    /// 17h is not present.
-    pub fn number_of_supported_log_type_descriptors(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x15)
-    }
-
-    /// Number of bytes associated with each type
-    /// entry in the list below
-    /// The value is currently “hard-coded” as 2,
-    /// because each entry consists of two bytes.
-    /// This field’s presence allows future additions
-    /// to the type list. Software that interprets the
-    /// following list should not assume a list entry’s
-    /// length.
-    pub fn length_of_each_log_type_descriptor(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x16)
+    field_accessors! {
+        /// Length, in bytes, of the overall event log area,
+        /// from the first byte of header to the last byte of data
+        log_area_length, log_area_length_access: word @ 0x04;
+
+        /// Defines the starting offset (or index) within the
+        /// nonvolatile storage of the event-log’s header,
+        /// from the Access Method Address
+        /// For single-byte indexed I/O accesses, the
+        /// most-significant byte of the start offset is set
+        /// to 00h.
+        log_header_start_offset, log_header_start_offset_access: word @ 0x06;
+
+        /// Defines the starting offset (or index) within the
+        /// nonvolatile storage of the event-log’s first
+        /// data byte, from the Access Method Address
+        /// For single-byte indexed I/O accesses, the
+        /// most-significant byte of the start offset is set
+        /// to 00h.
+        ///
+        /// NOTE: The data directly follows any header
+        /// information. Therefore, the header length
+        /// can be determined by subtracting the
+        /// Header Start Offset from the Data Start
+        /// Offset.
+        log_data_start_offset, log_data_start_offset_access: word @ 0x08;
+
+        /// Defines the Location and Method used by higher-level software to access the log area
+        access_method, access_method_access: byte @ 0x0A => AccessMethodData;
+
+        /// Current status of the system event-log
+        log_status, log_status_access: byte @ 0x0B => LogStatus;
+
+        /// Unique token that is reassigned every time
+        /// the event log changes
+        ///
+        /// Can be used to determine if additional events
+        /// have occurred since the last time the log was
+        /// read.
+        log_change_token, log_change_token_access: dword @ 0x0C;
+
+        /// Address associated with the access method
+        ///
+        /// The data present depends on the Access
+        /// Method field value
+        access_method_address, access_method_address_access: dword @ 0x10;
+
+        /// Format of the log header area
+        log_header_format, log_header_format_access: byte @ 0x14 => HeaderFormatData;
+
+        /// Number of supported event log type
+        /// descriptors that follow
+        ///
+        /// If the value is 0, the list that starts at offset
+        /// 17h is not present.
+        number_of_supported_log_type_descriptors,
+        number_of_supported_log_type_descriptors_access: byte @ 0x15;
+
+        /// Number of bytes associated with each type
+        /// entry in the list below
+        /// The value is currently “hard-coded” as 2,
+        /// because each entry consists of two bytes.
+        /// This field’s presence allows future additions
+        /// to the type list. Software that interprets the
+        /// following list should not assume a list entry’s
+        /// length.
+        length_of_each_log_type_descriptor, length_of_each_log_type_descriptor_access: byte @ 0x16;
     }
 
     /// Type Descriptors
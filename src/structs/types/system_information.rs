@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{SMBiosStruct, StructFields, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::{
     array::TryFromSliceError,
@@ -36,70 +36,66 @@ impl<'a> SMBiosStruct<'a> for SMBiosSystemInformation<'a> {
 }
 
 impl<'a> SMBiosSystemInformation<'a> {
-    /// Manufacturer
-    pub fn manufacturer(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
+    field_accessors! {
+        /// Manufacturer
+        manufacturer, manufacturer_access: string @ 0x04;
 
-    /// Product name
-    pub fn product_name(&self) -> Option<String> {
-        self.parts.get_field_string(0x05)
-    }
+        /// Product name
+        product_name, product_name_access: string @ 0x05;
 
-    /// Version
-    pub fn version(&self) -> Option<String> {
-        self.parts.get_field_string(0x06)
-    }
+        /// Version
+        version, version_access: string @ 0x06;
 
-    /// Serial number
-    pub fn serial_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x07)
+        /// Serial number
+        serial_number, serial_number_access: string @ 0x07;
     }
 
     /// System UUID
+    ///
+    /// Added in SMBIOS 2.1; None for earlier structures.
     pub fn uuid(&self) -> Option<SystemUuidData> {
         self.parts
             .get_field_data(0x08, 0x18)
             .map(|raw| SystemUuidData::try_from(raw).expect("A GUID is 0x10 bytes"))
     }
 
-    /// Wake-up type
-    ///
-    /// Identifies the event that caused the system to power up.
-    pub fn wakeup_type(&self) -> Option<SystemWakeUpTypeData> {
-        self.parts
-            .get_field_byte(0x18)
-            .map(|raw| SystemWakeUpTypeData::from(raw))
-    }
-
-    /// SKU Number
-    ///
-    /// This text string identifies a particular computer
-    /// configuration for sale. It is sometimes also
-    /// called a product ID or purchase order number.
-    /// This number is frequently found in existing
-    /// fields, but there is no standard format.
-    /// Typically for a given system board from a
-    /// given OEM, there are tens of unique
-    /// processor, memory, hard drive, and optical
-    /// drive configurations.
-    pub fn sku_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x19)
-    }
-
-    /// Family
-    ///
-    /// This text string identifies the family to which a
-    /// particular computer belongs. A family refers to
-    /// a set of computers that are similar but not
-    /// identical from a hardware or software point of
-    /// view. Typically, a family is composed of
-    /// different computer models, which have
-    /// different configurations and pricing points.
-    /// Computers in the same family often have
-    /// similar branding and cosmetic features.
-    pub fn family(&self) -> Option<String> {
-        self.parts.get_field_string(0x1A)
+    field_accessors! {
+        /// Wake-up type
+        ///
+        /// Identifies the event that caused the system to power up.
+        ///
+        /// Added in SMBIOS 2.1; None for earlier structures.
+        wakeup_type, wakeup_type_access: byte @ 0x18 => SystemWakeUpTypeData;
+
+        /// SKU Number
+        ///
+        /// This text string identifies a particular computer
+        /// configuration for sale. It is sometimes also
+        /// called a product ID or purchase order number.
+        /// This number is frequently found in existing
+        /// fields, but there is no standard format.
+        /// Typically for a given system board from a
+        /// given OEM, there are tens of unique
+        /// processor, memory, hard drive, and optical
+        /// drive configurations.
+        ///
+        /// Added in SMBIOS 2.4; None for earlier structures.
+        sku_number, sku_number_access: string @ 0x19;
+
+        /// Family
+        ///
+        /// This text string identifies the family to which a
+        /// particular computer belongs. A family refers to
+        /// a set of computers that are similar but not
+        /// identical from a hardware or software point of
+        /// view. Typically, a family is composed of
+        /// different computer models, which have
+        /// different configurations and pricing points.
+        /// Computers in the same family often have
+        /// similar branding and cosmetic features.
+        ///
+        /// Added in SMBIOS 2.4; None for earlier structures.
+        family, family_access: string @ 0x1A;
     }
 }
 
@@ -152,9 +148,9 @@ pub enum SystemUuidData {
 impl SystemUuidData {
     fn new<'a>(array: &'a [u8; 0x10]) -> SystemUuidData {
         if array.iter().all(|&x| x == 0) {
-            SystemUuidData::IdNotPresentButSettable
-        } else if array.iter().all(|&x| x == 0xFF) {
             SystemUuidData::IdNotPresent
+        } else if array.iter().all(|&x| x == 0xFF) {
+            SystemUuidData::IdNotPresentButSettable
         } else {
             SystemUuidData::Uuid(SystemUuid::from(array))
         }
@@ -180,6 +176,13 @@ impl fmt::Display for SystemUuidData {
 }
 
 /// # System - UUID
+///
+/// Since SMBIOS 2.6 the first three fields of the UUID (time_low, time_mid and
+/// time_high_and_version) are stored little-endian, while the remaining bytes are
+/// stored in order. The accessors and the canonical [fmt::Display] form apply
+/// this ordering.
+///
+/// With the `uuid` feature, a [SystemUuid] converts into a `uuid::Uuid`.
 #[derive(PartialEq, Eq)]
 pub struct SystemUuid {
     /// Raw byte array for this UUID
@@ -245,6 +248,20 @@ impl fmt::Display for SystemUuid {
     }
 }
 
+#[cfg(feature = "uuid")]
+impl From<&SystemUuid> for uuid::Uuid {
+    fn from(system_uuid: &SystemUuid) -> Self {
+        uuid::Uuid::from_bytes_le(system_uuid.raw)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<SystemUuid> for uuid::Uuid {
+    fn from(system_uuid: SystemUuid) -> Self {
+        uuid::Uuid::from(&system_uuid)
+    }
+}
+
 impl fmt::Debug for SystemUuid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", &self)
@@ -321,7 +338,7 @@ pub enum SystemWakeUpType {
     /// APM Timer
     ApmTimer,
     /// Modem Ring
-    ModernRing,
+    ModemRing,
     /// LAN Remote
     LanRemote,
     /// Power Switch
@@ -341,7 +358,7 @@ impl From<u8> for SystemWakeUpTypeData {
                 0x01 => SystemWakeUpType::Other,
                 0x02 => SystemWakeUpType::Unknown,
                 0x03 => SystemWakeUpType::ApmTimer,
-                0x04 => SystemWakeUpType::ModernRing,
+                0x04 => SystemWakeUpType::ModemRing,
                 0x05 => SystemWakeUpType::LanRemote,
                 0x06 => SystemWakeUpType::PowerSwitch,
                 0x07 => SystemWakeUpType::PciPme,
@@ -393,4 +410,78 @@ mod tests {
         );
         assert_eq!(test_struct.family(), Some("ThinkStation P520".to_string()));
     }
+
+    #[test]
+    fn test_structure_versions() {
+        // 2.0 structure (length 08h) has no uuid, wake-up type, SKU number or family
+        let struct_type1 = vec![
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x02, 0x00, 0x00, b'O', b'E', b'M', 0x00, b'P', b'C',
+            0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type1);
+        let test_struct = SMBiosSystemInformation::new(&parts);
+
+        assert_eq!(test_struct.manufacturer(), Some("OEM".to_string()));
+        assert_eq!(test_struct.product_name(), Some("PC".to_string()));
+        assert!(test_struct.uuid().is_none());
+        assert!(test_struct.wakeup_type().is_none());
+        assert!(test_struct.sku_number().is_none());
+        assert!(test_struct.family().is_none());
+
+        // 2.1 structure (length 19h) has uuid and wake-up type, but no SKU number or family
+        let mut struct_type1 = vec![0x01, 0x19, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00];
+        struct_type1.extend_from_slice(&[0xFF; 0x10]);
+        struct_type1.extend_from_slice(&[0x04, b'O', b'E', b'M', 0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type1);
+        let test_struct = SMBiosSystemInformation::new(&parts);
+
+        assert!(matches!(
+            test_struct.uuid(),
+            Some(SystemUuidData::IdNotPresentButSettable)
+        ));
+        assert_eq!(
+            *test_struct.wakeup_type().unwrap(),
+            SystemWakeUpType::ModemRing
+        );
+        assert!(test_struct.sku_number().is_none());
+        assert!(test_struct.family().is_none());
+    }
+
+    #[test]
+    fn test_system_uuid() {
+        let raw = [
+            0xD2, 0x01, 0x25, 0x3E, 0x48, 0xE6, 0x11, 0xE8, 0xBA, 0xD3, 0x70, 0x20, 0x84, 0x0F,
+            0x9D, 0x47,
+        ];
+
+        match SystemUuidData::try_from(&raw[..]).unwrap() {
+            SystemUuidData::Uuid(system_uuid) => {
+                assert_eq!(system_uuid.time_low(), 0x3E2501D2);
+                assert_eq!(system_uuid.time_mid(), 0xE648);
+                assert_eq!(system_uuid.time_high_and_version(), 0xE811);
+                assert_eq!(
+                    format!("{}", system_uuid),
+                    "3E2501D2-E648-E811-BAD3-7020840F9D47"
+                );
+
+                #[cfg(feature = "uuid")]
+                assert_eq!(
+                    uuid::Uuid::from(&system_uuid).to_string(),
+                    "3e2501d2-e648-e811-bad3-7020840f9d47"
+                );
+            }
+            other => panic!("Expected Uuid, found {:?}", other),
+        }
+
+        assert!(matches!(
+            SystemUuidData::try_from(&[0x00; 0x10][..]).unwrap(),
+            SystemUuidData::IdNotPresent
+        ));
+        assert!(matches!(
+            SystemUuidData::try_from(&[0xFF; 0x10][..]).unwrap(),
+            SystemUuidData::IdNotPresentButSettable
+        ));
+    }
 }
@@ -32,44 +32,37 @@ impl<'a> SMBiosStruct<'a> for SMBiosSystemPowerControls<'a> {
 }
 
 impl<'a> SMBiosSystemPowerControls<'a> {
-    /// Next scheduled power-on month
-    ///
-    /// BCD value of the month on which the next scheduled
-    /// power-on is to occur, in the range 01h to 12h.
-    pub fn next_scheduled_power_on_month(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x04)
-    }
+    field_accessors! {
+        /// Next scheduled power-on month
+        ///
+        /// BCD value of the month on which the next scheduled
+        /// power-on is to occur, in the range 01h to 12h.
+        next_scheduled_power_on_month, next_scheduled_power_on_month_access: byte @ 0x04;
 
-    /// Next scheduled power-on day-of month
-    ///
-    /// BCD value of the day-of-month on which the next
-    /// scheduled power-on is to occur, in the range 01h to 31h.
-    pub fn next_scheduled_power_on_day_of_month(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x05)
-    }
+        /// Next scheduled power-on day-of month
+        ///
+        /// BCD value of the day-of-month on which the next
+        /// scheduled power-on is to occur, in the range 01h to 31h.
+        next_scheduled_power_on_day_of_month,
+        next_scheduled_power_on_day_of_month_access: byte @ 0x05;
 
-    /// Next scheduled power-on hour
-    ///
-    /// BCD value of the hour on which the next scheduled power-on
-    /// is to occur, in the range 00h to 23h.
-    pub fn next_scheduled_power_on_hour(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x06)
-    }
+        /// Next scheduled power-on hour
+        ///
+        /// BCD value of the hour on which the next scheduled power-on
+        /// is to occur, in the range 00h to 23h.
+        next_scheduled_power_on_hour, next_scheduled_power_on_hour_access: byte @ 0x06;
 
-    /// Next scheduled power-on minute
-    ///
-    /// BCD value of the minute on which the next scheduled
-    /// power-on is to occur, in the range 00h to 59h.
-    pub fn next_scheduled_power_on_minute(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x07)
-    }
+        /// Next scheduled power-on minute
+        ///
+        /// BCD value of the minute on which the next scheduled
+        /// power-on is to occur, in the range 00h to 59h.
+        next_scheduled_power_on_minute, next_scheduled_power_on_minute_access: byte @ 0x07;
 
-    /// Next scheduled power-on second
-    ///
-    /// BCD value of the second on which the next scheduled
-    /// power-on is to occur, in the range 00h to 59h.
-    pub fn next_scheduled_power_on_second(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x08)
+        /// Next scheduled power-on second
+        ///
+        /// BCD value of the second on which the next scheduled
+        /// power-on is to occur, in the range 00h to 59h.
+        next_scheduled_power_on_second, next_scheduled_power_on_second_access: byte @ 0x08;
     }
 }
 
@@ -1,4 +1,7 @@
-use crate::{Handle, SMBiosStruct, UndefinedStruct};
+use crate::{
+    Handle, SMBiosCoolingDevice, SMBiosData, SMBiosElectricalCurrentProbe, SMBiosStruct,
+    SMBiosVoltageProbe, UndefinedStruct,
+};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
 #[cfg(feature = "no_std")]
@@ -31,126 +34,126 @@ impl<'a> SMBiosStruct<'a> for SMBiosSystemPowerSupply<'a> {
 }
 
 impl<'a> SMBiosSystemPowerSupply<'a> {
-    /// Power unit group
-    ///
-    /// Power unit group to which this power supply is
-    /// associated
-    ///
-    /// Specifying the same Power Unit Group value for more
-    /// than one System Power Supply structure indicates a
-    /// redundant power supply configuration. The field’s value is
-    /// 00h if the power supply is not a member of a redundant
-    /// power unit. Non-zero values imply redundancy and that
-    /// at least one other power supply will be enumerated with
-    /// the same value.
-    pub fn power_unit_group(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x04)
-    }
-
-    /// Location
-    ///
-    /// Identifies the location of the power supply.
-    ///
-    /// EXAMPLES: "in the back, on the left-hand side" or
-    /// "Left Supply Bay"
-    pub fn location(&self) -> Option<String> {
-        self.parts.get_field_string(0x05)
-    }
-
-    /// Device name
-    ///
-    /// Number of the string that names the power supply device
-    ///
-    /// EXAMPLE: "DR-36"
-    pub fn device_name(&self) -> Option<String> {
-        self.parts.get_field_string(0x06)
-    }
-
-    /// Manufacturer
-    ///
-    /// Names the company that manufactured the supply
-    pub fn manufacturer(&self) -> Option<String> {
-        self.parts.get_field_string(0x07)
-    }
-
-    /// Serial number
-    ///
-    /// The serial number for the power supply
-    pub fn serial_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x08)
-    }
-
-    /// Asset tag number
-    pub fn asset_tag_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x09)
-    }
-
-    /// Model part number
-    ///
-    /// The OEM part order number
-    pub fn model_part_number(&self) -> Option<String> {
-        self.parts.get_field_string(0x0A)
-    }
-
-    /// Revision level
-    ///
-    /// Power supply revision string
-    ///
-    /// EXAMPLE: "2.30"
-    pub fn revision_level(&self) -> Option<String> {
-        self.parts.get_field_string(0x0B)
-    }
-
-    /// Max power capacity
-    ///
-    /// Maximum sustained power output in Watts
-    ///
-    /// Set to 0x8000 if unknown. Note that the units specified by
-    /// the DMTF for this field are milliWatts.
-    pub fn max_power_capacity(&self) -> Option<MaxPowerCapacity> {
-        self.parts
-            .get_field_word(0x0C)
-            .map(|raw| MaxPowerCapacity::from(raw))
-    }
-
-    /// Power supply characteristics
-    pub fn power_supply_characteristics(&self) -> Option<PowerSupplyCharacteristics> {
-        self.parts
-            .get_field_word(0x0E)
-            .map(|raw| PowerSupplyCharacteristics::from(raw))
+    field_accessors! {
+        /// Power unit group
+        ///
+        /// Power unit group to which this power supply is
+        /// associated
+        ///
+        /// Specifying the same Power Unit Group value for more
+        /// than one System Power Supply structure indicates a
+        /// redundant power supply configuration. The field’s value is
+        /// 00h if the power supply is not a member of a redundant
+        /// power unit. Non-zero values imply redundancy and that
+        /// at least one other power supply will be enumerated with
+        /// the same value.
+        power_unit_group, power_unit_group_access: byte @ 0x04;
+
+        /// Location
+        ///
+        /// Identifies the location of the power supply.
+        ///
+        /// EXAMPLES: "in the back, on the left-hand side" or
+        /// "Left Supply Bay"
+        location, location_access: string @ 0x05;
+
+        /// Device name
+        ///
+        /// Number of the string that names the power supply device
+        ///
+        /// EXAMPLE: "DR-36"
+        device_name, device_name_access: string @ 0x06;
+
+        /// Manufacturer
+        ///
+        /// Names the company that manufactured the supply
+        manufacturer, manufacturer_access: string @ 0x07;
+
+        /// Serial number
+        ///
+        /// The serial number for the power supply
+        serial_number, serial_number_access: string @ 0x08;
+
+        /// Asset tag number
+        asset_tag_number, asset_tag_number_access: string @ 0x09;
+
+        /// Model part number
+        ///
+        /// The OEM part order number
+        model_part_number, model_part_number_access: string @ 0x0A;
+
+        /// Revision level
+        ///
+        /// Power supply revision string
+        ///
+        /// EXAMPLE: "2.30"
+        revision_level, revision_level_access: string @ 0x0B;
+
+        /// Max power capacity
+        ///
+        /// Maximum sustained power output in Watts
+        ///
+        /// Set to 0x8000 if unknown. Note that the units specified by
+        /// the DMTF for this field are milliWatts.
+        max_power_capacity, max_power_capacity_access: word @ 0x0C => MaxPowerCapacity;
+
+        /// Power supply characteristics
+        power_supply_characteristics, power_supply_characteristics_access: word @ 0x0E
+            => PowerSupplyCharacteristics;
+
+        /// Input voltage probe handle
+        ///
+        /// Handle, or instance number, of a [super::SMBiosVoltageProbe] (Type 26)
+        /// monitoring this power supply's input voltage
+        ///
+        /// A value of 0xFFFF indicates that no probe is provided
+        input_voltage_probe_handle, input_voltage_probe_handle_access: handle @ 0x10;
+
+        /// Cooling device handle
+        ///
+        /// Handle, or instance number, of a [super::SMBiosCoolingDevice] (Type
+        /// 27) associated with this power supply
+        ///
+        /// A value of 0xFFFF indicates that no cooling device is
+        /// provided.
+        cooling_device_handle, cooling_device_handle_access: handle @ 0x12;
+
+        /// Input current probe handle
+        ///
+        /// Handle, or instance number, of the [super::SMBiosElectricalCurrentProbe]
+        /// (Type 29) monitoring this power supply’s input
+        /// current
+        ///
+        /// A value of 0xFFFF indicates that no current probe is
+        /// provided.
+        input_current_probe_handle, input_current_probe_handle_access: handle @ 0x14;
     }
 
-    /// Input voltage probe handle
+    /// Input voltage probe
     ///
-    /// Handle, or instance number, of a [super::SMBiosVoltageProbe] (Type 26)
-    /// monitoring this power supply's input voltage
-    ///
-    /// A value of 0xFFFF indicates that no probe is provided
-    pub fn input_voltage_probe_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x10)
+    /// The [SMBiosVoltageProbe] referenced by [Self::input_voltage_probe_handle],
+    /// or [None] if no probe is provided or `data` has no such structure.
+    pub fn input_voltage_probe<'b>(&self, data: &'b SMBiosData) -> Option<SMBiosVoltageProbe<'b>> {
+        data.resolve(&self.input_voltage_probe_handle()?)
     }
 
-    /// Cooling device handle
-    ///
-    /// Handle, or instance number, of a [super::SMBiosCoolingDevice] (Type
-    /// 27) associated with this power supply
+    /// Cooling device
     ///
-    /// A value of 0xFFFF indicates that no cooling device is
-    /// provided.
-    pub fn cooling_device_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x12)
+    /// The [SMBiosCoolingDevice] referenced by [Self::cooling_device_handle],
+    /// or [None] if no cooling device is provided or `data` has no such structure.
+    pub fn cooling_device<'b>(&self, data: &'b SMBiosData) -> Option<SMBiosCoolingDevice<'b>> {
+        data.resolve(&self.cooling_device_handle()?)
     }
 
-    /// Input current probe handle
+    /// Input current probe
     ///
-    /// Handle, or instance number, of the [super::SMBiosElectricalCurrentProbe]
-    /// (Type 29) monitoring this power supply’s input
-    /// current
-    ///
-    /// A value of 0xFFFF indicates that no current probe is
-    /// provided.
-    pub fn input_current_probe_handle(&self) -> Option<Handle> {
-        self.parts.get_field_handle(0x14)
+    /// The [SMBiosElectricalCurrentProbe] referenced by [Self::input_current_probe_handle],
+    /// or [None] if no current probe is provided or `data` has no such structure.
+    pub fn input_current_probe<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosElectricalCurrentProbe<'b>> {
+        data.resolve(&self.input_current_probe_handle()?)
     }
 }
 
@@ -495,4 +498,71 @@ mod tests {
         assert_eq!(*test_struct.cooling_device_handle().unwrap(), 56);
         assert_eq!(*test_struct.input_current_probe_handle().unwrap(), 57);
     }
+
+    #[test]
+    fn test_power_supply_characteristics() {
+        // hot-replaceable, present, auto-switch, OK, switching
+        let characteristics = PowerSupplyCharacteristics::from(0b0001_0001_1010_0011);
+        assert!(characteristics.hot_replaceable());
+        assert!(characteristics.is_present());
+        assert!(!characteristics.unplugged_from_wall());
+        assert_eq!(
+            characteristics.input_voltage_range_switching(),
+            InputVoltageRangeSwitching::AutoSwitch
+        );
+        assert_eq!(characteristics.power_supply_status(), PowerSupplyStatus::OK);
+        assert_eq!(
+            characteristics.power_supply_type(),
+            PowerSupplyType::Switching
+        );
+
+        // unplugged, wide range, critical, UPS
+        let characteristics = PowerSupplyCharacteristics::from(0b0001_1010_1010_1100);
+        assert!(!characteristics.hot_replaceable());
+        assert!(!characteristics.is_present());
+        assert!(characteristics.unplugged_from_wall());
+        assert_eq!(
+            characteristics.input_voltage_range_switching(),
+            InputVoltageRangeSwitching::WideRange
+        );
+        assert_eq!(
+            characteristics.power_supply_status(),
+            PowerSupplyStatus::Critical
+        );
+        assert_eq!(characteristics.power_supply_type(), PowerSupplyType::Ups);
+
+        let characteristics = PowerSupplyCharacteristics::from(0);
+        assert_eq!(
+            characteristics.input_voltage_range_switching(),
+            InputVoltageRangeSwitching::None
+        );
+        assert_eq!(
+            characteristics.power_supply_status(),
+            PowerSupplyStatus::None
+        );
+        assert_eq!(characteristics.power_supply_type(), PowerSupplyType::None);
+    }
+
+    #[test]
+    fn test_resolve_handles() {
+        let table = vec![
+            // power supply: voltage probe 0x0036, no cooling device, current probe 0x0037
+            0x27, 0x16, 0x3A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80,
+            0xA2, 0x11, 0x36, 0x00, 0xFF, 0xFF, 0x37, 0x00, 0x00, 0x00,
+            // voltage probe 0x0036
+            0x1A, 0x14, 0x36, 0x00, 0x00, 0x6A, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80,
+            0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // temperature probe 0x0037 (not a current probe)
+            0x1C, 0x14, 0x37, 0x00, 0x00, 0x6A, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80,
+            0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let data = SMBiosData::from_vec_and_version(table, None);
+        let power_supply = data.first::<SMBiosSystemPowerSupply<'_>>().unwrap();
+
+        let voltage_probe = power_supply.input_voltage_probe(&data).unwrap();
+        assert_eq!(*voltage_probe.parts().header.handle(), 0x0036);
+        assert!(power_supply.cooling_device(&data).is_none());
+        assert!(power_supply.input_current_probe(&data).is_none());
+    }
 }
@@ -32,56 +32,38 @@ impl<'a> SMBiosStruct<'a> for SMBiosSystemReset<'a> {
 }
 
 impl<'a> SMBiosSystemReset<'a> {
-    /// Capabilities bit-field
-    ///
-    /// Identifies the system-reset capabilities for the system
-    pub fn capabilities(&self) -> Option<SystemResetCapabilities> {
-        self.parts
-            .get_field_byte(0x04)
-            .map(|raw| SystemResetCapabilities::from(raw))
-    }
-
-    /// Reset count
-    ///
-    /// Number of automatic system resets since the last intentional
-    /// reset
-    pub fn reset_count(&self) -> Option<ResetCount> {
-        self.parts
-            .get_field_word(0x05)
-            .map(|raw| ResetCount::from(raw))
-    }
-
-    /// Reset limit
-    ///
-    /// Number of consecutive times the system reset is attempted
-    pub fn reset_limit(&self) -> Option<ResetLimit> {
-        self.parts
-            .get_field_word(0x07)
-            .map(|raw| ResetLimit::from(raw))
-    }
-
-    /// Timer interval
-    ///
-    /// Number of minutes to use for the watchdog timer
-    ///
-    /// If the timer is not reset within this interval, the system reset
-    /// timeout begins.
-    pub fn timer_interval(&self) -> Option<TimerInterval> {
-        self.parts
-            .get_field_word(0x09)
-            .map(|raw| TimerInterval::from(raw))
-    }
-
-    /// Timeout
-    ///
-    /// Number of minutes before the reboot is initiated
-    ///
-    /// It is used after a system power cycle, system reset (local or
-    /// remote), and automatic system reset.
-    pub fn timeout(&self) -> Option<Timeout> {
-        self.parts
-            .get_field_word(0x0B)
-            .map(|raw| Timeout::from(raw))
+    field_accessors! {
+        /// Capabilities bit-field
+        ///
+        /// Identifies the system-reset capabilities for the system
+        capabilities, capabilities_access: byte @ 0x04 => SystemResetCapabilities;
+
+        /// Reset count
+        ///
+        /// Number of automatic system resets since the last intentional
+        /// reset
+        reset_count, reset_count_access: word @ 0x05 => ResetCount;
+
+        /// Reset limit
+        ///
+        /// Number of consecutive times the system reset is attempted
+        reset_limit, reset_limit_access: word @ 0x07 => ResetLimit;
+
+        /// Timer interval
+        ///
+        /// Number of minutes to use for the watchdog timer
+        ///
+        /// If the timer is not reset within this interval, the system reset
+        /// timeout begins.
+        timer_interval, timer_interval_access: word @ 0x09 => TimerInterval;
+
+        /// Timeout
+        ///
+        /// Number of minutes before the reboot is initiated
+        ///
+        /// It is used after a system power cycle, system reset (local or
+        /// remote), and automatic system reset.
+        timeout, timeout_access: word @ 0x0B => Timeout;
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{SMBiosStruct, StructFields, UndefinedStruct};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use core::{convert::TryInto, fmt, ops::Deref, any};
 #[cfg(feature = "no_std")]
@@ -25,37 +25,21 @@ impl<'a> SMBiosStruct<'a> for SMBiosSystemSlot<'a> {
 }
 
 impl<'a> SMBiosSystemSlot<'a> {
-    /// Slot Designation
-    pub fn slot_designation(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
+    field_accessors! {
+        /// Slot Designation
+        slot_designation, slot_designation_access: string @ 0x04;
 
-    /// Slot Type
-    pub fn system_slot_type(&self) -> Option<SystemSlotTypeData> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| SystemSlotTypeData::from(raw))
-    }
+        /// Slot Type
+        system_slot_type, system_slot_type_access: byte @ 0x05 => SystemSlotTypeData;
 
-    /// Slot Data Bus Width
-    pub fn slot_data_bus_width(&self) -> Option<SlotWidthData> {
-        self.parts
-            .get_field_byte(0x06)
-            .map(|raw| SlotWidthData::from(raw))
-    }
+        /// Slot Data Bus Width
+        slot_data_bus_width, slot_data_bus_width_access: byte @ 0x06 => SlotWidthData;
 
-    /// Current Usage
-    pub fn current_usage(&self) -> Option<SlotCurrentUsageData> {
-        self.parts
-            .get_field_byte(0x07)
-            .map(|raw| SlotCurrentUsageData::from(raw))
-    }
+        /// Current Usage
+        current_usage, current_usage_access: byte @ 0x07 => SlotCurrentUsageData;
 
-    /// Slot Length
-    pub fn slot_length(&self) -> Option<SlotLengthData> {
-        self.parts
-            .get_field_byte(0x08)
-            .map(|raw| SlotLengthData::from(raw))
+        /// Slot Length
+        slot_length, slot_length_access: byte @ 0x08 => SlotLengthData;
     }
 
     /// Slot Id
@@ -65,44 +49,26 @@ impl<'a> SMBiosSystemSlot<'a> {
             .map(|id| SystemSlotId(id.try_into().unwrap()))
     }
 
-    /// Slot Characteristics 1
-    pub fn slot_characteristics_1(&self) -> Option<SystemSlotCharacteristics1> {
-        self.parts
-            .get_field_byte(0x0B)
-            .map(|raw| SystemSlotCharacteristics1::from(raw))
-    }
+    field_accessors! {
+        /// Slot Characteristics 1
+        slot_characteristics_1, slot_characteristics_1_access: byte @ 0x0B
+            => SystemSlotCharacteristics1;
 
-    /// Slot Characteristics 2
-    pub fn slot_characteristics_2(&self) -> Option<SystemSlotCharacteristics2> {
-        self.parts
-            .get_field_byte(0x0C)
-            .map(|raw| SystemSlotCharacteristics2::from(raw))
-    }
+        /// Slot Characteristics 2
+        slot_characteristics_2, slot_characteristics_2_access: byte @ 0x0C
+            => SystemSlotCharacteristics2;
 
-    /// Segment Group Number (Base)
-    pub fn segment_group_number(&self) -> Option<SegmentGroupNumber> {
-        self.parts
-            .get_field_word(0x0D)
-            .map(|raw| SegmentGroupNumber::from(raw))
-    }
+        /// Segment Group Number (Base)
+        segment_group_number, segment_group_number_access: word @ 0x0D => SegmentGroupNumber;
 
-    /// Bus Number (Base)
-    pub fn bus_number(&self) -> Option<BusNumber> {
-        self.parts
-            .get_field_byte(0x0F)
-            .map(|raw| BusNumber::from(raw))
-    }
+        /// Bus Number (Base)
+        bus_number, bus_number_access: byte @ 0x0F => BusNumber;
 
-    /// Device/Function Number (Base)
-    pub fn device_function_number(&self) -> Option<DeviceFunctionNumber> {
-        self.parts
-            .get_field_byte(0x10)
-            .map(|raw| DeviceFunctionNumber::from(raw))
-    }
+        /// Device/Function Number (Base)
+        device_function_number, device_function_number_access: byte @ 0x10 => DeviceFunctionNumber;
 
-    /// Data Bus Width (Base)
-    pub fn data_bus_width(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x11)
+        /// Data Bus Width (Base)
+        data_bus_width, data_bus_width_access: byte @ 0x11;
     }
 
     /// Number of peer Segment/Bus/Device/Function/Width groups that follow
@@ -124,9 +90,12 @@ impl<'a> SMBiosSystemSlot<'a> {
     }
 
     /// Slot Information
+    ///
+    /// The meaning of this field depends on the slot type; for PCI Express slots
+    /// it holds the PCI Express generation (for example 4 for PCI Express Gen 4).
     pub fn slot_information(&self) -> Option<u8> {
         self.peer_group_size()
-            .and_then(|size| self.parts.get_field_byte(size + 0x13)) // TODO: watch for an errata, 0x13 is 0x14 in the 3.4.0 spec.
+            .and_then(|size| self.parts.get_field_byte(size + 0x13))
     }
 
     /// Slot Physical Width
@@ -160,6 +129,17 @@ impl<'a> SMBiosSystemSlot<'a> {
         self.peer_group_size()
             .and_then(|size| self.parts.get_field_word(size + 0x15))
     }
+
+    /// Slot Height
+    ///
+    /// Indicates the maximum supported card height for the slot.
+    pub fn slot_height(&self) -> Option<SlotHeightData> {
+        self.peer_group_size().and_then(|size| {
+            self.parts
+                .get_field_byte(size + 0x17)
+                .map(SlotHeightData::from)
+        })
+    }
 }
 
 impl fmt::Debug for SMBiosSystemSlot<'_> {
@@ -183,6 +163,7 @@ impl fmt::Debug for SMBiosSystemSlot<'_> {
             .field("slot_information", &self.slot_information())
             .field("slot_physical_width", &self.slot_physical_width())
             .field("slot_pitch", &self.slot_pitch())
+            .field("slot_height", &self.slot_height())
             .finish()
     }
 }
@@ -192,7 +173,7 @@ impl Serialize for SMBiosSystemSlot<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosSystemSlot", 18)?;
+        let mut state = serializer.serialize_struct("SMBiosSystemSlot", 19)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field("slot_designation", &self.slot_designation())?;
         state.serialize_field("system_slot_type", &self.system_slot_type())?;
@@ -211,6 +192,7 @@ impl Serialize for SMBiosSystemSlot<'_> {
         state.serialize_field("slot_information", &self.slot_information())?;
         state.serialize_field("slot_physical_width", &self.slot_physical_width())?;
         state.serialize_field("slot_pitch", &self.slot_pitch())?;
+        state.serialize_field("slot_height", &self.slot_height())?;
         state.end()
     }
 }
@@ -808,6 +790,82 @@ pub enum SlotLength {
     None,
 }
 
+/// # System Slot Height Data
+pub struct SlotHeightData {
+    /// Raw value
+    ///
+    /// _raw_ is most useful when _value_ is None.
+    /// This is most likely to occur when the standard was updated but
+    /// this library code has not been updated to match the current
+    /// standard.
+    pub raw: u8,
+    /// The contained [SlotHeight] value
+    pub value: SlotHeight,
+}
+
+impl Deref for SlotHeightData {
+    type Target = SlotHeight;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl From<u8> for SlotHeightData {
+    fn from(raw: u8) -> Self {
+        use SlotHeight::*;
+        SlotHeightData {
+            value: match raw {
+                0x00 => NotApplicable,
+                0x01 => Other,
+                0x02 => Unknown,
+                0x03 => FullHeight,
+                0x04 => LowProfile,
+                _ => None,
+            },
+            raw,
+        }
+    }
+}
+
+impl fmt::Debug for SlotHeightData {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(any::type_name::<SlotHeightData>())
+            .field("raw", &self.raw)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl Serialize for SlotHeightData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("SlotHeightData", 2)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+
+/// # System Slot Height
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum SlotHeight {
+    /// Not applicable
+    NotApplicable,
+    /// Other
+    Other,
+    /// Unknown
+    Unknown,
+    /// Full height
+    FullHeight,
+    /// Low-profile
+    LowProfile,
+    /// A value unknown to this standard, check the raw value
+    None,
+}
+
 /// # System Slot Characteristics 1
 #[derive(PartialEq, Eq)]
 pub struct SystemSlotCharacteristics1 {
@@ -1335,13 +1393,41 @@ mod tests {
         assert_eq!(first.data_bus_width(), Some(0x06));
 
         // 3.4 fields
-        // TODO:
-        // Note: There will be an erratum published for these fields.  For this test case
-        // the field offsets have been shifted back by 1 from 0x14, 0x15, 0x16 (+ 5 * n), to 0x13...
         assert_eq!(test_struct.slot_information(), Some(0x07));
         assert_eq!(*test_struct.slot_physical_width().unwrap(), SlotWidth::X1);
         assert_eq!(test_struct.slot_pitch(), Some(0x09AB));
 
+        // 3.5 fields are absent from a 3.4 structure
+        assert!(test_struct.slot_height().is_none());
+
         println!("{:?}", test_struct);
+
+        // 3.5 System Slot structure with two peer groups
+        let struct_type9 = vec![
+            0x09, 0x22, 0x1D, 0x00, 0x01, 0xB6, 0x0D, 0x03, 0x04, 0x01, 0x00, 0x0C, 0x01, 0x00,
+            0x00, 0x00, 0x08, 0x0D, 0x02, 0x00, 0x00, 0x65, 0x00, 0x08, 0x01, 0x00, 0x66, 0x08,
+            0x08, 0x04, 0x0D, 0x00, 0x00, 0x04, 0x53, 0x4C, 0x4F, 0x54, 0x31, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type9);
+        let test_struct = SMBiosSystemSlot::new(&parts);
+
+        assert_eq!(test_struct.slot_designation(), Some("SLOT1".to_string()));
+        assert_eq!(test_struct.peer_group_count(), Some(2));
+
+        let peers: Vec<SlotPeerGroup<'_>> = test_struct.peer_group_iterator().collect();
+        assert_eq!(peers.len(), 2);
+        assert_eq!(peers[0].segment_group_number(), Some(0x0000));
+        assert_eq!(peers[0].bus_number(), Some(0x65));
+        assert_eq!(peers[0].device_function_number(), Some(0x00));
+        assert_eq!(peers[0].data_bus_width(), Some(0x08));
+        assert_eq!(peers[1].segment_group_number(), Some(0x0001));
+        assert_eq!(peers[1].bus_number(), Some(0x66));
+        assert_eq!(peers[1].device_function_number(), Some(0x08));
+        assert_eq!(peers[1].data_bus_width(), Some(0x08));
+
+        assert_eq!(test_struct.slot_information(), Some(0x04));
+        assert_eq!(*test_struct.slot_physical_width().unwrap(), SlotWidth::X16);
+        assert_eq!(test_struct.slot_pitch(), Some(0x0000));
+        assert_eq!(*test_struct.slot_height().unwrap(), SlotHeight::LowProfile);
     }
 }
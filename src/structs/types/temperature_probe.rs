@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{MaybeValue, SMBiosStruct, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
 #[cfg(feature = "no_std")]
@@ -27,94 +27,65 @@ impl<'a> SMBiosStruct<'a> for SMBiosTemperatureProbe<'a> {
 }
 
 impl<'a> SMBiosTemperatureProbe<'a> {
-    /// Description
-    ///
-    /// additional descriptive information about the probe or its location
-    pub fn description(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
-
-    /// Location and status
-    ///
-    /// Probe’s physical location and the status of the temperature
-    /// monitored by this temperature probe
-    pub fn location_and_status(&self) -> Option<TemperatureProbeLocationAndStatus> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| TemperatureProbeLocationAndStatus::from(raw))
-    }
-
-    /// Maximum value
-    ///
-    /// Maximum temperature readable by this probe, in 1/10th degrees C
-    ///
-    /// If the value is unknown, the field is set to 0x8000.
-    pub fn maximum_value(&self) -> Option<ProbeTemperature> {
-        self.parts
-            .get_field_word(0x06)
-            .map(|raw| ProbeTemperature::from(raw))
-    }
-
-    /// Minimum value
-    ///
-    /// Minimum temperature readable by this probe, in 1/10th degrees C
-    ///
-    /// If the value is unknown, the field is set to 0x8000.
-    pub fn minimum_value(&self) -> Option<ProbeTemperature> {
-        self.parts
-            .get_field_word(0x08)
-            .map(|raw| ProbeTemperature::from(raw))
-    }
-
-    /// Resolution
-    ///
-    /// Resolution for the probe’s reading, in 1/1000th degrees C
-    ///
-    /// If the value is unknown, the field is set to 0x8000.
-    pub fn resolution(&self) -> Option<TemperatureProbeResolution> {
-        self.parts
-            .get_field_word(0x0A)
-            .map(|raw| TemperatureProbeResolution::from(raw))
-    }
-
-    /// Tolerance
-    ///
-    /// Tolerance for reading from this probe, in plus/minus 1/10th degrees C
-    ///
-    /// If the value is unknown, the field is set to 0x8000.
-    pub fn tolerance(&self) -> Option<ProbeTemperature> {
-        self.parts
-            .get_field_word(0x0C)
-            .map(|raw| ProbeTemperature::from(raw))
-    }
-
-    /// Accuracy
-    ///
-    /// Accuracy for reading from this probe, in plus/minus 1/100th of a percent
-    ///
-    /// If the value is unknown, the field is set to 0x8000.
-    pub fn accuracy(&self) -> Option<TemperatureProbeAccuracy> {
-        self.parts
-            .get_field_word(0x0E)
-            .map(|raw| TemperatureProbeAccuracy::from(raw))
-    }
-
-    /// OEM defined
-    ///
-    /// OEM- or BIOS vendor-specific information
-    pub fn oem_defined(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x10)
-    }
-
-    /// Nominal value for the probe’s reading in 1/10th degrees C
-    ///
-    /// If the value is unknown, the field is set to 0x8000. This field is
-    /// present in the structure only if the structure’s Length is larger
-    /// than 14h.
-    pub fn nominal_value(&self) -> Option<ProbeTemperature> {
-        self.parts
-            .get_field_word(0x14)
-            .map(|raw| ProbeTemperature::from(raw))
+    field_accessors! {
+        /// Description
+        ///
+        /// additional descriptive information about the probe or its location
+        description, description_access: string @ 0x04;
+
+        /// Location and status
+        ///
+        /// Probe’s physical location and the status of the temperature
+        /// monitored by this temperature probe
+        location_and_status, location_and_status_access: byte @ 0x05
+            => TemperatureProbeLocationAndStatus;
+
+        /// Maximum value
+        ///
+        /// Maximum temperature readable by this probe, in 1/10th degrees C
+        ///
+        /// If the value is unknown, the field is set to 0x8000.
+        maximum_value, maximum_value_access: word @ 0x06 => ProbeTemperature;
+
+        /// Minimum value
+        ///
+        /// Minimum temperature readable by this probe, in 1/10th degrees C
+        ///
+        /// If the value is unknown, the field is set to 0x8000.
+        minimum_value, minimum_value_access: word @ 0x08 => ProbeTemperature;
+
+        /// Resolution
+        ///
+        /// Resolution for the probe’s reading, in 1/1000th degrees C
+        ///
+        /// If the value is unknown, the field is set to 0x8000.
+        resolution, resolution_access: word @ 0x0A => TemperatureProbeResolution;
+
+        /// Tolerance
+        ///
+        /// Tolerance for reading from this probe, in plus/minus 1/10th degrees C
+        ///
+        /// If the value is unknown, the field is set to 0x8000.
+        tolerance, tolerance_access: word @ 0x0C => ProbeTemperature;
+
+        /// Accuracy
+        ///
+        /// Accuracy for reading from this probe, in plus/minus 1/100th of a percent
+        ///
+        /// If the value is unknown, the field is set to 0x8000.
+        accuracy, accuracy_access: word @ 0x0E => TemperatureProbeAccuracy;
+
+        /// OEM defined
+        ///
+        /// OEM- or BIOS vendor-specific information
+        oem_defined, oem_defined_access: dword @ 0x10;
+
+        /// Nominal value for the probe’s reading in 1/10th degrees C
+        ///
+        /// If the value is unknown, the field is set to 0x8000. This field is
+        /// present in the structure only if the structure’s Length is larger
+        /// than 14h.
+        nominal_value, nominal_value_access: word @ 0x14 => ProbeTemperature;
     }
 }
 
@@ -251,7 +222,7 @@ pub enum TemperatureProbeLocation {
     Disk,
     /// Peripheral Bay
     PeripheralBay,
-    /// System Management Moduel
+    /// System Management Module
     SystemManagementModule,
     /// Motherboard
     Motherboard,
@@ -299,10 +270,12 @@ impl From<u8> for TemperatureProbeLocation {
 }
 
 /// # Probe Temperature
-#[derive(Serialize, Debug)]
+///
+/// The field is a signed value, allowing temperatures below 0 degrees C.
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum ProbeTemperature {
     /// Temperature in 1/10 degrees C
-    OneTenthDegreesC(u16),
+    OneTenthDegreesC(i16),
     /// Temperature is unknown
     Unknown,
 }
@@ -311,13 +284,22 @@ impl From<u16> for ProbeTemperature {
     fn from(raw: u16) -> Self {
         match raw {
             0x8000 => ProbeTemperature::Unknown,
-            _ => ProbeTemperature::OneTenthDegreesC(raw),
+            _ => ProbeTemperature::OneTenthDegreesC(raw as i16),
+        }
+    }
+}
+
+impl From<ProbeTemperature> for MaybeValue<i16> {
+    fn from(temperature: ProbeTemperature) -> Self {
+        match temperature {
+            ProbeTemperature::OneTenthDegreesC(temperature) => MaybeValue::Value(temperature),
+            ProbeTemperature::Unknown => MaybeValue::Unknown,
         }
     }
 }
 
 /// # Temperature Probe Resolution
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum TemperatureProbeResolution {
     /// Resolution for the probe's reading in 1/1000 degrees C
     OneOneThousandthDegreesC(u16),
@@ -334,11 +316,22 @@ impl From<u16> for TemperatureProbeResolution {
     }
 }
 
+impl From<TemperatureProbeResolution> for MaybeValue<u16> {
+    fn from(resolution: TemperatureProbeResolution) -> Self {
+        match resolution {
+            TemperatureProbeResolution::OneOneThousandthDegreesC(resolution) => {
+                MaybeValue::Value(resolution)
+            }
+            TemperatureProbeResolution::Unknown => MaybeValue::Unknown,
+        }
+    }
+}
+
 /// # Temperature Probe Accuracy
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum TemperatureProbeAccuracy {
-    /// Accuracy for the probe's reading in 1/100 degrees C
-    OneOneHundredthDegreesC(u16),
+    /// Accuracy for the probe's reading in 1/100th of a percent
+    OneOneHundredthPercent(u16),
     /// Accuracy is unknown
     Unknown,
 }
@@ -347,7 +340,18 @@ impl From<u16> for TemperatureProbeAccuracy {
     fn from(raw: u16) -> Self {
         match raw {
             0x8000 => TemperatureProbeAccuracy::Unknown,
-            _ => TemperatureProbeAccuracy::OneOneHundredthDegreesC(raw),
+            _ => TemperatureProbeAccuracy::OneOneHundredthPercent(raw),
+        }
+    }
+}
+
+impl From<TemperatureProbeAccuracy> for MaybeValue<u16> {
+    fn from(accuracy: TemperatureProbeAccuracy) -> Self {
+        match accuracy {
+            TemperatureProbeAccuracy::OneOneHundredthPercent(accuracy) => {
+                MaybeValue::Value(accuracy)
+            }
+            TemperatureProbeAccuracy::Unknown => MaybeValue::Unknown,
         }
     }
 }
@@ -389,7 +393,7 @@ mod tests {
             ProbeTemperature::Unknown => (),
         }
         match test_struct.accuracy().unwrap() {
-            TemperatureProbeAccuracy::OneOneHundredthDegreesC(_) => panic!("expected unknown"),
+            TemperatureProbeAccuracy::OneOneHundredthPercent(_) => panic!("expected unknown"),
             TemperatureProbeAccuracy::Unknown => (),
         }
         assert_eq!(test_struct.oem_defined(), Some(0));
@@ -398,4 +402,57 @@ mod tests {
             ProbeTemperature::Unknown => (),
         }
     }
+
+    #[test]
+    fn test_location_and_status_and_values() {
+        let struct_type28 = vec![
+            0x1C, 0x16, 0x2B, 0x00, 0x00, 0x8F, 0xE8, 0x03, 0x70, 0xFE, 0xF4, 0x01, 0x14, 0x00,
+            0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x5E, 0x01, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type28);
+        let test_struct = SMBiosTemperatureProbe::new(&parts);
+
+        let location_and_status = test_struct.location_and_status().unwrap();
+        assert_eq!(
+            location_and_status.status(),
+            TemperatureProbeStatus::NonCritical
+        );
+        assert_eq!(
+            location_and_status.location(),
+            TemperatureProbeLocation::DriveBackPlane
+        );
+        assert_eq!(
+            test_struct.maximum_value(),
+            Some(ProbeTemperature::OneTenthDegreesC(1000))
+        );
+        assert_eq!(
+            test_struct.minimum_value(),
+            Some(ProbeTemperature::OneTenthDegreesC(-400))
+        );
+        assert_eq!(
+            test_struct.resolution(),
+            Some(TemperatureProbeResolution::OneOneThousandthDegreesC(500))
+        );
+        assert_eq!(
+            test_struct.tolerance(),
+            Some(ProbeTemperature::OneTenthDegreesC(20))
+        );
+        assert_eq!(
+            test_struct.accuracy(),
+            Some(TemperatureProbeAccuracy::Unknown)
+        );
+        assert_eq!(
+            test_struct.nominal_value(),
+            Some(ProbeTemperature::OneTenthDegreesC(350))
+        );
+        assert_eq!(
+            MaybeValue::from(test_struct.minimum_value().unwrap()),
+            MaybeValue::Value(-400)
+        );
+        assert_eq!(
+            MaybeValue::from(test_struct.accuracy().unwrap()),
+            MaybeValue::<u16>::Unknown
+        );
+    }
 }
@@ -1,8 +1,8 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{SMBiosStruct, StructFields, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::{array::TryFromSliceError, convert::TryFrom, fmt, ops::Deref, any};
 #[cfg(feature = "no_std")]
-use alloc::string::String;
+use alloc::{format, string::String};
 
 /// # TPM Device (Type 43)
 pub struct SMBiosTpmDevice<'a> {
@@ -36,73 +36,59 @@ impl<'a> SMBiosTpmDevice<'a> {
             .and_then(|array| Some(VendorId::try_from(array).expect("Vendor Id is 4 bytes")))
     }
 
-    /// Major spec version
-    ///
-    /// Major TPM version supported by the TPM device. For
-    /// example, the value is 01h for TPM v1.2 and is 02h for
-    /// TPM v2.0.
-    pub fn major_spec_version(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x08)
-    }
-
-    /// Minor spec version
-    ///
-    /// Minor TPM version supported by the TPM device. For
-    /// example, the value is 02h for TPM v1.2 and is 00h for
-    /// TPM v2.0.
-    pub fn minor_spec_version(&self) -> Option<u8> {
-        self.parts.get_field_byte(0x09)
-    }
-
-    /// Firmware version 1
-    ///
-    /// For Major Spec Version 01h, this field contains the
-    /// TPM_VERSION structure defined in the TPM Main
-    /// Specification, Part 2, Section 5.3.
-    ///
-    /// For Major Spec Version 02h, this field contains the
-    /// most significant 32 bits of a TPM vendor-specific value
-    /// for firmware version (see
-    /// TPM_PT_FIRMWARE_VERSION_1 in TPM Structures
-    /// specification).
-    pub fn firmware_version_1(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x0A)
-    }
-
-    /// Firmware version 2
-    ///
-    /// For Major Spec Version 01h, this field contains 00h.
-    ///
-    /// For Major Spec Version 02h, this field contains the
-    /// least significant 32 bits of a TPM vendor-specific value
-    /// for firmware version (see
-    /// TPM_PT_FIRMWARE_VERSION_2 in TPM Structures
-    /// specification).
-    pub fn firmware_version_2(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x0E)
-    }
-
-    /// Description
-    ///
-    /// Descriptive information of the TPM device.
-    pub fn description(&self) -> Option<String> {
-        self.parts.get_field_string(0x12)
-    }
-
-    /// Characteristics
-    ///
-    /// TPM device characteristics information.
-    pub fn characteristics(&self) -> Option<TpmDeviceCharacteristics> {
-        self.parts
-            .get_field_qword(0x13)
-            .map(|raw| TpmDeviceCharacteristics::from(raw))
-    }
-
-    /// OEM defined
-    ///
-    /// OEM- or BIOS vendor-specific information
-    pub fn oem_defined(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x1B)
+    field_accessors! {
+        /// Major spec version
+        ///
+        /// Major TPM version supported by the TPM device. For
+        /// example, the value is 01h for TPM v1.2 and is 02h for
+        /// TPM v2.0.
+        major_spec_version, major_spec_version_access: byte @ 0x08;
+
+        /// Minor spec version
+        ///
+        /// Minor TPM version supported by the TPM device. For
+        /// example, the value is 02h for TPM v1.2 and is 00h for
+        /// TPM v2.0.
+        minor_spec_version, minor_spec_version_access: byte @ 0x09;
+
+        /// Firmware version 1
+        ///
+        /// For Major Spec Version 01h, this field contains the
+        /// TPM_VERSION structure defined in the TPM Main
+        /// Specification, Part 2, Section 5.3.
+        ///
+        /// For Major Spec Version 02h, this field contains the
+        /// most significant 32 bits of a TPM vendor-specific value
+        /// for firmware version (see
+        /// TPM_PT_FIRMWARE_VERSION_1 in TPM Structures
+        /// specification).
+        firmware_version_1, firmware_version_1_access: dword @ 0x0A;
+
+        /// Firmware version 2
+        ///
+        /// For Major Spec Version 01h, this field contains 00h.
+        ///
+        /// For Major Spec Version 02h, this field contains the
+        /// least significant 32 bits of a TPM vendor-specific value
+        /// for firmware version (see
+        /// TPM_PT_FIRMWARE_VERSION_2 in TPM Structures
+        /// specification).
+        firmware_version_2, firmware_version_2_access: dword @ 0x0E;
+
+        /// Description
+        ///
+        /// Descriptive information of the TPM device.
+        description, description_access: string @ 0x12;
+
+        /// Characteristics
+        ///
+        /// TPM device characteristics information.
+        characteristics, characteristics_access: qword @ 0x13 => TpmDeviceCharacteristics;
+
+        /// OEM defined
+        ///
+        /// OEM- or BIOS vendor-specific information
+        oem_defined, oem_defined_access: dword @ 0x1B;
     }
 }
 
@@ -162,11 +148,39 @@ impl<'a> TryFrom<&'a [u8]> for VendorId<'a> {
     }
 }
 
+impl<'a> VendorId<'a> {
+    /// The vendor id characters, without trailing nulls
+    ///
+    /// Example: Vendor Id string of "ABC" = (41 42 43 00) gives (41 42 43)
+    pub fn trimmed(&self) -> &'a [u8] {
+        let array: &'a [u8; 4] = self.array;
+        let length = array
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |position| position + 1);
+        &array[..length]
+    }
+}
+
+impl<'a> fmt::Display for VendorId<'a> {
+    /// Displays the vendor id as ASCII, replacing characters which are not
+    /// printable with '.'
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.trimmed() {
+            match byte {
+                0x20..=0x7E => write!(f, "{}", *byte as char)?,
+                _ => write!(f, ".")?,
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<'a> fmt::Debug for VendorId<'a> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(any::type_name::<VendorId<'_>>())
             .field("array", &self.array)
-            .field("string", &String::from_utf8_lossy(self.array))
+            .field("string", &format!("{}", self))
             .finish()
     }
 }
@@ -178,7 +192,7 @@ impl<'a> Serialize for VendorId<'a> {
     {
         let mut state = serializer.serialize_struct("VendorId", 2)?;
         state.serialize_field("array", &self.array)?;
-        state.serialize_field("string", &String::from_utf8_lossy(self.array))?;
+        state.serialize_field("string", &format!("{}", self))?;
         state.end()
     }
 }
@@ -322,4 +336,21 @@ mod tests {
         );
         assert_eq!(test_struct.oem_defined(), Some(0));
     }
+
+    #[test]
+    fn test_vendor_id() {
+        let vendor_id = VendorId::try_from(&[b'I', b'F', b'X', 0x00][..]).unwrap();
+        assert_eq!(vendor_id.trimmed(), b"IFX");
+        assert_eq!(format!("{}", vendor_id), "IFX");
+
+        let vendor_id = VendorId::try_from(&b"MSFT"[..]).unwrap();
+        assert_eq!(format!("{}", vendor_id), "MSFT");
+
+        let vendor_id = VendorId::try_from(&[0x00, b'X', b'F', b'I'][..]).unwrap();
+        assert_eq!(format!("{}", vendor_id), ".XFI");
+
+        let vendor_id = VendorId::try_from(&[0x00; 4][..]).unwrap();
+        assert_eq!(vendor_id.trimmed(), b"");
+        assert_eq!(format!("{}", vendor_id), "");
+    }
 }
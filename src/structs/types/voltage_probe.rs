@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{MaybeValue, SMBiosStruct, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use core::{fmt, any};
 #[cfg(feature = "no_std")]
@@ -27,87 +27,58 @@ impl<'a> SMBiosStruct<'a> for SMBiosVoltageProbe<'a> {
 }
 
 impl<'a> SMBiosVoltageProbe<'a> {
-    /// Description
-    ///
-    /// Additional descriptive information about the probe or its location
-    pub fn description(&self) -> Option<String> {
-        self.parts.get_field_string(0x04)
-    }
-
-    /// Location and status bit-field
-    ///
-    /// Probe’s physical location and status of the voltage
-    /// monitored by this voltage probe
-    pub fn location_and_status(&self) -> Option<VoltageProbeLocationAndStatus> {
-        self.parts
-            .get_field_byte(0x05)
-            .map(|raw| VoltageProbeLocationAndStatus::from(raw))
-    }
-
-    /// Maximum value
-    ///
-    /// Maximum voltage level readable by this probe, in
-    /// millivolts
-    pub fn maximum_value(&self) -> Option<ProbeVoltage> {
-        self.parts
-            .get_field_word(0x06)
-            .map(|raw| ProbeVoltage::from(raw))
-    }
-
-    /// Minimum value
-    ///
-    /// Minimum voltage level readable by this probe, in millivolts
-    pub fn minimum_value(&self) -> Option<ProbeVoltage> {
-        self.parts
-            .get_field_word(0x08)
-            .map(|raw| ProbeVoltage::from(raw))
-    }
-
-    /// Resolution
-    ///
-    /// Resolution for the probe’s reading, in tenths of millivolts
-    pub fn resolution(&self) -> Option<VoltageProbeResolution> {
-        self.parts
-            .get_field_word(0x0A)
-            .map(|raw| VoltageProbeResolution::from(raw))
-    }
-
-    /// Tolerance
-    ///
-    /// Tolerance for reading from this probe, in plus/minus
-    /// millivolts
-    pub fn tolerance(&self) -> Option<ProbeVoltage> {
-        self.parts
-            .get_field_word(0x0C)
-            .map(|raw| ProbeVoltage::from(raw))
-    }
-
-    /// Accuracy
-    ///
-    /// Accuracy for reading from this probe, in plus/minus
-    /// 1/100th of a percent
-    pub fn accuracy(&self) -> Option<VoltageProbeAccuracy> {
-        self.parts
-            .get_field_word(0x0E)
-            .map(|raw| VoltageProbeAccuracy::from(raw))
-    }
-
-    /// OEM defined
-    ///
-    /// OEM- or BIOS vendor-specific information.
-    pub fn oem_defined(&self) -> Option<u32> {
-        self.parts.get_field_dword(0x10)
-    }
-
-    /// Nominal value
-    ///
-    /// Nominal value for the probe’s reading in millivolts
-    /// This field is present in the structure only if the structure's
-    /// length is larger than 14h.
-    pub fn nominal_value(&self) -> Option<ProbeVoltage> {
-        self.parts
-            .get_field_word(0x14)
-            .map(|raw| ProbeVoltage::from(raw))
+    field_accessors! {
+        /// Description
+        ///
+        /// Additional descriptive information about the probe or its location
+        description, description_access: string @ 0x04;
+
+        /// Location and status bit-field
+        ///
+        /// Probe’s physical location and status of the voltage
+        /// monitored by this voltage probe
+        location_and_status, location_and_status_access: byte @ 0x05
+            => VoltageProbeLocationAndStatus;
+
+        /// Maximum value
+        ///
+        /// Maximum voltage level readable by this probe, in
+        /// millivolts
+        maximum_value, maximum_value_access: word @ 0x06 => ProbeVoltage;
+
+        /// Minimum value
+        ///
+        /// Minimum voltage level readable by this probe, in millivolts
+        minimum_value, minimum_value_access: word @ 0x08 => ProbeVoltage;
+
+        /// Resolution
+        ///
+        /// Resolution for the probe’s reading, in tenths of millivolts
+        resolution, resolution_access: word @ 0x0A => VoltageProbeResolution;
+
+        /// Tolerance
+        ///
+        /// Tolerance for reading from this probe, in plus/minus
+        /// millivolts
+        tolerance, tolerance_access: word @ 0x0C => ProbeVoltage;
+
+        /// Accuracy
+        ///
+        /// Accuracy for reading from this probe, in plus/minus
+        /// 1/100th of a percent
+        accuracy, accuracy_access: word @ 0x0E => VoltageProbeAccuracy;
+
+        /// OEM defined
+        ///
+        /// OEM- or BIOS vendor-specific information.
+        oem_defined, oem_defined_access: dword @ 0x10;
+
+        /// Nominal value
+        ///
+        /// Nominal value for the probe’s reading in millivolts
+        /// This field is present in the structure only if the structure's
+        /// length is larger than 14h.
+        nominal_value, nominal_value_access: word @ 0x14 => ProbeVoltage;
     }
 }
 
@@ -244,7 +215,7 @@ pub enum VoltageProbeLocation {
     Disk,
     /// Peripheral Bay
     PeripheralBay,
-    /// System Management Moduel
+    /// System Management Module
     SystemManagementModule,
     /// Motherboard
     Motherboard,
@@ -280,10 +251,12 @@ impl From<u8> for VoltageProbeLocation {
 }
 
 /// # Probe Voltage
-#[derive(Serialize, Debug)]
+///
+/// The field is a signed value, allowing negative voltage rails.
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum ProbeVoltage {
     /// Voltage in millivolts
-    Millivolts(u16),
+    Millivolts(i16),
     /// Voltage is unknown
     Unknown,
 }
@@ -292,13 +265,22 @@ impl From<u16> for ProbeVoltage {
     fn from(raw: u16) -> Self {
         match raw {
             0x8000 => ProbeVoltage::Unknown,
-            _ => ProbeVoltage::Millivolts(raw),
+            _ => ProbeVoltage::Millivolts(raw as i16),
+        }
+    }
+}
+
+impl From<ProbeVoltage> for MaybeValue<i16> {
+    fn from(voltage: ProbeVoltage) -> Self {
+        match voltage {
+            ProbeVoltage::Millivolts(millivolts) => MaybeValue::Value(millivolts),
+            ProbeVoltage::Unknown => MaybeValue::Unknown,
         }
     }
 }
 
 /// # Voltage Probe Resolution
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum VoltageProbeResolution {
     /// Resolution for the probe's reading in tenths of millivolts
     TenthsOfMillivolts(u16),
@@ -315,8 +297,17 @@ impl From<u16> for VoltageProbeResolution {
     }
 }
 
+impl From<VoltageProbeResolution> for MaybeValue<u16> {
+    fn from(resolution: VoltageProbeResolution) -> Self {
+        match resolution {
+            VoltageProbeResolution::TenthsOfMillivolts(resolution) => MaybeValue::Value(resolution),
+            VoltageProbeResolution::Unknown => MaybeValue::Unknown,
+        }
+    }
+}
+
 /// # Voltage Probe Accuracy
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq)]
 pub enum VoltageProbeAccuracy {
     /// Accuracy for the probe's reading in 1/100th of a percent
     OneOneHundredthPercent(u16),
@@ -333,6 +324,15 @@ impl From<u16> for VoltageProbeAccuracy {
     }
 }
 
+impl From<VoltageProbeAccuracy> for MaybeValue<u16> {
+    fn from(accuracy: VoltageProbeAccuracy) -> Self {
+        match accuracy {
+            VoltageProbeAccuracy::OneOneHundredthPercent(accuracy) => MaybeValue::Value(accuracy),
+            VoltageProbeAccuracy::Unknown => MaybeValue::Unknown,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -378,4 +378,43 @@ mod tests {
             ProbeVoltage::Unknown => (),
         }
     }
+
+    #[test]
+    fn test_location_and_status_and_values() {
+        let struct_type26 = vec![
+            26, 0x16, 0x2B, 0x00, 0x00, 0xA3, 0xE0, 0x2E, 0x20, 0xD1, 0x0A, 0x00, 0x00, 0x80, 0x96,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x78, 0xEC, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type26);
+        let test_struct = SMBiosVoltageProbe::new(&parts);
+
+        let location_and_status = test_struct.location_and_status().unwrap();
+        assert_eq!(location_and_status.status(), VoltageProbeStatus::Critical);
+        assert_eq!(
+            location_and_status.location(),
+            VoltageProbeLocation::Processor
+        );
+        assert_eq!(
+            test_struct.maximum_value(),
+            Some(ProbeVoltage::Millivolts(12000))
+        );
+        assert_eq!(
+            test_struct.minimum_value(),
+            Some(ProbeVoltage::Millivolts(-12000))
+        );
+        assert_eq!(
+            test_struct.resolution(),
+            Some(VoltageProbeResolution::TenthsOfMillivolts(10))
+        );
+        assert_eq!(test_struct.tolerance(), Some(ProbeVoltage::Unknown));
+        assert_eq!(
+            test_struct.accuracy(),
+            Some(VoltageProbeAccuracy::OneOneHundredthPercent(150))
+        );
+        assert_eq!(
+            test_struct.nominal_value(),
+            Some(ProbeVoltage::Millivolts(-5000))
+        );
+    }
 }
@@ -0,0 +1,287 @@
+use crate::core::{trim_padding, MaybeValue, SMBiosData};
+use crate::structs::*;
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+use core::fmt;
+use serde::Serialize;
+
+/// # System Summary
+///
+/// An overview of the system described by a table, see
+/// [SMBiosData::summary]. Displays as a short report:
+///
+/// ```text
+/// System:     Contoso Server 1000 (serial 123456)
+/// BIOS:       Contoso 1.2.3 (01/31/2024)
+/// Processors: 2 x Xeon Gold 6342, 48 cores, 96 threads
+/// Memory:     128 GiB in 8 of 16 slots
+/// Chassis:    RackMountChassis
+/// ```
+#[derive(Debug, Serialize)]
+pub struct SystemSummary {
+    /// Manufacturer of the system (Type 1)
+    pub manufacturer: Option<String>,
+    /// Product name of the system (Type 1)
+    pub product: Option<String>,
+    /// Serial number of the system (Type 1)
+    pub serial_number: Option<String>,
+    /// Vendor of the BIOS (Type 0)
+    pub bios_vendor: Option<String>,
+    /// Version of the BIOS (Type 0)
+    pub bios_version: Option<String>,
+    /// Release date of the BIOS (Type 0)
+    pub bios_release_date: Option<String>,
+    /// Version of the first populated processor (Type 4)
+    pub processor_model: Option<String>,
+    /// Number of populated processor sockets (Type 4)
+    pub processor_count: usize,
+    /// Number of cores of the populated processors, None when the count
+    /// of a processor is unknown
+    pub core_count: Option<u32>,
+    /// Number of threads of the populated processors, None when the count
+    /// of a processor is unknown
+    pub thread_count: Option<u32>,
+    /// Size of the installed memory devices in bytes (Type 17)
+    pub total_memory: u64,
+    /// Number of installed memory devices (Type 17)
+    pub memory_devices_installed: usize,
+    /// Number of memory device sockets (Type 17)
+    pub memory_device_slots: usize,
+    /// Type of the chassis (Type 3)
+    pub chassis_type: Option<ChassisTypeData>,
+}
+
+impl SMBiosData {
+    /// An overview of the system described by the table
+    ///
+    /// Strings are trimmed, an empty string being None. Memory devices of
+    /// unknown size count as installed without adding to the total memory.
+    pub fn summary(&self) -> SystemSummary {
+        let system_info = self.first::<SMBiosSystemInformation<'_>>();
+        let bios_info = self.first::<SMBiosInformation<'_>>();
+
+        let mut processor_model = None;
+        let mut processor_count = 0;
+        let mut core_count = Some(0u32);
+        let mut thread_count = Some(0u32);
+        for processor_info in self.defined_struct_iter::<SMBiosProcessorInformation<'_>>() {
+            let populated = processor_info
+                .status()
+                .map(|status| status.socket_populated())
+                .unwrap_or(false);
+            if !populated {
+                continue;
+            }
+
+            processor_count += 1;
+            if processor_model.is_none() {
+                processor_model = trim_padding(processor_info.processor_version());
+            }
+
+            let cores = processor_info.effective_core_count().map(u32::from);
+            core_count = core_count.and_then(|total| cores.map(|cores| total + cores));
+
+            let threads = processor_info.effective_thread_count().map(u32::from);
+            thread_count = thread_count.and_then(|total| threads.map(|threads| total + threads));
+        }
+
+        let mut total_memory = 0;
+        let mut memory_devices_installed = 0;
+        let mut memory_device_slots = 0;
+        for memory_device in self.defined_struct_iter::<SMBiosMemoryDevice<'_>>() {
+            memory_device_slots += 1;
+            let size = match memory_device.effective_size() {
+                MaybeValue::NotProvided => continue,
+                size => size.as_bytes().unwrap_or(0),
+            };
+
+            memory_devices_installed += 1;
+            total_memory += size;
+        }
+
+        SystemSummary {
+            manufacturer: trim_padding(system_info.as_ref().and_then(|info| info.manufacturer())),
+            product: trim_padding(system_info.as_ref().and_then(|info| info.product_name())),
+            serial_number: trim_padding(system_info.as_ref().and_then(|info| info.serial_number())),
+            bios_vendor: trim_padding(bios_info.as_ref().and_then(|info| info.vendor())),
+            bios_version: trim_padding(bios_info.as_ref().and_then(|info| info.version())),
+            bios_release_date: trim_padding(
+                bios_info.as_ref().and_then(|info| info.release_date()),
+            ),
+            processor_model,
+            processor_count,
+            core_count: core_count.filter(|_| processor_count > 0),
+            thread_count: thread_count.filter(|_| processor_count > 0),
+            total_memory,
+            memory_devices_installed,
+            memory_device_slots,
+            chassis_type: self
+                .first::<SMBiosSystemChassisInformation<'_>>()
+                .and_then(|chassis_info| chassis_info.chassis_type()),
+        }
+    }
+}
+
+impl fmt::Display for SystemSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unknown = |value: &Option<String>| -> String {
+            value.clone().unwrap_or_else(|| String::from("Unknown"))
+        };
+
+        write!(f, "System:     {}", unknown(&self.manufacturer))?;
+        if let Some(product) = &self.product {
+            write!(f, " {}", product)?;
+        }
+        if let Some(serial_number) = &self.serial_number {
+            write!(f, " (serial {})", serial_number)?;
+        }
+        writeln!(f)?;
+
+        write!(f, "BIOS:       {}", unknown(&self.bios_vendor))?;
+        if let Some(version) = &self.bios_version {
+            write!(f, " {}", version)?;
+        }
+        if let Some(release_date) = &self.bios_release_date {
+            write!(f, " ({})", release_date)?;
+        }
+        writeln!(f)?;
+
+        write!(
+            f,
+            "Processors: {} x {}",
+            self.processor_count,
+            unknown(&self.processor_model)
+        )?;
+        if let Some(core_count) = self.core_count {
+            write!(f, ", {} cores", core_count)?;
+        }
+        if let Some(thread_count) = self.thread_count {
+            write!(f, ", {} threads", thread_count)?;
+        }
+        writeln!(f)?;
+
+        // tenths of the largest unit, rounded
+        let (unit, name) = match self.total_memory {
+            size if size >= 1 << 30 => (1u64 << 30, "GiB"),
+            size if size >= 1 << 20 => (1 << 20, "MiB"),
+            _ => (1 << 10, "KiB"),
+        };
+        let tenths = (self.total_memory * 10 + unit / 2) / unit;
+        let (whole, fraction) = (tenths / 10, tenths - tenths / 10 * 10);
+        write!(f, "Memory:     {}", whole)?;
+        if fraction != 0 {
+            write!(f, ".{}", fraction)?;
+        }
+        writeln!(
+            f,
+            " {} in {} of {} slots",
+            name, self.memory_devices_installed, self.memory_device_slots
+        )?;
+
+        match &self.chassis_type {
+            Some(chassis_type) => write!(f, "Chassis:    {}", chassis_type),
+            None => write!(f, "Chassis:    Unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::{SMBiosTableBuilder, StructBuilder, SystemInformationBuilder};
+    use crate::core::SMBiosVersion;
+
+    #[test]
+    fn test_summary() {
+        let mut builder = SMBiosTableBuilder::new(SMBiosVersion::new(3, 4, 0));
+        builder
+            .add(
+                StructBuilder::new(0)
+                    .string("Vendor")
+                    .string("1.2.3 ")
+                    .word(0xE800)
+                    .string("01/31/2024")
+                    .byte(0xFF)
+                    .qword(0x08),
+            )
+            .unwrap();
+        builder
+            .add(
+                SystemInformationBuilder::new()
+                    .manufacturer("Manufacturer")
+                    .product_name("Product"),
+            )
+            .unwrap();
+        builder
+            .add(
+                StructBuilder::new(3)
+                    .string("Manufacturer")
+                    .byte(0x17) // Rack Mount Chassis
+                    .string("")
+                    .string("")
+                    .string(""),
+            )
+            .unwrap();
+        for status in [0x41u8, 0x00].iter() {
+            builder
+                .add(
+                    StructBuilder::new(4)
+                        .string("CPU")
+                        .byte(3)
+                        .byte(0xB3)
+                        .string("Intel")
+                        .qword(0)
+                        .string("Xeon")
+                        .byte(0)
+                        .word(0)
+                        .word(4000)
+                        .word(2100)
+                        .byte(*status)
+                        .byte(1)
+                        .data(&[0xFF; 6])
+                        .string("")
+                        .string("")
+                        .string("")
+                        .byte(24)
+                        .byte(24)
+                        .byte(48),
+                )
+                .unwrap();
+        }
+        for size in [0x4000u16, 0, 0x4000, 0].iter() {
+            builder
+                .add(
+                    StructBuilder::new(17)
+                        .word(0x1000)
+                        .word(0xFFFE)
+                        .word(72)
+                        .word(64)
+                        .word(*size)
+                        .byte(0x09)
+                        .byte(0)
+                        .string("DIMM")
+                        .string("")
+                        .byte(0x1A)
+                        .word(0),
+                )
+                .unwrap();
+        }
+        let summary = builder.build().unwrap().summary();
+
+        assert_eq!(summary.manufacturer, Some("Manufacturer".to_string()));
+        assert_eq!(summary.serial_number, None);
+        assert_eq!(summary.bios_version, Some("1.2.3".to_string()));
+        assert_eq!(summary.processor_count, 1);
+        assert_eq!(summary.core_count, Some(24));
+        assert_eq!(summary.total_memory, 32 << 30);
+        assert_eq!(summary.memory_devices_installed, 2);
+        assert_eq!(summary.memory_device_slots, 4);
+        assert_eq!(
+            format!("{}", summary),
+            "System:     Manufacturer Product\n\
+             BIOS:       Vendor 1.2.3 (01/31/2024)\n\
+             Processors: 1 x Xeon, 24 cores, 48 threads\n\
+             Memory:     32 GiB in 2 of 4 slots\n\
+             Chassis:    RackMountChassis"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! UEFI SMBIOS.
+//!
+//! Functions and structures for locating SMBIOS within the EFI System Table
+//! of a pre-boot (UEFI) application.
+//!
+//! The [EfiConfigurationTable] layout matches EFI_CONFIGURATION_TABLE,
+//! allowing the configuration table of the EFI System Table (such as the one
+//! provided by the `uefi` crate) to be handed to [load_uefi_smbios_data].
+
+use crate::core::{
+    SMBiosData, SMBiosEntryPoint32, SMBiosEntryPoint32Error, SMBiosEntryPoint64,
+    SMBiosEntryPoint64Error, SMBiosVersion,
+};
+#[cfg(feature = "no_std")]
+use alloc::{format, vec::Vec};
+use core::{convert::TryFrom, ffi::c_void, fmt};
+
+/// SMBIOS_TABLE_GUID {EB9D2D31-2D88-11D3-9A16-0090273FC14D}
+///
+/// Identifies the SMBIOS 2.1 (32 bit) entry point in the EFI Configuration Table.
+pub const SMBIOS_TABLE_GUID: [u8; 16] = [
+    0x31, 0x2D, 0x9D, 0xEB, 0x88, 0x2D, 0xD3, 0x11, 0x9A, 0x16, 0x00, 0x90, 0x27, 0x3F, 0xC1, 0x4D,
+];
+
+/// SMBIOS3_TABLE_GUID {F2FD1544-9794-4A2C-992E-E5BBCF20E394}
+///
+/// Identifies the SMBIOS 3.0 (64 bit) entry point in the EFI Configuration Table.
+pub const SMBIOS3_TABLE_GUID: [u8; 16] = [
+    0x44, 0x15, 0xFD, 0xF2, 0x94, 0x97, 0x2C, 0x4A, 0x99, 0x2E, 0xE5, 0xBB, 0xCF, 0x20, 0xE3, 0x94,
+];
+
+/// # EFI Configuration Table
+///
+/// An entry of the configuration table found in the EFI System Table
+/// (EFI_CONFIGURATION_TABLE).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct EfiConfigurationTable {
+    /// The GUID identifying the configuration table, in its in-memory byte order
+    pub vendor_guid: [u8; 16],
+    /// A pointer to the table associated with `vendor_guid`
+    pub vendor_table: *const c_void,
+}
+
+/// UEFI SMBIOS load errors
+pub enum UefiLoadError {
+    /// Neither SMBIOS3_TABLE_GUID nor SMBIOS_TABLE_GUID is present in the configuration table
+    TableNotFound,
+    /// The 64 bit entry point referenced by SMBIOS3_TABLE_GUID is invalid
+    EntryPoint64(SMBiosEntryPoint64Error),
+    /// The 32 bit entry point referenced by SMBIOS_TABLE_GUID is invalid
+    EntryPoint32(SMBiosEntryPoint32Error),
+}
+
+impl fmt::Debug for UefiLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("UefiLoadError")
+            .field(&format!("{}", &self))
+            .finish()
+    }
+}
+
+impl fmt::Display for UefiLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UefiLoadError::TableNotFound => {
+                f.write_str("SMBIOS configuration table GUID not found")
+            }
+            UefiLoadError::EntryPoint64(error) => write!(f, "{}", error),
+            UefiLoadError::EntryPoint32(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for UefiLoadError {}
+
+/// Loads [SMBiosData] from the EFI System Table configuration table
+///
+/// The SMBIOS 3.0 entry point (SMBIOS3_TABLE_GUID) is preferred, falling back
+/// to the SMBIOS 2.1 entry point (SMBIOS_TABLE_GUID). The entry point is
+/// reported against by [SMBiosData::integrity].
+///
+/// # Safety
+///
+/// `configuration_table` must be the configuration table of the EFI System Table
+/// (or otherwise reference valid memory). The entry point and the structure table
+/// are read directly from the addresses given, which requires identity mapped
+/// memory as is the case while boot services are active.
+pub unsafe fn load_uefi_smbios_data(
+    configuration_table: &[EfiConfigurationTable],
+) -> Result<SMBiosData, UefiLoadError> {
+    let find_table = |guid: &[u8; 16]| {
+        configuration_table
+            .iter()
+            .find(|entry| &entry.vendor_guid == guid && !entry.vendor_table.is_null())
+    };
+
+    if let Some(entry) = find_table(&SMBIOS3_TABLE_GUID) {
+        let raw = read_physical(entry.vendor_table as u64, SMBiosEntryPoint64::MINIMUM_SIZE);
+        load_entry_point_64(raw, |address, length| read_physical(address, length))
+    } else if let Some(entry) = find_table(&SMBIOS_TABLE_GUID) {
+        let raw = read_physical(entry.vendor_table as u64, SMBiosEntryPoint32::MINIMUM_SIZE);
+        load_entry_point_32(raw, |address, length| read_physical(address, length))
+    } else {
+        Err(UefiLoadError::TableNotFound)
+    }
+}
+
+/// Loads the table described by the raw 64 bit entry point `raw`
+///
+/// `read_table` reads the given number of bytes at the given physical address.
+fn load_entry_point_64<F: FnOnce(u64, usize) -> Vec<u8>>(
+    raw: Vec<u8>,
+    read_table: F,
+) -> Result<SMBiosData, UefiLoadError> {
+    let entry_point = SMBiosEntryPoint64::try_from(raw).map_err(UefiLoadError::EntryPoint64)?;
+    let table = read_table(
+        entry_point.structure_table_address(),
+        entry_point.structure_table_maximum_size() as usize,
+    );
+
+    let version = SMBiosVersion::from(&entry_point);
+
+    Ok(SMBiosData::from_vec_and_version(table, Some(version)).with_entry_point(entry_point.raw()))
+}
+
+/// Loads the table described by the raw 32 bit entry point `raw`
+///
+/// `read_table` reads the given number of bytes at the given physical address.
+fn load_entry_point_32<F: FnOnce(u64, usize) -> Vec<u8>>(
+    raw: Vec<u8>,
+    read_table: F,
+) -> Result<SMBiosData, UefiLoadError> {
+    let entry_point = SMBiosEntryPoint32::try_from(raw).map_err(UefiLoadError::EntryPoint32)?;
+    let table = read_table(
+        entry_point.structure_table_address() as u64,
+        entry_point.structure_table_length() as usize,
+    );
+
+    let version = SMBiosVersion::from(&entry_point);
+
+    Ok(SMBiosData::from_vec_and_version(table, Some(version)).with_entry_point(entry_point.raw()))
+}
+
+/// Copies `length` bytes located at the (identity mapped) physical `address`
+unsafe fn read_physical(address: u64, length: usize) -> Vec<u8> {
+    core::slice::from_raw_parts(address as usize as *const u8, length).to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::{SMBiosStruct, SMBiosSystemInformation};
+
+    fn test_table() -> Vec<u8> {
+        vec![
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, b'O', b'E', b'M', 0x00, 0x00, 0x7F,
+            0x04, 0x02, 0x00, 0x00, 0x00,
+        ]
+    }
+
+    #[test]
+    fn test_load_uefi_smbios3_data() {
+        let table = test_table();
+        let address = (table.as_ptr() as u64).to_le_bytes();
+        let length = (table.len() as u32).to_le_bytes();
+
+        let mut entry_point = vec![
+            0x5F, 0x53, 0x4D, 0x33, 0x5F, 0x00, 0x18, 0x03, 0x04, 0x00, 0x01, 0x00,
+        ];
+        entry_point.extend_from_slice(&length);
+        entry_point.extend_from_slice(&address);
+        SMBiosEntryPoint64::fix_checksums(&mut entry_point).unwrap();
+
+        let configuration_table = [
+            EfiConfigurationTable {
+                vendor_guid: [0xAA; 16],
+                vendor_table: core::ptr::null(),
+            },
+            EfiConfigurationTable {
+                vendor_guid: SMBIOS3_TABLE_GUID,
+                vendor_table: entry_point.as_ptr() as *const c_void,
+            },
+        ];
+
+        let data = unsafe { load_uefi_smbios_data(&configuration_table) }.unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 4, 0)));
+        assert!(data.integrity().unwrap().is_consistent());
+
+        let system_information = data.first::<SMBiosSystemInformation<'_>>().unwrap();
+        assert_eq!(
+            system_information.parts().header.struct_type(),
+            SMBiosSystemInformation::STRUCT_TYPE
+        );
+        assert_eq!(system_information.manufacturer(), Some("OEM".to_string()));
+    }
+
+    #[test]
+    fn test_load_entry_point_32() {
+        // A 32 bit entry point, whose table may lie anywhere below 4 GiB
+        let mut entry_point = vec![
+            0x5F, 0x53, 0x4D, 0x5F, 0x00, 0x1F, 0x02, 0x08, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x5F, 0x44, 0x4D, 0x49, 0x5F, 0x00, 0x13, 0x00, 0x00, 0x10, 0xF0, 0xFE,
+            0x02, 0x00, 0x28,
+        ];
+        SMBiosEntryPoint32::fix_checksums(&mut entry_point).unwrap();
+
+        let data = load_entry_point_32(entry_point.clone(), |address, length| {
+            assert_eq!(address, 0xFEF0_1000);
+            assert_eq!(length, test_table().len());
+            test_table()
+        })
+        .unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(2, 8, 0)));
+        assert_eq!(data.iter().count(), 2);
+        assert!(data.integrity().unwrap().is_consistent());
+
+        entry_point[SMBiosEntryPoint32::INTERMEDIATE_ANCHOR_OFFSET] = b'-';
+        SMBiosEntryPoint32::fix_checksums(&mut entry_point).unwrap();
+        match load_entry_point_32(entry_point, |_, _| unreachable!()) {
+            Err(UefiLoadError::EntryPoint32(SMBiosEntryPoint32Error::DMINotFound)) => (),
+            other => panic!("Expected DMINotFound, found {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_load_uefi_smbios_data_not_found() {
+        let configuration_table = [EfiConfigurationTable {
+            vendor_guid: [0xAA; 16],
+            vendor_table: core::ptr::null(),
+        }];
+
+        match unsafe { load_uefi_smbios_data(&configuration_table) } {
+            Err(UefiLoadError::TableNotFound) => (),
+            other => panic!("Expected TableNotFound, found {:?}", other.map(|_| ())),
+        }
+    }
+}
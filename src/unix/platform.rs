@@ -1,17 +1,38 @@
+#[cfg(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    test
+))]
+use crate::core::locate_structure_table;
 use crate::*;
-use std::{io::Error, io::ErrorKind};
+#[cfg(target_os = "linux")]
+use std::io::ErrorKind;
 
-    #[cfg(any(target_os = "linux"))]
+    #[cfg(target_os = "linux")]
     /// Full path to smbios_entry_point file on Linux (contains entry point data)
     pub const SYS_ENTRY_FILE: &'static str = "/sys/firmware/dmi/tables/smbios_entry_point";
 
-    #[cfg(any(target_os = "linux"))]
+    #[cfg(target_os = "linux")]
     /// Full path to the DMI file on Linux (contains BIOS table data)
     pub const SYS_TABLE_FILE: &'static str = "/sys/firmware/dmi/tables/DMI";
 
     /// Full path to the memory device (contains BIOS entry point and table data on *nix platforms)
     pub const DEV_MEM_FILE: &'static str = "/dev/mem";
 
+    #[cfg(target_os = "freebsd")]
+    /// Kernel environment hint holding the physical address of the entry point on FreeBSD
+    pub const KENV_SMBIOS_ENTRY_POINT: &'static str = "hint.smbios.0.mem";
+
+    #[cfg(any(target_os = "solaris", target_os = "illumos"))]
+    /// Full path to the smbios device on Solaris and illumos (contains entry point and table data)
+    pub const DEV_SMBIOS_FILE: &'static str = "/dev/smbios";
+
+    #[cfg(target_os = "netbsd")]
+    /// Sysctl holding the physical address of the entry point on NetBSD
+    pub const SYSCTL_SMBIOS_ENTRY_POINT: &'static str = "machdep.smbios";
+
 // Example of Linux structure:
 /*
     /sys/firmware/dmi/tables$ sudo hexdump -C smbios_entry_point
@@ -44,170 +65,285 @@ use std::{io::Error, io::ErrorKind};
 // These are useful for cross checking against the results this library produces when reading
 // /sys/firmware/dmi/tables/DMI
 
-#[cfg(any(target_os = "linux"))]
+#[cfg(target_os = "linux")]
 /// Loads [SMBiosData] from the device via /sys/firmware/dmi/tables (on Linux)
+///
+/// When the sysfs DMI tables are unavailable (older kernels, locked-down sysfs)
+/// the legacy /dev/mem scan of [load_linux_dev_mem_smbios_data] is used instead.
 pub fn table_load_from_device() -> Result<SMBiosData, Error> {
-    let version: SMBiosVersion;
-    let entry_path = std::path::Path::new(SYS_ENTRY_FILE);
-
-    match SMBiosEntryPoint64::try_load_from_file(entry_path) {
-        Ok(entry_point) => {
-            version = SMBiosVersion {
-                major: entry_point.major_version(),
-                minor: entry_point.minor_version(),
-                revision: entry_point.docrev(),
-            }
-        }
-        Err(err) => match err.kind() {
-            ErrorKind::InvalidData => {
-                match SMBiosEntryPoint32::try_load_from_file(entry_path) {
-                    Ok(entry_point) => {
-                        version = SMBiosVersion {
-                            major: entry_point.major_version(),
-                            minor: entry_point.minor_version(),
-                            revision: 0,
-                        }
-                    }
-                    Err(err) => return Err(err),
+    let kind = |error: &Error| error.io_error().map(std::io::Error::kind);
+
+    load_linux_smbios_data().or_else(|error| match kind(&error) {
+        Some(ErrorKind::NotFound) | Some(ErrorKind::PermissionDenied) => {
+            load_linux_dev_mem_smbios_data().map_err(|dev_mem_error| {
+                // Being denied /dev/mem is more actionable than sysfs being absent
+                if kind(&error) == Some(ErrorKind::NotFound)
+                    && kind(&dev_mem_error) == Some(ErrorKind::PermissionDenied)
+                {
+                    dev_mem_error
+                } else {
+                    error
                 }
-            }
-            _ => return Err(err),
-        },
-    }
-
-    SMBiosData::try_load_from_file(SYS_TABLE_FILE, Some(version))
+            })
+        }
+        _ => Err(error),
+    })
 }
 
-#[cfg(any(target_os = "freebsd"))]
-/// Loads [SMBiosData] from the device via /dev/mem (on FreeBSD)
-pub fn table_load_from_device() -> Result<SMBiosData, Error> {
-    const RANGE_START: u64 = 0x000F0000u64;
-    const RANGE_END: u64 = 0x000FFFFFu64;
-    let structure_table_address: u64;
-    let structure_table_length: u32;
-    let version: SMBiosVersion;
+#[cfg(target_os = "linux")]
+/// Loads [SMBiosData] from the Linux sysfs firmware tables
+///
+/// The entry point found in [SYS_ENTRY_FILE] determines the [SMBiosVersion]
+/// of the table data read from [SYS_TABLE_FILE], and is reported against by
+/// [SMBiosData::integrity].
+pub fn load_linux_smbios_data() -> Result<SMBiosData, Error> {
+    let entry_point = std::fs::read(SYS_ENTRY_FILE)?;
+    SMBiosData::try_from_entry_point_and_vec(&entry_point, std::fs::read(SYS_TABLE_FILE)?)
+}
 
+#[cfg(target_os = "linux")]
+/// Loads [SMBiosData] by scanning /dev/mem for an entry point (on Linux)
+///
+/// The physical memory range 000F0000h to 000FFFFFh is scanned for the
+/// _SM3_ or _SM_ anchor. Once a valid entry point is found the structure
+/// table is read from the physical address it indicates, and the entry
+/// point is reported against by [SMBiosData::integrity].
+pub fn load_linux_dev_mem_smbios_data() -> Result<SMBiosData, Error> {
     let mut dev_mem = std::fs::File::open(DEV_MEM_FILE)?;
+    let entry_point = scan_for_entry_point(&mut dev_mem)?;
+    let (structure_table_address, structure_table_length, version) =
+        locate_structure_table(&entry_point)?;
+
+    let table = UndefinedStructTable::try_load_from_file_offset(
+        &mut dev_mem,
+        structure_table_address,
+        structure_table_length,
+    )?;
 
-    match SMBiosEntryPoint32::try_scan_from_file(&mut dev_mem, RANGE_START..=RANGE_END) {
-        Ok(entry_point) => {
-            structure_table_address = entry_point.structure_table_address() as u64;
-            structure_table_length = entry_point.structure_table_length() as u32;
+    Ok(SMBiosData::new(table, Some(version)).with_entry_point(&entry_point))
+}
 
-           version = SMBiosVersion {
-                major: entry_point.major_version(),
-                minor: entry_point.minor_version(),
-                revision: 0,
-            }
-        }
-        Err(error) => {
-            if error.kind() != ErrorKind::UnexpectedEof {
-                return Err(error);
-            }
+/// Scans the legacy BIOS range (000F0000h to 000FFFFFh) of `file` for an entry point
+///
+/// The 64-bit (_SM3_) entry point is preferred over the 32-bit (_SM_) entry point.
+/// Both the anchor and checksums of the entry point are verified.
+///
+/// Returns the raw entry point.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    test
+))]
+fn scan_for_entry_point(file: &mut std::fs::File) -> Result<Vec<u8>, Error> {
+    const RANGE_START: u64 = 0x000F0000u64;
+    const RANGE_END: u64 = 0x000FFFFFu64;
 
+    match SMBiosEntryPoint64::try_scan_from_file(file, RANGE_START..=RANGE_END) {
+        Ok(entry_point) => Ok(entry_point.raw().to_vec()),
+        Err(Error::EntryPointNotFound) => {
             let entry_point =
-                SMBiosEntryPoint64::try_scan_from_file(&mut dev_mem, RANGE_START..=RANGE_END)?;
-
-            structure_table_address = entry_point.structure_table_address();
-            structure_table_length = entry_point.structure_table_maximum_size();
+                SMBiosEntryPoint32::try_scan_from_file(file, RANGE_START..=RANGE_END)?;
 
-           version = SMBiosVersion {
-                major: entry_point.major_version(),
-                minor: entry_point.minor_version(),
-                revision: entry_point.docrev(),
-            }
+            Ok(entry_point.raw().to_vec())
         }
+        Err(error) => Err(error),
     }
+}
 
-    if structure_table_address < RANGE_START || structure_table_address > RANGE_END {
-        return Err(Error::new(
-            ErrorKind::InvalidData,
-            format!(
-                "The entry point has given an out of range start address for the table: {}",
-                structure_table_address
-            ),
-        ));
-    }
-
-    if structure_table_address + structure_table_length as u64 > RANGE_END {
-        return Err(Error::new(
-            ErrorKind::InvalidData,
-            format!(
-                "The entry point has given a length which exceeds the range: {}",
-                structure_table_length
-            ),
-        ));
-    }
+#[cfg(any(target_os = "freebsd", target_os = "netbsd", target_os = "openbsd"))]
+/// Loads [SMBiosData] from the device via /dev/mem (on FreeBSD, NetBSD and OpenBSD)
+///
+/// The entry point is located using the platform hint where one exists
+/// ([KENV_SMBIOS_ENTRY_POINT] on FreeBSD, [SYSCTL_SMBIOS_ENTRY_POINT] on NetBSD),
+/// falling back to scanning the legacy BIOS range. The entry point is
+/// reported against by [SMBiosData::integrity].
+pub fn table_load_from_device() -> Result<SMBiosData, Error> {
+    let mut dev_mem = std::fs::File::open(DEV_MEM_FILE)?;
+    let entry_point = locate_bsd_entry_point(&mut dev_mem)?;
+    let (structure_table_address, structure_table_length, version) =
+        locate_structure_table(&entry_point)?;
 
     let table = UndefinedStructTable::try_load_from_file_offset(
         &mut dev_mem,
         structure_table_address,
-        structure_table_length as usize,
+        structure_table_length,
     )?;
-    
-    Ok(SMBiosData::new(table, Some(version)))
-}
 
-#[cfg(any(target_os = "linux"))]
-/// Returns smbios raw data via /sys/firmware/dmi/tables (on Linux)
-pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
-    Ok(std::fs::read(SYS_TABLE_FILE)?)
+    Ok(SMBiosData::new(table, Some(version)).with_entry_point(&entry_point))
 }
 
-#[cfg(any(target_os = "freebsd"))]
-/// Returns smbios raw data via /dev/mem (on FreeBSD)
-pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
-    use std::io::{prelude::*, SeekFrom};
-    const RANGE_START: u64 = 0x000F0000u64;
-    const RANGE_END: u64 = 0x000FFFFFu64;
-    let structure_table_address: u64;
-    let structure_table_length: usize;
+#[cfg(target_os = "freebsd")]
+/// Locates the entry point via kenv(2) or, when no hint exists, by scanning /dev/mem
+fn locate_bsd_entry_point(dev_mem: &mut std::fs::File) -> Result<Vec<u8>, Error> {
+    locate_entry_point(dev_mem, kenv_entry_point_address())
+}
 
-    let mut dev_mem = std::fs::File::open(DEV_MEM_FILE)?;
+#[cfg(target_os = "netbsd")]
+/// Locates the entry point via sysctl(3) or, when it is not provided, by scanning /dev/mem
+fn locate_bsd_entry_point(dev_mem: &mut std::fs::File) -> Result<Vec<u8>, Error> {
+    locate_entry_point(dev_mem, sysctl_entry_point_address())
+}
 
-    match SMBiosEntryPoint32::try_scan_from_file(&mut dev_mem, RANGE_START..=RANGE_END) {
-        Ok(entry_point) => {
-            structure_table_address = entry_point.structure_table_address() as u64;
-            structure_table_length = entry_point.structure_table_length() as usize;
-        }
-        Err(error) => {
-            if error.kind() != ErrorKind::UnexpectedEof {
-                return Err(error);
-            }
+#[cfg(target_os = "openbsd")]
+/// Locates the entry point by scanning /dev/mem
+///
+/// OpenBSD does not export the entry point address; reading /dev/mem
+/// requires the `kern.allowkmem` sysctl to be enabled.
+fn locate_bsd_entry_point(dev_mem: &mut std::fs::File) -> Result<Vec<u8>, Error> {
+    locate_entry_point(dev_mem, None)
+}
 
-            let entry_point =
-                SMBiosEntryPoint64::try_scan_from_file(&mut dev_mem, RANGE_START..=RANGE_END)?;
+#[cfg(any(
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    test
+))]
+/// Locates the entry point at the physical address `hint` reported by the
+/// platform or, when there is none, by scanning the legacy BIOS range
+///
+/// Returns the raw entry point.
+fn locate_entry_point(dev_mem: &mut std::fs::File, hint: Option<u64>) -> Result<Vec<u8>, Error> {
+    match hint {
+        Some(address) => read_entry_point_at(dev_mem, address),
+        None => scan_for_entry_point(dev_mem),
+    }
+}
 
-            structure_table_address = entry_point.structure_table_address();
-            structure_table_length = entry_point.structure_table_maximum_size() as usize;
-        }
+#[cfg(target_os = "netbsd")]
+/// Physical address of the entry point as reported by the `machdep.smbios` sysctl
+///
+/// Returns None when the sysctl does not exist or reports no address.
+fn sysctl_entry_point_address() -> Option<u64> {
+    use std::ffi::CString;
+
+    let name = CString::new(SYSCTL_SMBIOS_ENTRY_POINT).ok()?;
+    let mut address: libc::c_ulong = 0;
+    let mut length = std::mem::size_of::<libc::c_ulong>();
+
+    let result = unsafe {
+        libc::sysctlbyname(
+            name.as_ptr(),
+            &mut address as *mut libc::c_ulong as *mut libc::c_void,
+            &mut length,
+            std::ptr::null(),
+            0,
+        )
+    };
+
+    if result != 0 || address == 0 {
+        None
+    } else {
+        Some(address as u64)
     }
+}
 
-    if structure_table_address < RANGE_START || structure_table_address > RANGE_END {
-        return Err(Error::new(
-            ErrorKind::InvalidData,
-            format!(
-                "The entry point has given an out of range start address for the table: {}",
-                structure_table_address
-            ),
-        ));
+#[cfg(target_os = "freebsd")]
+/// Physical address of the entry point as reported by the kernel environment
+///
+/// Returns None when the hint is not set or cannot be parsed.
+fn kenv_entry_point_address() -> Option<u64> {
+    use std::ffi::{CStr, CString};
+
+    // KENV_MVALLEN (128) + terminating NUL
+    const KENV_VALUE_LENGTH: usize = 129;
+
+    let name = CString::new(KENV_SMBIOS_ENTRY_POINT).ok()?;
+    let mut value = [0 as libc::c_char; KENV_VALUE_LENGTH];
+
+    let result = unsafe {
+        libc::kenv(
+            libc::KENV_GET,
+            name.as_ptr(),
+            value.as_mut_ptr(),
+            KENV_VALUE_LENGTH as libc::c_int,
+        )
+    };
+
+    if result <= 0 {
+        return None;
     }
 
-    if structure_table_address + structure_table_length as u64 > RANGE_END {
-        return Err(Error::new(
-            ErrorKind::InvalidData,
-            format!(
-                "The entry point has given a length which exceeds the range: {}",
-                structure_table_length
-            ),
-        ));
+    let value = unsafe { CStr::from_ptr(value.as_ptr()) }.to_str().ok()?;
+    parse_address_hint(value)
+}
+
+#[cfg(any(target_os = "freebsd", test))]
+/// Parses a physical address hint, hexadecimal when prefixed by 0x and decimal otherwise
+fn parse_address_hint(value: &str) -> Option<u64> {
+    let value = value.trim();
+
+    if value.starts_with("0x") || value.starts_with("0X") {
+        u64::from_str_radix(&value[2..], 16).ok()
+    } else {
+        value.parse::<u64>().ok()
     }
+}
+
+#[cfg(any(
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    test
+))]
+/// Reads and verifies an entry point located at the given physical `address`
+///
+/// Returns the raw entry point.
+fn read_entry_point_at(file: &mut std::fs::File, address: u64) -> Result<Vec<u8>, Error> {
+    use std::io::{prelude::*, SeekFrom};
+
+    let mut anchor = [0u8; 5];
+    file.seek(SeekFrom::Start(address))?;
+    file.read_exact(&mut anchor)?;
+
+    let mut raw = if anchor == SMBiosEntryPoint64::SM3_ANCHOR {
+        vec![0u8; SMBiosEntryPoint64::MINIMUM_SIZE]
+    } else {
+        vec![0u8; SMBiosEntryPoint32::MINIMUM_SIZE]
+    };
+    file.seek(SeekFrom::Start(address))?;
+    file.read_exact(&mut raw)?;
+
+    locate_structure_table(&raw)?;
+    Ok(raw)
+}
+
+#[cfg(any(target_os = "solaris", target_os = "illumos"))]
+/// Loads [SMBiosData] from the device via /dev/smbios (on Solaris and illumos)
+///
+/// The smbios(4D) driver exports an image in the same layout as
+/// `dmidecode --dump-bin`: an entry point whose structure table address
+/// is the offset of the table within the image.
+pub fn table_load_from_device() -> Result<SMBiosData, Error> {
+    SMBiosData::try_from_dump_bin(&std::fs::read(DEV_SMBIOS_FILE)?)
+}
+
+#[cfg(any(target_os = "solaris", target_os = "illumos"))]
+/// Returns smbios raw data via /dev/smbios (on Solaris and illumos)
+pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
+    let image = std::fs::read(DEV_SMBIOS_FILE)?;
+    Ok(SMBiosData::raw_table_from_dump_bin(&image)?.to_vec())
+}
+
+#[cfg(target_os = "linux")]
+/// Returns smbios raw data via /sys/firmware/dmi/tables (on Linux)
+pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
+    Ok(std::fs::read(SYS_TABLE_FILE)?)
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "netbsd", target_os = "openbsd"))]
+/// Returns smbios raw data via /dev/mem (on FreeBSD, NetBSD and OpenBSD)
+pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
+    use std::io::{prelude::*, SeekFrom};
+
+    let mut dev_mem = std::fs::File::open(DEV_MEM_FILE)?;
+    let (structure_table_address, structure_table_length, _) =
+        locate_structure_table(&locate_bsd_entry_point(&mut dev_mem)?)?;
 
     if structure_table_length < Header::SIZE + 2 {
-        return Err(Error::new(
-            ErrorKind::InvalidData,
-            format!("The table has an invalid size: {}", structure_table_length),
-        ));
+        return Err(Error::InvalidTableLength(structure_table_length));
     }
 
     dev_mem.seek(SeekFrom::Start(structure_table_address))?;
@@ -222,7 +358,7 @@ pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
 mod tests {
     use super::*;
     use std::fs::File;
-    use std::io;
+    use std::io::{self, ErrorKind};
 
     #[test]
     fn test_dev_mem_scan() -> io::Result<()> {
@@ -248,11 +384,7 @@ mod tests {
                 );
                 println!("Table at: {:#010X}.", entry_point.structure_table_address());
             }
-            Err(error) => {
-                if error.kind() != ErrorKind::UnexpectedEof {
-                    return Err(error);
-                }
-
+            Err(Error::EntryPointNotFound) => {
                 let entry_point =
                     SMBiosEntryPoint64::try_scan_from_file(&mut dev_mem, RANGE_START..=RANGE_END)?;
 
@@ -271,10 +403,11 @@ mod tests {
                 );
                 println!("Table at: {:#010X}.", entry_point.structure_table_address());
             }
+            Err(error) => return Err(error.into()),
         }
 
         if structure_table_address < RANGE_START || structure_table_address > RANGE_END {
-            return Err(Error::new(
+            return Err(io::Error::new(
                 ErrorKind::InvalidData,
                 format!(
                     "The entry point has given an out of range start address for the table: {}",
@@ -284,7 +417,7 @@ mod tests {
         }
 
         if structure_table_address + structure_table_length as u64 > RANGE_END {
-            return Err(Error::new(
+            return Err(io::Error::new(
                 ErrorKind::InvalidData,
                 format!(
                     "The entry point has given a length which exceeds the range: {}",
@@ -302,4 +435,146 @@ mod tests {
 
         Ok(())
     }
+
+    /// Creates a file standing in for /dev/mem, holding `contents` at their physical addresses
+    fn physical_memory_file(
+        name: &str,
+        contents: &[(u64, &[u8])],
+    ) -> io::Result<(std::path::PathBuf, File)> {
+        use std::io::{Seek, SeekFrom, Write};
+
+        let path = std::env::temp_dir().join(format!(
+            "smbioslib_test_{}_{}.bin",
+            name,
+            std::process::id()
+        ));
+        let mut file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)?;
+        for (address, bytes) in contents {
+            file.seek(SeekFrom::Start(*address))?;
+            file.write_all(bytes)?;
+        }
+
+        Ok((path, file))
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_scan_for_entry_point() -> io::Result<()> {
+        // A 64-bit entry point at 000F0010h pointing to a table at 00100000h
+        let entry_point = [
+            0x5f, 0x53, 0x4d, 0x33, 0x5f, 0x34, 0x18, 0x03, 0x03, 0x00, 0x01, 0x00, 0x0c, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let table = [0x01, 0x04, 0x00, 0x00, 0x00, 0x00, 0x7F, 0x04, 0x01, 0x00, 0x00, 0x00];
+
+        let (path, mut file) = physical_memory_file(
+            "scan_for_entry_point",
+            &[(0x000F0010, &entry_point), (0x00100000, &table)],
+        )?;
+
+        let raw = scan_for_entry_point(&mut file)?;
+        assert_eq!(raw, entry_point);
+        let (address, length, version) = locate_structure_table(&raw)?;
+        assert_eq!(address, 0x00100000);
+        assert_eq!(length, table.len());
+        assert_eq!(version, SMBiosVersion::new(3, 3, 0));
+
+        let table = UndefinedStructTable::try_load_from_file_offset(&mut file, address, length)?;
+        assert_eq!(table.iter().count(), 2);
+
+        std::fs::remove_file(&path)
+    }
+
+    #[test]
+    fn test_parse_address_hint() {
+        assert_eq!(parse_address_hint("0xf0800"), Some(0x000F0800));
+        assert_eq!(parse_address_hint(" 0XF0800\n"), Some(0x000F0800));
+        assert_eq!(parse_address_hint("985088"), Some(0x000F0800));
+        assert_eq!(parse_address_hint("0x"), None);
+        assert_eq!(parse_address_hint("smbios"), None);
+    }
+
+    #[test]
+    fn test_read_entry_point_at() -> io::Result<()> {
+        // A 32-bit entry point at the hinted 000F0800h pointing to a table at 000F1000h
+        let mut entry_point = vec![
+            0x5F, 0x53, 0x4D, 0x5F, 0x00, 0x1F, 0x02, 0x08, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x5F, 0x44, 0x4D, 0x49, 0x5F, 0x00, 0x0C, 0x00, 0x00, 0x10, 0x0F, 0x00,
+            0x02, 0x00, 0x28,
+        ];
+        SMBiosEntryPoint32::fix_checksums(&mut entry_point).unwrap();
+
+        let (path, mut file) =
+            physical_memory_file("read_entry_point_at", &[(0x000F0800, &entry_point)])?;
+
+        let (address, length, version) =
+            locate_structure_table(&read_entry_point_at(&mut file, 0x000F0800)?)?;
+        assert_eq!(address, 0x000F1000);
+        assert_eq!(length, 0x0C);
+        assert_eq!(version, SMBiosVersion::new(2, 8, 0));
+
+        // A stale hint pointing elsewhere than the entry point
+        assert!(matches!(
+            read_entry_point_at(&mut file, 0x000F0000),
+            Err(Error::InvalidEntryPoint32(
+                SMBiosEntryPoint32Error::SMNotFound
+            ))
+        ));
+
+        std::fs::remove_file(&path)
+    }
+
+    #[test]
+    fn test_locate_entry_point() -> io::Result<()> {
+        // A 64-bit entry point found by scanning, pointing to a table at 00100000h
+        let entry_point_64 = [
+            0x5f, 0x53, 0x4d, 0x33, 0x5f, 0x34, 0x18, 0x03, 0x03, 0x00, 0x01, 0x00, 0x0c, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        // A 32-bit entry point at the address reported by the platform,
+        // pointing to a table at 000F1000h
+        let mut entry_point_32 = vec![
+            0x5F, 0x53, 0x4D, 0x5F, 0x00, 0x1F, 0x02, 0x08, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x5F, 0x44, 0x4D, 0x49, 0x5F, 0x00, 0x0C, 0x00, 0x00, 0x10, 0x0F, 0x00,
+            0x02, 0x00, 0x28,
+        ];
+        SMBiosEntryPoint32::fix_checksums(&mut entry_point_32).unwrap();
+        let table = [
+            0x01, 0x04, 0x00, 0x00, 0x00, 0x00, 0x7F, 0x04, 0x01, 0x00, 0x00, 0x00,
+        ];
+
+        let (path, mut file) = physical_memory_file(
+            "locate_entry_point",
+            &[
+                (0x000F0010, &entry_point_64),
+                (0x0007F000, &entry_point_32),
+                (0x000F1000, &table),
+            ],
+        )?;
+
+        // NetBSD (machdep.smbios) and FreeBSD (kenv) report the address
+        let (address, length, version) =
+            locate_structure_table(&locate_entry_point(&mut file, Some(0x0007F000))?)?;
+        assert_eq!(
+            (address, length, version),
+            (0x000F1000, 0x0C, SMBiosVersion::new(2, 8, 0))
+        );
+        let table = UndefinedStructTable::try_load_from_file_offset(&mut file, address, length)?;
+        assert_eq!(table.iter().count(), 2);
+
+        // OpenBSD does not, the legacy BIOS range is scanned
+        let (address, length, version) =
+            locate_structure_table(&locate_entry_point(&mut file, None)?)?;
+        assert_eq!(
+            (address, length, version),
+            (0x00100000, 0x0C, SMBiosVersion::new(3, 3, 0))
+        );
+
+        std::fs::remove_file(&path)
+    }
 }
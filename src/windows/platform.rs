@@ -1,9 +1,9 @@
 use std::{
     convert::TryInto,
-    io::{Error, ErrorKind},
+    io::{self, ErrorKind},
 };
 
-use crate::SMBiosData;
+use crate::{Error, SMBiosData};
 
 use super::WinSMBiosData;
 
@@ -22,10 +22,7 @@ mod ffi {
 
 /// Calls the Windows kernel32 function [GetSystemFirmwareTable](https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getsystemfirmwaretable)
 pub fn load_windows_smbios_data() -> Result<WinSMBiosData, Error> {
-    match raw_smbios_from_device() {
-        Ok(raw) => WinSMBiosData::new(raw),
-        Err(e) => Err(e),
-    }
+    Ok(WinSMBiosData::try_new(raw_smbios_from_device()?)?)
 }
 
 /// Loads SMBIOS table data ([SMBiosData]) from the device
@@ -46,9 +43,13 @@ pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
         let buffer_size =
             ffi::GetSystemFirmwareTable(RAW_SMBIOS_SIGNATURE, 0, firmware_table_buffer_ptr, 0);
 
-        // 0 is win32 exception, > i32::MAX is memory exception
-        if buffer_size == 0 || buffer_size > max_i32 {
-            return Err(Error::new(ErrorKind::Other, MEMORY_ERROR_MESSAGE));
+        // 0 is win32 exception
+        if buffer_size == 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        // > i32::MAX is memory exception
+        if buffer_size > max_i32 {
+            return Err(io::Error::new(ErrorKind::Other, MEMORY_ERROR_MESSAGE).into());
         }
 
         let mut firmware_table_buffer = Vec::with_capacity(buffer_size as usize);
@@ -62,11 +63,11 @@ pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
         );
         // 0 is win32 exception
         if buffer_size == 0 {
-            Err(Error::last_os_error())
+            Err(io::Error::last_os_error().into())
         }
         // > i32::MAX is memory exception
         else if buffer_size > max_i32 {
-            Err(Error::new(ErrorKind::Other, MEMORY_ERROR_MESSAGE))
+            Err(io::Error::new(ErrorKind::Other, MEMORY_ERROR_MESSAGE).into())
         } else {
             firmware_table_buffer.set_len(buffer_size as usize);
             Ok(firmware_table_buffer)
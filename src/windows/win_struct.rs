@@ -5,15 +5,20 @@ use std::{
     io::{Error, ErrorKind},
 };
 
-use crate::core::{SMBiosData, SMBiosVersion};
+use crate::core::{SMBiosData, SMBiosTableRef, SMBiosVersion};
 
 /// # Raw SMBIOS Data
 ///
 /// When Windows kernel32 [GetSystemFirmwareTable](https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getsystemfirmwaretable) function is called for RSMB,
 /// the raw SMBIOS table provider ('RSMB') it retrieves the contents of this
 /// raw SMBIOS firmware table structure.
+///
+/// The raw data is held once, the header and the table data being views of
+/// it, see [WinSMBiosData::windows_header] and [WinSMBiosData::table_data].
+/// [WinSMBiosData::smbios_data] holds a copy of each structure of the table
+/// data; [WinSMBiosData::table_ref] reads them from the raw data instead.
 pub struct WinSMBiosData {
-    windows_header: Vec<u8>,
+    raw_smbios_data: Vec<u8>,
     /// SMBios table data
     pub smbios_data: SMBiosData,
 }
@@ -43,36 +48,62 @@ impl WinSMBiosData {
     ///
     /// The new() is provided publicly to allow loading data from other sources
     /// such as a file or from memory array as is done with testing.
+    ///
+    /// See [WinSMBiosData::try_new] for the field which failed validation.
     pub fn new(raw_smbios_data: Vec<u8>) -> Result<WinSMBiosData, Error> {
-        if !WinSMBiosData::is_valid_win_smbios_data(&raw_smbios_data) {
-            Err(Error::new(
-                ErrorKind::InvalidData,
-                "Invalid WinSMBiosData structure",
-            ))
-        } else {
-            let windows_header =
-                Vec::from(&raw_smbios_data[..WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET]);
-            let version = WinSMBiosData::version_from_raw_header(&windows_header);
-            Ok(WinSMBiosData {
-                windows_header,
-                smbios_data: {
-                    SMBiosData::from_vec_and_version(
-                        Vec::from(&raw_smbios_data[WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET..]),
-                        Some(version),
-                    )
-                },
-            })
-        }
+        WinSMBiosData::try_new(raw_smbios_data)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// Creates an instance of [WinSMBiosData], reporting the header field
+    /// which failed validation
+    pub fn try_new(raw_smbios_data: Vec<u8>) -> Result<WinSMBiosData, WinSMBiosDataError> {
+        WinSMBiosData::validate_header(&raw_smbios_data)?;
+
+        let version = WinSMBiosData::version_from_raw_header(&raw_smbios_data);
+        let smbios_data = SMBiosData::from_slice_and_version(
+            &raw_smbios_data[WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET..],
+            Some(version),
+        );
+        Ok(WinSMBiosData {
+            raw_smbios_data,
+            smbios_data,
+        })
     }
 
     /// Verify if a block of data is a valid WinSMBiosData structure
     ///
     /// This only checks if the structure itself is valid and not whether the contained
-    /// [SMBiosData] structure is valid or not.
+    /// [SMBiosData] structure is valid or not. See [WinSMBiosData::validate_header]
+    /// for the checks.
     pub fn is_valid_win_smbios_data(raw_data: &Vec<u8>) -> bool {
+        WinSMBiosData::validate_header(raw_data).is_ok()
+    }
+
+    /// Validates the header of a block of data, returning the first field
+    /// which is invalid
+    ///
+    /// The block must hold table data of the length given by the header, the
+    /// Used20CallingMethod field must be a boolean (0 or 1) and the version
+    /// must be one the SMBIOS specification defines: a major version of 2
+    /// with a minor version of 0 to 8, or a major version of 3 or later.
+    pub fn validate_header(raw_data: &[u8]) -> Result<(), WinSMBiosDataError> {
         let length = raw_data.len();
         if length <= WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET {
-            return false;
+            return Err(WinSMBiosDataError::TooShort(length));
+        }
+
+        let used20_calling_method = raw_data[WinSMBiosData::USED20_CALLING_METHOD_OFFSET];
+        if used20_calling_method > 1 {
+            return Err(WinSMBiosDataError::InvalidCallingMethod(
+                used20_calling_method,
+            ));
+        }
+
+        let major = raw_data[WinSMBiosData::SMBIOS_MAJOR_VERSION_OFFSET];
+        let minor = raw_data[WinSMBiosData::SMBIOS_MINOR_VERSION_OFFSET];
+        if major < 2 || (major == 2 && minor > 8) {
+            return Err(WinSMBiosDataError::InvalidVersion { major, minor });
         }
 
         // retrieve the table data length field
@@ -88,35 +119,60 @@ impl WinSMBiosData {
                 .expect("array length does not match type width"),
         ) as usize;
 
-        table_data_length == length - WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET
+        let actual = length - WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET;
+        if table_data_length != actual {
+            return Err(WinSMBiosDataError::TableDataLengthMismatch {
+                declared: table_data_length,
+                actual,
+            });
+        }
+
+        Ok(())
     }
 
-    /// The raw SMBIOS data this structure is wrapping
+    /// The raw SMBIOS data this structure is wrapping, the header followed
+    /// by the table data
     pub fn raw_smbios_data(&self) -> &[u8] {
-        self.windows_header.as_slice()
+        self.raw_smbios_data.as_slice()
+    }
+
+    /// The header of the raw SMBIOS data, preceding the table data
+    pub fn windows_header(&self) -> &[u8] {
+        &self.raw_smbios_data[..WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET]
+    }
+
+    /// The raw SMBIOS table data (the SMBIOSTableData field)
+    pub fn table_data(&self) -> &[u8] {
+        &self.raw_smbios_data[WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET..]
+    }
+
+    /// The table data borrowed as an [SMBiosTableRef], whose structures are
+    /// parsed as it is iterated without copying them
+    pub fn table_ref(&self) -> SMBiosTableRef<'_> {
+        SMBiosTableRef::new(self.table_data())
     }
 
     /// Used20CallingMethod
     pub fn used20_calling_method(&self) -> u8 {
-        self.windows_header[WinSMBiosData::USED20_CALLING_METHOD_OFFSET]
+        self.raw_smbios_data[WinSMBiosData::USED20_CALLING_METHOD_OFFSET]
     }
 
     /// SMBIOS major version
     pub fn smbios_major_version(&self) -> u8 {
-        self.windows_header[WinSMBiosData::SMBIOS_MAJOR_VERSION_OFFSET]
+        self.raw_smbios_data[WinSMBiosData::SMBIOS_MAJOR_VERSION_OFFSET]
     }
 
     /// SMBIOS minor version
     pub fn smbios_minor_version(&self) -> u8 {
-        self.windows_header[WinSMBiosData::SMBIOS_MINOR_VERSION_OFFSET]
+        self.raw_smbios_data[WinSMBiosData::SMBIOS_MINOR_VERSION_OFFSET]
     }
 
     /// DMI revision
     pub fn dmi_revision(&self) -> u8 {
-        self.windows_header[WinSMBiosData::DMI_REVISION_OFFSET]
+        self.raw_smbios_data[WinSMBiosData::DMI_REVISION_OFFSET]
     }
 
-    fn version_from_raw_header(windows_header: &Vec<u8>) -> SMBiosVersion {
+    fn version_from_raw_header(windows_header: &[u8]) -> SMBiosVersion {
         SMBiosVersion {
             major: windows_header[WinSMBiosData::SMBIOS_MAJOR_VERSION_OFFSET],
             minor: windows_header[WinSMBiosData::SMBIOS_MINOR_VERSION_OFFSET],
@@ -127,7 +183,7 @@ impl WinSMBiosData {
     /// Length of the smbios table data
     pub fn table_data_length(&self) -> u32 {
         let slice = self
-            .windows_header
+            .raw_smbios_data
             .get(
                 WinSMBiosData::TABLE_DATA_LENGTH_OFFSET
                     ..WinSMBiosData::TABLE_DATA_LENGTH_OFFSET + 4,
@@ -141,6 +197,67 @@ impl WinSMBiosData {
     }
 }
 
+/// WinSMBiosData header validation errors
+///
+/// The field of the header which failed [WinSMBiosData::validate_header].
+pub enum WinSMBiosDataError {
+    /// The data, of the given length, is too short to hold the header and any table data
+    TooShort(usize),
+    /// The Used20CallingMethod field is not a boolean (0 or 1)
+    InvalidCallingMethod(u8),
+    /// The SMBIOSMajorVersion and SMBIOSMinorVersion fields are not a version of the specification
+    InvalidVersion {
+        /// SMBIOS major version
+        major: u8,
+        /// SMBIOS minor version
+        minor: u8,
+    },
+    /// The Length field does not match the length of the table data
+    TableDataLengthMismatch {
+        /// Length declared by the Length field
+        declared: usize,
+        /// Length of the table data following the header
+        actual: usize,
+    },
+}
+
+impl fmt::Debug for WinSMBiosDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WinSMBiosDataError")
+            .field(&format!("{}", &self))
+            .finish()
+    }
+}
+
+impl fmt::Display for WinSMBiosDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WinSMBiosDataError::TooShort(length) => write!(
+                f,
+                "Invalid WinSMBiosData structure: {} bytes is too short",
+                length
+            ),
+            WinSMBiosDataError::InvalidCallingMethod(value) => write!(
+                f,
+                "Invalid WinSMBiosData structure: Used20CallingMethod is {}, expected 0 or 1",
+                value
+            ),
+            WinSMBiosDataError::InvalidVersion { major, minor } => write!(
+                f,
+                "Invalid WinSMBiosData structure: SMBIOS version {}.{} does not exist",
+                major, minor
+            ),
+            WinSMBiosDataError::TableDataLengthMismatch { declared, actual } => write!(
+                f,
+                "Invalid WinSMBiosData structure: Length is {} but {} bytes of table data follow",
+                declared, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WinSMBiosDataError {}
+
 impl fmt::Debug for WinSMBiosData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(std::any::type_name::<WinSMBiosData>())
@@ -187,6 +304,51 @@ mod tests {
         // Bad structure (bad table data length)
         let struct_data = vec![0x00u8, 0x03, 0x03, 0x00, 0xFF, 0x00, 0x00, 0x00, 0xAB];
         assert!(!WinSMBiosData::is_valid_win_smbios_data(&struct_data));
+
+        // Bad structure (Used20CallingMethod is not a boolean)
+        let struct_data = vec![0x02u8, 0x03, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0xAB];
+        assert!(!WinSMBiosData::is_valid_win_smbios_data(&struct_data));
+
+        // Bad structure (version 2.9 does not exist)
+        let struct_data = vec![0x01u8, 0x02, 0x09, 0x00, 0x01, 0x00, 0x00, 0x00, 0xAB];
+        assert!(!WinSMBiosData::is_valid_win_smbios_data(&struct_data));
+    }
+
+    #[test]
+    fn test_win_smbios_data_try_new() {
+        let error = |raw: Vec<u8>| WinSMBiosData::try_new(raw).unwrap_err();
+
+        assert!(matches!(
+            error(vec![0x00u8, 0x03, 0x03]),
+            WinSMBiosDataError::TooShort(3)
+        ));
+        assert!(matches!(
+            error(vec![0xFFu8, 0x03, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0xAB]),
+            WinSMBiosDataError::InvalidCallingMethod(0xFF)
+        ));
+        assert!(matches!(
+            error(vec![0x00u8, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xAB]),
+            WinSMBiosDataError::InvalidVersion { major: 1, minor: 0 }
+        ));
+        let mismatch = error(vec![0x00u8, 0x02, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0xAB]);
+        assert!(matches!(
+            mismatch,
+            WinSMBiosDataError::TableDataLengthMismatch {
+                declared: 2,
+                actual: 1
+            }
+        ));
+        assert_eq!(
+            mismatch.to_string(),
+            "Invalid WinSMBiosData structure: Length is 2 but 1 bytes of table data follow"
+        );
+
+        let error =
+            WinSMBiosData::new(vec![0x00u8, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        let raw = std::fs::read("tests/jeffgerlap_3_2_0.dat").unwrap();
+        assert!(WinSMBiosData::try_new(raw).is_ok());
     }
 
     #[test]
@@ -208,9 +370,12 @@ mod tests {
 
         let win_smbios_data = WinSMBiosData::new(raw_win_data.clone()).unwrap();
 
+        assert_eq!(win_smbios_data.windows_header(), &raw_win_data[..8]);
+        assert_eq!(win_smbios_data.table_data(), &raw_win_data[8..]);
+        assert_eq!(win_smbios_data.raw_smbios_data(), raw_win_data.as_slice());
         assert_eq!(
-            win_smbios_data.windows_header.as_slice(),
-            &raw_win_data[..8]
+            win_smbios_data.table_ref().raw_bytes().as_ptr(),
+            win_smbios_data.table_data().as_ptr()
         );
     }
 }
@@ -11,6 +11,29 @@ fn windows_dump() {
     }
 }
 
+#[test]
+fn load_from_device() {
+    match load() {
+        Ok(data) => {
+            // The version is reported by every backend and the firmware
+            // always provides the BIOS Information (Type 0) structure
+            assert!(data.version.is_some());
+            assert!(data.first::<SMBiosInformation>().is_some());
+        }
+        Err(err) => {
+            // Without privileges or an SMBIOS interface (such as in a container)
+            // the failure is classified, retaining the error of the OS
+            match err {
+                Error::PermissionDenied(_) | Error::TableNotFound(_) => {
+                    assert!(err.io_error().is_some())
+                }
+                Error::EntryPointNotFound => {}
+                _ => panic!("failure: {:?}", err),
+            }
+        }
+    }
+}
+
 #[test]
 fn retrieve_system_uuid() {
     match table_load_from_device() {